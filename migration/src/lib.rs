@@ -3,6 +3,18 @@ pub use sea_orm_migration::prelude::*;
 mod m20250123_000001_create_tables;
 mod m20250126_000001_create_system_settings;
 mod m20260201_000001_add_image_compression_settings;
+mod m20261019_000001_add_class_join_requests;
+mod m20261019_000002_add_class_groups;
+mod m20261019_000003_add_announcements;
+mod m20261019_000004_add_profile_fields;
+mod m20261019_000005_add_homework_publishing;
+mod m20261019_000006_add_homework_deadline_reminders;
+mod m20261019_000007_add_homework_extensions;
+mod m20261019_000008_add_late_penalties;
+mod m20261019_000009_add_rubrics;
+mod m20261019_000010_add_homework_questions;
+mod m20261019_000011_add_question_bank;
+mod m20261019_000012_add_auto_grader;
 
 pub struct Migrator;
 
@@ -13,6 +25,18 @@ impl MigratorTrait for Migrator {
             Box::new(m20250123_000001_create_tables::Migration),
             Box::new(m20250126_000001_create_system_settings::Migration),
             Box::new(m20260201_000001_add_image_compression_settings::Migration),
+            Box::new(m20261019_000001_add_class_join_requests::Migration),
+            Box::new(m20261019_000002_add_class_groups::Migration),
+            Box::new(m20261019_000003_add_announcements::Migration),
+            Box::new(m20261019_000004_add_profile_fields::Migration),
+            Box::new(m20261019_000005_add_homework_publishing::Migration),
+            Box::new(m20261019_000006_add_homework_deadline_reminders::Migration),
+            Box::new(m20261019_000007_add_homework_extensions::Migration),
+            Box::new(m20261019_000008_add_late_penalties::Migration),
+            Box::new(m20261019_000009_add_rubrics::Migration),
+            Box::new(m20261019_000010_add_homework_questions::Migration),
+            Box::new(m20261019_000011_add_question_bank::Migration),
+            Box::new(m20261019_000012_add_auto_grader::Migration),
         ]
     }
 }
@@ -0,0 +1,163 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // ==================== 班级加入策略 ====================
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Classes::Table)
+                    .add_column(
+                        ColumnDef::new(Classes::JoinPolicy)
+                            .string()
+                            .not_null()
+                            .default("open"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // ==================== 加入班级申请表 ====================
+        manager
+            .create_table(
+                Table::create()
+                    .table(ClassJoinRequests::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ClassJoinRequests::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ClassJoinRequests::ClassId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ClassJoinRequests::UserId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ClassJoinRequests::Status)
+                            .string()
+                            .not_null()
+                            .default("pending"),
+                    )
+                    .col(ColumnDef::new(ClassJoinRequests::Message).text().null())
+                    .col(
+                        ColumnDef::new(ClassJoinRequests::HandledBy)
+                            .big_integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(ClassJoinRequests::HandledAt)
+                            .big_integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(ClassJoinRequests::CreatedAt)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(ClassJoinRequests::Table, ClassJoinRequests::ClassId)
+                            .to(Classes::Table, Classes::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(ClassJoinRequests::Table, ClassJoinRequests::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(ClassJoinRequests::Table, ClassJoinRequests::HandledBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 加入申请表索引
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_class_join_requests_class_status")
+                    .table(ClassJoinRequests::Table)
+                    .col(ClassJoinRequests::ClassId)
+                    .col(ClassJoinRequests::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_class_join_requests_user_id")
+                    .table(ClassJoinRequests::Table)
+                    .col(ClassJoinRequests::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ClassJoinRequests::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Classes::Table)
+                    .drop_column(Classes::JoinPolicy)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    #[sea_orm(iden = "users")]
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Classes {
+    #[sea_orm(iden = "classes")]
+    Table,
+    Id,
+    JoinPolicy,
+}
+
+#[derive(DeriveIden)]
+enum ClassJoinRequests {
+    #[sea_orm(iden = "class_join_requests")]
+    Table,
+    Id,
+    ClassId,
+    UserId,
+    Status,
+    Message,
+    HandledBy,
+    HandledAt,
+    CreatedAt,
+}
@@ -0,0 +1,210 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // ==================== 班级分组表 ====================
+        manager
+            .create_table(
+                Table::create()
+                    .table(ClassGroups::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ClassGroups::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ClassGroups::ClassId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ClassGroups::Name).string().not_null())
+                    .col(ColumnDef::new(ClassGroups::Description).text().null())
+                    .col(ColumnDef::new(ClassGroups::TaId).big_integer().null())
+                    .col(
+                        ColumnDef::new(ClassGroups::CreatedAt)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ClassGroups::UpdatedAt)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(ClassGroups::Table, ClassGroups::ClassId)
+                            .to(Classes::Table, Classes::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(ClassGroups::Table, ClassGroups::TaId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 同一班级内分组名称唯一
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_class_groups_class_name")
+                    .table(ClassGroups::Table)
+                    .col(ClassGroups::ClassId)
+                    .col(ClassGroups::Name)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // ==================== 班级成员所属分组 ====================
+        // SQLite 不支持通过 ALTER 添加外键，删除分组时由业务层清空成员的 group_id
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ClassUsers::Table)
+                    .add_column(ColumnDef::new(ClassUsers::GroupId).big_integer().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_class_users_group_id")
+                    .table(ClassUsers::Table)
+                    .col(ClassUsers::GroupId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // ==================== 作业分配分组关联表 ====================
+        manager
+            .create_table(
+                Table::create()
+                    .table(HomeworkGroups::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(HomeworkGroups::HomeworkId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(HomeworkGroups::GroupId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(HomeworkGroups::HomeworkId)
+                            .col(HomeworkGroups::GroupId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(HomeworkGroups::Table, HomeworkGroups::HomeworkId)
+                            .to(Homeworks::Table, Homeworks::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(HomeworkGroups::Table, HomeworkGroups::GroupId)
+                            .to(ClassGroups::Table, ClassGroups::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(HomeworkGroups::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_class_users_group_id")
+                    .table(ClassUsers::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ClassUsers::Table)
+                    .drop_column(ClassUsers::GroupId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(ClassGroups::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    #[sea_orm(iden = "users")]
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Classes {
+    #[sea_orm(iden = "classes")]
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum ClassUsers {
+    #[sea_orm(iden = "class_users")]
+    Table,
+    GroupId,
+}
+
+#[derive(DeriveIden)]
+enum Homeworks {
+    #[sea_orm(iden = "homeworks")]
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum ClassGroups {
+    #[sea_orm(iden = "class_groups")]
+    Table,
+    Id,
+    ClassId,
+    Name,
+    Description,
+    TaId,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum HomeworkGroups {
+    #[sea_orm(iden = "homework_groups")]
+    Table,
+    HomeworkId,
+    GroupId,
+}
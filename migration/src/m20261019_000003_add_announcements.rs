@@ -0,0 +1,308 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // ==================== 班级公告表 ====================
+        manager
+            .create_table(
+                Table::create()
+                    .table(Announcements::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Announcements::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(Announcements::ClassId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(Announcements::Title).string().not_null())
+                    .col(ColumnDef::new(Announcements::Content).text().not_null())
+                    .col(
+                        ColumnDef::new(Announcements::Pinned)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(
+                        ColumnDef::new(Announcements::CreatedBy)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(Announcements::CreatedAt)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(Announcements::UpdatedAt)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Announcements::Table, Announcements::ClassId)
+                            .to(Classes::Table, Classes::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Announcements::Table, Announcements::CreatedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_announcements_class_id")
+                    .table(Announcements::Table)
+                    .col(Announcements::ClassId)
+                    .col(Announcements::Pinned)
+                    .col(Announcements::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        // ==================== 公告附件关联表 ====================
+        manager
+            .create_table(
+                Table::create()
+                    .table(AnnouncementFiles::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AnnouncementFiles::AnnouncementId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AnnouncementFiles::FileId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(AnnouncementFiles::AnnouncementId)
+                            .col(AnnouncementFiles::FileId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(AnnouncementFiles::Table, AnnouncementFiles::AnnouncementId)
+                            .to(Announcements::Table, Announcements::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(AnnouncementFiles::Table, AnnouncementFiles::FileId)
+                            .to(Files::Table, Files::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // ==================== 公告已读回执表 ====================
+        manager
+            .create_table(
+                Table::create()
+                    .table(AnnouncementReads::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AnnouncementReads::AnnouncementId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AnnouncementReads::UserId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AnnouncementReads::ReadAt)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(AnnouncementReads::AnnouncementId)
+                            .col(AnnouncementReads::UserId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(AnnouncementReads::Table, AnnouncementReads::AnnouncementId)
+                            .to(Announcements::Table, Announcements::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(AnnouncementReads::Table, AnnouncementReads::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // ==================== 讨论评论表 ====================
+        // target_type + target_id 指向公告或作业，删除目标时由业务层清理评论
+        manager
+            .create_table(
+                Table::create()
+                    .table(Comments::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Comments::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Comments::TargetType).string().not_null())
+                    .col(ColumnDef::new(Comments::TargetId).big_integer().not_null())
+                    .col(ColumnDef::new(Comments::ClassId).big_integer().not_null())
+                    .col(ColumnDef::new(Comments::ParentId).big_integer().null())
+                    .col(ColumnDef::new(Comments::UserId).big_integer().not_null())
+                    .col(ColumnDef::new(Comments::Content).text().not_null())
+                    .col(ColumnDef::new(Comments::CreatedAt).big_integer().not_null())
+                    .col(ColumnDef::new(Comments::UpdatedAt).big_integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Comments::Table, Comments::ClassId)
+                            .to(Classes::Table, Classes::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Comments::Table, Comments::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_comments_target")
+                    .table(Comments::Table)
+                    .col(Comments::TargetType)
+                    .col(Comments::TargetId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_comments_parent_id")
+                    .table(Comments::Table)
+                    .col(Comments::ParentId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Comments::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(AnnouncementReads::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(AnnouncementFiles::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Announcements::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    #[sea_orm(iden = "users")]
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Classes {
+    #[sea_orm(iden = "classes")]
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Files {
+    #[sea_orm(iden = "files")]
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Announcements {
+    #[sea_orm(iden = "announcements")]
+    Table,
+    Id,
+    ClassId,
+    Title,
+    Content,
+    Pinned,
+    CreatedBy,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum AnnouncementFiles {
+    #[sea_orm(iden = "announcement_files")]
+    Table,
+    AnnouncementId,
+    FileId,
+}
+
+#[derive(DeriveIden)]
+enum AnnouncementReads {
+    #[sea_orm(iden = "announcement_reads")]
+    Table,
+    AnnouncementId,
+    UserId,
+    ReadAt,
+}
+
+#[derive(DeriveIden)]
+enum Comments {
+    #[sea_orm(iden = "comments")]
+    Table,
+    Id,
+    TargetType,
+    TargetId,
+    ClassId,
+    ParentId,
+    UserId,
+    Content,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,175 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // ==================== 自定义资料字段定义表 ====================
+        manager
+            .create_table(
+                Table::create()
+                    .table(ProfileFields::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ProfileFields::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ProfileFields::FieldKey)
+                            .string()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(ColumnDef::new(ProfileFields::Label).string().not_null())
+                    .col(
+                        ColumnDef::new(ProfileFields::FieldType)
+                            .string()
+                            .not_null()
+                            .default("text"),
+                    )
+                    .col(
+                        ColumnDef::new(ProfileFields::Required)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(
+                        ColumnDef::new(ProfileFields::IsUnique)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(ColumnDef::new(ProfileFields::Pattern).string().null())
+                    .col(ColumnDef::new(ProfileFields::MaxLength).integer().null())
+                    .col(ColumnDef::new(ProfileFields::Options).text().null())
+                    .col(
+                        ColumnDef::new(ProfileFields::SortOrder)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(ProfileFields::CreatedAt)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ProfileFields::UpdatedAt)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // ==================== 用户资料字段值表 ====================
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserProfileValues::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(UserProfileValues::UserId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(UserProfileValues::FieldId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(UserProfileValues::Value).string().not_null())
+                    .col(
+                        ColumnDef::new(UserProfileValues::UpdatedAt)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(UserProfileValues::UserId)
+                            .col(UserProfileValues::FieldId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(UserProfileValues::Table, UserProfileValues::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(UserProfileValues::Table, UserProfileValues::FieldId)
+                            .to(ProfileFields::Table, ProfileFields::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 按字段值查找用户（唯一性校验、学号匹配、搜索）
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_user_profile_values_field_value")
+                    .table(UserProfileValues::Table)
+                    .col(UserProfileValues::FieldId)
+                    .col(UserProfileValues::Value)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(UserProfileValues::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(ProfileFields::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    #[sea_orm(iden = "users")]
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum ProfileFields {
+    #[sea_orm(iden = "profile_fields")]
+    Table,
+    Id,
+    FieldKey,
+    Label,
+    FieldType,
+    Required,
+    IsUnique,
+    Pattern,
+    MaxLength,
+    Options,
+    SortOrder,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum UserProfileValues {
+    #[sea_orm(iden = "user_profile_values")]
+    Table,
+    UserId,
+    FieldId,
+    Value,
+    UpdatedAt,
+}
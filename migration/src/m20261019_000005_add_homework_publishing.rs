@@ -0,0 +1,88 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // ==================== 作业发布状态 ====================
+        // 已有作业视为已发布
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Homeworks::Table)
+                    .add_column(
+                        ColumnDef::new(Homeworks::Status)
+                            .string()
+                            .not_null()
+                            .default("published"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Homeworks::Table)
+                    .add_column(ColumnDef::new(Homeworks::PublishAt).big_integer().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // 定时发布扫描索引
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_homeworks_status_publish_at")
+                    .table(Homeworks::Table)
+                    .col(Homeworks::Status)
+                    .col(Homeworks::PublishAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_homeworks_status_publish_at")
+                    .table(Homeworks::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Homeworks::Table)
+                    .drop_column(Homeworks::PublishAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Homeworks::Table)
+                    .drop_column(Homeworks::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Homeworks {
+    #[sea_orm(iden = "homeworks")]
+    Table,
+    Status,
+    PublishAt,
+}
@@ -0,0 +1,128 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // ==================== 作业截止提醒记录表 ====================
+        // 主键包含截止时间：截止时间修改后按新的截止时间重新提醒
+        manager
+            .create_table(
+                Table::create()
+                    .table(HomeworkDeadlineReminders::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(HomeworkDeadlineReminders::HomeworkId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(HomeworkDeadlineReminders::OffsetMinutes)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(HomeworkDeadlineReminders::Deadline)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(HomeworkDeadlineReminders::SentAt)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(HomeworkDeadlineReminders::HomeworkId)
+                            .col(HomeworkDeadlineReminders::OffsetMinutes)
+                            .col(HomeworkDeadlineReminders::Deadline),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(
+                                HomeworkDeadlineReminders::Table,
+                                HomeworkDeadlineReminders::HomeworkId,
+                            )
+                            .to(Homeworks::Table, Homeworks::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // ==================== 截止提醒配置项 ====================
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+
+        let insert = Query::insert()
+            .into_table(SystemSettings::Table)
+            .columns([
+                SystemSettings::Key,
+                SystemSettings::Value,
+                SystemSettings::ValueType,
+                SystemSettings::Description,
+                SystemSettings::UpdatedAt,
+            ])
+            .values_panic([
+                "notification.deadline_reminder_offsets".into(),
+                r#"["1440","60"]"#.into(),
+                "json_array".into(),
+                "作业截止提醒提前量（分钟），为空表示不提醒".into(),
+                now.into(),
+            ])
+            .to_owned();
+
+        manager.exec_stmt(insert).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let delete = Query::delete()
+            .from_table(SystemSettings::Table)
+            .and_where(Expr::col(SystemSettings::Key).eq("notification.deadline_reminder_offsets"))
+            .to_owned();
+
+        manager.exec_stmt(delete).await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(HomeworkDeadlineReminders::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum HomeworkDeadlineReminders {
+    Table,
+    HomeworkId,
+    OffsetMinutes,
+    Deadline,
+    SentAt,
+}
+
+#[derive(DeriveIden)]
+enum Homeworks {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum SystemSettings {
+    #[sea_orm(iden = "system_settings")]
+    Table,
+    Key,
+    Value,
+    ValueType,
+    Description,
+    UpdatedAt,
+}
@@ -0,0 +1,156 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // ==================== 作业延期表 ====================
+        // user_id 与 group_id 必居其一，分别表示个人延期和分组延期
+        manager
+            .create_table(
+                Table::create()
+                    .table(HomeworkExtensions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(HomeworkExtensions::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(HomeworkExtensions::HomeworkId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(HomeworkExtensions::UserId)
+                            .big_integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(HomeworkExtensions::GroupId)
+                            .big_integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(HomeworkExtensions::Deadline)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(HomeworkExtensions::Reason).text().null())
+                    .col(
+                        ColumnDef::new(HomeworkExtensions::CreatedBy)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(HomeworkExtensions::CreatedAt)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(HomeworkExtensions::UpdatedAt)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(HomeworkExtensions::Table, HomeworkExtensions::HomeworkId)
+                            .to(Homeworks::Table, Homeworks::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(HomeworkExtensions::Table, HomeworkExtensions::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(HomeworkExtensions::Table, HomeworkExtensions::GroupId)
+                            .to(ClassGroups::Table, ClassGroups::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(HomeworkExtensions::Table, HomeworkExtensions::CreatedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 同一作业每个学生、每个分组最多一条延期（NULL 不参与唯一约束）
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_homework_extensions_user")
+                    .table(HomeworkExtensions::Table)
+                    .col(HomeworkExtensions::HomeworkId)
+                    .col(HomeworkExtensions::UserId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_homework_extensions_group")
+                    .table(HomeworkExtensions::Table)
+                    .col(HomeworkExtensions::HomeworkId)
+                    .col(HomeworkExtensions::GroupId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(HomeworkExtensions::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum HomeworkExtensions {
+    Table,
+    Id,
+    HomeworkId,
+    UserId,
+    GroupId,
+    Deadline,
+    Reason,
+    CreatedBy,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Homeworks {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum ClassGroups {
+    Table,
+    Id,
+}
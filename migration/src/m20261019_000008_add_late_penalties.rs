@@ -0,0 +1,110 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // ==================== 作业迟交策略 ====================
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Homeworks::Table)
+                    .add_column(ColumnDef::new(Homeworks::LateCutoff).big_integer().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Homeworks::Table)
+                    .add_column(ColumnDef::new(Homeworks::LatePenalty).text().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // ==================== 评分迟交扣分与教师调整 ====================
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Grades::Table)
+                    .add_column(
+                        ColumnDef::new(Grades::Penalty)
+                            .double()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Grades::Table)
+                    .add_column(ColumnDef::new(Grades::ScoreOverride).double().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Grades::Table)
+                    .drop_column(Grades::ScoreOverride)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Grades::Table)
+                    .drop_column(Grades::Penalty)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Homeworks::Table)
+                    .drop_column(Homeworks::LatePenalty)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Homeworks::Table)
+                    .drop_column(Homeworks::LateCutoff)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Homeworks {
+    #[sea_orm(iden = "homeworks")]
+    Table,
+    LateCutoff,
+    LatePenalty,
+}
+
+#[derive(DeriveIden)]
+enum Grades {
+    #[sea_orm(iden = "grades")]
+    Table,
+    Penalty,
+    ScoreOverride,
+}
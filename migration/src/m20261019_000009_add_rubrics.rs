@@ -0,0 +1,193 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // ==================== 评分细则表（教师的评分细则库） ====================
+        manager
+            .create_table(
+                Table::create()
+                    .table(Rubrics::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Rubrics::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Rubrics::Title).string().not_null())
+                    .col(ColumnDef::new(Rubrics::Description).text().null())
+                    .col(ColumnDef::new(Rubrics::CreatedBy).big_integer().not_null())
+                    .col(ColumnDef::new(Rubrics::CreatedAt).big_integer().not_null())
+                    .col(ColumnDef::new(Rubrics::UpdatedAt).big_integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Rubrics::Table, Rubrics::CreatedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_rubrics_created_by")
+                    .table(Rubrics::Table)
+                    .col(Rubrics::CreatedBy)
+                    .to_owned(),
+            )
+            .await?;
+
+        // ==================== 评分细则评分项表 ====================
+        // levels 为 JSON 数组：[{ "title", "description", "points" }]
+        manager
+            .create_table(
+                Table::create()
+                    .table(RubricCriteria::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(RubricCriteria::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(RubricCriteria::RubricId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RubricCriteria::Position)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(ColumnDef::new(RubricCriteria::Title).string().not_null())
+                    .col(ColumnDef::new(RubricCriteria::Description).text().null())
+                    .col(ColumnDef::new(RubricCriteria::Levels).text().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(RubricCriteria::Table, RubricCriteria::RubricId)
+                            .to(Rubrics::Table, Rubrics::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_rubric_criteria_rubric_id")
+                    .table(RubricCriteria::Table)
+                    .col(RubricCriteria::RubricId)
+                    .col(RubricCriteria::Position)
+                    .to_owned(),
+            )
+            .await?;
+
+        // ==================== 作业关联评分细则 ====================
+        // 删除评分细则时由应用层将关联置空
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Homeworks::Table)
+                    .add_column(ColumnDef::new(Homeworks::RubricId).big_integer().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // ==================== 评分的细则得分快照 ====================
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Grades::Table)
+                    .add_column(ColumnDef::new(Grades::RubricScores).text().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Grades::Table)
+                    .drop_column(Grades::RubricScores)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Homeworks::Table)
+                    .drop_column(Homeworks::RubricId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(RubricCriteria::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Rubrics::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Rubrics {
+    Table,
+    Id,
+    Title,
+    Description,
+    CreatedBy,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum RubricCriteria {
+    Table,
+    Id,
+    RubricId,
+    Position,
+    Title,
+    Description,
+    Levels,
+}
+
+#[derive(DeriveIden)]
+enum Homeworks {
+    #[sea_orm(iden = "homeworks")]
+    Table,
+    RubricId,
+}
+
+#[derive(DeriveIden)]
+enum Grades {
+    #[sea_orm(iden = "grades")]
+    Table,
+    RubricScores,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
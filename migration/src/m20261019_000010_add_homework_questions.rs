@@ -0,0 +1,214 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // ==================== 作业题目表（客观题与简答题） ====================
+        // spec 为 JSON：{ "type": "single_choice", "options": [...], "answer": 0 } 等
+        manager
+            .create_table(
+                Table::create()
+                    .table(HomeworkQuestions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(HomeworkQuestions::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(HomeworkQuestions::HomeworkId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(HomeworkQuestions::Position)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(ColumnDef::new(HomeworkQuestions::Prompt).text().not_null())
+                    .col(
+                        ColumnDef::new(HomeworkQuestions::Points)
+                            .double()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(HomeworkQuestions::Spec).text().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(HomeworkQuestions::Table, HomeworkQuestions::HomeworkId)
+                            .to(Homeworks::Table, Homeworks::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_homework_questions_homework_id")
+                    .table(HomeworkQuestions::Table)
+                    .col(HomeworkQuestions::HomeworkId)
+                    .col(HomeworkQuestions::Position)
+                    .to_owned(),
+            )
+            .await?;
+
+        // ==================== 提交答案表 ====================
+        // is_correct / points 为空表示待教师批阅（简答题）
+        manager
+            .create_table(
+                Table::create()
+                    .table(SubmissionAnswers::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SubmissionAnswers::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SubmissionAnswers::SubmissionId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SubmissionAnswers::QuestionId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(SubmissionAnswers::Answer).text().not_null())
+                    .col(
+                        ColumnDef::new(SubmissionAnswers::IsCorrect)
+                            .boolean()
+                            .null(),
+                    )
+                    .col(ColumnDef::new(SubmissionAnswers::Points).double().null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(SubmissionAnswers::Table, SubmissionAnswers::SubmissionId)
+                            .to(Submissions::Table, Submissions::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(SubmissionAnswers::Table, SubmissionAnswers::QuestionId)
+                            .to(HomeworkQuestions::Table, HomeworkQuestions::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_submission_answers_submission_question")
+                    .table(SubmissionAnswers::Table)
+                    .col(SubmissionAnswers::SubmissionId)
+                    .col(SubmissionAnswers::QuestionId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_submission_answers_question_id")
+                    .table(SubmissionAnswers::Table)
+                    .col(SubmissionAnswers::QuestionId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // ==================== 评分待批阅标记 ====================
+        // 含简答题的自动评分为部分评分，教师批阅后清除
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Grades::Table)
+                    .add_column(
+                        ColumnDef::new(Grades::PendingReview)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Grades::Table)
+                    .drop_column(Grades::PendingReview)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(SubmissionAnswers::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(HomeworkQuestions::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum HomeworkQuestions {
+    Table,
+    Id,
+    HomeworkId,
+    Position,
+    Prompt,
+    Points,
+    Spec,
+}
+
+#[derive(DeriveIden)]
+enum SubmissionAnswers {
+    Table,
+    Id,
+    SubmissionId,
+    QuestionId,
+    Answer,
+    IsCorrect,
+    Points,
+}
+
+#[derive(DeriveIden)]
+enum Homeworks {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Submissions {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Grades {
+    #[sea_orm(iden = "grades")]
+    Table,
+    PendingReview,
+}
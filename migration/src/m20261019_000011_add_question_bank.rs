@@ -0,0 +1,343 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // ==================== 题库表 ====================
+        // spec 与作业题目相同；tags 为 JSON 字符串数组
+        manager
+            .create_table(
+                Table::create()
+                    .table(BankQuestions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(BankQuestions::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(BankQuestions::CreatedBy)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(BankQuestions::IsShared)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(ColumnDef::new(BankQuestions::Prompt).text().not_null())
+                    .col(ColumnDef::new(BankQuestions::Points).double().not_null())
+                    .col(ColumnDef::new(BankQuestions::Spec).text().not_null())
+                    .col(
+                        ColumnDef::new(BankQuestions::Tags)
+                            .text()
+                            .not_null()
+                            .default("[]"),
+                    )
+                    .col(
+                        ColumnDef::new(BankQuestions::Difficulty)
+                            .integer()
+                            .not_null()
+                            .default(3),
+                    )
+                    .col(
+                        ColumnDef::new(BankQuestions::KnowledgePoint)
+                            .string()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(BankQuestions::CreatedAt)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(BankQuestions::UpdatedAt)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(BankQuestions::Table, BankQuestions::CreatedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_bank_questions_created_by")
+                    .table(BankQuestions::Table)
+                    .col(BankQuestions::CreatedBy)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_bank_questions_is_shared")
+                    .table(BankQuestions::Table)
+                    .col(BankQuestions::IsShared)
+                    .to_owned(),
+            )
+            .await?;
+
+        // ==================== 题库题目附件表 ====================
+        manager
+            .create_table(
+                Table::create()
+                    .table(BankQuestionFiles::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(BankQuestionFiles::BankQuestionId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(BankQuestionFiles::FileId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(BankQuestionFiles::BankQuestionId)
+                            .col(BankQuestionFiles::FileId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(BankQuestionFiles::Table, BankQuestionFiles::BankQuestionId)
+                            .to(BankQuestions::Table, BankQuestions::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(BankQuestionFiles::Table, BankQuestionFiles::FileId)
+                            .to(Files::Table, Files::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // ==================== 作业题目附件表 ====================
+        // 从题库选题时复制题目附件
+        manager
+            .create_table(
+                Table::create()
+                    .table(HomeworkQuestionFiles::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(HomeworkQuestionFiles::QuestionId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(HomeworkQuestionFiles::FileId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(HomeworkQuestionFiles::QuestionId)
+                            .col(HomeworkQuestionFiles::FileId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(
+                                HomeworkQuestionFiles::Table,
+                                HomeworkQuestionFiles::QuestionId,
+                            )
+                            .to(HomeworkQuestions::Table, HomeworkQuestions::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(HomeworkQuestionFiles::Table, HomeworkQuestionFiles::FileId)
+                            .to(Files::Table, Files::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // ==================== 随机抽题池表 ====================
+        // 池内候选题目为 homework_questions 中 pool_id 指向该池的题目，
+        // 每个学生按固定随机种子从中抽取 draw_count 道
+        manager
+            .create_table(
+                Table::create()
+                    .table(HomeworkQuestionPools::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(HomeworkQuestionPools::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(HomeworkQuestionPools::HomeworkId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(HomeworkQuestionPools::Label)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(HomeworkQuestionPools::DrawCount)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(HomeworkQuestionPools::Points)
+                            .double()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(
+                                HomeworkQuestionPools::Table,
+                                HomeworkQuestionPools::HomeworkId,
+                            )
+                            .to(Homeworks::Table, Homeworks::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_homework_question_pools_homework_id")
+                    .table(HomeworkQuestionPools::Table)
+                    .col(HomeworkQuestionPools::HomeworkId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // ==================== 作业题目所属抽题池 ====================
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(HomeworkQuestions::Table)
+                    .add_column(
+                        ColumnDef::new(HomeworkQuestions::PoolId)
+                            .big_integer()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(HomeworkQuestions::Table)
+                    .drop_column(HomeworkQuestions::PoolId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(HomeworkQuestionPools::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(HomeworkQuestionFiles::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BankQuestionFiles::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BankQuestions::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum BankQuestions {
+    Table,
+    Id,
+    CreatedBy,
+    IsShared,
+    Prompt,
+    Points,
+    Spec,
+    Tags,
+    Difficulty,
+    KnowledgePoint,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum BankQuestionFiles {
+    Table,
+    BankQuestionId,
+    FileId,
+}
+
+#[derive(DeriveIden)]
+enum HomeworkQuestionFiles {
+    Table,
+    QuestionId,
+    FileId,
+}
+
+#[derive(DeriveIden)]
+enum HomeworkQuestionPools {
+    Table,
+    Id,
+    HomeworkId,
+    Label,
+    DrawCount,
+    Points,
+}
+
+#[derive(DeriveIden)]
+enum HomeworkQuestions {
+    Table,
+    Id,
+    PoolId,
+}
+
+#[derive(DeriveIden)]
+enum Homeworks {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Files {
+    Table,
+    Id,
+}
@@ -0,0 +1,142 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // ==================== 作业关联的自动评测器 ====================
+        // 评测器在配置文件中定义，这里只保存名称
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Homeworks::Table)
+                    .add_column(ColumnDef::new(Homeworks::AutoGrader).string().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // ==================== 自动评测记录表（每个提交一条） ====================
+        // tests 为 JSON 数组：[{ "name", "passed", "score", "max_score", "output" }]
+        manager
+            .create_table(
+                Table::create()
+                    .table(AutoGradeRuns::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AutoGradeRuns::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(AutoGradeRuns::SubmissionId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(AutoGradeRuns::Grader).string().not_null())
+                    .col(
+                        ColumnDef::new(AutoGradeRuns::Status)
+                            .string()
+                            .not_null()
+                            .default("pending"),
+                    )
+                    .col(ColumnDef::new(AutoGradeRuns::Score).double().null())
+                    .col(ColumnDef::new(AutoGradeRuns::Feedback).text().null())
+                    .col(ColumnDef::new(AutoGradeRuns::Tests).text().null())
+                    .col(ColumnDef::new(AutoGradeRuns::Error).text().null())
+                    .col(
+                        ColumnDef::new(AutoGradeRuns::CreatedAt)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(AutoGradeRuns::StartedAt).big_integer().null())
+                    .col(
+                        ColumnDef::new(AutoGradeRuns::FinishedAt)
+                            .big_integer()
+                            .null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(AutoGradeRuns::Table, AutoGradeRuns::SubmissionId)
+                            .to(Submissions::Table, Submissions::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_auto_grade_runs_submission_id")
+                    .table(AutoGradeRuns::Table)
+                    .col(AutoGradeRuns::SubmissionId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_auto_grade_runs_status")
+                    .table(AutoGradeRuns::Table)
+                    .col(AutoGradeRuns::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AutoGradeRuns::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Homeworks::Table)
+                    .drop_column(Homeworks::AutoGrader)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum AutoGradeRuns {
+    Table,
+    Id,
+    SubmissionId,
+    Grader,
+    Status,
+    Score,
+    Feedback,
+    Tests,
+    Error,
+    CreatedAt,
+    StartedAt,
+    FinishedAt,
+}
+
+#[derive(DeriveIden)]
+enum Homeworks {
+    #[sea_orm(iden = "homeworks")]
+    Table,
+    AutoGrader,
+}
+
+#[derive(DeriveIden)]
+enum Submissions {
+    Table,
+    Id,
+}
@@ -1,7 +1,7 @@
 use config::{Config, ConfigError, Environment, File};
 use std::sync::OnceLock;
 
-use super::AppConfig;
+use super::{AppConfig, GraderType};
 
 static APP_CONFIG: OnceLock<AppConfig> = OnceLock::new();
 
@@ -62,9 +62,38 @@ impl AppConfig {
             app_config.database.pool_size = (app_config.server.workers as u32).max(10);
         }
 
+        app_config.validate_auto_graders()?;
+
         Ok(app_config)
     }
 
+    /// 校验评测器配置
+    fn validate_auto_graders(&self) -> Result<(), ConfigError> {
+        let mut names = std::collections::HashSet::new();
+        for grader in &self.auto_grader.graders {
+            if grader.name.trim().is_empty() {
+                return Err(ConfigError::Message("评测器名称不能为空".to_string()));
+            }
+            if !names.insert(grader.name.as_str()) {
+                return Err(ConfigError::Message(format!(
+                    "评测器名称重复: {}",
+                    grader.name
+                )));
+            }
+            let valid = match grader.grader_type {
+                GraderType::Command => !grader.command.is_empty(),
+                GraderType::Http => !grader.url.is_empty(),
+            };
+            if !valid {
+                return Err(ConfigError::Message(format!(
+                    "评测器 {} 缺少 command 或 url 配置",
+                    grader.name
+                )));
+            }
+        }
+        Ok(())
+    }
+
     /// 获取全局配置实例
     pub fn get() -> &'static AppConfig {
         APP_CONFIG.get_or_init(|| {
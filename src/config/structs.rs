@@ -12,6 +12,8 @@ pub struct AppConfig {
     pub upload: UploadConfig,
     #[serde(default)]
     pub argon2: Argon2Config,
+    #[serde(default)]
+    pub auto_grader: AutoGraderConfig,
 }
 
 /// 应用设置
@@ -198,3 +200,73 @@ impl Default for Argon2Config {
         }
     }
 }
+
+/// 自动评测配置
+///
+/// 评测器只能在配置文件中定义，作业通过名称引用。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutoGraderConfig {
+    #[serde(default = "default_auto_grader_max_concurrency")]
+    pub max_concurrency: usize, // 同时运行的评测任务数
+    #[serde(default = "default_auto_grader_max_output_size")]
+    pub max_output_size: usize, // 评测输出最大字节数（超出部分截断）
+    #[serde(default)]
+    pub graders: Vec<GraderConfig>, // 评测器列表
+}
+
+impl Default for AutoGraderConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrency: default_auto_grader_max_concurrency(),
+            max_output_size: default_auto_grader_max_output_size(),
+            graders: vec![],
+        }
+    }
+}
+
+impl AutoGraderConfig {
+    /// 按名称查找评测器
+    pub fn find(&self, name: &str) -> Option<&GraderConfig> {
+        self.graders.iter().find(|g| g.name == name)
+    }
+}
+
+fn default_auto_grader_max_concurrency() -> usize {
+    2
+}
+
+fn default_auto_grader_max_output_size() -> usize {
+    65536 // 64KB
+}
+
+/// 评测器类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GraderType {
+    /// 本地命令：在临时目录中运行，标准输出为 JSON 结果
+    Command,
+    /// 本地 HTTP 服务：以 multipart 上传提交内容，响应体为 JSON 结果
+    Http,
+}
+
+/// 评测器配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraderConfig {
+    pub name: String, // 评测器名称（作业中引用）
+    #[serde(default)]
+    pub description: Option<String>, // 说明（展示给教师）
+    #[serde(rename = "type")]
+    pub grader_type: GraderType,
+    #[serde(default)]
+    pub command: Vec<String>, // 命令及参数（command 类型）
+    #[serde(default)]
+    pub url: String, // 评测服务地址（http 类型）
+    #[serde(default = "default_grader_timeout")]
+    pub timeout: u64, // 超时时间 (秒)
+    #[serde(default)]
+    pub memory_limit: u64, // 内存限制 (MB)，0 表示不限制（command 类型，仅 Unix）
+}
+
+fn default_grader_timeout() -> u64 {
+    60
+}
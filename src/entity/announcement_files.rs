@@ -0,0 +1,42 @@
+//! 公告附件关联实体
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "announcement_files")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub announcement_id: i64,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub file_id: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::announcements::Entity",
+        from = "Column::AnnouncementId",
+        to = "super::announcements::Column::Id"
+    )]
+    Announcement,
+    #[sea_orm(
+        belongs_to = "super::files::Entity",
+        from = "Column::FileId",
+        to = "super::files::Column::Id"
+    )]
+    File,
+}
+
+impl Related<super::announcements::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Announcement.def()
+    }
+}
+
+impl Related<super::files::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::File.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
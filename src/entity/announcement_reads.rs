@@ -0,0 +1,59 @@
+//! 公告已读回执实体
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "announcement_reads")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub announcement_id: i64,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub user_id: i64,
+    pub read_at: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::announcements::Entity",
+        from = "Column::AnnouncementId",
+        to = "super::announcements::Column::Id"
+    )]
+    Announcement,
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::UserId",
+        to = "super::users::Column::Id"
+    )]
+    User,
+}
+
+impl Related<super::announcements::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Announcement.def()
+    }
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+// 从数据库模型转换为业务模型
+impl Model {
+    pub fn into_announcement_read(
+        self,
+    ) -> crate::models::announcements::entities::AnnouncementRead {
+        use crate::models::announcements::entities::AnnouncementRead;
+        use chrono::{DateTime, Utc};
+
+        AnnouncementRead {
+            announcement_id: self.announcement_id,
+            user_id: self.user_id,
+            read_at: DateTime::<Utc>::from_timestamp(self.read_at, 0).unwrap_or_default(),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+//! 班级公告实体
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "announcements")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+    pub class_id: i64,
+    pub title: String,
+    #[sea_orm(column_type = "Text")]
+    pub content: String,
+    pub pinned: bool,
+    pub created_by: i64,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::classes::Entity",
+        from = "Column::ClassId",
+        to = "super::classes::Column::Id"
+    )]
+    Class,
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::CreatedBy",
+        to = "super::users::Column::Id"
+    )]
+    Creator,
+    #[sea_orm(has_many = "super::announcement_files::Entity")]
+    AnnouncementFiles,
+    #[sea_orm(has_many = "super::announcement_reads::Entity")]
+    AnnouncementReads,
+}
+
+impl Related<super::classes::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Class.def()
+    }
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Creator.def()
+    }
+}
+
+impl Related<super::announcement_files::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AnnouncementFiles.def()
+    }
+}
+
+impl Related<super::announcement_reads::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AnnouncementReads.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+// 从数据库模型转换为业务模型
+impl Model {
+    pub fn into_announcement(self) -> crate::models::announcements::entities::Announcement {
+        use crate::models::announcements::entities::Announcement;
+        use chrono::{DateTime, Utc};
+
+        Announcement {
+            id: self.id,
+            class_id: self.class_id,
+            title: self.title,
+            content: self.content,
+            pinned: self.pinned,
+            created_by: self.created_by,
+            created_at: DateTime::<Utc>::from_timestamp(self.created_at, 0).unwrap_or_default(),
+            updated_at: DateTime::<Utc>::from_timestamp(self.updated_at, 0).unwrap_or_default(),
+        }
+    }
+}
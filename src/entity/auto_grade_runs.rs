@@ -0,0 +1,75 @@
+//! 自动评测记录实体
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "auto_grade_runs")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+    #[sea_orm(unique)]
+    pub submission_id: i64,
+    pub grader: String,
+    pub status: String,
+    #[sea_orm(column_type = "Double", nullable)]
+    pub score: Option<f64>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub feedback: Option<String>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub tests: Option<String>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub error: Option<String>,
+    pub created_at: i64,
+    pub started_at: Option<i64>,
+    pub finished_at: Option<i64>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::submissions::Entity",
+        from = "Column::SubmissionId",
+        to = "super::submissions::Column::Id"
+    )]
+    Submission,
+}
+
+impl Related<super::submissions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Submission.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+// 从数据库模型转换为业务模型
+impl Model {
+    pub fn into_auto_grade_run(self) -> crate::models::auto_graders::entities::AutoGradeRun {
+        use crate::models::auto_graders::entities::{AutoGradeRun, AutoGradeRunStatus};
+        use chrono::{DateTime, Utc};
+
+        AutoGradeRun {
+            id: self.id,
+            submission_id: self.submission_id,
+            grader: self.grader,
+            status: self
+                .status
+                .parse::<AutoGradeRunStatus>()
+                .unwrap_or(AutoGradeRunStatus::Failed),
+            score: self.score,
+            feedback: self.feedback,
+            tests: self
+                .tests
+                .and_then(|s| serde_json::from_str(&s).ok())
+                .unwrap_or_default(),
+            error: self.error,
+            created_at: DateTime::<Utc>::from_timestamp(self.created_at, 0).unwrap_or_default(),
+            started_at: self
+                .started_at
+                .and_then(|ts| DateTime::<Utc>::from_timestamp(ts, 0)),
+            finished_at: self
+                .finished_at
+                .and_then(|ts| DateTime::<Utc>::from_timestamp(ts, 0)),
+        }
+    }
+}
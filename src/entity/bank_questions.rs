@@ -0,0 +1,78 @@
+//! 题库题目实体
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "bank_questions")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+    pub created_by: i64,
+    pub is_shared: bool,
+    #[sea_orm(column_type = "Text")]
+    pub prompt: String,
+    #[sea_orm(column_type = "Double")]
+    pub points: f64,
+    #[sea_orm(column_type = "Text")]
+    pub spec: String,
+    #[sea_orm(column_type = "Text")]
+    pub tags: String,
+    pub difficulty: i32,
+    pub knowledge_point: Option<String>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::CreatedBy",
+        to = "super::users::Column::Id"
+    )]
+    Creator,
+    #[sea_orm(has_many = "super::bank_question_files::Entity")]
+    Files,
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Creator.def()
+    }
+}
+
+impl Related<super::bank_question_files::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Files.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+// 从数据库模型转换为业务模型
+// 题目定义无法解析时返回 None
+impl Model {
+    pub fn into_bank_question(
+        self,
+        attachments: Vec<crate::models::files::responses::FileInfo>,
+    ) -> Option<crate::models::question_bank::entities::BankQuestion> {
+        use crate::models::question_bank::entities::BankQuestion;
+        use chrono::{DateTime, Utc};
+
+        let spec = serde_json::from_str(&self.spec).ok()?;
+        Some(BankQuestion {
+            id: self.id,
+            created_by: self.created_by,
+            is_shared: self.is_shared,
+            prompt: self.prompt,
+            points: self.points,
+            spec,
+            tags: serde_json::from_str(&self.tags).unwrap_or_default(),
+            difficulty: self.difficulty,
+            knowledge_point: self.knowledge_point,
+            attachments,
+            created_at: DateTime::<Utc>::from_timestamp(self.created_at, 0).unwrap_or_default(),
+            updated_at: DateTime::<Utc>::from_timestamp(self.updated_at, 0).unwrap_or_default(),
+        })
+    }
+}
@@ -0,0 +1,73 @@
+//! 班级分组实体
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "class_groups")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+    pub class_id: i64,
+    pub name: String,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub description: Option<String>,
+    pub ta_id: Option<i64>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::classes::Entity",
+        from = "Column::ClassId",
+        to = "super::classes::Column::Id"
+    )]
+    Class,
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::TaId",
+        to = "super::users::Column::Id"
+    )]
+    Ta,
+    #[sea_orm(has_many = "super::homework_groups::Entity")]
+    HomeworkGroups,
+}
+
+impl Related<super::classes::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Class.def()
+    }
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Ta.def()
+    }
+}
+
+impl Related<super::homework_groups::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::HomeworkGroups.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+// 从数据库模型转换为业务模型
+impl Model {
+    pub fn into_class_group(self) -> crate::models::class_groups::entities::ClassGroup {
+        use crate::models::class_groups::entities::ClassGroup;
+        use chrono::{DateTime, Utc};
+
+        ClassGroup {
+            id: self.id,
+            class_id: self.class_id,
+            name: self.name,
+            description: self.description,
+            ta_id: self.ta_id,
+            created_at: DateTime::<Utc>::from_timestamp(self.created_at, 0).unwrap_or_default(),
+            updated_at: DateTime::<Utc>::from_timestamp(self.updated_at, 0).unwrap_or_default(),
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! 加入班级申请实体
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "class_join_requests")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+    pub class_id: i64,
+    pub user_id: i64,
+    pub status: String,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub message: Option<String>,
+    pub handled_by: Option<i64>,
+    pub handled_at: Option<i64>,
+    pub created_at: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::classes::Entity",
+        from = "Column::ClassId",
+        to = "super::classes::Column::Id"
+    )]
+    Class,
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::UserId",
+        to = "super::users::Column::Id"
+    )]
+    User,
+}
+
+impl Related<super::classes::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Class.def()
+    }
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+// 从数据库模型转换为业务模型
+impl Model {
+    pub fn into_class_join_request(self) -> crate::models::class_users::entities::ClassJoinRequest {
+        use crate::models::class_users::entities::{ClassJoinRequest, ClassJoinRequestStatus};
+        use chrono::{DateTime, Utc};
+
+        ClassJoinRequest {
+            id: self.id,
+            class_id: self.class_id,
+            user_id: self.user_id,
+            status: self
+                .status
+                .parse::<ClassJoinRequestStatus>()
+                .unwrap_or(ClassJoinRequestStatus::Pending),
+            message: self.message,
+            handled_by: self.handled_by,
+            handled_at: self
+                .handled_at
+                .and_then(|ts| DateTime::<Utc>::from_timestamp(ts, 0)),
+            created_at: DateTime::<Utc>::from_timestamp(self.created_at, 0).unwrap_or_default(),
+        }
+    }
+}
@@ -10,6 +10,7 @@ pub struct Model {
     pub class_id: i64,
     pub user_id: i64,
     pub role: String,
+    pub group_id: Option<i64>,
     pub joined_at: i64,
 }
 
@@ -57,6 +58,7 @@ impl Model {
                 .role
                 .parse::<ClassUserRole>()
                 .unwrap_or(ClassUserRole::Student),
+            group_id: self.group_id,
             joined_at: DateTime::<Utc>::from_timestamp(self.joined_at, 0).unwrap_or_default(),
         }
     }
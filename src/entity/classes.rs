@@ -13,6 +13,7 @@ pub struct Model {
     pub teacher_id: i64,
     #[sea_orm(unique)]
     pub invite_code: String,
+    pub join_policy: String,
     pub created_at: i64,
     pub updated_at: i64,
 }
@@ -29,6 +30,8 @@ pub enum Relation {
     ClassUsers,
     #[sea_orm(has_many = "super::homeworks::Entity")]
     Homeworks,
+    #[sea_orm(has_many = "super::class_join_requests::Entity")]
+    JoinRequests,
 }
 
 impl Related<super::users::Entity> for Entity {
@@ -49,12 +52,18 @@ impl Related<super::homeworks::Entity> for Entity {
     }
 }
 
+impl Related<super::class_join_requests::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::JoinRequests.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
 
 // 从数据库模型转换为业务模型
 impl Model {
     pub fn into_class(self) -> crate::models::classes::entities::Class {
-        use crate::models::classes::entities::Class;
+        use crate::models::classes::entities::{Class, ClassJoinPolicy};
         use chrono::{DateTime, Utc};
 
         Class {
@@ -63,6 +72,10 @@ impl Model {
             description: self.description,
             teacher_id: self.teacher_id,
             invite_code: self.invite_code,
+            join_policy: self
+                .join_policy
+                .parse::<ClassJoinPolicy>()
+                .unwrap_or(ClassJoinPolicy::Open),
             created_at: DateTime::<Utc>::from_timestamp(self.created_at, 0).unwrap_or_default(),
             updated_at: DateTime::<Utc>::from_timestamp(self.updated_at, 0).unwrap_or_default(),
         }
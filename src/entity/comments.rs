@@ -0,0 +1,72 @@
+//! 讨论评论实体
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "comments")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+    pub target_type: String,
+    pub target_id: i64,
+    pub class_id: i64,
+    pub parent_id: Option<i64>,
+    pub user_id: i64,
+    #[sea_orm(column_type = "Text")]
+    pub content: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::classes::Entity",
+        from = "Column::ClassId",
+        to = "super::classes::Column::Id"
+    )]
+    Class,
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::UserId",
+        to = "super::users::Column::Id"
+    )]
+    User,
+}
+
+impl Related<super::classes::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Class.def()
+    }
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+// 从数据库模型转换为业务模型
+impl Model {
+    pub fn into_comment(self) -> crate::models::comments::entities::Comment {
+        use crate::models::comments::entities::{Comment, CommentTargetType};
+        use chrono::{DateTime, Utc};
+
+        Comment {
+            id: self.id,
+            target_type: self
+                .target_type
+                .parse::<CommentTargetType>()
+                .unwrap_or(CommentTargetType::Announcement),
+            target_id: self.target_id,
+            class_id: self.class_id,
+            parent_id: self.parent_id,
+            user_id: self.user_id,
+            content: self.content,
+            created_at: DateTime::<Utc>::from_timestamp(self.created_at, 0).unwrap_or_default(),
+            updated_at: DateTime::<Utc>::from_timestamp(self.updated_at, 0).unwrap_or_default(),
+        }
+    }
+}
@@ -11,8 +11,13 @@ pub struct Model {
     pub submission_id: i64,
     pub grader_id: i64,
     pub score: f64,
+    pub penalty: f64,
+    pub score_override: Option<f64>,
     #[sea_orm(column_type = "Text", nullable)]
     pub comment: Option<String>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub rubric_scores: Option<String>,
+    pub pending_review: bool,
     pub graded_at: i64,
     pub updated_at: i64,
 }
@@ -49,16 +54,37 @@ impl ActiveModelBehavior for ActiveModel {}
 
 // 从数据库模型转换为业务模型
 impl Model {
+    /// 最终得分：教师调整分优先，否则为原始分数减去迟交扣分
+    pub fn effective_score(&self) -> f64 {
+        self.score_override
+            .unwrap_or_else(|| (self.score - self.penalty).max(0.0))
+    }
+
+    /// 评分细则得分明细
+    pub fn rubric_score_list(&self) -> Vec<crate::models::rubrics::entities::RubricScore> {
+        self.rubric_scores
+            .as_deref()
+            .and_then(|s| serde_json::from_str(s).ok())
+            .unwrap_or_default()
+    }
+
     pub fn into_grade(self) -> crate::models::grades::entities::Grade {
         use crate::models::grades::entities::Grade;
         use chrono::{DateTime, Utc};
 
+        let effective_score = self.effective_score();
+        let rubric_scores = self.rubric_score_list();
         Grade {
             id: self.id,
             submission_id: self.submission_id,
             grader_id: self.grader_id,
             score: self.score,
+            penalty: self.penalty,
+            score_override: self.score_override,
+            effective_score,
             comment: self.comment,
+            rubric_scores,
+            pending_review: self.pending_review,
             graded_at: DateTime::<Utc>::from_timestamp(self.graded_at, 0).unwrap_or_default(),
             updated_at: DateTime::<Utc>::from_timestamp(self.updated_at, 0).unwrap_or_default(),
         }
@@ -0,0 +1,33 @@
+//! 作业截止提醒记录实体
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "homework_deadline_reminders")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub homework_id: i64,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub offset_minutes: i64,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub deadline: i64,
+    pub sent_at: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::homeworks::Entity",
+        from = "Column::HomeworkId",
+        to = "super::homeworks::Column::Id"
+    )]
+    Homework,
+}
+
+impl Related<super::homeworks::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Homework.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,83 @@
+//! 作业延期实体
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "homework_extensions")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+    pub homework_id: i64,
+    pub user_id: Option<i64>,
+    pub group_id: Option<i64>,
+    pub deadline: i64,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub reason: Option<String>,
+    pub created_by: i64,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::homeworks::Entity",
+        from = "Column::HomeworkId",
+        to = "super::homeworks::Column::Id"
+    )]
+    Homework,
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::UserId",
+        to = "super::users::Column::Id"
+    )]
+    User,
+    #[sea_orm(
+        belongs_to = "super::class_groups::Entity",
+        from = "Column::GroupId",
+        to = "super::class_groups::Column::Id"
+    )]
+    Group,
+}
+
+impl Related<super::homeworks::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Homework.def()
+    }
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl Related<super::class_groups::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Group.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+// 从数据库模型转换为业务模型
+impl Model {
+    pub fn into_homework_extension(
+        self,
+    ) -> crate::models::homework_extensions::entities::HomeworkExtension {
+        use crate::models::homework_extensions::entities::HomeworkExtension;
+        use chrono::{DateTime, Utc};
+
+        HomeworkExtension {
+            id: self.id,
+            homework_id: self.homework_id,
+            user_id: self.user_id,
+            group_id: self.group_id,
+            deadline: DateTime::<Utc>::from_timestamp(self.deadline, 0).unwrap_or_default(),
+            reason: self.reason,
+            created_by: self.created_by,
+            created_at: DateTime::<Utc>::from_timestamp(self.created_at, 0).unwrap_or_default(),
+            updated_at: DateTime::<Utc>::from_timestamp(self.updated_at, 0).unwrap_or_default(),
+        }
+    }
+}
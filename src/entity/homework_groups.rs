@@ -0,0 +1,42 @@
+//! 作业分配分组关联实体
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "homework_groups")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub homework_id: i64,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub group_id: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::homeworks::Entity",
+        from = "Column::HomeworkId",
+        to = "super::homeworks::Column::Id"
+    )]
+    Homework,
+    #[sea_orm(
+        belongs_to = "super::class_groups::Entity",
+        from = "Column::GroupId",
+        to = "super::class_groups::Column::Id"
+    )]
+    Group,
+}
+
+impl Related<super::homeworks::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Homework.def()
+    }
+}
+
+impl Related<super::class_groups::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Group.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,42 @@
+//! 作业题目附件关联实体
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "homework_question_files")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub question_id: i64,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub file_id: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::homework_questions::Entity",
+        from = "Column::QuestionId",
+        to = "super::homework_questions::Column::Id"
+    )]
+    Question,
+    #[sea_orm(
+        belongs_to = "super::files::Entity",
+        from = "Column::FileId",
+        to = "super::files::Column::Id"
+    )]
+    File,
+}
+
+impl Related<super::homework_questions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Question.def()
+    }
+}
+
+impl Related<super::files::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::File.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
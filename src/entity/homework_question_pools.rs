@@ -0,0 +1,50 @@
+//! 随机抽题池实体
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "homework_question_pools")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+    pub homework_id: i64,
+    pub label: String,
+    pub draw_count: i32,
+    #[sea_orm(column_type = "Double")]
+    pub points: f64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::homeworks::Entity",
+        from = "Column::HomeworkId",
+        to = "super::homeworks::Column::Id"
+    )]
+    Homework,
+}
+
+impl Related<super::homeworks::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Homework.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+// 从数据库模型转换为业务模型
+impl Model {
+    pub fn into_question_pool(
+        self,
+    ) -> crate::models::homework_questions::entities::HomeworkQuestionPool {
+        use crate::models::homework_questions::entities::HomeworkQuestionPool;
+
+        HomeworkQuestionPool {
+            id: self.id,
+            homework_id: self.homework_id,
+            label: self.label,
+            draw_count: self.draw_count,
+            points: self.points,
+        }
+    }
+}
@@ -0,0 +1,76 @@
+//! 作业题目实体
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "homework_questions")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+    pub homework_id: i64,
+    pub position: i32,
+    #[sea_orm(column_type = "Text")]
+    pub prompt: String,
+    #[sea_orm(column_type = "Double")]
+    pub points: f64,
+    #[sea_orm(column_type = "Text")]
+    pub spec: String,
+    /// 所属随机抽题池（为空表示所有学生都作答的固定题目）
+    pub pool_id: Option<i64>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::homeworks::Entity",
+        from = "Column::HomeworkId",
+        to = "super::homeworks::Column::Id"
+    )]
+    Homework,
+    #[sea_orm(has_many = "super::submission_answers::Entity")]
+    Answers,
+    #[sea_orm(has_many = "super::homework_question_files::Entity")]
+    Files,
+}
+
+impl Related<super::homeworks::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Homework.def()
+    }
+}
+
+impl Related<super::submission_answers::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Answers.def()
+    }
+}
+
+impl Related<super::homework_question_files::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Files.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+// 从数据库模型转换为业务模型
+// 题目定义无法解析时返回 None
+impl Model {
+    pub fn into_homework_question(
+        self,
+    ) -> Option<crate::models::homework_questions::entities::HomeworkQuestion> {
+        use crate::models::homework_questions::entities::HomeworkQuestion;
+
+        let spec = serde_json::from_str(&self.spec).ok()?;
+        Some(HomeworkQuestion {
+            id: self.id,
+            homework_id: self.homework_id,
+            position: self.position,
+            prompt: self.prompt,
+            points: self.points,
+            spec,
+            pool_id: self.pool_id,
+            attachments: vec![],
+        })
+    }
+}
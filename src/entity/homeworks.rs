@@ -14,6 +14,13 @@ pub struct Model {
     pub max_score: f64,
     pub deadline: Option<i64>,
     pub allow_late: bool,
+    pub late_cutoff: Option<i64>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub late_penalty: Option<String>,
+    pub rubric_id: Option<i64>,
+    pub auto_grader: Option<String>,
+    pub status: String,
+    pub publish_at: Option<i64>,
     pub created_by: i64,
     pub created_at: i64,
     pub updated_at: i64,
@@ -37,6 +44,8 @@ pub enum Relation {
     Submissions,
     #[sea_orm(has_many = "super::homework_files::Entity")]
     HomeworkFiles,
+    #[sea_orm(has_many = "super::homework_groups::Entity")]
+    HomeworkGroups,
 }
 
 impl Related<super::classes::Entity> for Entity {
@@ -63,12 +72,19 @@ impl Related<super::homework_files::Entity> for Entity {
     }
 }
 
+impl Related<super::homework_groups::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::HomeworkGroups.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
 
 // 从数据库模型转换为业务模型
+// 注意：group_ids 需由存储层另行填充
 impl Model {
     pub fn into_homework(self) -> crate::models::homeworks::entities::Homework {
-        use crate::models::homeworks::entities::Homework;
+        use crate::models::homeworks::entities::{Homework, HomeworkStatus};
         use chrono::{DateTime, Utc};
 
         Homework {
@@ -81,6 +97,22 @@ impl Model {
                 .deadline
                 .and_then(|ts| DateTime::<Utc>::from_timestamp(ts, 0)),
             allow_late: self.allow_late,
+            late_cutoff: self
+                .late_cutoff
+                .and_then(|ts| DateTime::<Utc>::from_timestamp(ts, 0)),
+            late_penalty: self
+                .late_penalty
+                .and_then(|s| serde_json::from_str(&s).ok()),
+            rubric_id: self.rubric_id,
+            auto_grader: self.auto_grader,
+            status: self
+                .status
+                .parse::<HomeworkStatus>()
+                .unwrap_or(HomeworkStatus::Published),
+            publish_at: self
+                .publish_at
+                .and_then(|ts| DateTime::<Utc>::from_timestamp(ts, 0)),
+            group_ids: vec![],
             created_by: self.created_by,
             created_at: DateTime::<Utc>::from_timestamp(self.created_at, 0).unwrap_or_default(),
             updated_at: DateTime::<Utc>::from_timestamp(self.updated_at, 0).unwrap_or_default(),
@@ -5,15 +5,35 @@
 
 pub mod prelude;
 
+pub mod announcement_files;
+pub mod announcement_reads;
+pub mod announcements;
+pub mod auto_grade_runs;
+pub mod bank_question_files;
+pub mod bank_questions;
+pub mod class_groups;
+pub mod class_join_requests;
 pub mod class_users;
 pub mod classes;
+pub mod comments;
 pub mod files;
 pub mod grades;
+pub mod homework_deadline_reminders;
+pub mod homework_extensions;
 pub mod homework_files;
+pub mod homework_groups;
+pub mod homework_question_files;
+pub mod homework_question_pools;
+pub mod homework_questions;
 pub mod homeworks;
 pub mod notifications;
+pub mod profile_fields;
+pub mod rubric_criteria;
+pub mod rubrics;
+pub mod submission_answers;
 pub mod submission_files;
 pub mod submissions;
 pub mod system_settings;
 pub mod system_settings_audit;
+pub mod user_profile_values;
 pub mod users;
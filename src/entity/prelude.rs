@@ -1,20 +1,88 @@
 //! 预导入模块，方便使用
 
+pub use super::announcement_files::{
+    ActiveModel as AnnouncementFileActiveModel, Entity as AnnouncementFiles,
+    Model as AnnouncementFileModel,
+};
+pub use super::announcement_reads::{
+    ActiveModel as AnnouncementReadActiveModel, Entity as AnnouncementReads,
+    Model as AnnouncementReadModel,
+};
+pub use super::announcements::{
+    ActiveModel as AnnouncementActiveModel, Entity as Announcements, Model as AnnouncementModel,
+};
+pub use super::auto_grade_runs::{
+    ActiveModel as AutoGradeRunActiveModel, Entity as AutoGradeRuns, Model as AutoGradeRunModel,
+};
+pub use super::bank_question_files::{
+    ActiveModel as BankQuestionFileActiveModel, Entity as BankQuestionFiles,
+    Model as BankQuestionFileModel,
+};
+pub use super::bank_questions::{
+    ActiveModel as BankQuestionActiveModel, Entity as BankQuestions, Model as BankQuestionModel,
+};
+pub use super::class_groups::{
+    ActiveModel as ClassGroupActiveModel, Entity as ClassGroups, Model as ClassGroupModel,
+};
+pub use super::class_join_requests::{
+    ActiveModel as ClassJoinRequestActiveModel, Entity as ClassJoinRequests,
+    Model as ClassJoinRequestModel,
+};
 pub use super::class_users::{
     ActiveModel as ClassUserActiveModel, Entity as ClassUsers, Model as ClassUserModel,
 };
 pub use super::classes::{ActiveModel as ClassActiveModel, Entity as Classes, Model as ClassModel};
+pub use super::comments::{
+    ActiveModel as CommentActiveModel, Entity as Comments, Model as CommentModel,
+};
 pub use super::files::{ActiveModel as FileActiveModel, Entity as Files, Model as FileModel};
 pub use super::grades::{ActiveModel as GradeActiveModel, Entity as Grades, Model as GradeModel};
+pub use super::homework_deadline_reminders::{
+    ActiveModel as HomeworkDeadlineReminderActiveModel, Entity as HomeworkDeadlineReminders,
+    Model as HomeworkDeadlineReminderModel,
+};
+pub use super::homework_extensions::{
+    ActiveModel as HomeworkExtensionActiveModel, Entity as HomeworkExtensions,
+    Model as HomeworkExtensionModel,
+};
 pub use super::homework_files::{
     ActiveModel as HomeworkFileActiveModel, Entity as HomeworkFiles, Model as HomeworkFileModel,
 };
+pub use super::homework_groups::{
+    ActiveModel as HomeworkGroupActiveModel, Entity as HomeworkGroups, Model as HomeworkGroupModel,
+};
+pub use super::homework_question_files::{
+    ActiveModel as HomeworkQuestionFileActiveModel, Entity as HomeworkQuestionFiles,
+    Model as HomeworkQuestionFileModel,
+};
+pub use super::homework_question_pools::{
+    ActiveModel as HomeworkQuestionPoolActiveModel, Entity as HomeworkQuestionPools,
+    Model as HomeworkQuestionPoolModel,
+};
+pub use super::homework_questions::{
+    ActiveModel as HomeworkQuestionActiveModel, Entity as HomeworkQuestions,
+    Model as HomeworkQuestionModel,
+};
 pub use super::homeworks::{
     ActiveModel as HomeworkActiveModel, Entity as Homeworks, Model as HomeworkModel,
 };
 pub use super::notifications::{
     ActiveModel as NotificationActiveModel, Entity as Notifications, Model as NotificationModel,
 };
+pub use super::profile_fields::{
+    ActiveModel as ProfileFieldActiveModel, Entity as ProfileFields, Model as ProfileFieldModel,
+};
+pub use super::rubric_criteria::{
+    ActiveModel as RubricCriterionActiveModel, Entity as RubricCriteria,
+    Model as RubricCriterionModel,
+};
+pub use super::rubrics::{
+    ActiveModel as RubricActiveModel, Entity as Rubrics, Model as RubricModel,
+};
+pub use super::submission_answers::{
+    ActiveModel as SubmissionAnswerActiveModel, Entity as SubmissionAnswers,
+    Model as SubmissionAnswerModel,
+};
 pub use super::submission_files::{
     ActiveModel as SubmissionFileActiveModel, Entity as SubmissionFiles,
     Model as SubmissionFileModel,
@@ -29,4 +97,8 @@ pub use super::system_settings_audit::{
     ActiveModel as SystemSettingAuditActiveModel, Entity as SystemSettingsAudit,
     Model as SystemSettingAuditModel,
 };
+pub use super::user_profile_values::{
+    ActiveModel as UserProfileValueActiveModel, Entity as UserProfileValues,
+    Model as UserProfileValueModel,
+};
 pub use super::users::{ActiveModel as UserActiveModel, Entity as Users, Model as UserModel};
@@ -0,0 +1,66 @@
+//! 自定义资料字段定义实体
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "profile_fields")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+    #[sea_orm(unique)]
+    pub field_key: String,
+    pub label: String,
+    pub field_type: String,
+    pub required: bool,
+    pub is_unique: bool,
+    pub pattern: Option<String>,
+    pub max_length: Option<i32>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub options: Option<String>,
+    pub sort_order: i32,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::user_profile_values::Entity")]
+    UserProfileValues,
+}
+
+impl Related<super::user_profile_values::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserProfileValues.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+// 从数据库模型转换为业务模型
+impl Model {
+    pub fn into_profile_field(self) -> crate::models::profile_fields::entities::ProfileField {
+        use crate::models::profile_fields::entities::{ProfileField, ProfileFieldType};
+        use chrono::{DateTime, Utc};
+
+        ProfileField {
+            id: self.id,
+            key: self.field_key,
+            label: self.label,
+            field_type: self
+                .field_type
+                .parse::<ProfileFieldType>()
+                .unwrap_or(ProfileFieldType::Text),
+            required: self.required,
+            unique: self.is_unique,
+            pattern: self.pattern,
+            max_length: self.max_length,
+            options: self
+                .options
+                .and_then(|s| serde_json::from_str(&s).ok())
+                .unwrap_or_default(),
+            sort_order: self.sort_order,
+            created_at: DateTime::<Utc>::from_timestamp(self.created_at, 0).unwrap_or_default(),
+            updated_at: DateTime::<Utc>::from_timestamp(self.updated_at, 0).unwrap_or_default(),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+//! 评分细则评分项实体
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "rubric_criteria")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+    pub rubric_id: i64,
+    pub position: i32,
+    pub title: String,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub description: Option<String>,
+    #[sea_orm(column_type = "Text")]
+    pub levels: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::rubrics::Entity",
+        from = "Column::RubricId",
+        to = "super::rubrics::Column::Id"
+    )]
+    Rubric,
+}
+
+impl Related<super::rubrics::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Rubric.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+// 从数据库模型转换为业务模型
+impl Model {
+    pub fn into_rubric_criterion(self) -> crate::models::rubrics::entities::RubricCriterion {
+        use crate::models::rubrics::entities::RubricCriterion;
+
+        RubricCriterion {
+            id: self.id,
+            title: self.title,
+            description: self.description,
+            levels: serde_json::from_str(&self.levels).unwrap_or_default(),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+//! 评分细则实体
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "rubrics")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+    pub title: String,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub description: Option<String>,
+    pub created_by: i64,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::rubric_criteria::Entity")]
+    Criteria,
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::CreatedBy",
+        to = "super::users::Column::Id"
+    )]
+    Creator,
+}
+
+impl Related<super::rubric_criteria::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Criteria.def()
+    }
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Creator.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+// 从数据库模型转换为业务模型
+impl Model {
+    pub fn into_rubric(
+        self,
+        criteria: Vec<crate::models::rubrics::entities::RubricCriterion>,
+    ) -> crate::models::rubrics::entities::Rubric {
+        use crate::models::rubrics::entities::Rubric;
+        use chrono::{DateTime, Utc};
+
+        Rubric {
+            id: self.id,
+            title: self.title,
+            description: self.description,
+            total_points: criteria.iter().map(|c| c.max_points()).sum(),
+            criteria,
+            created_by: self.created_by,
+            created_at: DateTime::<Utc>::from_timestamp(self.created_at, 0).unwrap_or_default(),
+            updated_at: DateTime::<Utc>::from_timestamp(self.updated_at, 0).unwrap_or_default(),
+        }
+    }
+}
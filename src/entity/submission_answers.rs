@@ -0,0 +1,65 @@
+//! 提交答案实体
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "submission_answers")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+    pub submission_id: i64,
+    pub question_id: i64,
+    #[sea_orm(column_type = "Text")]
+    pub answer: String,
+    pub is_correct: Option<bool>,
+    #[sea_orm(column_type = "Double", nullable)]
+    pub points: Option<f64>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::submissions::Entity",
+        from = "Column::SubmissionId",
+        to = "super::submissions::Column::Id"
+    )]
+    Submission,
+    #[sea_orm(
+        belongs_to = "super::homework_questions::Entity",
+        from = "Column::QuestionId",
+        to = "super::homework_questions::Column::Id"
+    )]
+    Question,
+}
+
+impl Related<super::submissions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Submission.def()
+    }
+}
+
+impl Related<super::homework_questions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Question.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+// 从数据库模型转换为业务模型
+// 作答内容无法解析时返回 None
+impl Model {
+    pub fn into_submission_answer(
+        self,
+    ) -> Option<crate::models::homework_questions::entities::SubmissionAnswer> {
+        use crate::models::homework_questions::entities::SubmissionAnswer;
+
+        let answer = serde_json::from_str(&self.answer).ok()?;
+        Some(SubmissionAnswer {
+            question_id: self.question_id,
+            answer,
+            is_correct: self.is_correct,
+            points: self.points,
+        })
+    }
+}
@@ -0,0 +1,44 @@
+//! 用户资料字段值实体
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "user_profile_values")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub user_id: i64,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub field_id: i64,
+    pub value: String,
+    pub updated_at: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::UserId",
+        to = "super::users::Column::Id"
+    )]
+    User,
+    #[sea_orm(
+        belongs_to = "super::profile_fields::Entity",
+        from = "Column::FieldId",
+        to = "super::profile_fields::Column::Id"
+    )]
+    ProfileField,
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl Related<super::profile_fields::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ProfileField.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -106,6 +106,7 @@ impl Model {
                 .and_then(|ts| DateTime::<Utc>::from_timestamp(ts, 0)),
             created_at: DateTime::<Utc>::from_timestamp(self.created_at, 0).unwrap_or_default(),
             updated_at: DateTime::<Utc>::from_timestamp(self.updated_at, 0).unwrap_or_default(),
+            profile: Default::default(),
         }
     }
 }
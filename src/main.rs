@@ -133,11 +133,19 @@ async fn main() -> std::io::Result<()> {
             )) // 设置最大请求体大小
             .configure(routes::configure_auth_routes) // 配置认证相关路由
             .configure(routes::configure_user_routes) // 配置用户相关路由
+            .configure(routes::configure_profile_fields_routes) // 配置自定义资料字段路由
+            .configure(routes::configure_comments_routes) // 配置讨论评论路由（必须在 announcements、classes、homeworks 之前）
+            .configure(routes::configure_announcements_routes) // 配置班级公告路由
             .configure(routes::configure_class_users_routes) //配置班级成员相关路由
+            .configure(routes::configure_class_groups_routes) // 配置班级分组相关路由
             .configure(routes::configure_classes_routes) // 配置班级相关路由
             .configure(routes::configure_submissions_routes) // 配置提交相关路由（必须在 homeworks 之前，因为有 /homeworks/{id}/submissions 路由）
+            .configure(routes::configure_homework_extensions_routes) // 配置作业延期路由（必须在 homeworks 之前）
             .configure(routes::configure_homeworks_routes) // 配置作业相关路由
             .configure(routes::configure_grades_routes) // 配置评分相关路由
+            .configure(routes::configure_rubrics_routes) // 配置评分细则路由
+            .configure(routes::configure_question_bank_routes) // 配置题库路由
+            .configure(routes::configure_auto_graders_routes) // 配置自动评测器路由
             .configure(routes::configure_notifications_routes) // 配置通知相关路由
             .configure(routes::configure_websocket_routes) // 配置 WebSocket 路由
             .configure(routes::configure_file_routes) // 配置文件相关路由
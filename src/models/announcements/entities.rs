@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::models::common::serialization;
+
+/// 班级公告（内容支持 Markdown）
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/announcement.ts")]
+pub struct Announcement {
+    #[serde(with = "serialization::i64_as_string")]
+    #[ts(type = "string")]
+    pub id: i64,
+    #[serde(with = "serialization::i64_as_string")]
+    #[ts(type = "string")]
+    pub class_id: i64,
+    pub title: String,
+    pub content: String,
+    // 置顶公告在列表中优先显示
+    pub pinned: bool,
+    #[serde(with = "serialization::i64_as_string")]
+    #[ts(type = "string")]
+    pub created_by: i64,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// 公告已读回执
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/announcement.ts")]
+pub struct AnnouncementRead {
+    #[serde(with = "serialization::i64_as_string")]
+    #[ts(type = "string")]
+    pub announcement_id: i64,
+    #[serde(with = "serialization::i64_as_string")]
+    #[ts(type = "string")]
+    pub user_id: i64,
+    pub read_at: chrono::DateTime<chrono::Utc>,
+}
@@ -0,0 +1,8 @@
+// 班级公告实体定义
+pub mod entities;
+
+// 班级公告请求模型
+pub mod requests;
+
+// 班级公告响应模型
+pub mod responses;
@@ -0,0 +1,36 @@
+use crate::models::common::PaginationQuery;
+use serde::Deserialize;
+use ts_rs::TS;
+
+// 创建公告请求
+#[derive(Debug, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/announcement.ts")]
+pub struct CreateAnnouncementRequest {
+    pub title: String,
+    pub content: String,
+    pub pinned: Option<bool>,
+    /// 附件 download_token 列表
+    pub attachments: Option<Vec<String>>,
+}
+
+// 更新公告请求
+#[derive(Debug, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/announcement.ts")]
+pub struct UpdateAnnouncementRequest {
+    pub title: Option<String>,
+    pub content: Option<String>,
+    pub pinned: Option<bool>,
+    /// 附件 download_token 列表（传入时整体替换）
+    pub attachments: Option<Vec<String>>,
+}
+
+// 公告列表查询参数
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/announcement.ts")]
+pub struct AnnouncementListQuery {
+    #[serde(flatten)]
+    #[ts(flatten)]
+    pub pagination: PaginationQuery,
+    /// 按标题搜索
+    pub search: Option<String>,
+}
@@ -0,0 +1,77 @@
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::models::PaginationInfo;
+use crate::models::announcements::entities::Announcement;
+use crate::models::class_users::responses::UserInfo;
+use crate::models::common::serialization::serialize_i64_as_string;
+use crate::models::files::responses::FileInfo;
+
+/// 公告列表响应（存储层返回）
+#[derive(Debug, Serialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/announcement.ts")]
+pub struct AnnouncementListResponse {
+    pub pagination: PaginationInfo,
+    pub items: Vec<Announcement>,
+}
+
+/// 公告列表项（包含发布者、已读状态和统计）
+#[derive(Debug, Serialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/announcement.ts")]
+pub struct AnnouncementListItem {
+    #[serde(flatten)]
+    pub announcement: Announcement,
+    pub creator: Option<UserInfo>,
+    /// 当前用户是否已读
+    pub is_read: bool,
+    #[serde(serialize_with = "serialize_i64_as_string")]
+    #[ts(type = "string")]
+    pub read_count: i64,
+    #[serde(serialize_with = "serialize_i64_as_string")]
+    #[ts(type = "string")]
+    pub comment_count: i64,
+}
+
+/// 公告详情列表响应
+#[derive(Debug, Serialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/announcement.ts")]
+pub struct AnnouncementDetailListResponse {
+    pub pagination: PaginationInfo,
+    pub items: Vec<AnnouncementListItem>,
+}
+
+/// 公告详情（包含附件）
+#[derive(Debug, Serialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/announcement.ts")]
+pub struct AnnouncementDetail {
+    #[serde(flatten)]
+    pub announcement: Announcement,
+    pub attachments: Vec<FileInfo>,
+    pub creator: Option<UserInfo>,
+    pub is_read: bool,
+    #[serde(serialize_with = "serialize_i64_as_string")]
+    #[ts(type = "string")]
+    pub read_count: i64,
+}
+
+/// 已读回执项
+#[derive(Debug, Serialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/announcement.ts")]
+pub struct AnnouncementReadReceipt {
+    pub user: UserInfo,
+    pub read_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// 公告已读回执响应（教师视图）
+#[derive(Debug, Serialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/announcement.ts")]
+pub struct AnnouncementReadsResponse {
+    #[serde(serialize_with = "serialize_i64_as_string")]
+    #[ts(type = "string")]
+    pub read_count: i64,
+    #[serde(serialize_with = "serialize_i64_as_string")]
+    #[ts(type = "string")]
+    pub unread_count: i64,
+    pub readers: Vec<AnnouncementReadReceipt>,
+    pub unread_users: Vec<UserInfo>,
+}
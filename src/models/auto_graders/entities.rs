@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::models::common::serialization;
+
+/// 自动评测状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "../frontend/src/types/generated/auto_grader.ts")]
+pub enum AutoGradeRunStatus {
+    /// 等待评测
+    Pending,
+    /// 评测中
+    Running,
+    /// 评测完成，已生成待确认的评分
+    Succeeded,
+    /// 评测失败（超时、评测器出错或结果无效）
+    Failed,
+}
+
+impl AutoGradeRunStatus {
+    pub const PENDING: &'static str = "pending";
+    pub const RUNNING: &'static str = "running";
+    pub const SUCCEEDED: &'static str = "succeeded";
+    pub const FAILED: &'static str = "failed";
+}
+
+impl std::fmt::Display for AutoGradeRunStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AutoGradeRunStatus::Pending => write!(f, "{}", AutoGradeRunStatus::PENDING),
+            AutoGradeRunStatus::Running => write!(f, "{}", AutoGradeRunStatus::RUNNING),
+            AutoGradeRunStatus::Succeeded => write!(f, "{}", AutoGradeRunStatus::SUCCEEDED),
+            AutoGradeRunStatus::Failed => write!(f, "{}", AutoGradeRunStatus::FAILED),
+        }
+    }
+}
+
+impl std::str::FromStr for AutoGradeRunStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(AutoGradeRunStatus::Pending),
+            "running" => Ok(AutoGradeRunStatus::Running),
+            "succeeded" => Ok(AutoGradeRunStatus::Succeeded),
+            "failed" => Ok(AutoGradeRunStatus::Failed),
+            _ => Err(format!("Invalid auto grade run status: {s}")),
+        }
+    }
+}
+
+/// 单个测试用例的评测结果
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/auto_grader.ts")]
+pub struct AutoGradeTest {
+    pub name: String,
+    pub passed: bool,
+    #[serde(default)]
+    pub score: Option<f64>,
+    #[serde(default)]
+    pub max_score: Option<f64>,
+    /// 测试输出（如实际输出与期望输出的差异）
+    #[serde(default)]
+    pub output: Option<String>,
+}
+
+/// 评测器输出的结果（命令的标准输出或 HTTP 响应体）
+#[derive(Debug, Clone, Deserialize)]
+pub struct AutoGradeResult {
+    pub score: f64,
+    #[serde(default)]
+    pub feedback: Option<String>,
+    #[serde(default)]
+    pub tests: Vec<AutoGradeTest>,
+}
+
+/// 提交的自动评测记录
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/auto_grader.ts")]
+pub struct AutoGradeRun {
+    #[serde(with = "serialization::i64_as_string")]
+    #[ts(type = "string")]
+    pub id: i64,
+    #[serde(with = "serialization::i64_as_string")]
+    #[ts(type = "string")]
+    pub submission_id: i64,
+    /// 评测器名称
+    pub grader: String,
+    pub status: AutoGradeRunStatus,
+    /// 评测得分（评测成功时）
+    pub score: Option<f64>,
+    /// 评测器给出的总体反馈
+    pub feedback: Option<String>,
+    /// 各测试用例结果
+    pub tests: Vec<AutoGradeTest>,
+    /// 失败原因或评测器的错误输出
+    pub error: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub started_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub finished_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// 可用的评测器（不含命令与地址等部署细节）
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/auto_grader.ts")]
+pub struct AutoGraderInfo {
+    pub name: String,
+    pub description: Option<String>,
+}
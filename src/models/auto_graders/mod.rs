@@ -0,0 +1,5 @@
+// 自动评测实体定义
+pub mod entities;
+
+// 自动评测响应模型
+pub mod responses;
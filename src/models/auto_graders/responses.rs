@@ -0,0 +1,11 @@
+use serde::Serialize;
+use ts_rs::TS;
+
+use super::entities::AutoGraderInfo;
+
+/// 可用评测器列表响应
+#[derive(Debug, Serialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/auto_grader.ts")]
+pub struct AutoGraderListResponse {
+    pub items: Vec<AutoGraderInfo>,
+}
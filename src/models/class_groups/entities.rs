@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::models::common::serialization;
+
+/// 班级分组（如实验小班），每个分组可指定一名助教
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/class-group.ts")]
+pub struct ClassGroup {
+    #[serde(with = "serialization::i64_as_string")]
+    #[ts(type = "string")]
+    pub id: i64,
+    #[serde(with = "serialization::i64_as_string")]
+    #[ts(type = "string")]
+    pub class_id: i64,
+    pub name: String,
+    pub description: Option<String>,
+    // 分组助教的用户 ID
+    #[serde(with = "serialization::option_i64_as_string")]
+    #[ts(type = "string | null")]
+    pub ta_id: Option<i64>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
@@ -0,0 +1,8 @@
+// 班级分组实体定义
+pub mod entities;
+
+// 班级分组请求模型
+pub mod requests;
+
+// 班级分组响应模型
+pub mod responses;
@@ -0,0 +1,35 @@
+use crate::models::common::serialization::{self, deserialize_option_string_to_i64};
+use serde::Deserialize;
+use ts_rs::TS;
+
+// 创建分组请求
+#[derive(Debug, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/class-group.ts")]
+pub struct CreateClassGroupRequest {
+    pub name: String,
+    pub description: Option<String>,
+    // 助教必须是班级成员
+    #[serde(default, deserialize_with = "deserialize_option_string_to_i64")]
+    #[ts(type = "string | null")]
+    pub ta_id: Option<i64>,
+}
+
+// 更新分组请求
+#[derive(Debug, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/class-group.ts")]
+pub struct UpdateClassGroupRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_option_string_to_i64")]
+    #[ts(type = "string | null")]
+    pub ta_id: Option<i64>,
+}
+
+// 批量设置分组成员请求
+#[derive(Debug, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/class-group.ts")]
+pub struct ClassGroupMembersRequest {
+    #[serde(with = "serialization::vec_i64_as_string")]
+    #[ts(type = "string[]")]
+    pub user_ids: Vec<i64>,
+}
@@ -0,0 +1,37 @@
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::models::class_groups::entities::ClassGroup;
+use crate::models::class_users::responses::UserInfo;
+use crate::models::common::serialization::serialize_i64_as_string;
+
+/// 分组详情（包含成员数量和助教信息）
+#[derive(Debug, Serialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/class-group.ts")]
+pub struct ClassGroupDetail {
+    #[serde(flatten)]
+    pub group: ClassGroup,
+    #[serde(serialize_with = "serialize_i64_as_string")]
+    #[ts(type = "string")]
+    pub member_count: i64,
+    pub ta: Option<UserInfo>,
+}
+
+/// 分组列表响应
+#[derive(Debug, Serialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/class-group.ts")]
+pub struct ClassGroupListResponse {
+    pub items: Vec<ClassGroupDetail>,
+}
+
+/// 批量设置分组成员结果
+#[derive(Debug, Serialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/class-group.ts")]
+pub struct ClassGroupMembersResponse {
+    /// 成功更新的成员数量
+    pub updated: usize,
+    /// 跳过的用户 ID（不是该班级成员或为教师）
+    #[serde(with = "crate::models::common::serialization::vec_i64_as_string")]
+    #[ts(type = "string[]")]
+    pub skipped_ids: Vec<i64>,
+}
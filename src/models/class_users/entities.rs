@@ -82,5 +82,87 @@ pub struct ClassUser {
     #[ts(type = "string")]
     pub user_id: i64,
     pub role: ClassUserRole,
+    // 所属分组 ID
+    #[serde(default, with = "serialization::option_i64_as_string")]
+    #[ts(type = "string | null")]
+    pub group_id: Option<i64>,
     pub joined_at: chrono::DateTime<chrono::Utc>,
 }
+
+// 加入班级申请状态
+#[derive(Debug, Clone, Serialize, PartialEq, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "../frontend/src/types/generated/class-user.ts")]
+pub enum ClassJoinRequestStatus {
+    Pending,  // 待审批
+    Approved, // 已通过
+    Rejected, // 已拒绝
+}
+
+impl ClassJoinRequestStatus {
+    pub const PENDING: &'static str = "pending";
+    pub const APPROVED: &'static str = "approved";
+    pub const REJECTED: &'static str = "rejected";
+}
+
+impl<'de> Deserialize<'de> for ClassJoinRequestStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.as_str() {
+            ClassJoinRequestStatus::PENDING => Ok(ClassJoinRequestStatus::Pending),
+            ClassJoinRequestStatus::APPROVED => Ok(ClassJoinRequestStatus::Approved),
+            ClassJoinRequestStatus::REJECTED => Ok(ClassJoinRequestStatus::Rejected),
+            _ => Err(serde::de::Error::custom(format!(
+                "无效的申请状态: '{s}'. 支持的状态: pending, approved, rejected"
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for ClassJoinRequestStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClassJoinRequestStatus::Pending => write!(f, "{}", Self::PENDING),
+            ClassJoinRequestStatus::Approved => write!(f, "{}", Self::APPROVED),
+            ClassJoinRequestStatus::Rejected => write!(f, "{}", Self::REJECTED),
+        }
+    }
+}
+
+impl std::str::FromStr for ClassJoinRequestStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(ClassJoinRequestStatus::Pending),
+            "approved" => Ok(ClassJoinRequestStatus::Approved),
+            "rejected" => Ok(ClassJoinRequestStatus::Rejected),
+            _ => Err(format!("Invalid class join request status: {s}")),
+        }
+    }
+}
+
+// 加入班级申请
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/class-user.ts")]
+pub struct ClassJoinRequest {
+    #[serde(with = "serialization::i64_as_string")]
+    #[ts(type = "string")]
+    pub id: i64,
+    #[serde(with = "serialization::i64_as_string")]
+    #[ts(type = "string")]
+    pub class_id: i64,
+    #[serde(with = "serialization::i64_as_string")]
+    #[ts(type = "string")]
+    pub user_id: i64,
+    pub status: ClassJoinRequestStatus,
+    pub message: Option<String>,
+    #[serde(with = "serialization::option_i64_as_string")]
+    #[ts(type = "string | null")]
+    pub handled_by: Option<i64>,
+    pub handled_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
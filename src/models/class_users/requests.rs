@@ -1,4 +1,8 @@
-use crate::models::{class_users::entities::ClassUserRole, common::PaginationQuery};
+use crate::models::common::serialization;
+use crate::models::{
+    class_users::entities::{ClassJoinRequestStatus, ClassUserRole},
+    common::PaginationQuery,
+};
 use serde::Deserialize;
 use ts_rs::TS;
 
@@ -7,6 +11,8 @@ use ts_rs::TS;
 #[ts(export, export_to = "../frontend/src/types/generated/class-user.ts")]
 pub struct JoinClassRequest {
     pub invite_code: String,
+    /// 申请留言（仅在班级需要审批时使用）
+    pub message: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, TS)]
@@ -24,4 +30,39 @@ pub struct ClassUserListQuery {
     pub pagination: PaginationQuery,
     pub search: Option<String>,
     pub role: Option<ClassUserRole>,
+    #[serde(
+        default,
+        deserialize_with = "serialization::deserialize_option_string_to_i64"
+    )]
+    #[ts(type = "string | null")]
+    pub group_id: Option<i64>,
+}
+
+// 加入申请列表查询参数
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/class-user.ts")]
+pub struct ClassJoinRequestListQuery {
+    #[serde(flatten)]
+    #[ts(flatten)]
+    pub pagination: PaginationQuery,
+    /// 按状态筛选，默认仅显示待审批
+    pub status: Option<ClassJoinRequestStatus>,
+}
+
+// 批量审批加入申请请求
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/class-user.ts")]
+pub struct HandleClassJoinRequestsRequest {
+    #[serde(with = "serialization::vec_i64_as_string")]
+    #[ts(type = "string[]")]
+    pub request_ids: Vec<i64>,
+}
+
+// 班级名单导入参数
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/class-user.ts")]
+pub struct ClassRosterImportParams {
+    /// 是否为不存在的用户创建账号（需教师或管理员全局角色）
+    #[serde(default)]
+    pub create_missing: bool,
 }
@@ -1,10 +1,13 @@
 use serde::Serialize;
 use ts_rs::TS;
 
-use crate::models::common::serialization::serialize_i64_as_string;
+use crate::models::common::serialization::{
+    serialize_i64_as_string, serialize_option_i64_as_string,
+};
+use crate::models::users::responses::ImportRowError;
 use crate::models::{
     PaginationInfo,
-    class_users::entities::{ClassUser, ClassUserRole},
+    class_users::entities::{ClassJoinRequest, ClassJoinRequestStatus, ClassUser, ClassUserRole},
 };
 
 /// 用户简要信息
@@ -33,6 +36,9 @@ pub struct ClassUserDetail {
     #[ts(type = "string")]
     pub user_id: i64,
     pub role: ClassUserRole,
+    #[serde(serialize_with = "serialize_option_i64_as_string")]
+    #[ts(type = "string | null")]
+    pub group_id: Option<i64>,
     pub joined_at: chrono::DateTime<chrono::Utc>,
     pub user: UserInfo,
 }
@@ -52,3 +58,78 @@ pub struct ClassUserDetailListResponse {
     pub pagination: PaginationInfo,
     pub items: Vec<ClassUserDetail>,
 }
+
+/// 加入申请详情（包含申请人信息）
+#[derive(Debug, Serialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/class-user.ts")]
+pub struct ClassJoinRequestDetail {
+    #[serde(serialize_with = "serialize_i64_as_string")]
+    #[ts(type = "string")]
+    pub id: i64,
+    #[serde(serialize_with = "serialize_i64_as_string")]
+    #[ts(type = "string")]
+    pub class_id: i64,
+    pub status: ClassJoinRequestStatus,
+    pub message: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub handled_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub user: UserInfo,
+}
+
+/// 加入申请列表响应
+#[derive(Debug, Serialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/class-user.ts")]
+pub struct ClassJoinRequestListResponse {
+    pub pagination: PaginationInfo,
+    pub items: Vec<ClassJoinRequest>,
+}
+
+/// 加入申请详情列表响应
+#[derive(Debug, Serialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/class-user.ts")]
+pub struct ClassJoinRequestDetailListResponse {
+    pub pagination: PaginationInfo,
+    pub items: Vec<ClassJoinRequestDetail>,
+}
+
+/// 批量审批结果
+#[derive(Debug, Serialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/class-user.ts")]
+pub struct HandleClassJoinRequestsResponse {
+    /// 成功处理的申请数量
+    pub processed: usize,
+    /// 跳过的申请 ID（不存在、不属于该班级或已处理）
+    #[serde(with = "crate::models::common::serialization::vec_i64_as_string")]
+    #[ts(type = "string[]")]
+    pub skipped_ids: Vec<i64>,
+}
+
+/// 名单导入中成功处理的成员
+#[derive(Debug, Serialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/class-user.ts")]
+pub struct ClassRosterImportedUser {
+    /// 文件中的行号
+    pub row: usize,
+    #[serde(serialize_with = "serialize_i64_as_string")]
+    #[ts(type = "string")]
+    pub user_id: i64,
+    pub username: String,
+    pub role: ClassUserRole,
+    /// 是否为本次导入新建的账号
+    pub created: bool,
+}
+
+/// 班级名单导入响应
+#[derive(Debug, Serialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/class-user.ts")]
+pub struct ClassRosterImportResponse {
+    pub total: usize,
+    /// 成功加入班级或更新角色的行数
+    pub success: usize,
+    /// 其中新建账号的数量
+    pub created: usize,
+    pub skipped: usize,
+    pub failed: usize,
+    pub imported: Vec<ClassRosterImportedUser>,
+    pub errors: Vec<ImportRowError>,
+}
@@ -3,6 +3,62 @@ use ts_rs::TS;
 
 use crate::models::common::serialization::serialize_i64_as_string;
 
+// 班级加入策略
+#[derive(Debug, Clone, Serialize, PartialEq, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "../frontend/src/types/generated/class.ts")]
+pub enum ClassJoinPolicy {
+    Open,     // 凭邀请码直接加入
+    Approval, // 凭邀请码提交申请，需教师审批
+    Closed,   // 关闭加入
+}
+
+impl ClassJoinPolicy {
+    pub const OPEN: &'static str = "open";
+    pub const APPROVAL: &'static str = "approval";
+    pub const CLOSED: &'static str = "closed";
+}
+
+impl<'de> Deserialize<'de> for ClassJoinPolicy {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.as_str() {
+            ClassJoinPolicy::OPEN => Ok(ClassJoinPolicy::Open),
+            ClassJoinPolicy::APPROVAL => Ok(ClassJoinPolicy::Approval),
+            ClassJoinPolicy::CLOSED => Ok(ClassJoinPolicy::Closed),
+            _ => Err(serde::de::Error::custom(format!(
+                "无效的班级加入策略: '{s}'. 支持的策略: open, approval, closed"
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for ClassJoinPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClassJoinPolicy::Open => write!(f, "{}", ClassJoinPolicy::OPEN),
+            ClassJoinPolicy::Approval => write!(f, "{}", ClassJoinPolicy::APPROVAL),
+            ClassJoinPolicy::Closed => write!(f, "{}", ClassJoinPolicy::CLOSED),
+        }
+    }
+}
+
+impl std::str::FromStr for ClassJoinPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "open" => Ok(ClassJoinPolicy::Open),
+            "approval" => Ok(ClassJoinPolicy::Approval),
+            "closed" => Ok(ClassJoinPolicy::Closed),
+            _ => Err(format!("Invalid class join policy: {s}")),
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, TS)]
 #[ts(export, export_to = "../frontend/src/types/generated/class.ts")]
 pub struct Class {
@@ -20,6 +76,8 @@ pub struct Class {
     pub teacher_id: i64,
     // 邀请码
     pub invite_code: String,
+    // 加入策略
+    pub join_policy: ClassJoinPolicy,
     // 创建时间
     pub created_at: chrono::DateTime<chrono::Utc>,
     // 更新时间
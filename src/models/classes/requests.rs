@@ -1,3 +1,4 @@
+use crate::models::classes::entities::ClassJoinPolicy;
 use crate::models::common::PaginationQuery;
 use crate::models::common::serialization::deserialize_option_string_to_i64;
 use serde::Deserialize;
@@ -33,6 +34,7 @@ pub struct CreateClassRequest {
     pub teacher_id: Option<i64>,
     pub name: String,
     pub description: Option<String>,
+    pub join_policy: Option<ClassJoinPolicy>,
 }
 
 // 更新班级请求
@@ -41,6 +43,7 @@ pub struct CreateClassRequest {
 pub struct UpdateClassRequest {
     pub name: Option<String>,
     pub description: Option<String>,
+    pub join_policy: Option<ClassJoinPolicy>,
     #[ts(skip)]
     pub _teacher_id: Option<i64>, // TODO: 未来计划实现班级转让
 }
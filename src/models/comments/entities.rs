@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::models::common::serialization;
+
+// 评论目标类型
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "../frontend/src/types/generated/comment.ts")]
+pub enum CommentTargetType {
+    Announcement, // 班级公告
+    Homework,     // 作业
+}
+
+impl CommentTargetType {
+    pub const ANNOUNCEMENT: &'static str = "announcement";
+    pub const HOMEWORK: &'static str = "homework";
+}
+
+impl<'de> Deserialize<'de> for CommentTargetType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl std::fmt::Display for CommentTargetType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommentTargetType::Announcement => write!(f, "{}", Self::ANNOUNCEMENT),
+            CommentTargetType::Homework => write!(f, "{}", Self::HOMEWORK),
+        }
+    }
+}
+
+impl std::str::FromStr for CommentTargetType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "announcement" => Ok(CommentTargetType::Announcement),
+            "homework" => Ok(CommentTargetType::Homework),
+            _ => Err(format!("Invalid comment target type: {s}")),
+        }
+    }
+}
+
+/// 讨论评论（parent_id 为空表示顶层评论）
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/comment.ts")]
+pub struct Comment {
+    #[serde(with = "serialization::i64_as_string")]
+    #[ts(type = "string")]
+    pub id: i64,
+    pub target_type: CommentTargetType,
+    #[serde(with = "serialization::i64_as_string")]
+    #[ts(type = "string")]
+    pub target_id: i64,
+    #[serde(with = "serialization::i64_as_string")]
+    #[ts(type = "string")]
+    pub class_id: i64,
+    #[serde(with = "serialization::option_i64_as_string")]
+    #[ts(type = "string | null")]
+    pub parent_id: Option<i64>,
+    #[serde(with = "serialization::i64_as_string")]
+    #[ts(type = "string")]
+    pub user_id: i64,
+    pub content: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
@@ -0,0 +1,8 @@
+// 讨论评论实体定义
+pub mod entities;
+
+// 讨论评论请求模型
+pub mod requests;
+
+// 讨论评论响应模型
+pub mod responses;
@@ -0,0 +1,21 @@
+use crate::models::common::serialization::deserialize_option_string_to_i64;
+use serde::Deserialize;
+use ts_rs::TS;
+
+// 发表评论请求
+#[derive(Debug, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/comment.ts")]
+pub struct CreateCommentRequest {
+    pub content: String,
+    /// 回复的评论 ID（为空表示顶层评论）
+    #[serde(default, deserialize_with = "deserialize_option_string_to_i64")]
+    #[ts(type = "string | null")]
+    pub parent_id: Option<i64>,
+}
+
+// 编辑评论请求
+#[derive(Debug, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/comment.ts")]
+pub struct UpdateCommentRequest {
+    pub content: String,
+}
@@ -0,0 +1,24 @@
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::models::class_users::responses::UserInfo;
+use crate::models::comments::entities::Comment;
+
+/// 评论节点（包含作者信息和嵌套回复）
+#[derive(Debug, Serialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/comment.ts")]
+pub struct CommentNode {
+    #[serde(flatten)]
+    pub comment: Comment,
+    pub author: UserInfo,
+    pub replies: Vec<CommentNode>,
+}
+
+/// 评论列表响应（按发表时间排序的评论树）
+#[derive(Debug, Serialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/comment.ts")]
+pub struct CommentListResponse {
+    /// 评论总数（含回复）
+    pub total: usize,
+    pub items: Vec<CommentNode>,
+}
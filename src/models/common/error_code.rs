@@ -50,18 +50,28 @@ pub enum ErrorCode {
     UserEmailAlreadyExists = 4013, // 用户邮箱已存在
     UserPasswordInvalid = 4014,    // 密码不符合策略要求
 
+    ProfileFieldNotFound = 4020,  // 资料字段未找到
+    ProfileFieldKeyExists = 4021, // 资料字段键已存在
+    ProfileFieldInvalid = 4022,   // 资料字段定义无效
+    ProfileValueInvalid = 4023,   // 资料字段值无效
+    ProfileValueConflict = 4024,  // 资料字段值与其他用户重复
+
     // 班级相关错误
-    ClassNotFound = 5000,          // 班级未找到
-    ClassAlreadyExists = 5001,     // 班级已存在
-    ClassCreationFailed = 5002,    // 班级创建失败
-    ClassUpdateFailed = 5003,      // 班级更新失败
-    ClassDeleteFailed = 5004,      // 班级删除失败
-    ClassPermissionDenied = 5005,  // 班级权限被拒绝
-    ClassJoinFailed = 5010,        // 加入班级失败
-    ClassInviteCodeInvalid = 5011, // 班级邀请码无效
-    ClassAlreadyJoined = 5012,     // 已经加入该班级
-    ClassJoinForbidden = 5013,     // 加入班级被禁止
-    ClassUserNotFound = 5014,      // 班级用户未找到
+    ClassNotFound = 5000,            // 班级未找到
+    ClassAlreadyExists = 5001,       // 班级已存在
+    ClassCreationFailed = 5002,      // 班级创建失败
+    ClassUpdateFailed = 5003,        // 班级更新失败
+    ClassDeleteFailed = 5004,        // 班级删除失败
+    ClassPermissionDenied = 5005,    // 班级权限被拒绝
+    ClassJoinFailed = 5010,          // 加入班级失败
+    ClassInviteCodeInvalid = 5011,   // 班级邀请码无效
+    ClassAlreadyJoined = 5012,       // 已经加入该班级
+    ClassJoinForbidden = 5013,       // 加入班级被禁止
+    ClassUserNotFound = 5014,        // 班级用户未找到
+    ClassJoinRequestPending = 5015,  // 已有待审批的加入申请
+    ClassJoinRequestNotFound = 5016, // 加入申请未找到
+    ClassGroupNotFound = 5017,       // 班级分组未找到
+    ClassGroupAlreadyExists = 5018,  // 班级分组名称已存在
 
     // 通用权限错误
     PermissionDenied = 6000, // 权限被拒绝
@@ -74,23 +84,45 @@ pub enum ErrorCode {
     ExportFailed = 7010,            // 导出失败
 
     // 作业相关错误
-    HomeworkNotFound = 8000,     // 作业未找到
-    HomeworkCreateFailed = 8001, // 作业创建失败
-    HomeworkUpdateFailed = 8002, // 作业更新失败
-    HomeworkDeleteFailed = 8003, // 作业删除失败
+    HomeworkNotFound = 8000,          // 作业未找到
+    HomeworkCreateFailed = 8001,      // 作业创建失败
+    HomeworkUpdateFailed = 8002,      // 作业更新失败
+    HomeworkDeleteFailed = 8003,      // 作业删除失败
+    HomeworkStatusInvalid = 8004,     // 作业发布状态无效
+    HomeworkExtensionInvalid = 8005,  // 作业延期参数无效
+    HomeworkExtensionNotFound = 8006, // 作业延期未找到
+    HomeworkLatePolicyInvalid = 8007, // 作业迟交策略无效
+    HomeworkQuestionInvalid = 8008,   // 作业题目定义无效
+    HomeworkQuestionsLocked = 8009,   // 作业已有提交，题目不可修改
+    HomeworkAutoGraderInvalid = 8010, // 作业自动评测器无效
 
     // 提交相关错误
-    SubmissionNotFound = 9000,     // 提交未找到
-    SubmissionCreateFailed = 9001, // 提交创建失败
-    SubmissionDeleteFailed = 9002, // 提交删除失败
+    SubmissionNotFound = 9000,      // 提交未找到
+    SubmissionCreateFailed = 9001,  // 提交创建失败
+    SubmissionDeleteFailed = 9002,  // 提交删除失败
+    SubmissionPastCutoff = 9003,    // 已超过迟交截止时间
+    SubmissionAnswerInvalid = 9004, // 提交的题目作答无效
 
     // 成绩相关错误
     GradeNotFound = 10000,     // 成绩未找到
     GradeCreateFailed = 10001, // 成绩创建失败
     GradeUpdateFailed = 10002, // 成绩更新失败
+    GradeScoreInvalid = 10003, // 成绩分数或评分细则选择无效
 
     // 通知相关错误
     NotificationNotFound = 11000, // 通知未找到
+
+    // 公告与讨论相关错误
+    AnnouncementNotFound = 12000, // 公告未找到
+    CommentNotFound = 12010,      // 评论未找到
+
+    // 评分细则相关错误
+    RubricNotFound = 13000, // 评分细则未找到
+    RubricInvalid = 13001,  // 评分细则内容无效
+
+    // 题库相关错误
+    BankQuestionNotFound = 14000, // 题库题目未找到
+    BankQuestionInvalid = 14001,  // 题库题目内容无效
 }
 
 impl ErrorCode {
@@ -106,11 +138,22 @@ impl ErrorCode {
             | ErrorCode::UserNameInvalid
             | ErrorCode::UserEmailInvalid
             | ErrorCode::UserPasswordInvalid
+            | ErrorCode::ProfileFieldInvalid
+            | ErrorCode::ProfileValueInvalid
             | ErrorCode::ClassInviteCodeInvalid
             | ErrorCode::ImportFileParseFailed
             | ErrorCode::ImportFileFormatInvalid
             | ErrorCode::ImportFileMissingColumn
-            | ErrorCode::ImportFileDataInvalid => StatusCode::BAD_REQUEST,
+            | ErrorCode::ImportFileDataInvalid
+            | ErrorCode::HomeworkStatusInvalid
+            | ErrorCode::HomeworkExtensionInvalid
+            | ErrorCode::HomeworkLatePolicyInvalid
+            | ErrorCode::HomeworkQuestionInvalid
+            | ErrorCode::HomeworkAutoGraderInvalid
+            | ErrorCode::SubmissionAnswerInvalid
+            | ErrorCode::GradeScoreInvalid
+            | ErrorCode::RubricInvalid
+            | ErrorCode::BankQuestionInvalid => StatusCode::BAD_REQUEST,
 
             ErrorCode::Unauthorized | ErrorCode::AuthFailed | ErrorCode::RegisterFailed => {
                 StatusCode::UNAUTHORIZED
@@ -119,24 +162,38 @@ impl ErrorCode {
             ErrorCode::Forbidden
             | ErrorCode::PermissionDenied
             | ErrorCode::ClassPermissionDenied
-            | ErrorCode::ClassJoinForbidden => StatusCode::FORBIDDEN,
+            | ErrorCode::ClassJoinForbidden
+            | ErrorCode::SubmissionPastCutoff => StatusCode::FORBIDDEN,
 
             ErrorCode::NotFound
             | ErrorCode::FileNotFound
             | ErrorCode::UserNotFound
+            | ErrorCode::ProfileFieldNotFound
             | ErrorCode::ClassNotFound
             | ErrorCode::ClassUserNotFound
+            | ErrorCode::ClassJoinRequestNotFound
+            | ErrorCode::ClassGroupNotFound
             | ErrorCode::HomeworkNotFound
+            | ErrorCode::HomeworkExtensionNotFound
             | ErrorCode::SubmissionNotFound
             | ErrorCode::GradeNotFound
-            | ErrorCode::NotificationNotFound => StatusCode::NOT_FOUND,
+            | ErrorCode::NotificationNotFound
+            | ErrorCode::AnnouncementNotFound
+            | ErrorCode::CommentNotFound
+            | ErrorCode::RubricNotFound
+            | ErrorCode::BankQuestionNotFound => StatusCode::NOT_FOUND,
 
             ErrorCode::Conflict
             | ErrorCode::UserAlreadyExists
             | ErrorCode::UserNameAlreadyExists
             | ErrorCode::UserEmailAlreadyExists
+            | ErrorCode::ProfileFieldKeyExists
+            | ErrorCode::ProfileValueConflict
             | ErrorCode::ClassAlreadyExists
-            | ErrorCode::ClassAlreadyJoined => StatusCode::CONFLICT,
+            | ErrorCode::ClassAlreadyJoined
+            | ErrorCode::ClassJoinRequestPending
+            | ErrorCode::ClassGroupAlreadyExists
+            | ErrorCode::HomeworkQuestionsLocked => StatusCode::CONFLICT,
 
             ErrorCode::RateLimitExceeded => StatusCode::TOO_MANY_REQUESTS,
 
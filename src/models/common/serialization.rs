@@ -256,3 +256,98 @@ pub mod option_i64_as_string {
         super::deserialize_option_string_to_i64(deserializer)
     }
 }
+
+/// Serde module: Vec<i64> 序列化/反序列化为字符串数组
+///
+/// 反序列化时每个元素同时支持数字和字符串。
+///
+/// 使用方式：
+/// ```rust,ignore
+/// use crate::models::common::serialization;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Example {
+///     #[serde(with = "serialization::vec_i64_as_string")]
+///     ids: Vec<i64>,
+/// }
+/// ```
+pub mod vec_i64_as_string {
+    use super::*;
+    use serde::ser::SerializeSeq;
+
+    pub fn serialize<S>(value: &[i64], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(value.len()))?;
+        for v in value {
+            seq.serialize_element(&v.to_string())?;
+        }
+        seq.end()
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<i64>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        struct Item(#[serde(deserialize_with = "super::deserialize_string_to_i64")] i64);
+
+        let items: Vec<Item> = serde::Deserialize::deserialize(deserializer)?;
+        Ok(items.into_iter().map(|item| item.0).collect())
+    }
+}
+
+/// Serde module: Option<Vec<i64>> 序列化/反序列化为字符串数组
+///
+/// 用于更新请求中"未提供"与"提供列表"需要区分的场景，需配合 `#[serde(default)]` 使用。
+pub mod option_vec_i64_as_string {
+    use super::*;
+
+    pub fn serialize<S>(value: &Option<Vec<i64>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match value {
+            Some(v) => super::vec_i64_as_string::serialize(v, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Vec<i64>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        struct Wrapper(#[serde(with = "super::vec_i64_as_string")] Vec<i64>);
+
+        let value: Option<Wrapper> = serde::Deserialize::deserialize(deserializer)?;
+        Ok(value.map(|w| w.0))
+    }
+}
+
+/// 反序列化可清空的更新字段
+///
+/// 需配合 `#[serde(default)]` 使用，区分三种输入：
+/// - 未提供: `None`（保持不变）
+/// - null: `Some(None)`（清空）
+/// - 有值: `Some(Some(value))`（更新）
+pub fn deserialize_nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: serde::Deserialize<'de>,
+{
+    serde::Deserialize::deserialize(deserializer).map(Some)
+}
+
+/// 反序列化可清空的 ID 更新字段（支持字符串或数字）
+///
+/// 需配合 `#[serde(default)]` 使用，语义同 [`deserialize_nullable`]
+pub fn deserialize_nullable_string_to_i64<'de, D>(
+    deserializer: D,
+) -> Result<Option<Option<i64>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_option_string_to_i64(deserializer).map(Some)
+}
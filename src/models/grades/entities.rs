@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
 use crate::models::common::serialization::serialize_i64_as_string;
+use crate::models::rubrics::entities::RubricScore;
 
 /// 评分实体
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -16,8 +17,19 @@ pub struct Grade {
     #[serde(serialize_with = "serialize_i64_as_string")]
     #[ts(type = "string")]
     pub grader_id: i64,
+    /// 原始分数
     pub score: f64,
+    /// 迟交扣分
+    pub penalty: f64,
+    /// 教师调整后的最终得分（覆盖迟交扣分计算结果）
+    pub score_override: Option<f64>,
+    /// 最终得分
+    pub effective_score: f64,
     pub comment: Option<String>,
+    /// 按评分细则评分时各评分项的得分明细
+    pub rubric_scores: Vec<RubricScore>,
+    /// 是否仍有主观题等待教师批阅
+    pub pending_review: bool,
     pub graded_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -1,7 +1,9 @@
 use crate::models::common::PaginationQuery;
 use crate::models::common::serialization::{
-    deserialize_option_string_to_i64, deserialize_string_to_i64,
+    deserialize_nullable, deserialize_option_string_to_i64, deserialize_string_to_i64,
 };
+use crate::models::homework_questions::requests::QuestionScoreInput;
+use crate::models::rubrics::requests::RubricSelection;
 use serde::Deserialize;
 use ts_rs::TS;
 
@@ -12,8 +14,13 @@ pub struct CreateGradeRequest {
     #[serde(deserialize_with = "deserialize_string_to_i64")]
     #[ts(type = "string")]
     pub submission_id: i64,
-    pub score: f64,
+    /// 原始分数（按评分细则评分时由各评分项得分合计，无需填写）
+    pub score: Option<f64>,
+    /// 教师调整的最终得分（不填则按迟交扣分规则计算）
+    pub score_override: Option<f64>,
     pub comment: Option<String>,
+    /// 按评分细则评分：每个评分项选择一个档位
+    pub rubric_scores: Option<Vec<RubricSelection>>,
 }
 
 /// 更新评分请求
@@ -21,7 +28,15 @@ pub struct CreateGradeRequest {
 #[ts(export, export_to = "../frontend/src/types/generated/grade.ts")]
 pub struct UpdateGradeRequest {
     pub score: Option<f64>,
+    /// 教师调整的最终得分，传 null 取消调整
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    #[ts(type = "number | null", optional)]
+    pub score_override: Option<Option<f64>>,
     pub comment: Option<String>,
+    /// 按评分细则重新评分（只传 score 时清除原有评分明细）
+    pub rubric_scores: Option<Vec<RubricSelection>>,
+    /// 按题目给分（批阅简答题或调整自动评分），原始分数由各题得分合计
+    pub question_scores: Option<Vec<QuestionScoreInput>>,
 }
 
 /// 评分列表查询参数
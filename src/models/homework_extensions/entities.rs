@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::models::common::serialization;
+
+/// 作业截止时间延期，针对单个学生或班级分组（二者必居其一）
+///
+/// 学生的有效截止时间优先取个人延期，其次取所在分组的延期，最后取作业截止时间。
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../frontend/src/types/generated/homework-extension.ts"
+)]
+pub struct HomeworkExtension {
+    #[serde(with = "serialization::i64_as_string")]
+    #[ts(type = "string")]
+    pub id: i64,
+    #[serde(with = "serialization::i64_as_string")]
+    #[ts(type = "string")]
+    pub homework_id: i64,
+    #[serde(with = "serialization::option_i64_as_string")]
+    #[ts(type = "string | null")]
+    pub user_id: Option<i64>,
+    #[serde(with = "serialization::option_i64_as_string")]
+    #[ts(type = "string | null")]
+    pub group_id: Option<i64>,
+    /// 延期后的截止时间
+    pub deadline: chrono::DateTime<chrono::Utc>,
+    /// 延期原因（如病假）
+    pub reason: Option<String>,
+    #[serde(with = "serialization::i64_as_string")]
+    #[ts(type = "string")]
+    pub created_by: i64,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
@@ -0,0 +1,8 @@
+// 作业延期实体定义
+pub mod entities;
+
+// 作业延期请求模型
+pub mod requests;
+
+// 作业延期响应模型
+pub mod responses;
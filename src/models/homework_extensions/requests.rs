@@ -0,0 +1,21 @@
+use crate::models::common::serialization::deserialize_option_string_to_i64;
+use serde::Deserialize;
+use ts_rs::TS;
+
+// 设置延期请求（同一学生或分组已有延期时覆盖）
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../frontend/src/types/generated/homework-extension.ts"
+)]
+pub struct SetHomeworkExtensionRequest {
+    // user_id 与 group_id 必须且只能提供一个
+    #[serde(default, deserialize_with = "deserialize_option_string_to_i64")]
+    #[ts(type = "string | null")]
+    pub user_id: Option<i64>,
+    #[serde(default, deserialize_with = "deserialize_option_string_to_i64")]
+    #[ts(type = "string | null")]
+    pub group_id: Option<i64>,
+    pub deadline: chrono::DateTime<chrono::Utc>,
+    pub reason: Option<String>,
+}
@@ -0,0 +1,28 @@
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::models::class_users::responses::UserInfo;
+use crate::models::homework_extensions::entities::HomeworkExtension;
+
+/// 延期详情（包含学生信息或分组名称）
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../frontend/src/types/generated/homework-extension.ts"
+)]
+pub struct HomeworkExtensionDetail {
+    #[serde(flatten)]
+    pub extension: HomeworkExtension,
+    pub user: Option<UserInfo>,
+    pub group_name: Option<String>,
+}
+
+/// 延期列表响应
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../frontend/src/types/generated/homework-extension.ts"
+)]
+pub struct HomeworkExtensionListResponse {
+    pub items: Vec<HomeworkExtensionDetail>,
+}
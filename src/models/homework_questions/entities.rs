@@ -0,0 +1,384 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::models::common::serialization::{
+    serialize_i64_as_string, serialize_option_i64_as_string,
+};
+use crate::models::files::responses::FileInfo;
+
+/// 单选、多选题选项数量上限
+const MAX_OPTIONS: usize = 20;
+
+/// 数值比较的浮点误差
+const NUMERIC_EPSILON: f64 = 1e-9;
+
+/// 题目类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(
+    export,
+    export_to = "../frontend/src/types/generated/homework_question.ts"
+)]
+pub enum QuestionType {
+    SingleChoice,
+    MultipleChoice,
+    TrueFalse,
+    FillBlank,
+    Numeric,
+    Essay,
+}
+
+/// 题目定义（答案字段仅教师可见，学生视角为 null）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[ts(
+    export,
+    export_to = "../frontend/src/types/generated/homework_question.ts"
+)]
+pub enum QuestionSpec {
+    /// 单选题，answer 为正确选项下标
+    SingleChoice {
+        options: Vec<String>,
+        answer: Option<usize>,
+    },
+    /// 多选题，须选中全部正确选项且不多选才得分
+    MultipleChoice {
+        options: Vec<String>,
+        answers: Option<Vec<usize>>,
+    },
+    /// 判断题
+    TrueFalse { answer: Option<bool> },
+    /// 填空题，与任一可接受答案一致即得分（忽略首尾空白）
+    FillBlank {
+        accepted_answers: Option<Vec<String>>,
+        #[serde(default)]
+        case_sensitive: bool,
+    },
+    /// 数值题，与答案的差不超过 tolerance 即得分
+    Numeric {
+        answer: Option<f64>,
+        tolerance: Option<f64>,
+    },
+    /// 简答题，由教师批阅
+    Essay,
+}
+
+/// 学生作答内容
+///
+/// 单选题为选项下标，多选题为选项下标数组，判断题为布尔值，
+/// 填空题和简答题为文本，数值题为数字。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(untagged)]
+#[ts(
+    export,
+    export_to = "../frontend/src/types/generated/homework_question.ts"
+)]
+pub enum AnswerValue {
+    Boolean(bool),
+    Number(f64),
+    Text(String),
+    Choices(Vec<usize>),
+}
+
+impl QuestionSpec {
+    pub fn question_type(&self) -> QuestionType {
+        match self {
+            QuestionSpec::SingleChoice { .. } => QuestionType::SingleChoice,
+            QuestionSpec::MultipleChoice { .. } => QuestionType::MultipleChoice,
+            QuestionSpec::TrueFalse { .. } => QuestionType::TrueFalse,
+            QuestionSpec::FillBlank { .. } => QuestionType::FillBlank,
+            QuestionSpec::Numeric { .. } => QuestionType::Numeric,
+            QuestionSpec::Essay => QuestionType::Essay,
+        }
+    }
+
+    /// 是否需要教师批阅
+    pub fn is_manual(&self) -> bool {
+        matches!(self, QuestionSpec::Essay)
+    }
+
+    /// 校验题目定义（选项与标准答案）
+    pub fn validate(&self) -> Result<(), String> {
+        let check_options = |options: &[String]| {
+            if options.len() < 2 || options.len() > MAX_OPTIONS {
+                return Err(format!("选项数量须在 2 到 {MAX_OPTIONS} 之间"));
+            }
+            if options.iter().any(|o| o.trim().is_empty()) {
+                return Err("选项内容不能为空".to_string());
+            }
+            Ok(())
+        };
+        match self {
+            QuestionSpec::SingleChoice { options, answer } => {
+                check_options(options)?;
+                match answer {
+                    Some(a) if *a < options.len() => {}
+                    _ => return Err("单选题须指定有效的正确选项".to_string()),
+                }
+            }
+            QuestionSpec::MultipleChoice { options, answers } => {
+                check_options(options)?;
+                let Some(answers) = answers.as_ref().filter(|a| !a.is_empty()) else {
+                    return Err("多选题须至少指定一个正确选项".to_string());
+                };
+                if answers.iter().any(|a| *a >= options.len()) {
+                    return Err("多选题的正确选项超出范围".to_string());
+                }
+            }
+            QuestionSpec::TrueFalse { answer } => {
+                if answer.is_none() {
+                    return Err("判断题须指定正确答案".to_string());
+                }
+            }
+            QuestionSpec::FillBlank {
+                accepted_answers, ..
+            } => {
+                let valid = accepted_answers
+                    .as_ref()
+                    .is_some_and(|a| !a.is_empty() && a.iter().all(|s| !s.trim().is_empty()));
+                if !valid {
+                    return Err("填空题须至少指定一个非空的可接受答案".to_string());
+                }
+            }
+            QuestionSpec::Numeric { answer, tolerance } => {
+                if !answer.is_some_and(f64::is_finite) {
+                    return Err("数值题须指定正确答案".to_string());
+                }
+                if tolerance.is_some_and(|t| !t.is_finite() || t < 0.0) {
+                    return Err("数值题的误差范围不能为负数".to_string());
+                }
+            }
+            QuestionSpec::Essay => {}
+        }
+        Ok(())
+    }
+
+    /// 隐藏标准答案（学生视角）
+    pub fn redacted(&self) -> QuestionSpec {
+        match self {
+            QuestionSpec::SingleChoice { options, .. } => QuestionSpec::SingleChoice {
+                options: options.clone(),
+                answer: None,
+            },
+            QuestionSpec::MultipleChoice { options, .. } => QuestionSpec::MultipleChoice {
+                options: options.clone(),
+                answers: None,
+            },
+            QuestionSpec::TrueFalse { .. } => QuestionSpec::TrueFalse { answer: None },
+            QuestionSpec::FillBlank { .. } => QuestionSpec::FillBlank {
+                accepted_answers: None,
+                case_sensitive: false,
+            },
+            QuestionSpec::Numeric { .. } => QuestionSpec::Numeric {
+                answer: None,
+                tolerance: None,
+            },
+            QuestionSpec::Essay => QuestionSpec::Essay,
+        }
+    }
+
+    /// 规范化作答内容，作答形式与题型不符时返回错误
+    pub fn normalize_answer(&self, answer: AnswerValue) -> Result<AnswerValue, String> {
+        match (self, answer) {
+            (QuestionSpec::SingleChoice { options, .. }, AnswerValue::Number(n))
+                if n.fract() == 0.0 && n >= 0.0 && (n as usize) < options.len() =>
+            {
+                Ok(AnswerValue::Number(n))
+            }
+            (QuestionSpec::MultipleChoice { options, .. }, AnswerValue::Choices(mut choices))
+                if choices.iter().all(|c| *c < options.len()) =>
+            {
+                choices.sort_unstable();
+                choices.dedup();
+                Ok(AnswerValue::Choices(choices))
+            }
+            (QuestionSpec::TrueFalse { .. }, AnswerValue::Boolean(b)) => {
+                Ok(AnswerValue::Boolean(b))
+            }
+            (QuestionSpec::FillBlank { .. } | QuestionSpec::Essay, AnswerValue::Text(text)) => {
+                Ok(AnswerValue::Text(text.trim().to_string()))
+            }
+            (QuestionSpec::Numeric { .. }, AnswerValue::Number(n)) if n.is_finite() => {
+                Ok(AnswerValue::Number(n))
+            }
+            _ => Err("作答内容与题型不符".to_string()),
+        }
+    }
+
+    /// 判断作答是否正确，简答题返回 None
+    pub fn check(&self, answer: &AnswerValue) -> Option<bool> {
+        let correct = match (self, answer) {
+            (QuestionSpec::SingleChoice { answer: key, .. }, AnswerValue::Number(n)) => {
+                key.is_some_and(|k| k as f64 == *n)
+            }
+            (QuestionSpec::MultipleChoice { answers: key, .. }, AnswerValue::Choices(choices)) => {
+                key.as_ref().is_some_and(|k| {
+                    let mut k = k.clone();
+                    k.sort_unstable();
+                    k.dedup();
+                    k == *choices
+                })
+            }
+            (QuestionSpec::TrueFalse { answer: key }, AnswerValue::Boolean(b)) => *key == Some(*b),
+            (
+                QuestionSpec::FillBlank {
+                    accepted_answers,
+                    case_sensitive,
+                },
+                AnswerValue::Text(text),
+            ) => accepted_answers.iter().flatten().any(|accepted| {
+                let accepted = accepted.trim();
+                if *case_sensitive {
+                    accepted == text.trim()
+                } else {
+                    accepted.to_lowercase() == text.trim().to_lowercase()
+                }
+            }),
+            (
+                QuestionSpec::Numeric {
+                    answer: key,
+                    tolerance,
+                },
+                AnswerValue::Number(n),
+            ) => key.is_some_and(|k| (k - n).abs() <= tolerance.unwrap_or(0.0) + NUMERIC_EPSILON),
+            (QuestionSpec::Essay, _) => return None,
+            _ => false,
+        };
+        Some(correct)
+    }
+}
+
+/// 作业题目
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../frontend/src/types/generated/homework_question.ts"
+)]
+pub struct HomeworkQuestion {
+    #[serde(serialize_with = "serialize_i64_as_string")]
+    #[ts(type = "string")]
+    pub id: i64,
+    #[serde(serialize_with = "serialize_i64_as_string")]
+    #[ts(type = "string")]
+    pub homework_id: i64,
+    /// 题目序号（从 0 开始）
+    pub position: i32,
+    /// 题干
+    pub prompt: String,
+    /// 分值
+    pub points: f64,
+    pub spec: QuestionSpec,
+    /// 所属随机抽题池（为空表示固定题目）
+    #[serde(serialize_with = "serialize_option_i64_as_string")]
+    #[ts(type = "string | null")]
+    pub pool_id: Option<i64>,
+    /// 题目附件
+    #[serde(skip_deserializing)]
+    pub attachments: Vec<FileInfo>,
+}
+
+impl HomeworkQuestion {
+    /// 学生视角：隐藏标准答案
+    pub fn redacted(mut self) -> Self {
+        self.spec = self.spec.redacted();
+        self
+    }
+}
+
+/// 随机抽题池
+///
+/// 池内候选题目为 pool_id 指向该池的作业题目，每个学生抽取 draw_count 道，
+/// 每道题分值均为 points。
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../frontend/src/types/generated/homework_question.ts"
+)]
+pub struct HomeworkQuestionPool {
+    #[serde(serialize_with = "serialize_i64_as_string")]
+    #[ts(type = "string")]
+    pub id: i64,
+    #[serde(serialize_with = "serialize_i64_as_string")]
+    #[ts(type = "string")]
+    pub homework_id: i64,
+    /// 抽题条件说明（如「标签：函数」）
+    pub label: String,
+    /// 每个学生抽取的题目数量
+    pub draw_count: i32,
+    /// 每道题的分值
+    pub points: f64,
+}
+
+/// 作业题目总分：固定题目分值之和加上各抽题池的抽取分值
+pub fn question_total(questions: &[HomeworkQuestion], pools: &[HomeworkQuestionPool]) -> f64 {
+    let fixed: f64 = questions
+        .iter()
+        .filter(|q| q.pool_id.is_none())
+        .map(|q| q.points)
+        .sum();
+    let drawn: f64 = pools.iter().map(|p| p.draw_count as f64 * p.points).sum();
+    fixed + drawn
+}
+
+/// 选出某个学生的题目变体
+///
+/// 固定题目全部保留；每个抽题池以 (作业, 学生, 抽题池) 为种子对候选题目排序后
+/// 取前 draw_count 道，同一学生每次得到的题目相同。结果按题目顺序排列并重新编号。
+pub fn select_question_variant(
+    questions: Vec<HomeworkQuestion>,
+    pools: &[HomeworkQuestionPool],
+    user_id: i64,
+) -> Vec<HomeworkQuestion> {
+    let mut selected: Vec<HomeworkQuestion> = Vec::with_capacity(questions.len());
+    let mut candidates: std::collections::HashMap<i64, Vec<HomeworkQuestion>> =
+        std::collections::HashMap::new();
+    for question in questions {
+        match question.pool_id {
+            Some(pool_id) => candidates.entry(pool_id).or_default().push(question),
+            None => selected.push(question),
+        }
+    }
+
+    for pool in pools {
+        let Some(mut pool_questions) = candidates.remove(&pool.id) else {
+            continue;
+        };
+        let seed = splitmix64(
+            splitmix64(splitmix64(pool.homework_id as u64) ^ user_id as u64) ^ pool.id as u64,
+        );
+        pool_questions.sort_by_key(|q| (splitmix64(seed ^ q.id as u64), q.id));
+        pool_questions.truncate(pool.draw_count.max(0) as usize);
+        selected.extend(pool_questions);
+    }
+
+    selected.sort_by_key(|q| (q.position, q.id));
+    for (position, question) in selected.iter_mut().enumerate() {
+        question.position = position as i32;
+    }
+    selected
+}
+
+/// SplitMix64 混合函数（用于稳定的伪随机排序）
+fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// 提交中的作答记录
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../frontend/src/types/generated/homework_question.ts"
+)]
+pub struct SubmissionAnswer {
+    #[serde(serialize_with = "serialize_i64_as_string")]
+    #[ts(type = "string")]
+    pub question_id: i64,
+    pub answer: AnswerValue,
+    /// 是否正确（简答题为 null）
+    pub is_correct: Option<bool>,
+    /// 得分（简答题待批阅时为 null）
+    pub points: Option<f64>,
+}
@@ -0,0 +1,8 @@
+// 作业题目实体定义
+pub mod entities;
+
+// 作业题目请求模型
+pub mod requests;
+
+// 作业题目响应模型
+pub mod responses;
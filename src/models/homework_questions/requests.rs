@@ -0,0 +1,43 @@
+use serde::Deserialize;
+use ts_rs::TS;
+
+use super::entities::{AnswerValue, QuestionSpec};
+use crate::models::common::serialization::deserialize_string_to_i64;
+
+/// 题目定义（创建或整体替换作业题目时使用）
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../frontend/src/types/generated/homework_question.ts"
+)]
+pub struct HomeworkQuestionInput {
+    pub prompt: String,
+    pub points: f64,
+    pub spec: QuestionSpec,
+}
+
+/// 学生对单个题目的作答
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../frontend/src/types/generated/homework_question.ts"
+)]
+pub struct SubmissionAnswerInput {
+    #[serde(deserialize_with = "deserialize_string_to_i64")]
+    #[ts(type = "string")]
+    pub question_id: i64,
+    pub answer: AnswerValue,
+}
+
+/// 教师对单个题目给分（用于批阅简答题或调整自动评分）
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../frontend/src/types/generated/homework_question.ts"
+)]
+pub struct QuestionScoreInput {
+    #[serde(deserialize_with = "deserialize_string_to_i64")]
+    #[ts(type = "string")]
+    pub question_id: i64,
+    pub points: f64,
+}
@@ -0,0 +1,46 @@
+use serde::Serialize;
+use ts_rs::TS;
+
+use super::entities::{HomeworkQuestion, HomeworkQuestionPool, QuestionType};
+use crate::models::common::serialization::serialize_i64_as_string;
+
+/// 题目作答统计
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../frontend/src/types/generated/homework_question.ts"
+)]
+pub struct QuestionStat {
+    #[serde(serialize_with = "serialize_i64_as_string")]
+    #[ts(type = "string")]
+    pub question_id: i64,
+    pub position: i32,
+    pub prompt: String,
+    pub question_type: QuestionType,
+    pub points: f64,
+    /// 作答人数
+    #[serde(serialize_with = "serialize_i64_as_string")]
+    #[ts(type = "string")]
+    pub answered_count: i64,
+    /// 答对人数（简答题为 0）
+    #[serde(serialize_with = "serialize_i64_as_string")]
+    #[ts(type = "string")]
+    pub correct_count: i64,
+    /// 正确率（百分比，按作答人数计算；简答题为 null）
+    pub correct_rate: Option<f64>,
+    /// 平均得分（按已给分的作答计算）
+    pub average_points: Option<f64>,
+}
+
+/// 作业题目列表（教师视角，含随机抽题池）
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../frontend/src/types/generated/homework_question.ts"
+)]
+pub struct HomeworkQuestionsResponse {
+    pub questions: Vec<HomeworkQuestion>,
+    pub question_pools: Vec<HomeworkQuestionPool>,
+    /// 每个学生的题目总分
+    pub total_points: f64,
+}
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
-use crate::models::common::serialization::serialize_i64_as_string;
+use crate::models::common::serialization::{self, serialize_i64_as_string};
 
 /// 作业用户状态（学生视角）
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
@@ -31,6 +31,155 @@ pub enum DeadlineFilter {
     All,
 }
 
+/// 作业发布状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "../frontend/src/types/generated/homework.ts")]
+pub enum HomeworkStatus {
+    /// 草稿，仅教师可见
+    Draft,
+    /// 定时发布，到达 publish_at 后自动发布
+    Scheduled,
+    /// 已发布，学生可见
+    Published,
+}
+
+impl HomeworkStatus {
+    pub const DRAFT: &'static str = "draft";
+    pub const SCHEDULED: &'static str = "scheduled";
+    pub const PUBLISHED: &'static str = "published";
+}
+
+impl<'de> Deserialize<'de> for HomeworkStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.as_str() {
+            HomeworkStatus::DRAFT => Ok(HomeworkStatus::Draft),
+            HomeworkStatus::SCHEDULED => Ok(HomeworkStatus::Scheduled),
+            HomeworkStatus::PUBLISHED => Ok(HomeworkStatus::Published),
+            _ => Err(serde::de::Error::custom(format!(
+                "无效的作业状态: '{s}'. 支持的状态: draft, scheduled, published"
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for HomeworkStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HomeworkStatus::Draft => write!(f, "{}", HomeworkStatus::DRAFT),
+            HomeworkStatus::Scheduled => write!(f, "{}", HomeworkStatus::SCHEDULED),
+            HomeworkStatus::Published => write!(f, "{}", HomeworkStatus::PUBLISHED),
+        }
+    }
+}
+
+impl std::str::FromStr for HomeworkStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "draft" => Ok(HomeworkStatus::Draft),
+            "scheduled" => Ok(HomeworkStatus::Scheduled),
+            "published" => Ok(HomeworkStatus::Published),
+            _ => Err(format!("Invalid homework status: {s}")),
+        }
+    }
+}
+
+/// 迟交扣分规则
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[ts(export, export_to = "../frontend/src/types/generated/homework.ts")]
+pub enum LatePenalty {
+    /// 迟交即扣除固定分数
+    Fixed { deduction: f64 },
+    /// 每迟交一天（不足一天按一天计）扣除原始分数的百分比，可设置扣除上限
+    PercentPerDay {
+        percent: f64,
+        max_percent: Option<f64>,
+    },
+    /// 阶梯扣分：取第一档 `days` 不小于迟交天数的扣分比例，超出最后一档按最后一档计
+    Steps { steps: Vec<LatePenaltyStep> },
+}
+
+/// 阶梯扣分档位
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/homework.ts")]
+pub struct LatePenaltyStep {
+    /// 迟交天数上限（含）
+    pub days: u32,
+    /// 扣除原始分数的百分比
+    pub percent: f64,
+}
+
+impl LatePenalty {
+    /// 校验规则参数
+    pub fn validate(&self) -> Result<(), String> {
+        let valid_percent = |p: f64| (0.0..=100.0).contains(&p);
+        match self {
+            LatePenalty::Fixed { deduction } => {
+                if !deduction.is_finite() || *deduction < 0.0 {
+                    return Err("固定扣分不能为负数".to_string());
+                }
+            }
+            LatePenalty::PercentPerDay {
+                percent,
+                max_percent,
+            } => {
+                if !valid_percent(*percent) || max_percent.is_some_and(|m| !valid_percent(m)) {
+                    return Err("扣分百分比必须在 0 到 100 之间".to_string());
+                }
+            }
+            LatePenalty::Steps { steps } => {
+                if steps.is_empty() {
+                    return Err("阶梯扣分至少需要一档".to_string());
+                }
+                if steps
+                    .iter()
+                    .any(|s| s.days == 0 || !valid_percent(s.percent))
+                {
+                    return Err("阶梯天数必须大于 0，扣分百分比必须在 0 到 100 之间".to_string());
+                }
+                if steps.windows(2).any(|w| w[0].days >= w[1].days) {
+                    return Err("阶梯天数必须递增".to_string());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// 计算迟交扣分（分数），`late_seconds` 为超过截止时间的秒数
+    pub fn penalty(&self, score: f64, late_seconds: i64) -> f64 {
+        if late_seconds <= 0 {
+            return 0.0;
+        }
+        let late_days = (late_seconds + 86_399) / 86_400;
+        let penalty = match self {
+            LatePenalty::Fixed { deduction } => *deduction,
+            LatePenalty::PercentPerDay {
+                percent,
+                max_percent,
+            } => {
+                let percent = (percent * late_days as f64).min(max_percent.unwrap_or(100.0));
+                score * percent / 100.0
+            }
+            LatePenalty::Steps { steps } => {
+                let step = steps
+                    .iter()
+                    .find(|s| i64::from(s.days) >= late_days)
+                    .or(steps.last());
+                step.map_or(0.0, |s| score * s.percent / 100.0)
+            }
+        };
+        // 扣分不超过原始分数，保留两位小数
+        (penalty.min(score).max(0.0) * 100.0).round() / 100.0
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(export, export_to = "../frontend/src/types/generated/homework.ts")]
 pub struct Homework {
@@ -52,6 +201,24 @@ pub struct Homework {
     pub deadline: Option<chrono::DateTime<chrono::Utc>>,
     // 是否允许迟交
     pub allow_late: bool,
+    // 迟交截止时间，超过后不再接受提交
+    pub late_cutoff: Option<chrono::DateTime<chrono::Utc>>,
+    // 迟交扣分规则
+    pub late_penalty: Option<LatePenalty>,
+    // 关联的评分细则
+    #[serde(with = "serialization::option_i64_as_string")]
+    #[ts(type = "string | null")]
+    pub rubric_id: Option<i64>,
+    // 自动评测器名称
+    pub auto_grader: Option<String>,
+    // 发布状态
+    pub status: HomeworkStatus,
+    // 发布时间（定时发布的计划时间，或实际发布时间）
+    pub publish_at: Option<chrono::DateTime<chrono::Utc>>,
+    // 分配的班级分组 ID（为空表示面向全班）
+    #[serde(default, with = "serialization::vec_i64_as_string")]
+    #[ts(type = "string[]")]
+    pub group_ids: Vec<i64>,
     // 创建者 ID
     #[serde(serialize_with = "serialize_i64_as_string")]
     #[ts(type = "string")]
@@ -61,3 +228,10 @@ pub struct Homework {
     // 作业更新时间
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
+
+impl Homework {
+    /// 是否已对学生发布
+    pub fn is_published(&self) -> bool {
+        self.status == HomeworkStatus::Published
+    }
+}
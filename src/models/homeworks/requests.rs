@@ -1,8 +1,11 @@
 use crate::models::common::pagination::PaginationQuery;
 use crate::models::common::serialization::{
-    deserialize_option_string_to_i64, deserialize_string_to_i64,
+    self, deserialize_option_string_to_i64, deserialize_string_to_i64,
+};
+use crate::models::homework_questions::requests::HomeworkQuestionInput;
+use crate::models::homeworks::entities::{
+    DeadlineFilter, HomeworkStatus, HomeworkUserStatus, LatePenalty,
 };
-use crate::models::homeworks::entities::{DeadlineFilter, HomeworkUserStatus};
 use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use ts_rs::TS;
@@ -19,7 +22,27 @@ pub struct CreateHomeworkRequest {
     pub max_score: Option<f64>,
     pub deadline: Option<DateTime<Utc>>, // ISO 8601 格式，如 "2026-01-24T12:00:00Z"
     pub allow_late: Option<bool>,
+    /// 迟交截止时间（须晚于截止时间），超过后不再接受提交
+    pub late_cutoff: Option<DateTime<Utc>>,
+    /// 迟交扣分规则
+    pub late_penalty: Option<LatePenalty>,
+    /// 关联的评分细则 ID
+    #[serde(default, deserialize_with = "deserialize_option_string_to_i64")]
+    #[ts(type = "string | null")]
+    pub rubric_id: Option<i64>,
+    /// 自动评测器名称（须为服务器配置中的评测器）
+    pub auto_grader: Option<String>,
     pub attachments: Option<Vec<String>>, // download_token 列表
+    /// 发布状态，不填时有 publish_at 为 scheduled，否则为 published
+    pub status: Option<HomeworkStatus>,
+    /// 定时发布时间（status 为 scheduled 时必填，且须晚于当前时间）
+    pub publish_at: Option<DateTime<Utc>>,
+    /// 分配的班级分组 ID，不填或为空表示面向全班
+    #[serde(default, with = "serialization::option_vec_i64_as_string")]
+    #[ts(type = "string[] | null")]
+    pub group_ids: Option<Vec<i64>>,
+    /// 客观题/简答题列表，按顺序编号
+    pub questions: Option<Vec<HomeworkQuestionInput>>,
 }
 
 /// 更新作业请求
@@ -31,7 +54,36 @@ pub struct UpdateHomeworkRequest {
     pub max_score: Option<f64>,
     pub deadline: Option<DateTime<Utc>>, // ISO 8601 格式
     pub allow_late: Option<bool>,
+    /// 迟交截止时间，传 null 取消
+    #[serde(default, deserialize_with = "serialization::deserialize_nullable")]
+    #[ts(type = "string | null", optional)]
+    pub late_cutoff: Option<Option<DateTime<Utc>>>,
+    /// 迟交扣分规则，传 null 取消
+    #[serde(default, deserialize_with = "serialization::deserialize_nullable")]
+    #[ts(type = "LatePenalty | null", optional)]
+    pub late_penalty: Option<Option<LatePenalty>>,
+    /// 关联的评分细则 ID，传 null 取消关联
+    #[serde(
+        default,
+        deserialize_with = "serialization::deserialize_nullable_string_to_i64"
+    )]
+    #[ts(type = "string | null", optional)]
+    pub rubric_id: Option<Option<i64>>,
+    /// 自动评测器名称，传 null 取消自动评测
+    #[serde(default, deserialize_with = "serialization::deserialize_nullable")]
+    #[ts(type = "string | null", optional)]
+    pub auto_grader: Option<Option<String>>,
     pub attachments: Option<Vec<String>>, // download_token 列表
+    /// 发布状态（已发布的作业不能改回草稿或定时发布）
+    pub status: Option<HomeworkStatus>,
+    /// 定时发布时间
+    pub publish_at: Option<DateTime<Utc>>,
+    /// 分配的班级分组 ID，传空数组表示改为面向全班
+    #[serde(default, with = "serialization::option_vec_i64_as_string")]
+    #[ts(type = "string[] | null")]
+    pub group_ids: Option<Vec<i64>>,
+    /// 整体替换作业题目（已有提交时不可修改），传空数组表示清空
+    pub questions: Option<Vec<HomeworkQuestionInput>>,
 }
 
 /// 作业列表查询参数
@@ -68,3 +120,13 @@ pub struct AllHomeworksQuery {
     /// 是否包含统计信息（教师/管理员视角）
     pub include_stats: Option<bool>,
 }
+
+/// 作业统计查询参数
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/homework.ts")]
+pub struct HomeworkStatsQuery {
+    /// 按班级分组筛选统计范围
+    #[serde(default, deserialize_with = "deserialize_option_string_to_i64")]
+    #[ts(type = "string | null")]
+    pub group_id: Option<i64>,
+}
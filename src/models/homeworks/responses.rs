@@ -1,7 +1,9 @@
 use crate::models::common::pagination::PaginationInfo;
 use crate::models::common::serialization::serialize_i64_as_string;
 use crate::models::files::responses::FileInfo;
+use crate::models::homework_questions::entities::{HomeworkQuestion, HomeworkQuestionPool};
 use crate::models::homeworks::entities::Homework;
+use crate::models::rubrics::entities::Rubric;
 use serde::Serialize;
 use ts_rs::TS;
 
@@ -56,6 +58,8 @@ pub struct MySubmissionSummary {
     pub status: String,
     pub is_late: bool,
     pub score: Option<f64>,
+    /// 扣除迟交扣分（或教师调整）后的最终得分
+    pub effective_score: Option<f64>,
 }
 
 /// 作业统计摘要（用于教师视角列表显示）
@@ -87,6 +91,8 @@ pub struct HomeworkListItem {
     pub my_submission: Option<MySubmissionSummary>,
     /// 作业统计摘要（仅教师/管理员视角且请求 include_stats=true 时有值）
     pub stats_summary: Option<HomeworkStatsSummary>,
+    /// 当前学生的延期截止时间（仅学生视角且有个人或分组延期时有值）
+    pub extended_deadline: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 /// 作业详情（包含附件和创建者）
@@ -97,6 +103,14 @@ pub struct HomeworkDetail {
     pub homework: Homework,
     pub attachments: Vec<FileInfo>,
     pub creator: Option<HomeworkCreator>,
+    /// 当前学生的延期截止时间（仅学生视角且有个人或分组延期时有值）
+    pub extended_deadline: Option<chrono::DateTime<chrono::Utc>>,
+    /// 关联的评分细则（学生可据此了解评分标准）
+    pub rubric: Option<Rubric>,
+    /// 作业题目（学生视角为本人抽到的题目，不含标准答案）
+    pub questions: Vec<HomeworkQuestion>,
+    /// 随机抽题池（仅教师和管理员可见）
+    pub question_pools: Vec<HomeworkQuestionPool>,
 }
 
 #[derive(Debug, Serialize, TS)]
@@ -1,7 +1,10 @@
 use serde::Serialize;
 use ts_rs::TS;
 
-use crate::models::common::serialization::serialize_i64_as_string;
+use crate::models::common::serialization::{
+    serialize_i64_as_string, serialize_option_i64_as_string,
+};
+use crate::models::homework_questions::responses::QuestionStat;
 
 /// 作业统计响应
 #[derive(Debug, Serialize, TS)]
@@ -10,6 +13,10 @@ pub struct HomeworkStatsResponse {
     #[serde(serialize_with = "serialize_i64_as_string")]
     #[ts(type = "string")]
     pub homework_id: i64,
+    /// 统计范围的班级分组（为空表示全班）
+    #[serde(serialize_with = "serialize_option_i64_as_string")]
+    #[ts(type = "string | null")]
+    pub group_id: Option<i64>,
     #[serde(serialize_with = "serialize_i64_as_string")]
     #[ts(type = "string")]
     pub total_students: i64,
@@ -22,10 +29,21 @@ pub struct HomeworkStatsResponse {
     #[serde(serialize_with = "serialize_i64_as_string")]
     #[ts(type = "string")]
     pub late_count: i64,
+    /// 被迟交扣分的学生数
+    #[serde(serialize_with = "serialize_i64_as_string")]
+    #[ts(type = "string")]
+    pub penalized_count: i64,
+    /// 获得延期的学生数
+    #[serde(serialize_with = "serialize_i64_as_string")]
+    #[ts(type = "string")]
+    pub extended_count: i64,
     pub submission_rate: f64,
     pub score_stats: Option<ScoreStats>,
     pub score_distribution: Vec<ScoreRange>,
     pub unsubmitted_students: Vec<UnsubmittedStudent>,
+    pub extended_students: Vec<ExtendedStudent>,
+    /// 各题作答统计（按题目顺序，作业没有题目时为空）
+    pub question_stats: Vec<QuestionStat>,
 }
 
 /// 分数统计
@@ -58,3 +76,18 @@ pub struct UnsubmittedStudent {
     pub display_name: Option<String>,
     pub avatar_url: Option<String>,
 }
+
+/// 获得延期的学生
+#[derive(Debug, Serialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/homework.ts")]
+pub struct ExtendedStudent {
+    #[serde(serialize_with = "serialize_i64_as_string")]
+    #[ts(type = "string")]
+    pub id: i64,
+    pub username: String,
+    pub display_name: Option<String>,
+    /// 延期后的截止时间
+    pub extended_deadline: chrono::DateTime<chrono::Utc>,
+    /// 是否已提交
+    pub submitted: bool,
+}
@@ -7,6 +7,9 @@ pub mod auth;
 // 业务模块
 pub mod users;
 
+// 自定义资料字段模块
+pub mod profile_fields;
+
 // 文件模块
 pub mod files;
 
@@ -16,9 +19,33 @@ pub mod classes;
 // 班级成员模块
 pub mod class_users;
 
+// 班级分组模块
+pub mod class_groups;
+
+// 班级公告模块
+pub mod announcements;
+
+// 讨论评论模块
+pub mod comments;
+
 // 作业模块
 pub mod homeworks;
 
+// 作业延期模块
+pub mod homework_extensions;
+
+// 评分细则模块
+pub mod rubrics;
+
+// 作业题目模块
+pub mod homework_questions;
+
+// 题库模块
+pub mod question_bank;
+
+// 自动评测模块
+pub mod auto_graders;
+
 // 提交模块
 pub mod submissions;
 
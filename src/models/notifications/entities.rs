@@ -23,8 +23,14 @@ pub enum NotificationType {
     GradeUpdated,  // 评分修改（通知学生）
 
     // 班级相关
-    ClassJoined,      // 加入班级
-    ClassRoleChanged, // 班级角色变更
+    ClassJoined,        // 加入班级
+    ClassRoleChanged,   // 班级角色变更
+    ClassJoinRequested, // 收到加入班级申请（通知教师）
+    ClassJoinApproved,  // 加入申请已通过（通知学生）
+    ClassJoinRejected,  // 加入申请被拒绝（通知学生）
+
+    // 公告相关
+    AnnouncementPosted, // 新公告发布
 }
 
 impl NotificationType {
@@ -36,6 +42,10 @@ impl NotificationType {
     pub const GRADE_UPDATED: &'static str = "grade_updated";
     pub const CLASS_JOINED: &'static str = "class_joined";
     pub const CLASS_ROLE_CHANGED: &'static str = "class_role_changed";
+    pub const CLASS_JOIN_REQUESTED: &'static str = "class_join_requested";
+    pub const CLASS_JOIN_APPROVED: &'static str = "class_join_approved";
+    pub const CLASS_JOIN_REJECTED: &'static str = "class_join_rejected";
+    pub const ANNOUNCEMENT_POSTED: &'static str = "announcement_posted";
 }
 
 impl<'de> Deserialize<'de> for NotificationType {
@@ -59,6 +69,10 @@ impl std::fmt::Display for NotificationType {
             NotificationType::GradeUpdated => write!(f, "{}", Self::GRADE_UPDATED),
             NotificationType::ClassJoined => write!(f, "{}", Self::CLASS_JOINED),
             NotificationType::ClassRoleChanged => write!(f, "{}", Self::CLASS_ROLE_CHANGED),
+            NotificationType::ClassJoinRequested => write!(f, "{}", Self::CLASS_JOIN_REQUESTED),
+            NotificationType::ClassJoinApproved => write!(f, "{}", Self::CLASS_JOIN_APPROVED),
+            NotificationType::ClassJoinRejected => write!(f, "{}", Self::CLASS_JOIN_REJECTED),
+            NotificationType::AnnouncementPosted => write!(f, "{}", Self::ANNOUNCEMENT_POSTED),
         }
     }
 }
@@ -76,6 +90,10 @@ impl std::str::FromStr for NotificationType {
             "grade_updated" => Ok(NotificationType::GradeUpdated),
             "class_joined" => Ok(NotificationType::ClassJoined),
             "class_role_changed" => Ok(NotificationType::ClassRoleChanged),
+            "class_join_requested" => Ok(NotificationType::ClassJoinRequested),
+            "class_join_approved" => Ok(NotificationType::ClassJoinApproved),
+            "class_join_rejected" => Ok(NotificationType::ClassJoinRejected),
+            "announcement_posted" => Ok(NotificationType::AnnouncementPosted),
             _ => Err(format!("Invalid notification type: {s}")),
         }
     }
@@ -90,6 +108,7 @@ pub enum ReferenceType {
     Submission,
     Grade,
     Class,
+    Announcement,
 }
 
 impl<'de> Deserialize<'de> for ReferenceType {
@@ -109,6 +128,7 @@ impl std::fmt::Display for ReferenceType {
             ReferenceType::Submission => write!(f, "submission"),
             ReferenceType::Grade => write!(f, "grade"),
             ReferenceType::Class => write!(f, "class"),
+            ReferenceType::Announcement => write!(f, "announcement"),
         }
     }
 }
@@ -122,6 +142,7 @@ impl std::str::FromStr for ReferenceType {
             "submission" => Ok(ReferenceType::Submission),
             "grade" => Ok(ReferenceType::Grade),
             "class" => Ok(ReferenceType::Class),
+            "announcement" => Ok(ReferenceType::Announcement),
             _ => Err(format!("Invalid reference type: {s}")),
         }
     }
@@ -0,0 +1,131 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::models::common::serialization;
+
+// 资料字段类型
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "../frontend/src/types/generated/profile-field.ts")]
+pub enum ProfileFieldType {
+    Text,   // 文本
+    Number, // 数字（仅数字字符，保留前导零，如学号）
+    Email,  // 邮箱
+    Select, // 单选（取值限定在 options 中）
+}
+
+impl ProfileFieldType {
+    pub const TEXT: &'static str = "text";
+    pub const NUMBER: &'static str = "number";
+    pub const EMAIL: &'static str = "email";
+    pub const SELECT: &'static str = "select";
+}
+
+impl<'de> Deserialize<'de> for ProfileFieldType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl std::fmt::Display for ProfileFieldType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProfileFieldType::Text => write!(f, "{}", Self::TEXT),
+            ProfileFieldType::Number => write!(f, "{}", Self::NUMBER),
+            ProfileFieldType::Email => write!(f, "{}", Self::EMAIL),
+            ProfileFieldType::Select => write!(f, "{}", Self::SELECT),
+        }
+    }
+}
+
+impl std::str::FromStr for ProfileFieldType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(ProfileFieldType::Text),
+            "number" => Ok(ProfileFieldType::Number),
+            "email" => Ok(ProfileFieldType::Email),
+            "select" => Ok(ProfileFieldType::Select),
+            _ => Err(format!("Invalid profile field type: {s}")),
+        }
+    }
+}
+
+/// 自定义资料字段定义（如学号、院系、年级）
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/profile-field.ts")]
+pub struct ProfileField {
+    #[serde(with = "serialization::i64_as_string")]
+    #[ts(type = "string")]
+    pub id: i64,
+    /// 字段键，同时用作导入导出的列名
+    pub key: String,
+    /// 显示名称，用于 XLSX 报表表头
+    pub label: String,
+    pub field_type: ProfileFieldType,
+    /// 创建用户时必填
+    pub required: bool,
+    /// 字段值在所有用户中唯一
+    pub unique: bool,
+    /// 值须匹配的正则表达式
+    pub pattern: Option<String>,
+    /// 值的最大字符数
+    pub max_length: Option<i32>,
+    /// 单选类型的可选值
+    pub options: Vec<String>,
+    pub sort_order: i32,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl ProfileField {
+    /// 校验字段值，返回去除首尾空白后的值
+    pub fn validate_value(&self, value: &str) -> Result<String, String> {
+        let value = value.trim();
+
+        if let Some(max_length) = self.max_length
+            && value.chars().count() > max_length.max(0) as usize
+        {
+            return Err(format!("{}不能超过 {} 个字符", self.label, max_length));
+        }
+
+        match self.field_type {
+            ProfileFieldType::Text => {}
+            ProfileFieldType::Number => {
+                if !value.chars().all(|c| c.is_ascii_digit()) {
+                    return Err(format!("{}只能包含数字", self.label));
+                }
+            }
+            ProfileFieldType::Email => {
+                if crate::utils::validate::validate_email(value).is_err() {
+                    return Err(format!("{}不是有效的邮箱地址", self.label));
+                }
+            }
+            ProfileFieldType::Select => {
+                if !self.options.iter().any(|o| o == value) {
+                    return Err(format!(
+                        "{}的取值必须是: {}",
+                        self.label,
+                        self.options.join(", ")
+                    ));
+                }
+            }
+        }
+
+        if let Some(pattern) = self.pattern.as_deref() {
+            let re = Regex::new(&format!("^(?:{pattern})$"))
+                .map_err(|e| format!("{}的校验规则无效: {e}", self.label))?;
+            if !re.is_match(value) {
+                return Err(format!("{}格式不正确", self.label));
+            }
+        }
+
+        Ok(value.to_string())
+    }
+}
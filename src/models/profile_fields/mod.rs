@@ -0,0 +1,8 @@
+// 自定义资料字段实体定义
+pub mod entities;
+
+// 自定义资料字段请求模型
+pub mod requests;
+
+// 自定义资料字段响应模型
+pub mod responses;
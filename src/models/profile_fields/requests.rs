@@ -0,0 +1,34 @@
+use super::entities::ProfileFieldType;
+use serde::Deserialize;
+use ts_rs::TS;
+
+// 创建资料字段请求
+#[derive(Debug, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/profile-field.ts")]
+pub struct CreateProfileFieldRequest {
+    pub key: String,
+    pub label: String,
+    pub field_type: Option<ProfileFieldType>,
+    pub required: Option<bool>,
+    pub unique: Option<bool>,
+    pub pattern: Option<String>,
+    pub max_length: Option<i32>,
+    pub options: Option<Vec<String>>,
+    pub sort_order: Option<i32>,
+}
+
+// 更新资料字段请求（字段键创建后不可修改）
+#[derive(Debug, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/profile-field.ts")]
+pub struct UpdateProfileFieldRequest {
+    pub label: Option<String>,
+    pub field_type: Option<ProfileFieldType>,
+    pub required: Option<bool>,
+    pub unique: Option<bool>,
+    /// 传入空字符串清除校验规则
+    pub pattern: Option<String>,
+    /// 传入 0 清除长度限制
+    pub max_length: Option<i32>,
+    pub options: Option<Vec<String>>,
+    pub sort_order: Option<i32>,
+}
@@ -0,0 +1,11 @@
+use serde::Serialize;
+use ts_rs::TS;
+
+use super::entities::ProfileField;
+
+/// 资料字段列表响应（按 sort_order 排序）
+#[derive(Debug, Serialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/profile-field.ts")]
+pub struct ProfileFieldListResponse {
+    pub items: Vec<ProfileField>,
+}
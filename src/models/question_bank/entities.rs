@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::models::common::serialization;
+use crate::models::files::responses::FileInfo;
+use crate::models::homework_questions::entities::QuestionSpec;
+
+/// 题库题目（个人题库，可共享给所有教师）
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/question_bank.ts")]
+pub struct BankQuestion {
+    #[serde(with = "serialization::i64_as_string")]
+    #[ts(type = "string")]
+    pub id: i64,
+    #[serde(with = "serialization::i64_as_string")]
+    #[ts(type = "string")]
+    pub created_by: i64,
+    /// 是否共享给其他教师
+    pub is_shared: bool,
+    /// 题干（富文本）
+    pub prompt: String,
+    /// 默认分值
+    pub points: f64,
+    pub spec: QuestionSpec,
+    pub tags: Vec<String>,
+    /// 难度（1-5）
+    pub difficulty: i32,
+    /// 知识点
+    pub knowledge_point: Option<String>,
+    pub attachments: Vec<FileInfo>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// 题库查询范围
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "../frontend/src/types/generated/question_bank.ts")]
+pub enum BankQuestionScope {
+    /// 自己创建的题目
+    Mine,
+    /// 共享的题目（含自己共享的）
+    Shared,
+    /// 自己的和共享的题目（管理员为全部题目）
+    #[default]
+    All,
+}
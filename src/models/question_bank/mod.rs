@@ -0,0 +1,8 @@
+// 题库实体定义
+pub mod entities;
+
+// 题库请求模型
+pub mod requests;
+
+// 题库响应模型
+pub mod responses;
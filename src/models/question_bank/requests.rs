@@ -0,0 +1,132 @@
+use serde::Deserialize;
+use ts_rs::TS;
+
+use super::entities::{BankQuestion, BankQuestionScope};
+use crate::models::common::pagination::PaginationQuery;
+use crate::models::common::serialization::{
+    deserialize_nullable, deserialize_option_string_to_i64, option_vec_i64_as_string,
+};
+use crate::models::homework_questions::entities::{QuestionSpec, QuestionType};
+
+/// 创建题库题目请求
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/question_bank.ts")]
+pub struct CreateBankQuestionRequest {
+    pub prompt: String,
+    pub points: f64,
+    pub spec: QuestionSpec,
+    pub tags: Option<Vec<String>>,
+    /// 难度（1-5，默认 3）
+    pub difficulty: Option<i32>,
+    pub knowledge_point: Option<String>,
+    /// 是否共享给其他教师（默认不共享）
+    pub is_shared: Option<bool>,
+    /// 附件 download_token 列表
+    pub attachments: Option<Vec<String>>,
+}
+
+/// 更新题库题目请求（传入 attachments 时整体替换附件）
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/question_bank.ts")]
+pub struct UpdateBankQuestionRequest {
+    pub prompt: Option<String>,
+    pub points: Option<f64>,
+    pub spec: Option<QuestionSpec>,
+    pub tags: Option<Vec<String>>,
+    pub difficulty: Option<i32>,
+    /// 传 null 清除知识点
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    #[ts(optional, type = "string | null")]
+    pub knowledge_point: Option<Option<String>>,
+    pub is_shared: Option<bool>,
+    pub attachments: Option<Vec<String>>,
+}
+
+/// 题库列表查询参数
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/question_bank.ts")]
+pub struct BankQuestionListQuery {
+    #[serde(flatten)]
+    #[ts(flatten)]
+    pub pagination: PaginationQuery,
+    pub scope: Option<BankQuestionScope>,
+    pub tag: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_option_string_to_i64")]
+    #[ts(type = "number | null")]
+    pub difficulty: Option<i64>,
+    pub knowledge_point: Option<String>,
+    pub question_type: Option<QuestionType>,
+    /// 按题干搜索
+    pub search: Option<String>,
+}
+
+/// 题库导出参数
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/question_bank.ts")]
+pub struct BankQuestionExportParams {
+    /// 导出格式：xlsx（默认）或 gift
+    #[serde(default = "default_export_format")]
+    pub format: String,
+    pub scope: Option<BankQuestionScope>,
+    pub tag: Option<String>,
+    pub difficulty: Option<i32>,
+    pub knowledge_point: Option<String>,
+    pub question_type: Option<QuestionType>,
+    pub search: Option<String>,
+}
+
+fn default_export_format() -> String {
+    "xlsx".to_string()
+}
+
+/// 题库查询条件（列表、导出与随机抽题共用）
+#[derive(Debug, Clone, Default)]
+pub struct BankQuestionFilter {
+    /// 当前用户
+    pub viewer_id: i64,
+    /// 是否可查看所有题目（管理员）
+    pub is_admin: bool,
+    pub scope: BankQuestionScope,
+    pub tag: Option<String>,
+    pub difficulty: Option<i32>,
+    pub knowledge_point: Option<String>,
+    pub question_type: Option<QuestionType>,
+    pub search: Option<String>,
+    /// 限定题目 ID
+    pub ids: Option<Vec<i64>>,
+}
+
+/// 从题库随机抽题的条件
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/question_bank.ts")]
+pub struct BankQuestionDraw {
+    pub tag: Option<String>,
+    pub difficulty: Option<i32>,
+    pub knowledge_point: Option<String>,
+    pub question_type: Option<QuestionType>,
+    /// 每个学生抽取的题目数量
+    pub count: i32,
+    /// 每道题的分值（不传时使用题库中的分值，候选题目分值须一致）
+    pub points: Option<f64>,
+}
+
+/// 从题库向作业添加题目请求
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/question_bank.ts")]
+pub struct AddBankQuestionsRequest {
+    /// 手动选择的题库题目（按顺序追加为固定题目）
+    #[serde(default, with = "option_vec_i64_as_string")]
+    #[ts(type = "Array<string> | null")]
+    pub question_ids: Option<Vec<i64>>,
+    /// 随机抽题条件（每个条件生成一个抽题池）
+    pub draws: Option<Vec<BankQuestionDraw>>,
+}
+
+/// 待写入作业的抽题池（服务层按抽题条件选出候选题目后生成）
+#[derive(Debug, Clone)]
+pub struct QuestionPoolDraft {
+    pub label: String,
+    pub draw_count: i32,
+    pub points: f64,
+    pub candidates: Vec<BankQuestion>,
+}
@@ -0,0 +1,24 @@
+use serde::Serialize;
+use ts_rs::TS;
+
+use super::entities::BankQuestion;
+use crate::models::PaginationInfo;
+use crate::models::users::responses::ImportRowError;
+
+/// 题库列表响应
+#[derive(Debug, Serialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/question_bank.ts")]
+pub struct BankQuestionListResponse {
+    pub items: Vec<BankQuestion>,
+    pub pagination: PaginationInfo,
+}
+
+/// 题库导入响应
+#[derive(Debug, Serialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/question_bank.ts")]
+pub struct BankQuestionImportResponse {
+    pub total: usize,
+    pub success: usize,
+    pub failed: usize,
+    pub errors: Vec<ImportRowError>,
+}
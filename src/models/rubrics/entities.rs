@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::models::common::serialization;
+
+/// 评分细则（教师的评分细则库，可关联到多个作业）
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/rubric.ts")]
+pub struct Rubric {
+    #[serde(with = "serialization::i64_as_string")]
+    #[ts(type = "string")]
+    pub id: i64,
+    pub title: String,
+    pub description: Option<String>,
+    /// 评分项（按顺序）
+    pub criteria: Vec<RubricCriterion>,
+    /// 满分合计（各评分项最高档分值之和）
+    pub total_points: f64,
+    #[serde(with = "serialization::i64_as_string")]
+    #[ts(type = "string")]
+    pub created_by: i64,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// 评分项
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/rubric.ts")]
+pub struct RubricCriterion {
+    #[serde(with = "serialization::i64_as_string")]
+    #[ts(type = "string")]
+    pub id: i64,
+    pub title: String,
+    pub description: Option<String>,
+    /// 评分档位（评分时按下标选择）
+    pub levels: Vec<RubricLevel>,
+}
+
+impl RubricCriterion {
+    /// 该评分项的最高分值
+    pub fn max_points(&self) -> f64 {
+        self.levels.iter().map(|l| l.points).fold(0.0, f64::max)
+    }
+}
+
+/// 评分档位
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/rubric.ts")]
+pub struct RubricLevel {
+    pub title: String,
+    pub description: Option<String>,
+    pub points: f64,
+}
+
+/// 评分项得分（评分时的快照，评分细则修改或删除后保持不变）
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/rubric.ts")]
+pub struct RubricScore {
+    #[serde(with = "serialization::i64_as_string")]
+    #[ts(type = "string")]
+    pub criterion_id: i64,
+    pub criterion_title: String,
+    pub level_title: String,
+    pub points: f64,
+    /// 该评分项的最高分值
+    pub max_points: f64,
+    pub comment: Option<String>,
+}
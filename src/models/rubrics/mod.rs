@@ -0,0 +1,8 @@
+// 评分细则实体定义
+pub mod entities;
+
+// 评分细则请求模型
+pub mod requests;
+
+// 评分细则响应模型
+pub mod responses;
@@ -0,0 +1,44 @@
+use serde::Deserialize;
+use ts_rs::TS;
+
+use super::entities::RubricLevel;
+use crate::models::common::serialization::deserialize_string_to_i64;
+
+/// 评分项定义
+#[derive(Debug, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/rubric.ts")]
+pub struct RubricCriterionInput {
+    pub title: String,
+    pub description: Option<String>,
+    pub levels: Vec<RubricLevel>,
+}
+
+/// 创建评分细则请求
+#[derive(Debug, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/rubric.ts")]
+pub struct CreateRubricRequest {
+    pub title: String,
+    pub description: Option<String>,
+    pub criteria: Vec<RubricCriterionInput>,
+}
+
+/// 更新评分细则请求（传入 criteria 时整体替换评分项）
+#[derive(Debug, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/rubric.ts")]
+pub struct UpdateRubricRequest {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub criteria: Option<Vec<RubricCriterionInput>>,
+}
+
+/// 评分时对单个评分项的档位选择
+#[derive(Debug, Deserialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/rubric.ts")]
+pub struct RubricSelection {
+    #[serde(deserialize_with = "deserialize_string_to_i64")]
+    #[ts(type = "string")]
+    pub criterion_id: i64,
+    /// 选择的档位下标
+    pub level: usize,
+    pub comment: Option<String>,
+}
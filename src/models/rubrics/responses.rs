@@ -0,0 +1,11 @@
+use serde::Serialize;
+use ts_rs::TS;
+
+use super::entities::Rubric;
+
+/// 评分细则列表响应
+#[derive(Debug, Serialize, TS)]
+#[ts(export, export_to = "../frontend/src/types/generated/rubric.ts")]
+pub struct RubricListResponse {
+    pub items: Vec<Rubric>,
+}
@@ -2,6 +2,7 @@ use crate::models::common::PaginationQuery;
 use crate::models::common::serialization::{
     deserialize_option_string_to_i64, deserialize_string_to_i64,
 };
+use crate::models::homework_questions::requests::SubmissionAnswerInput;
 use serde::Deserialize;
 use ts_rs::TS;
 
@@ -14,6 +15,8 @@ pub struct CreateSubmissionRequest {
     pub homework_id: i64,
     pub content: String,
     pub attachments: Option<Vec<String>>,
+    /// 作业题目的作答（作业包含题目时使用）
+    pub answers: Option<Vec<SubmissionAnswerInput>>,
 }
 
 /// 更新提交请求
@@ -49,4 +52,8 @@ pub struct SubmissionSummaryQuery {
     pub pagination: PaginationQuery,
     /// 筛选是否已批改：true=已批改，false=待批改，None=全部
     pub graded: Option<bool>,
+    /// 按班级分组筛选
+    #[serde(default, deserialize_with = "deserialize_option_string_to_i64")]
+    #[ts(type = "string | null")]
+    pub group_id: Option<i64>,
 }
@@ -4,6 +4,8 @@ use ts_rs::TS;
 use crate::models::PaginationInfo;
 use crate::models::common::serialization::serialize_i64_as_string;
 use crate::models::files::responses::FileInfo;
+use crate::models::homework_questions::entities::SubmissionAnswer;
+use crate::models::rubrics::entities::RubricScore;
 
 /// 提交者信息
 #[derive(Debug, Serialize, TS)]
@@ -48,6 +50,8 @@ pub struct SubmissionResponse {
     pub version: i32,
     pub is_late: bool,
     pub homework: Option<SubmissionHomeworkInfo>,
+    /// 题目作答及判分结果
+    pub answers: Vec<SubmissionAnswer>,
 }
 
 /// 提交中的评分信息
@@ -58,7 +62,13 @@ pub struct SubmissionGradeInfo {
     #[ts(type = "string")]
     pub id: i64,
     pub score: f64,
+    /// 扣除迟交扣分（或教师调整）后的最终得分
+    pub effective_score: f64,
     pub comment: Option<String>,
+    /// 评分细则得分明细
+    pub rubric_scores: Vec<RubricScore>,
+    /// 是否仍有主观题等待教师批阅
+    pub pending_review: bool,
     pub graded_at: String,
 }
 
@@ -80,6 +80,8 @@ pub enum KnownSettingKey {
     UploadThumbnailMaxWidth,
     UploadThumbnailMaxHeight,
     UploadThumbnailQuality,
+    // 通知配置
+    DeadlineReminderOffsets,
 }
 
 impl KnownSettingKey {
@@ -101,6 +103,7 @@ impl KnownSettingKey {
             KnownSettingKey::UploadThumbnailMaxWidth => "upload.thumbnail_max_width",
             KnownSettingKey::UploadThumbnailMaxHeight => "upload.thumbnail_max_height",
             KnownSettingKey::UploadThumbnailQuality => "upload.thumbnail_quality",
+            KnownSettingKey::DeadlineReminderOffsets => "notification.deadline_reminder_offsets",
         }
     }
 
@@ -122,6 +125,7 @@ impl KnownSettingKey {
             KnownSettingKey::UploadThumbnailMaxWidth => SettingValueType::Integer,
             KnownSettingKey::UploadThumbnailMaxHeight => SettingValueType::Integer,
             KnownSettingKey::UploadThumbnailQuality => SettingValueType::Integer,
+            KnownSettingKey::DeadlineReminderOffsets => SettingValueType::JsonArray,
         }
     }
 
@@ -143,6 +147,7 @@ impl KnownSettingKey {
             KnownSettingKey::UploadThumbnailMaxWidth,
             KnownSettingKey::UploadThumbnailMaxHeight,
             KnownSettingKey::UploadThumbnailQuality,
+            KnownSettingKey::DeadlineReminderOffsets,
         ]
     }
 }
@@ -170,6 +175,9 @@ impl std::str::FromStr for KnownSettingKey {
             "upload.thumbnail_max_width" => Ok(KnownSettingKey::UploadThumbnailMaxWidth),
             "upload.thumbnail_max_height" => Ok(KnownSettingKey::UploadThumbnailMaxHeight),
             "upload.thumbnail_quality" => Ok(KnownSettingKey::UploadThumbnailQuality),
+            "notification.deadline_reminder_offsets" => {
+                Ok(KnownSettingKey::DeadlineReminderOffsets)
+            }
             _ => Err(format!("Unknown setting key: {s}")),
         }
     }
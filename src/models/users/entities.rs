@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use ts_rs::TS;
 
 use crate::models::common::serialization;
@@ -141,6 +142,10 @@ pub struct User {
     pub last_login: Option<chrono::DateTime<chrono::Utc>>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
+    /// 自定义资料字段值（字段键 -> 值）
+    #[serde(default)]
+    #[ts(type = "Record<string, string>")]
+    pub profile: BTreeMap<String, String>,
 }
 
 impl User {
@@ -1,6 +1,7 @@
 use super::entities::{UserRole, UserStatus};
 use crate::models::common::PaginationQuery;
 use serde::Deserialize;
+use std::collections::BTreeMap;
 use ts_rs::TS;
 
 // 用户查询参数
@@ -25,6 +26,10 @@ pub struct CreateUserRequest {
     pub role: UserRole,
     pub display_name: Option<String>,
     pub avatar_url: Option<String>,
+    /// 自定义资料字段值（字段键 -> 值）
+    #[serde(default)]
+    #[ts(type = "Record<string, string> | null")]
+    pub profile: Option<BTreeMap<String, String>>,
 }
 
 // 用户更新请求
@@ -37,6 +42,10 @@ pub struct UpdateUserRequest {
     pub status: Option<UserStatus>,
     pub display_name: Option<String>,
     pub avatar_url: Option<String>,
+    /// 自定义资料字段值，空字符串表示清除该字段
+    #[serde(default)]
+    #[ts(type = "Record<string, string> | null")]
+    pub profile: Option<BTreeMap<String, String>>,
 }
 
 // 用户导出参数
@@ -0,0 +1,169 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult, web};
+use once_cell::sync::Lazy;
+
+use crate::middlewares;
+use crate::models::announcements::requests::{
+    AnnouncementListQuery, CreateAnnouncementRequest, UpdateAnnouncementRequest,
+};
+use crate::models::class_users::entities::ClassUserRole;
+use crate::services::AnnouncementService;
+use crate::utils::SafeClassIdI64;
+
+use crate::define_safe_i64_extractor;
+
+// 用于从请求路径中安全地提取 announcement_id
+define_safe_i64_extractor!(SafeAnnouncementID, "announcement_id");
+
+// 懒加载的全局 ANNOUNCEMENT_SERVICE 实例
+static ANNOUNCEMENT_SERVICE: Lazy<AnnouncementService> = Lazy::new(AnnouncementService::new_lazy);
+
+// HTTP处理程序
+pub async fn list_announcements(
+    req: HttpRequest,
+    path: SafeClassIdI64,
+    query: web::Query<AnnouncementListQuery>,
+) -> ActixResult<HttpResponse> {
+    ANNOUNCEMENT_SERVICE
+        .list_announcements(&req, path.0, query.into_inner())
+        .await
+}
+
+pub async fn create_announcement(
+    req: HttpRequest,
+    path: SafeClassIdI64,
+    body: web::Json<CreateAnnouncementRequest>,
+) -> ActixResult<HttpResponse> {
+    ANNOUNCEMENT_SERVICE
+        .create_announcement(&req, path.0, body.into_inner())
+        .await
+}
+
+pub async fn get_announcement(
+    req: HttpRequest,
+    path: web::Path<(SafeClassIdI64, SafeAnnouncementID)>,
+) -> ActixResult<HttpResponse> {
+    let class_id = path.0.0;
+    let announcement_id = path.1.0;
+    ANNOUNCEMENT_SERVICE
+        .get_announcement(&req, class_id, announcement_id)
+        .await
+}
+
+pub async fn update_announcement(
+    req: HttpRequest,
+    path: web::Path<(SafeClassIdI64, SafeAnnouncementID)>,
+    body: web::Json<UpdateAnnouncementRequest>,
+) -> ActixResult<HttpResponse> {
+    let class_id = path.0.0;
+    let announcement_id = path.1.0;
+    ANNOUNCEMENT_SERVICE
+        .update_announcement(&req, class_id, announcement_id, body.into_inner())
+        .await
+}
+
+pub async fn delete_announcement(
+    req: HttpRequest,
+    path: web::Path<(SafeClassIdI64, SafeAnnouncementID)>,
+) -> ActixResult<HttpResponse> {
+    let class_id = path.0.0;
+    let announcement_id = path.1.0;
+    ANNOUNCEMENT_SERVICE
+        .delete_announcement(&req, class_id, announcement_id)
+        .await
+}
+
+pub async fn mark_announcement_read(
+    req: HttpRequest,
+    path: web::Path<(SafeClassIdI64, SafeAnnouncementID)>,
+) -> ActixResult<HttpResponse> {
+    let class_id = path.0.0;
+    let announcement_id = path.1.0;
+    ANNOUNCEMENT_SERVICE
+        .mark_announcement_read(&req, class_id, announcement_id)
+        .await
+}
+
+pub async fn list_announcement_reads(
+    req: HttpRequest,
+    path: web::Path<(SafeClassIdI64, SafeAnnouncementID)>,
+) -> ActixResult<HttpResponse> {
+    let class_id = path.0.0;
+    let announcement_id = path.1.0;
+    ANNOUNCEMENT_SERVICE
+        .list_announcement_reads(&req, class_id, announcement_id)
+        .await
+}
+
+// 配置路由
+pub fn configure_announcements_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/api/v1/classes/{class_id}/announcements")
+            .wrap(middlewares::RequireJWT)
+            .service(
+                web::resource("")
+                    .route(
+                        web::get()
+                            .to(list_announcements)
+                            // 列出班级公告 - 班级成员均可查看
+                            .wrap(middlewares::RequireClassRole::new_any(
+                                ClassUserRole::all_roles(),
+                            )),
+                    )
+                    .route(
+                        web::post()
+                            .to(create_announcement)
+                            // 发布公告 - 仅班级教师权限
+                            .wrap(middlewares::RequireClassRole::new_any(
+                                ClassUserRole::class_teacher_roles(),
+                            )),
+                    ),
+            )
+            .service(
+                web::resource("/{announcement_id}")
+                    .route(
+                        web::get()
+                            .to(get_announcement)
+                            // 获取公告详情 - 班级成员均可查看
+                            .wrap(middlewares::RequireClassRole::new_any(
+                                ClassUserRole::all_roles(),
+                            )),
+                    )
+                    .route(
+                        web::put()
+                            .to(update_announcement)
+                            // 更新公告 - 仅班级教师权限
+                            .wrap(middlewares::RequireClassRole::new_any(
+                                ClassUserRole::class_teacher_roles(),
+                            )),
+                    )
+                    .route(
+                        web::delete()
+                            .to(delete_announcement)
+                            // 删除公告 - 仅班级教师权限
+                            .wrap(middlewares::RequireClassRole::new_any(
+                                ClassUserRole::class_teacher_roles(),
+                            )),
+                    ),
+            )
+            .service(
+                web::resource("/{announcement_id}/read").route(
+                    web::post()
+                        .to(mark_announcement_read)
+                        // 标记公告已读 - 班级成员
+                        .wrap(middlewares::RequireClassRole::new_any(
+                            ClassUserRole::all_roles(),
+                        )),
+                ),
+            )
+            .service(
+                web::resource("/{announcement_id}/reads").route(
+                    web::get()
+                        .to(list_announcement_reads)
+                        // 查看已读回执 - 仅班级教师权限
+                        .wrap(middlewares::RequireClassRole::new_any(
+                            ClassUserRole::class_teacher_roles(),
+                        )),
+                ),
+            ),
+    );
+}
@@ -0,0 +1,28 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult, web};
+use once_cell::sync::Lazy;
+
+use crate::middlewares;
+use crate::models::users::entities::UserRole;
+use crate::services::AutoGraderService;
+
+// 懒加载的全局 AUTO_GRADER_SERVICE 实例
+static AUTO_GRADER_SERVICE: Lazy<AutoGraderService> = Lazy::new(AutoGraderService::new_lazy);
+
+// HTTP处理程序
+pub async fn list_auto_graders(req: HttpRequest) -> ActixResult<HttpResponse> {
+    AUTO_GRADER_SERVICE.list_auto_graders(&req).await
+}
+
+// 配置路由
+pub fn configure_auto_graders_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/api/v1/auto-graders")
+            .wrap(middlewares::RequireJWT)
+            // 可用评测器列表 - 教师/管理员（创建作业时选择）
+            .service(
+                web::scope("")
+                    .wrap(middlewares::RequireRole::new_any(UserRole::teacher_roles()))
+                    .route("", web::get().to(list_auto_graders)),
+            ),
+    );
+}
@@ -0,0 +1,148 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult, web};
+use once_cell::sync::Lazy;
+
+use crate::middlewares;
+use crate::models::class_groups::requests::{
+    ClassGroupMembersRequest, CreateClassGroupRequest, UpdateClassGroupRequest,
+};
+use crate::models::class_users::entities::ClassUserRole;
+use crate::services::ClassGroupService;
+use crate::utils::SafeClassIdI64;
+
+use crate::define_safe_i64_extractor;
+
+// 用于从请求路径中安全地提取 group_id
+define_safe_i64_extractor!(SafeGroupID, "group_id");
+
+// 懒加载的全局 CLASS_GROUP_SERVICE 实例
+static CLASS_GROUP_SERVICE: Lazy<ClassGroupService> = Lazy::new(ClassGroupService::new_lazy);
+
+// HTTP处理程序
+pub async fn list_class_groups(
+    req: HttpRequest,
+    path: SafeClassIdI64,
+) -> ActixResult<HttpResponse> {
+    CLASS_GROUP_SERVICE.list_class_groups(&req, path.0).await
+}
+
+pub async fn create_class_group(
+    req: HttpRequest,
+    path: SafeClassIdI64,
+    body: web::Json<CreateClassGroupRequest>,
+) -> ActixResult<HttpResponse> {
+    CLASS_GROUP_SERVICE
+        .create_class_group(&req, path.0, body.into_inner())
+        .await
+}
+
+pub async fn update_class_group(
+    req: HttpRequest,
+    path: web::Path<(SafeClassIdI64, SafeGroupID)>,
+    body: web::Json<UpdateClassGroupRequest>,
+) -> ActixResult<HttpResponse> {
+    let class_id = path.0.0;
+    let group_id = path.1.0;
+    CLASS_GROUP_SERVICE
+        .update_class_group(&req, class_id, group_id, body.into_inner())
+        .await
+}
+
+pub async fn delete_class_group(
+    req: HttpRequest,
+    path: web::Path<(SafeClassIdI64, SafeGroupID)>,
+) -> ActixResult<HttpResponse> {
+    let class_id = path.0.0;
+    let group_id = path.1.0;
+    CLASS_GROUP_SERVICE
+        .delete_class_group(&req, class_id, group_id)
+        .await
+}
+
+pub async fn add_class_group_members(
+    req: HttpRequest,
+    path: web::Path<(SafeClassIdI64, SafeGroupID)>,
+    body: web::Json<ClassGroupMembersRequest>,
+) -> ActixResult<HttpResponse> {
+    let class_id = path.0.0;
+    let group_id = path.1.0;
+    CLASS_GROUP_SERVICE
+        .add_class_group_members(&req, class_id, group_id, body.into_inner())
+        .await
+}
+
+pub async fn remove_class_group_members(
+    req: HttpRequest,
+    path: web::Path<(SafeClassIdI64, SafeGroupID)>,
+    body: web::Json<ClassGroupMembersRequest>,
+) -> ActixResult<HttpResponse> {
+    let class_id = path.0.0;
+    let group_id = path.1.0;
+    CLASS_GROUP_SERVICE
+        .remove_class_group_members(&req, class_id, group_id, body.into_inner())
+        .await
+}
+
+// 配置路由
+pub fn configure_class_groups_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/api/v1/classes/{class_id}/groups")
+            .wrap(middlewares::RequireJWT)
+            .service(
+                web::resource("")
+                    .route(
+                        web::get()
+                            .to(list_class_groups)
+                            // 列出班级分组 - 班级成员均可查看
+                            .wrap(middlewares::RequireClassRole::new_any(
+                                ClassUserRole::all_roles(),
+                            )),
+                    )
+                    .route(
+                        web::post()
+                            .to(create_class_group)
+                            // 创建班级分组 - 仅班级教师权限
+                            .wrap(middlewares::RequireClassRole::new_any(
+                                ClassUserRole::class_teacher_roles(),
+                            )),
+                    ),
+            )
+            .service(
+                web::resource("/{group_id}")
+                    .route(
+                        web::put()
+                            .to(update_class_group)
+                            // 更新班级分组 - 仅班级教师权限
+                            .wrap(middlewares::RequireClassRole::new_any(
+                                ClassUserRole::class_teacher_roles(),
+                            )),
+                    )
+                    .route(
+                        web::delete()
+                            .to(delete_class_group)
+                            // 删除班级分组 - 仅班级教师权限
+                            .wrap(middlewares::RequireClassRole::new_any(
+                                ClassUserRole::class_teacher_roles(),
+                            )),
+                    ),
+            )
+            .service(
+                web::resource("/{group_id}/members")
+                    .route(
+                        web::post()
+                            .to(add_class_group_members)
+                            // 批量加入分组成员 - 仅班级教师权限
+                            .wrap(middlewares::RequireClassRole::new_any(
+                                ClassUserRole::class_teacher_roles(),
+                            )),
+                    )
+                    .route(
+                        web::delete()
+                            .to(remove_class_group_members)
+                            // 批量移出分组成员 - 仅班级教师权限
+                            .wrap(middlewares::RequireClassRole::new_any(
+                                ClassUserRole::class_teacher_roles(),
+                            )),
+                    ),
+            ),
+    );
+}
@@ -1,13 +1,17 @@
+use actix_multipart::Multipart;
 use actix_web::{HttpRequest, HttpResponse, Result as ActixResult, web};
 use once_cell::sync::Lazy;
 
+use crate::config::AppConfig;
 use crate::middlewares;
 use crate::models::class_users::entities::ClassUserRole;
 use crate::models::class_users::requests::{
-    ClassUserListQuery, JoinClassRequest, UpdateClassUserRequest,
+    ClassJoinRequestListQuery, ClassRosterImportParams, ClassUserListQuery,
+    HandleClassJoinRequestsRequest, JoinClassRequest, UpdateClassUserRequest,
 };
 use crate::models::users::entities::UserRole;
 use crate::services::ClassUserService;
+use crate::services::class_users::ClassJoinRequestAction;
 use crate::utils::SafeClassIdI64;
 
 use crate::define_safe_i64_extractor;
@@ -75,8 +79,61 @@ pub async fn delete_class_user(
         .await
 }
 
+pub async fn import_class_roster(
+    req: HttpRequest,
+    path: SafeClassIdI64,
+    query: web::Query<ClassRosterImportParams>,
+    payload: Multipart,
+) -> ActixResult<HttpResponse> {
+    CLASS_STUDENT_SERVICE
+        .import_class_roster(&req, path.0, query.into_inner(), payload)
+        .await
+}
+
+pub async fn list_class_join_requests(
+    req: HttpRequest,
+    path: SafeClassIdI64,
+    query: web::Query<ClassJoinRequestListQuery>,
+) -> ActixResult<HttpResponse> {
+    CLASS_STUDENT_SERVICE
+        .list_class_join_requests(&req, path.0, query.into_inner())
+        .await
+}
+
+pub async fn approve_class_join_requests(
+    req: HttpRequest,
+    path: SafeClassIdI64,
+    body: web::Json<HandleClassJoinRequestsRequest>,
+) -> ActixResult<HttpResponse> {
+    CLASS_STUDENT_SERVICE
+        .handle_class_join_requests(
+            &req,
+            path.0,
+            body.into_inner(),
+            ClassJoinRequestAction::Approve,
+        )
+        .await
+}
+
+pub async fn reject_class_join_requests(
+    req: HttpRequest,
+    path: SafeClassIdI64,
+    body: web::Json<HandleClassJoinRequestsRequest>,
+) -> ActixResult<HttpResponse> {
+    CLASS_STUDENT_SERVICE
+        .handle_class_join_requests(
+            &req,
+            path.0,
+            body.into_inner(),
+            ClassJoinRequestAction::Reject,
+        )
+        .await
+}
+
 // 配置路由
 pub fn configure_class_users_routes(cfg: &mut web::ServiceConfig) {
+    let config = AppConfig::get();
+
     cfg.service(
         web::scope("/api/v1/classes/{class_id}/students")
             .wrap(middlewares::RequireJWT)
@@ -97,6 +154,19 @@ pub fn configure_class_users_routes(cfg: &mut web::ServiceConfig) {
                             )),
                     ),
             )
+            .service(
+                // 名单导入单独设置 payload 限制（使用 upload.max_size）
+                web::resource("/import")
+                    .app_data(web::PayloadConfig::new(config.upload.max_size))
+                    .route(
+                        web::post()
+                            .to(import_class_roster)
+                            // 导入班级名单 - 仅班级教师权限
+                            .wrap(middlewares::RequireClassRole::new_any(
+                                ClassUserRole::class_teacher_roles(),
+                            )),
+                    ),
+            )
             .service(
                 web::resource("/{user_id}")
                     .route(
@@ -125,4 +195,39 @@ pub fn configure_class_users_routes(cfg: &mut web::ServiceConfig) {
                     ),
             ),
     );
+
+    cfg.service(
+        web::scope("/api/v1/classes/{class_id}/join-requests")
+            .wrap(middlewares::RequireJWT)
+            .service(
+                web::resource("").route(
+                    web::get()
+                        .to(list_class_join_requests)
+                        // 列出加入申请 - 仅班级教师权限
+                        .wrap(middlewares::RequireClassRole::new_any(
+                            ClassUserRole::class_teacher_roles(),
+                        )),
+                ),
+            )
+            .service(
+                web::resource("/approve").route(
+                    web::post()
+                        .to(approve_class_join_requests)
+                        // 批量通过加入申请 - 仅班级教师权限
+                        .wrap(middlewares::RequireClassRole::new_any(
+                            ClassUserRole::class_teacher_roles(),
+                        )),
+                ),
+            )
+            .service(
+                web::resource("/reject").route(
+                    web::post()
+                        .to(reject_class_join_requests)
+                        // 批量拒绝加入申请 - 仅班级教师权限
+                        .wrap(middlewares::RequireClassRole::new_any(
+                            ClassUserRole::class_teacher_roles(),
+                        )),
+                ),
+            ),
+    );
 }
@@ -0,0 +1,167 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult, web};
+use once_cell::sync::Lazy;
+
+use crate::middlewares;
+use crate::models::comments::requests::{CreateCommentRequest, UpdateCommentRequest};
+use crate::services::CommentService;
+use crate::services::comments::CommentTarget;
+use crate::utils::{SafeClassIdI64, SafeHomeworkIdI64};
+
+use crate::define_safe_i64_extractor;
+
+// 用于从请求路径中安全地提取 announcement_id 与 comment_id
+define_safe_i64_extractor!(SafeAnnouncementID, "announcement_id");
+define_safe_i64_extractor!(SafeCommentID, "comment_id");
+
+// 懒加载的全局 COMMENT_SERVICE 实例
+static COMMENT_SERVICE: Lazy<CommentService> = Lazy::new(CommentService::new_lazy);
+
+fn announcement_target(
+    class_id: &SafeClassIdI64,
+    announcement_id: &SafeAnnouncementID,
+) -> CommentTarget {
+    CommentTarget::Announcement {
+        class_id: class_id.0,
+        announcement_id: announcement_id.0,
+    }
+}
+
+// 公告讨论区 HTTP处理程序
+pub async fn list_announcement_comments(
+    req: HttpRequest,
+    path: web::Path<(SafeClassIdI64, SafeAnnouncementID)>,
+) -> ActixResult<HttpResponse> {
+    COMMENT_SERVICE
+        .list_comments(&req, announcement_target(&path.0, &path.1))
+        .await
+}
+
+pub async fn create_announcement_comment(
+    req: HttpRequest,
+    path: web::Path<(SafeClassIdI64, SafeAnnouncementID)>,
+    body: web::Json<CreateCommentRequest>,
+) -> ActixResult<HttpResponse> {
+    COMMENT_SERVICE
+        .create_comment(
+            &req,
+            announcement_target(&path.0, &path.1),
+            body.into_inner(),
+        )
+        .await
+}
+
+pub async fn update_announcement_comment(
+    req: HttpRequest,
+    path: web::Path<(SafeClassIdI64, SafeAnnouncementID, SafeCommentID)>,
+    body: web::Json<UpdateCommentRequest>,
+) -> ActixResult<HttpResponse> {
+    COMMENT_SERVICE
+        .update_comment(
+            &req,
+            announcement_target(&path.0, &path.1),
+            path.2.0,
+            body.into_inner(),
+        )
+        .await
+}
+
+pub async fn delete_announcement_comment(
+    req: HttpRequest,
+    path: web::Path<(SafeClassIdI64, SafeAnnouncementID, SafeCommentID)>,
+) -> ActixResult<HttpResponse> {
+    COMMENT_SERVICE
+        .delete_comment(&req, announcement_target(&path.0, &path.1), path.2.0)
+        .await
+}
+
+// 作业讨论区 HTTP处理程序
+pub async fn list_homework_comments(
+    req: HttpRequest,
+    path: SafeHomeworkIdI64,
+) -> ActixResult<HttpResponse> {
+    COMMENT_SERVICE
+        .list_comments(
+            &req,
+            CommentTarget::Homework {
+                homework_id: path.0,
+            },
+        )
+        .await
+}
+
+pub async fn create_homework_comment(
+    req: HttpRequest,
+    path: SafeHomeworkIdI64,
+    body: web::Json<CreateCommentRequest>,
+) -> ActixResult<HttpResponse> {
+    COMMENT_SERVICE
+        .create_comment(
+            &req,
+            CommentTarget::Homework {
+                homework_id: path.0,
+            },
+            body.into_inner(),
+        )
+        .await
+}
+
+pub async fn update_homework_comment(
+    req: HttpRequest,
+    path: web::Path<(SafeHomeworkIdI64, SafeCommentID)>,
+    body: web::Json<UpdateCommentRequest>,
+) -> ActixResult<HttpResponse> {
+    let homework_id = path.0.0;
+    let comment_id = path.1.0;
+    COMMENT_SERVICE
+        .update_comment(
+            &req,
+            CommentTarget::Homework { homework_id },
+            comment_id,
+            body.into_inner(),
+        )
+        .await
+}
+
+pub async fn delete_homework_comment(
+    req: HttpRequest,
+    path: web::Path<(SafeHomeworkIdI64, SafeCommentID)>,
+) -> ActixResult<HttpResponse> {
+    let homework_id = path.0.0;
+    let comment_id = path.1.0;
+    COMMENT_SERVICE
+        .delete_comment(&req, CommentTarget::Homework { homework_id }, comment_id)
+        .await
+}
+
+// 配置路由
+// 班级成员身份在服务层根据评论目标所属班级校验
+pub fn configure_comments_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/api/v1/classes/{class_id}/announcements/{announcement_id}/comments")
+            .wrap(middlewares::RequireJWT)
+            .service(
+                web::resource("")
+                    .route(web::get().to(list_announcement_comments))
+                    .route(web::post().to(create_announcement_comment)),
+            )
+            .service(
+                web::resource("/{comment_id}")
+                    .route(web::put().to(update_announcement_comment))
+                    .route(web::delete().to(delete_announcement_comment)),
+            ),
+    )
+    .service(
+        web::scope("/api/v1/homeworks/{homework_id}/comments")
+            .wrap(middlewares::RequireJWT)
+            .service(
+                web::resource("")
+                    .route(web::get().to(list_homework_comments))
+                    .route(web::post().to(create_homework_comment)),
+            )
+            .service(
+                web::resource("/{comment_id}")
+                    .route(web::put().to(update_homework_comment))
+                    .route(web::delete().to(delete_homework_comment)),
+            ),
+    );
+}
@@ -65,6 +65,21 @@ pub async fn update_grade(
         .await
 }
 
+// 确认评分
+pub async fn confirm_grade(req: HttpRequest, path: SafeIDI64) -> ActixResult<HttpResponse> {
+    let user_id = match RequireJWT::extract_user_id(&req) {
+        Some(id) => id,
+        None => {
+            return Ok(HttpResponse::Unauthorized().json(ApiResponse::error_empty(
+                ErrorCode::Unauthorized,
+                "无法获取用户信息",
+            )));
+        }
+    };
+
+    GRADE_SERVICE.confirm_grade(&req, path.0, user_id).await
+}
+
 // 配置路由
 pub fn configure_grades_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
@@ -91,6 +106,12 @@ pub fn configure_grades_routes(cfg: &mut web::ServiceConfig) {
                             .to(update_grade)
                             .wrap(middlewares::RequireRole::new_any(UserRole::teacher_roles())),
                     ),
+            )
+            .service(
+                web::resource("/{id}/confirm")
+                    // 确认待批阅的评分 - 仅教师和管理员（业务层校验评分者）
+                    .route(web::post().to(confirm_grade))
+                    .wrap(middlewares::RequireRole::new_any(UserRole::teacher_roles())),
             ),
     );
 }
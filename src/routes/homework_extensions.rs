@@ -0,0 +1,79 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult, web};
+use once_cell::sync::Lazy;
+
+use crate::middlewares;
+use crate::models::homework_extensions::requests::SetHomeworkExtensionRequest;
+use crate::models::users::entities::UserRole;
+use crate::services::HomeworkExtensionService;
+use crate::utils::SafeHomeworkIdI64;
+
+use crate::define_safe_i64_extractor;
+
+// 用于从请求路径中安全地提取 extension_id
+define_safe_i64_extractor!(SafeExtensionID, "extension_id");
+
+// 懒加载的全局 HOMEWORK_EXTENSION_SERVICE 实例
+static HOMEWORK_EXTENSION_SERVICE: Lazy<HomeworkExtensionService> =
+    Lazy::new(HomeworkExtensionService::new_lazy);
+
+// HTTP处理程序
+pub async fn list_homework_extensions(
+    req: HttpRequest,
+    path: SafeHomeworkIdI64,
+) -> ActixResult<HttpResponse> {
+    HOMEWORK_EXTENSION_SERVICE
+        .list_homework_extensions(&req, path.0)
+        .await
+}
+
+pub async fn set_homework_extension(
+    req: HttpRequest,
+    path: SafeHomeworkIdI64,
+    body: web::Json<SetHomeworkExtensionRequest>,
+) -> ActixResult<HttpResponse> {
+    HOMEWORK_EXTENSION_SERVICE
+        .set_homework_extension(&req, path.0, body.into_inner())
+        .await
+}
+
+pub async fn delete_homework_extension(
+    req: HttpRequest,
+    path: web::Path<(SafeHomeworkIdI64, SafeExtensionID)>,
+) -> ActixResult<HttpResponse> {
+    let homework_id = path.0.0;
+    let extension_id = path.1.0;
+    HOMEWORK_EXTENSION_SERVICE
+        .delete_homework_extension(&req, homework_id, extension_id)
+        .await
+}
+
+// 配置路由
+pub fn configure_homework_extensions_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/api/v1/homeworks/{homework_id}/extensions")
+            .wrap(middlewares::RequireJWT)
+            .service(
+                web::resource("")
+                    .route(
+                        web::get()
+                            .to(list_homework_extensions)
+                            // 列出作业延期 - 教师/管理员，服务层校验作业归属
+                            .wrap(middlewares::RequireRole::new_any(UserRole::teacher_roles())),
+                    )
+                    .route(
+                        web::post()
+                            .to(set_homework_extension)
+                            // 设置作业延期 - 教师/管理员，服务层校验作业归属
+                            .wrap(middlewares::RequireRole::new_any(UserRole::teacher_roles())),
+                    ),
+            )
+            .service(
+                web::resource("/{extension_id}").route(
+                    web::delete()
+                        .to(delete_homework_extension)
+                        // 删除作业延期 - 教师/管理员，服务层校验作业归属
+                        .wrap(middlewares::RequireRole::new_any(UserRole::teacher_roles())),
+                ),
+            ),
+    );
+}
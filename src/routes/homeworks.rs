@@ -3,8 +3,10 @@ use once_cell::sync::Lazy;
 
 use crate::middlewares::{self, RequireJWT};
 use crate::models::homeworks::requests::{
-    AllHomeworksQuery, CreateHomeworkRequest, HomeworkListQuery, UpdateHomeworkRequest,
+    AllHomeworksQuery, CreateHomeworkRequest, HomeworkListQuery, HomeworkStatsQuery,
+    UpdateHomeworkRequest,
 };
+use crate::models::question_bank::requests::AddBankQuestionsRequest;
 use crate::models::users::entities::UserRole;
 use crate::models::{ApiResponse, ErrorCode};
 use crate::services::HomeworkService;
@@ -86,14 +88,37 @@ pub async fn delete_homework(req: HttpRequest, path: SafeIDI64) -> ActixResult<H
         .await
 }
 
+// 从题库添加作业题目
+pub async fn add_bank_questions(
+    req: HttpRequest,
+    path: SafeIDI64,
+    body: web::Json<AddBankQuestionsRequest>,
+) -> ActixResult<HttpResponse> {
+    HOMEWORK_SERVICE
+        .add_bank_questions(&req, path.0, body.into_inner())
+        .await
+}
+
 // 获取作业统计
-pub async fn get_homework_stats(req: HttpRequest, path: SafeIDI64) -> ActixResult<HttpResponse> {
-    HOMEWORK_SERVICE.get_homework_stats(&req, path.0).await
+pub async fn get_homework_stats(
+    req: HttpRequest,
+    path: SafeIDI64,
+    query: web::Query<HomeworkStatsQuery>,
+) -> ActixResult<HttpResponse> {
+    HOMEWORK_SERVICE
+        .get_homework_stats(&req, path.0, query.into_inner())
+        .await
 }
 
 // 导出作业统计
-pub async fn export_homework_stats(req: HttpRequest, path: SafeIDI64) -> ActixResult<HttpResponse> {
-    HOMEWORK_SERVICE.export_homework_stats(&req, path.0).await
+pub async fn export_homework_stats(
+    req: HttpRequest,
+    path: SafeIDI64,
+    query: web::Query<HomeworkStatsQuery>,
+) -> ActixResult<HttpResponse> {
+    HOMEWORK_SERVICE
+        .export_homework_stats(&req, path.0, query.into_inner())
+        .await
 }
 
 // 获取学生作业统计
@@ -159,6 +184,12 @@ pub fn configure_homeworks_routes(cfg: &mut web::ServiceConfig) {
                             .wrap(middlewares::RequireRole::new_any(UserRole::teacher_roles())),
                     ),
             )
+            .service(
+                web::resource("/{id}/questions/bank")
+                    // 从题库添加题目 - 仅教师和管理员（业务层校验作业归属）
+                    .route(web::post().to(add_bank_questions))
+                    .wrap(middlewares::RequireRole::new_any(UserRole::teacher_roles())),
+            )
             .service(
                 web::resource("/{id}/stats")
                     // 权限在业务层检查（允许教师、课代表、管理员）
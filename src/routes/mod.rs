@@ -1,19 +1,35 @@
 pub mod auth;
 
+pub mod announcements;
+
 pub mod users;
 
+pub mod profile_fields;
+
 pub mod classes;
 
+pub mod comments;
+
+pub mod class_groups;
+
 pub mod class_users;
 
 pub mod files;
 
+pub mod homework_extensions;
+
 pub mod homeworks;
 
 pub mod submissions;
 
 pub mod grades;
 
+pub mod rubrics;
+
+pub mod question_bank;
+
+pub mod auto_graders;
+
 pub mod notifications;
 
 pub mod system;
@@ -22,14 +38,22 @@ pub mod frontend;
 
 pub mod websocket;
 
+pub use announcements::configure_announcements_routes;
 pub use auth::configure_auth_routes;
+pub use auto_graders::configure_auto_graders_routes;
+pub use class_groups::configure_class_groups_routes;
 pub use class_users::configure_class_users_routes;
 pub use classes::configure_classes_routes;
+pub use comments::configure_comments_routes;
 pub use files::configure_file_routes;
 pub use frontend::configure_frontend_routes;
 pub use grades::configure_grades_routes;
+pub use homework_extensions::configure_homework_extensions_routes;
 pub use homeworks::configure_homeworks_routes;
 pub use notifications::configure_notifications_routes;
+pub use profile_fields::configure_profile_fields_routes;
+pub use question_bank::configure_question_bank_routes;
+pub use rubrics::configure_rubrics_routes;
 pub use submissions::configure_submissions_routes;
 pub use system::configure_system_routes;
 pub use users::configure_user_routes;
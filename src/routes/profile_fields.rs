@@ -0,0 +1,61 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult, web};
+use once_cell::sync::Lazy;
+
+use crate::middlewares;
+use crate::models::profile_fields::requests::{
+    CreateProfileFieldRequest, UpdateProfileFieldRequest,
+};
+use crate::models::users::entities::UserRole;
+use crate::services::ProfileFieldService;
+use crate::utils::SafeIDI64;
+
+// 懒加载的全局 PROFILE_FIELD_SERVICE 实例
+static PROFILE_FIELD_SERVICE: Lazy<ProfileFieldService> = Lazy::new(ProfileFieldService::new_lazy);
+
+// HTTP处理程序
+pub async fn list_profile_fields(req: HttpRequest) -> ActixResult<HttpResponse> {
+    PROFILE_FIELD_SERVICE.list_profile_fields(&req).await
+}
+
+pub async fn create_profile_field(
+    req: HttpRequest,
+    body: web::Json<CreateProfileFieldRequest>,
+) -> ActixResult<HttpResponse> {
+    PROFILE_FIELD_SERVICE
+        .create_profile_field(&req, body.into_inner())
+        .await
+}
+
+pub async fn update_profile_field(
+    req: HttpRequest,
+    path: SafeIDI64,
+    body: web::Json<UpdateProfileFieldRequest>,
+) -> ActixResult<HttpResponse> {
+    PROFILE_FIELD_SERVICE
+        .update_profile_field(&req, path.0, body.into_inner())
+        .await
+}
+
+pub async fn delete_profile_field(req: HttpRequest, path: SafeIDI64) -> ActixResult<HttpResponse> {
+    PROFILE_FIELD_SERVICE
+        .delete_profile_field(&req, path.0)
+        .await
+}
+
+// 配置路由
+pub fn configure_profile_fields_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/api/v1/profile-fields")
+            .wrap(middlewares::RequireJWT)
+            // 所有登录用户可查看字段定义
+            .route("", web::get().to(list_profile_fields))
+            // 管理员专属路由
+            .service(
+                web::scope("")
+                    .wrap(middlewares::RequireRole::new_any(UserRole::admin_roles()))
+                    .route("", web::post().to(create_profile_field))
+                    .route("/{id}", web::put().to(update_profile_field))
+                    .route("/{id}", web::delete().to(delete_profile_field)),
+            ),
+    );
+}
@@ -0,0 +1,92 @@
+use actix_multipart::Multipart;
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult, web};
+use once_cell::sync::Lazy;
+
+use crate::middlewares;
+use crate::models::question_bank::requests::{
+    BankQuestionExportParams, BankQuestionListQuery, CreateBankQuestionRequest,
+    UpdateBankQuestionRequest,
+};
+use crate::models::users::entities::UserRole;
+use crate::services::QuestionBankService;
+use crate::utils::SafeIDI64;
+
+// 懒加载的全局 QUESTION_BANK_SERVICE 实例
+static QUESTION_BANK_SERVICE: Lazy<QuestionBankService> = Lazy::new(QuestionBankService::new_lazy);
+
+// HTTP处理程序
+pub async fn list_bank_questions(
+    req: HttpRequest,
+    query: web::Query<BankQuestionListQuery>,
+) -> ActixResult<HttpResponse> {
+    QUESTION_BANK_SERVICE
+        .list_bank_questions(&req, query.into_inner())
+        .await
+}
+
+pub async fn create_bank_question(
+    req: HttpRequest,
+    body: web::Json<CreateBankQuestionRequest>,
+) -> ActixResult<HttpResponse> {
+    QUESTION_BANK_SERVICE
+        .create_bank_question(&req, body.into_inner())
+        .await
+}
+
+pub async fn export_bank_questions(
+    req: HttpRequest,
+    query: web::Query<BankQuestionExportParams>,
+) -> ActixResult<HttpResponse> {
+    QUESTION_BANK_SERVICE
+        .export_bank_questions(&req, query.into_inner())
+        .await
+}
+
+pub async fn import_bank_questions(
+    req: HttpRequest,
+    payload: Multipart,
+) -> ActixResult<HttpResponse> {
+    QUESTION_BANK_SERVICE
+        .import_bank_questions(&req, payload)
+        .await
+}
+
+pub async fn get_bank_question(req: HttpRequest, path: SafeIDI64) -> ActixResult<HttpResponse> {
+    QUESTION_BANK_SERVICE.get_bank_question(&req, path.0).await
+}
+
+pub async fn update_bank_question(
+    req: HttpRequest,
+    path: SafeIDI64,
+    body: web::Json<UpdateBankQuestionRequest>,
+) -> ActixResult<HttpResponse> {
+    QUESTION_BANK_SERVICE
+        .update_bank_question(&req, path.0, body.into_inner())
+        .await
+}
+
+pub async fn delete_bank_question(req: HttpRequest, path: SafeIDI64) -> ActixResult<HttpResponse> {
+    QUESTION_BANK_SERVICE
+        .delete_bank_question(&req, path.0)
+        .await
+}
+
+// 配置路由
+pub fn configure_question_bank_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/api/v1/question-bank")
+            .wrap(middlewares::RequireJWT)
+            // 题库 - 教师/管理员，服务层校验归属与共享
+            .service(
+                web::scope("")
+                    .wrap(middlewares::RequireRole::new_any(UserRole::teacher_roles()))
+                    .route("", web::get().to(list_bank_questions))
+                    .route("", web::post().to(create_bank_question))
+                    .route("/export", web::get().to(export_bank_questions))
+                    .route("/import", web::post().to(import_bank_questions))
+                    .route("/{id}", web::get().to(get_bank_question))
+                    .route("/{id}", web::put().to(update_bank_question))
+                    .route("/{id}", web::delete().to(delete_bank_question)),
+            ),
+    );
+}
@@ -0,0 +1,59 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult, web};
+use once_cell::sync::Lazy;
+
+use crate::middlewares;
+use crate::models::rubrics::requests::{CreateRubricRequest, UpdateRubricRequest};
+use crate::models::users::entities::UserRole;
+use crate::services::RubricService;
+use crate::utils::SafeIDI64;
+
+// 懒加载的全局 RUBRIC_SERVICE 实例
+static RUBRIC_SERVICE: Lazy<RubricService> = Lazy::new(RubricService::new_lazy);
+
+// HTTP处理程序
+pub async fn list_rubrics(req: HttpRequest) -> ActixResult<HttpResponse> {
+    RUBRIC_SERVICE.list_rubrics(&req).await
+}
+
+pub async fn create_rubric(
+    req: HttpRequest,
+    body: web::Json<CreateRubricRequest>,
+) -> ActixResult<HttpResponse> {
+    RUBRIC_SERVICE.create_rubric(&req, body.into_inner()).await
+}
+
+pub async fn get_rubric(req: HttpRequest, path: SafeIDI64) -> ActixResult<HttpResponse> {
+    RUBRIC_SERVICE.get_rubric(&req, path.0).await
+}
+
+pub async fn update_rubric(
+    req: HttpRequest,
+    path: SafeIDI64,
+    body: web::Json<UpdateRubricRequest>,
+) -> ActixResult<HttpResponse> {
+    RUBRIC_SERVICE
+        .update_rubric(&req, path.0, body.into_inner())
+        .await
+}
+
+pub async fn delete_rubric(req: HttpRequest, path: SafeIDI64) -> ActixResult<HttpResponse> {
+    RUBRIC_SERVICE.delete_rubric(&req, path.0).await
+}
+
+// 配置路由
+pub fn configure_rubrics_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/api/v1/rubrics")
+            .wrap(middlewares::RequireJWT)
+            // 评分细则库 - 教师/管理员，服务层校验归属
+            .service(
+                web::scope("")
+                    .wrap(middlewares::RequireRole::new_any(UserRole::teacher_roles()))
+                    .route("", web::get().to(list_rubrics))
+                    .route("", web::post().to(create_rubric))
+                    .route("/{id}", web::get().to(get_rubric))
+                    .route("/{id}", web::put().to(update_rubric))
+                    .route("/{id}", web::delete().to(delete_rubric)),
+            ),
+    );
+}
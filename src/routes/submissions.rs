@@ -118,6 +118,7 @@ pub async fn get_submission_summary(
             Some(query.pagination.page),
             Some(query.pagination.page_size),
             query.graded,
+            query.group_id,
         )
         .await
 }
@@ -138,6 +139,26 @@ pub async fn get_submission_grade(req: HttpRequest, path: SafeIDI64) -> ActixRes
     SUBMISSION_SERVICE.get_submission_grade(&req, path.0).await
 }
 
+// 获取提交的自动评测结果
+pub async fn get_submission_auto_grade(
+    req: HttpRequest,
+    path: SafeIDI64,
+) -> ActixResult<HttpResponse> {
+    SUBMISSION_SERVICE
+        .get_submission_auto_grade(&req, path.0)
+        .await
+}
+
+// 重新自动评测
+pub async fn rerun_submission_auto_grade(
+    req: HttpRequest,
+    path: SafeIDI64,
+) -> ActixResult<HttpResponse> {
+    SUBMISSION_SERVICE
+        .rerun_submission_auto_grade(&req, path.0)
+        .await
+}
+
 // 配置路由
 pub fn configure_submissions_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
@@ -147,7 +168,13 @@ pub fn configure_submissions_routes(cfg: &mut web::ServiceConfig) {
             .route("", web::post().to(create_submission))
             .route("/{id}", web::get().to(get_submission))
             .route("/{id}", web::delete().to(delete_submission))
-            .route("/{id}/grade", web::get().to(get_submission_grade)),
+            .route("/{id}/grade", web::get().to(get_submission_grade))
+            // 自动评测结果与重新评测 - 权限在业务层检查（班级教师、作业创建者或管理员）
+            .route("/{id}/auto-grade", web::get().to(get_submission_auto_grade))
+            .route(
+                "/{id}/auto-grade/rerun",
+                web::post().to(rerun_submission_auto_grade),
+            ),
     );
 
     // 作业相关的提交路由
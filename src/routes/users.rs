@@ -59,9 +59,12 @@ pub async fn import_users(req: HttpRequest, payload: Multipart) -> ActixResult<H
 }
 
 pub async fn download_import_template(
+    req: HttpRequest,
     query: web::Query<ImportTemplateParams>,
 ) -> ActixResult<HttpResponse> {
-    USER_SERVICE.download_import_template(&query.format).await
+    USER_SERVICE
+        .download_import_template(&query.format, &req)
+        .await
 }
 
 pub async fn get_my_stats(req: HttpRequest) -> ActixResult<HttpResponse> {
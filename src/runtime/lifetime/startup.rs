@@ -157,6 +157,7 @@ async fn seed_admin(storage: &Arc<dyn Storage>) {
         role: UserRole::Admin,
         display_name: Some("Administrator".to_string()),
         avatar_url: None,
+        profile: None,
     };
 
     match storage.create_user(admin_request).await {
@@ -202,5 +203,15 @@ pub async fn prepare_server_startup() -> StartupContext {
     let cache = create_cache().await.expect("Failed to create cache");
     warn!("Cache backend initialized");
 
+    // 服务重启前未完成的自动评测重新排队，由定时任务重新评测
+    match storage.reset_running_auto_grade_runs().await {
+        Ok(0) => {}
+        Ok(count) => warn!("{} interrupted auto grade runs requeued", count),
+        Err(e) => warn!("Failed to requeue interrupted auto grade runs: {}", e),
+    }
+
+    // 启动后台定时任务（定时发布作业等）
+    crate::runtime::scheduler::spawn_scheduler(storage.clone());
+
     StartupContext { storage, cache }
 }
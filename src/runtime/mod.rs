@@ -1,5 +1,6 @@
 //! 运行时生命周期管理
 //!
-//! 包含服务启动和关闭逻辑。
+//! 包含服务启动和关闭逻辑，以及后台定时任务。
 
 pub mod lifetime;
+pub mod scheduler;
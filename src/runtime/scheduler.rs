@@ -0,0 +1,111 @@
+//! 后台定时任务
+//!
+//! 周期性执行：
+//! - 发布到期的定时发布作业并通知班级学生；
+//! - 在截止前的配置时间点提醒尚未提交的学生；
+//! - 评测等待中的自动评测记录（如服务重启前未完成的评测）。
+//!
+//! 提醒记录持久化在数据库中（作业 + 提前量 + 截止时间唯一），
+//! 重启或多实例部署时每条提醒只发送一次，截止时间修改后按新时间重新提醒。
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tracing::{debug, warn};
+
+use crate::services::auto_graders::process_pending_auto_grade_runs;
+use crate::services::homeworks::{notify_homework_deadline, notify_homework_published};
+use crate::services::system::DynamicConfig;
+use crate::storage::Storage;
+
+/// 定时任务扫描间隔
+const SCHEDULER_INTERVAL: Duration = Duration::from_secs(30);
+
+/// 启动后台定时任务
+pub fn spawn_scheduler(storage: Arc<dyn Storage>) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(SCHEDULER_INTERVAL);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        loop {
+            interval.tick().await;
+            publish_due_homeworks(&storage).await;
+            send_deadline_reminders(&storage).await;
+            process_pending_auto_grade_runs(&storage).await;
+        }
+    });
+}
+
+/// 发布已到发布时间的定时作业，并发送新作业通知
+async fn publish_due_homeworks(storage: &Arc<dyn Storage>) {
+    let now = chrono::Utc::now().timestamp();
+    match storage.publish_due_homeworks(now).await {
+        Ok(homeworks) => {
+            for homework in &homeworks {
+                debug!("Scheduled homework {} published", homework.id);
+                notify_homework_published(storage.clone(), homework).await;
+            }
+        }
+        Err(e) => warn!("Failed to publish scheduled homeworks: {}", e),
+    }
+}
+
+/// 向尚未提交的学生发送截止提醒
+///
+/// 多个提前量同时到期时（如作业发布较晚或截止时间被提前）只发送最近的一条。
+/// 提醒时间点早于作业发布时间的不发送，避免发布后立即收到提醒。
+async fn send_deadline_reminders(storage: &Arc<dyn Storage>) {
+    let offsets = DynamicConfig::deadline_reminder_offsets().await;
+    let Some(&max_offset) = offsets.first() else {
+        return;
+    };
+
+    let now = chrono::Utc::now().timestamp();
+    let homeworks = match storage
+        .list_published_homeworks_due_between(now, now + max_offset * 60)
+        .await
+    {
+        Ok(homeworks) => homeworks,
+        Err(e) => {
+            warn!("Failed to list homeworks for deadline reminders: {}", e);
+            return;
+        }
+    };
+
+    for homework in &homeworks {
+        let Some(deadline) = homework.deadline.map(|d| d.timestamp()) else {
+            continue;
+        };
+        let remaining = deadline - now;
+
+        // 已到期的最小提前量
+        let Some(offset) = offsets.iter().rev().copied().find(|&o| o * 60 >= remaining) else {
+            continue;
+        };
+
+        let published_at = homework
+            .publish_at
+            .unwrap_or(homework.created_at)
+            .timestamp();
+        if deadline - offset * 60 < published_at {
+            continue;
+        }
+
+        match storage
+            .claim_deadline_reminder(homework.id, offset, deadline)
+            .await
+        {
+            Ok(true) => {
+                debug!(
+                    "Sending deadline reminder for homework {} ({} minutes before)",
+                    homework.id, offset
+                );
+                notify_homework_deadline(storage.clone(), homework, remaining / 60).await;
+            }
+            Ok(false) => {}
+            Err(e) => warn!(
+                "Failed to claim deadline reminder for homework {}: {}",
+                homework.id, e
+            ),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use tracing::error;
+
+use super::AnnouncementService;
+use crate::middlewares::RequireJWT;
+use crate::models::announcements::requests::CreateAnnouncementRequest;
+use crate::models::notifications::entities::{NotificationType, ReferenceType};
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::notifications::trigger::{get_class_student_ids, send_notifications};
+use crate::services::{StorageProvider, error_response};
+
+pub async fn create_announcement(
+    service: &AnnouncementService,
+    request: &HttpRequest,
+    class_id: i64,
+    mut body: CreateAnnouncementRequest,
+) -> ActixResult<HttpResponse> {
+    let user_id = match RequireJWT::extract_user_id(request) {
+        Some(id) => id,
+        None => {
+            return Ok(HttpResponse::Unauthorized().json(ApiResponse::error_empty(
+                ErrorCode::Unauthorized,
+                "Unauthorized: missing user id",
+            )));
+        }
+    };
+
+    body.title = body.title.trim().to_string();
+    if body.title.is_empty() {
+        return Ok(HttpResponse::BadRequest().json(ApiResponse::error_empty(
+            ErrorCode::BadRequest,
+            "Announcement title must not be empty",
+        )));
+    }
+
+    let storage = service.get_storage(request)?;
+
+    let announcement = match storage.create_announcement(class_id, user_id, body).await {
+        Ok(announcement) => announcement,
+        Err(e) => {
+            error!("Failed to create announcement: {}", e);
+            return Ok(error_response(e));
+        }
+    };
+
+    // 异步通知班级学生
+    let storage_clone = storage.clone();
+    let announcement_id = announcement.id;
+    let title = announcement.title.clone();
+    tokio::spawn(async move {
+        let student_ids = get_class_student_ids(&storage_clone, class_id, &[]).await;
+        send_notifications(
+            storage_clone,
+            student_ids,
+            NotificationType::AnnouncementPosted,
+            format!("新公告：{}", title),
+            Some(format!("班级发布了新公告「{}」，请及时查看", title)),
+            Some(ReferenceType::Announcement),
+            Some(announcement_id),
+        )
+        .await;
+    });
+
+    Ok(HttpResponse::Created().json(ApiResponse::success(
+        announcement,
+        "Announcement created successfully",
+    )))
+}
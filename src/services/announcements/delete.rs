@@ -0,0 +1,33 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use tracing::error;
+
+use super::{AnnouncementService, load_class_announcement};
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::{StorageProvider, error_response};
+
+pub async fn delete_announcement(
+    service: &AnnouncementService,
+    request: &HttpRequest,
+    class_id: i64,
+    announcement_id: i64,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    if let Err(resp) = load_class_announcement(&storage, class_id, announcement_id).await {
+        return Ok(resp);
+    }
+
+    match storage.delete_announcement(announcement_id).await {
+        Ok(true) => Ok(HttpResponse::Ok().json(ApiResponse::success_empty(
+            "Announcement deleted successfully",
+        ))),
+        Ok(false) => Ok(HttpResponse::NotFound().json(ApiResponse::error_empty(
+            ErrorCode::AnnouncementNotFound,
+            "Announcement not found",
+        ))),
+        Err(e) => {
+            error!("Failed to delete announcement: {}", e);
+            Ok(error_response(e))
+        }
+    }
+}
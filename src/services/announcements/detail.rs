@@ -0,0 +1,96 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use tracing::warn;
+
+use super::{AnnouncementService, load_class_announcement, user_info_from_map};
+use crate::middlewares::RequireJWT;
+use crate::models::announcements::responses::AnnouncementDetail;
+use crate::models::files::responses::FileInfo;
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::StorageProvider;
+
+pub async fn get_announcement(
+    service: &AnnouncementService,
+    request: &HttpRequest,
+    class_id: i64,
+    announcement_id: i64,
+) -> ActixResult<HttpResponse> {
+    let user_id = match RequireJWT::extract_user_id(request) {
+        Some(id) => id,
+        None => {
+            return Ok(HttpResponse::Unauthorized().json(ApiResponse::error_empty(
+                ErrorCode::Unauthorized,
+                "Unauthorized: missing user id",
+            )));
+        }
+    };
+
+    let storage = service.get_storage(request)?;
+
+    let announcement = match load_class_announcement(&storage, class_id, announcement_id).await {
+        Ok(announcement) => announcement,
+        Err(resp) => return Ok(resp),
+    };
+
+    // 查看详情即视为已读（发布者本人除外）
+    let is_read = if announcement.created_by == user_id {
+        false
+    } else {
+        if let Err(e) = storage
+            .mark_announcement_read(announcement_id, user_id)
+            .await
+        {
+            warn!(
+                "Failed to mark announcement {} read: {}",
+                announcement_id, e
+            );
+        }
+        true
+    };
+
+    // 批量获取附件完整信息
+    let file_ids = storage
+        .get_announcement_file_ids(announcement_id)
+        .await
+        .unwrap_or_default();
+
+    let files_map = storage
+        .get_files_by_ids(&file_ids)
+        .await
+        .unwrap_or_default();
+
+    let attachments: Vec<FileInfo> = file_ids
+        .iter()
+        .filter_map(|id| {
+            files_map.get(id).map(|file| FileInfo {
+                download_token: file.download_token.clone(),
+                original_name: file.original_name.clone(),
+                file_size: file.file_size,
+                file_type: file.file_type.clone(),
+            })
+        })
+        .collect();
+
+    let creator = storage
+        .get_users_by_ids(&[announcement.created_by])
+        .await
+        .ok()
+        .map(|map| user_info_from_map(announcement.created_by, &map));
+
+    let read_count = storage
+        .count_announcement_reads(&[announcement_id])
+        .await
+        .ok()
+        .and_then(|counts| counts.get(&announcement_id).copied())
+        .unwrap_or(0);
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(
+        AnnouncementDetail {
+            announcement,
+            attachments,
+            creator,
+            is_read,
+            read_count,
+        },
+        "Announcement retrieved successfully",
+    )))
+}
@@ -0,0 +1,89 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use tracing::error;
+
+use super::{AnnouncementService, user_info_from_map};
+use crate::middlewares::RequireJWT;
+use crate::models::announcements::requests::AnnouncementListQuery;
+use crate::models::announcements::responses::{
+    AnnouncementDetailListResponse, AnnouncementListItem,
+};
+use crate::models::comments::entities::CommentTargetType;
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::{StorageProvider, error_response};
+
+pub async fn list_announcements(
+    service: &AnnouncementService,
+    request: &HttpRequest,
+    class_id: i64,
+    query: AnnouncementListQuery,
+) -> ActixResult<HttpResponse> {
+    let user_id = match RequireJWT::extract_user_id(request) {
+        Some(id) => id,
+        None => {
+            return Ok(HttpResponse::Unauthorized().json(ApiResponse::error_empty(
+                ErrorCode::Unauthorized,
+                "Unauthorized: missing user id",
+            )));
+        }
+    };
+
+    let storage = service.get_storage(request)?;
+
+    let response = match storage
+        .list_announcements_with_pagination(class_id, query)
+        .await
+    {
+        Ok(response) => response,
+        Err(e) => {
+            error!("Failed to list announcements: {}", e);
+            return Ok(error_response(e));
+        }
+    };
+
+    let announcement_ids: Vec<i64> = response.items.iter().map(|a| a.id).collect();
+
+    // 批量获取发布者、已读状态与统计
+    let creator_ids: Vec<i64> = response.items.iter().map(|a| a.created_by).collect();
+    let user_map = match storage.get_users_by_ids(&creator_ids).await {
+        Ok(map) => map,
+        Err(e) => return Ok(error_response(e)),
+    };
+    let read_ids = match storage
+        .list_read_announcement_ids(user_id, &announcement_ids)
+        .await
+    {
+        Ok(ids) => ids,
+        Err(e) => return Ok(error_response(e)),
+    };
+    let read_counts = match storage.count_announcement_reads(&announcement_ids).await {
+        Ok(counts) => counts,
+        Err(e) => return Ok(error_response(e)),
+    };
+    let comment_counts = match storage
+        .count_comments(CommentTargetType::Announcement, &announcement_ids)
+        .await
+    {
+        Ok(counts) => counts,
+        Err(e) => return Ok(error_response(e)),
+    };
+
+    let items = response
+        .items
+        .into_iter()
+        .map(|announcement| AnnouncementListItem {
+            creator: Some(user_info_from_map(announcement.created_by, &user_map)),
+            is_read: read_ids.contains(&announcement.id),
+            read_count: read_counts.get(&announcement.id).copied().unwrap_or(0),
+            comment_count: comment_counts.get(&announcement.id).copied().unwrap_or(0),
+            announcement,
+        })
+        .collect();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(
+        AnnouncementDetailListResponse {
+            pagination: response.pagination,
+            items,
+        },
+        "Announcements retrieved successfully",
+    )))
+}
@@ -0,0 +1,143 @@
+pub mod create;
+pub mod delete;
+pub mod detail;
+pub mod list;
+pub mod reads;
+pub mod update;
+
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::models::announcements::entities::Announcement;
+use crate::models::announcements::requests::{
+    AnnouncementListQuery, CreateAnnouncementRequest, UpdateAnnouncementRequest,
+};
+use crate::models::class_users::responses::UserInfo;
+use crate::models::users::entities::User;
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::{CacheProvider, StorageProvider, error_response};
+use crate::storage::Storage;
+
+pub struct AnnouncementService {
+    storage: Option<Arc<dyn Storage>>,
+}
+
+impl AnnouncementService {
+    pub fn new_lazy() -> Self {
+        Self { storage: None }
+    }
+
+    // 列出班级公告
+    pub async fn list_announcements(
+        &self,
+        req: &HttpRequest,
+        class_id: i64,
+        query: AnnouncementListQuery,
+    ) -> ActixResult<HttpResponse> {
+        list::list_announcements(self, req, class_id, query).await
+    }
+
+    // 发布公告
+    pub async fn create_announcement(
+        &self,
+        req: &HttpRequest,
+        class_id: i64,
+        body: CreateAnnouncementRequest,
+    ) -> ActixResult<HttpResponse> {
+        create::create_announcement(self, req, class_id, body).await
+    }
+
+    // 获取公告详情
+    pub async fn get_announcement(
+        &self,
+        req: &HttpRequest,
+        class_id: i64,
+        announcement_id: i64,
+    ) -> ActixResult<HttpResponse> {
+        detail::get_announcement(self, req, class_id, announcement_id).await
+    }
+
+    // 更新公告
+    pub async fn update_announcement(
+        &self,
+        req: &HttpRequest,
+        class_id: i64,
+        announcement_id: i64,
+        body: UpdateAnnouncementRequest,
+    ) -> ActixResult<HttpResponse> {
+        update::update_announcement(self, req, class_id, announcement_id, body).await
+    }
+
+    // 删除公告
+    pub async fn delete_announcement(
+        &self,
+        req: &HttpRequest,
+        class_id: i64,
+        announcement_id: i64,
+    ) -> ActixResult<HttpResponse> {
+        delete::delete_announcement(self, req, class_id, announcement_id).await
+    }
+
+    // 标记公告已读
+    pub async fn mark_announcement_read(
+        &self,
+        req: &HttpRequest,
+        class_id: i64,
+        announcement_id: i64,
+    ) -> ActixResult<HttpResponse> {
+        reads::mark_announcement_read(self, req, class_id, announcement_id).await
+    }
+
+    // 获取公告已读回执
+    pub async fn list_announcement_reads(
+        &self,
+        req: &HttpRequest,
+        class_id: i64,
+        announcement_id: i64,
+    ) -> ActixResult<HttpResponse> {
+        reads::list_announcement_reads(self, req, class_id, announcement_id).await
+    }
+}
+
+impl StorageProvider for AnnouncementService {
+    fn storage_ref(&self) -> Option<Arc<dyn Storage>> {
+        self.storage.clone()
+    }
+}
+
+impl CacheProvider for AnnouncementService {}
+
+/// 获取属于指定班级的公告，不存在或不属于该班级时返回 404
+pub(crate) async fn load_class_announcement(
+    storage: &Arc<dyn Storage>,
+    class_id: i64,
+    announcement_id: i64,
+) -> Result<Announcement, HttpResponse> {
+    match storage.get_announcement_by_id(announcement_id).await {
+        Ok(Some(announcement)) if announcement.class_id == class_id => Ok(announcement),
+        Ok(_) => Err(HttpResponse::NotFound().json(ApiResponse::error_empty(
+            ErrorCode::AnnouncementNotFound,
+            "Announcement not found",
+        ))),
+        Err(e) => Err(error_response(e)),
+    }
+}
+
+/// 从用户映射中构造用户简要信息，用户不存在时返回占位信息
+pub(crate) fn user_info_from_map(user_id: i64, user_map: &HashMap<i64, User>) -> UserInfo {
+    match user_map.get(&user_id) {
+        Some(u) => UserInfo {
+            id: u.id,
+            username: u.username.clone(),
+            display_name: u.display_name.clone(),
+            avatar_url: u.avatar_url.clone(),
+        },
+        None => UserInfo {
+            id: user_id,
+            username: "未知用户".to_string(),
+            display_name: None,
+            avatar_url: None,
+        },
+    }
+}
@@ -0,0 +1,106 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use std::collections::HashSet;
+use tracing::error;
+
+use super::{AnnouncementService, load_class_announcement, user_info_from_map};
+use crate::middlewares::RequireJWT;
+use crate::models::announcements::responses::{AnnouncementReadReceipt, AnnouncementReadsResponse};
+use crate::models::class_users::entities::ClassUserRole;
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::{StorageProvider, error_response};
+
+pub async fn mark_announcement_read(
+    service: &AnnouncementService,
+    request: &HttpRequest,
+    class_id: i64,
+    announcement_id: i64,
+) -> ActixResult<HttpResponse> {
+    let user_id = match RequireJWT::extract_user_id(request) {
+        Some(id) => id,
+        None => {
+            return Ok(HttpResponse::Unauthorized().json(ApiResponse::error_empty(
+                ErrorCode::Unauthorized,
+                "Unauthorized: missing user id",
+            )));
+        }
+    };
+
+    let storage = service.get_storage(request)?;
+
+    if let Err(resp) = load_class_announcement(&storage, class_id, announcement_id).await {
+        return Ok(resp);
+    }
+
+    match storage
+        .mark_announcement_read(announcement_id, user_id)
+        .await
+    {
+        Ok(_) => {
+            Ok(HttpResponse::Ok().json(ApiResponse::success_empty("Announcement marked as read")))
+        }
+        Err(e) => {
+            error!("Failed to mark announcement read: {}", e);
+            Ok(error_response(e))
+        }
+    }
+}
+
+/// 已读回执：列出已读学生与未读学生（仅统计非教师成员）
+pub async fn list_announcement_reads(
+    service: &AnnouncementService,
+    request: &HttpRequest,
+    class_id: i64,
+    announcement_id: i64,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    if let Err(resp) = load_class_announcement(&storage, class_id, announcement_id).await {
+        return Ok(resp);
+    }
+
+    let reads = match storage.list_announcement_reads(announcement_id).await {
+        Ok(reads) => reads,
+        Err(e) => return Ok(error_response(e)),
+    };
+
+    let student_ids: Vec<i64> = match storage.list_all_class_users(class_id).await {
+        Ok(members) => members
+            .into_iter()
+            .filter(|cu| cu.role != ClassUserRole::Teacher)
+            .map(|cu| cu.user_id)
+            .collect(),
+        Err(e) => return Ok(error_response(e)),
+    };
+    let student_set: HashSet<i64> = student_ids.iter().copied().collect();
+
+    let user_map = match storage.get_users_by_ids(&student_ids).await {
+        Ok(map) => map,
+        Err(e) => return Ok(error_response(e)),
+    };
+
+    let readers: Vec<AnnouncementReadReceipt> = reads
+        .iter()
+        .filter(|r| student_set.contains(&r.user_id))
+        .map(|r| AnnouncementReadReceipt {
+            user: user_info_from_map(r.user_id, &user_map),
+            read_at: r.read_at,
+        })
+        .collect();
+
+    let read_set: HashSet<i64> = reads.iter().map(|r| r.user_id).collect();
+    let unread_users: Vec<_> = student_ids
+        .iter()
+        .filter(|id| !read_set.contains(id))
+        .map(|&id| user_info_from_map(id, &user_map))
+        .collect();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(
+        AnnouncementReadsResponse {
+            read_count: readers.len() as i64,
+            unread_count: unread_users.len() as i64,
+            readers,
+            unread_users,
+        },
+        "Announcement read receipts retrieved successfully",
+    )))
+}
@@ -0,0 +1,60 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use tracing::error;
+
+use super::{AnnouncementService, load_class_announcement};
+use crate::middlewares::RequireJWT;
+use crate::models::announcements::requests::UpdateAnnouncementRequest;
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::{StorageProvider, error_response};
+
+pub async fn update_announcement(
+    service: &AnnouncementService,
+    request: &HttpRequest,
+    class_id: i64,
+    announcement_id: i64,
+    mut body: UpdateAnnouncementRequest,
+) -> ActixResult<HttpResponse> {
+    let user_id = match RequireJWT::extract_user_id(request) {
+        Some(id) => id,
+        None => {
+            return Ok(HttpResponse::Unauthorized().json(ApiResponse::error_empty(
+                ErrorCode::Unauthorized,
+                "Unauthorized: missing user id",
+            )));
+        }
+    };
+
+    if let Some(title) = body.title.as_mut() {
+        *title = title.trim().to_string();
+        if title.is_empty() {
+            return Ok(HttpResponse::BadRequest().json(ApiResponse::error_empty(
+                ErrorCode::BadRequest,
+                "Announcement title must not be empty",
+            )));
+        }
+    }
+
+    let storage = service.get_storage(request)?;
+
+    if let Err(resp) = load_class_announcement(&storage, class_id, announcement_id).await {
+        return Ok(resp);
+    }
+
+    match storage
+        .update_announcement(announcement_id, body, user_id)
+        .await
+    {
+        Ok(Some(announcement)) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            announcement,
+            "Announcement updated successfully",
+        ))),
+        Ok(None) => Ok(HttpResponse::NotFound().json(ApiResponse::error_empty(
+            ErrorCode::AnnouncementNotFound,
+            "Announcement not found",
+        ))),
+        Err(e) => {
+            error!("Failed to update announcement: {}", e);
+            Ok(error_response(e))
+        }
+    }
+}
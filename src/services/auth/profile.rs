@@ -68,6 +68,7 @@ pub async fn handle_update_profile(
         status: None,
         display_name: update_data.display_name,
         avatar_url: update_data.avatar_url,
+        profile: None,
     };
 
     match storage.update_user(current_user.id, storage_update).await {
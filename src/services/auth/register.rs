@@ -50,6 +50,8 @@ pub async fn handle_register(
         Ok(password_hash) => {
             // 将明文密码替换为哈希后的密码
             create_request.password = password_hash;
+            // 自定义资料字段由管理员维护，注册时忽略
+            create_request.profile = None;
 
             // 4. 创建用户
             match storage.create_user(create_request).await {
@@ -0,0 +1,28 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+
+use super::AutoGraderService;
+use crate::config::AppConfig;
+use crate::models::ApiResponse;
+use crate::models::auto_graders::entities::AutoGraderInfo;
+use crate::models::auto_graders::responses::AutoGraderListResponse;
+
+/// 列出服务器配置的评测器（仅名称与说明，不暴露命令或地址）
+pub async fn list_auto_graders(
+    _service: &AutoGraderService,
+    _request: &HttpRequest,
+) -> ActixResult<HttpResponse> {
+    let items = AppConfig::get()
+        .auto_grader
+        .graders
+        .iter()
+        .map(|grader| AutoGraderInfo {
+            name: grader.name.clone(),
+            description: grader.description.clone(),
+        })
+        .collect();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(
+        AutoGraderListResponse { items },
+        "查询成功",
+    )))
+}
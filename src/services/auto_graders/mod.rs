@@ -0,0 +1,32 @@
+pub mod list;
+pub mod runner;
+
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use std::sync::Arc;
+
+use crate::storage::Storage;
+
+pub(crate) use runner::{process_pending_auto_grade_runs, spawn_auto_grade};
+
+pub struct AutoGraderService {
+    storage: Option<Arc<dyn Storage>>,
+}
+
+impl AutoGraderService {
+    pub fn new_lazy() -> Self {
+        Self { storage: None }
+    }
+
+    // 列出可用的评测器
+    pub async fn list_auto_graders(&self, req: &HttpRequest) -> ActixResult<HttpResponse> {
+        list::list_auto_graders(self, req).await
+    }
+}
+
+use crate::services::StorageProvider;
+
+impl StorageProvider for AutoGraderService {
+    fn storage_ref(&self) -> Option<Arc<dyn Storage>> {
+        self.storage.clone()
+    }
+}
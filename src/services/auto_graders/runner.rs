@@ -0,0 +1,403 @@
+//! 自动评测执行
+//!
+//! 评测在后台任务中进行，同时运行的评测数量受 `auto_grader.max_concurrency` 限制。
+//! 评测记录的领取是原子的（等待中 -> 运行中），多实例部署时每条记录只评测一次。
+//!
+//! 评测器收到的数据：
+//! - 提交附件：command 类型复制到临时目录的 `files/` 子目录，http 类型作为 `files` 字段上传；
+//! - 提交信息（`submission.json` 或 `metadata` 字段）：提交/作业/学生 ID、版本、文本内容、满分与附件名。
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio::sync::Semaphore;
+use tracing::{debug, warn};
+
+use crate::config::{AppConfig, GraderConfig, GraderType};
+use crate::models::auto_graders::entities::{AutoGradeResult, AutoGradeRun};
+use crate::storage::Storage;
+
+/// 每次扫描处理的等待评测记录数量上限
+const PENDING_BATCH_SIZE: u64 = 50;
+/// 错误信息中保留的标准错误输出长度（字符数）
+const MAX_ERROR_LENGTH: usize = 2000;
+
+/// 评测并发限制
+static SEMAPHORE: Lazy<Semaphore> =
+    Lazy::new(|| Semaphore::new(AppConfig::get().auto_grader.max_concurrency.max(1)));
+
+/// 本进程中已排队的评测记录，避免同一记录重复排队
+static QUEUED: Lazy<Mutex<HashSet<i64>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// 提交信息（写入 submission.json 或作为 metadata 字段上传）
+#[derive(Debug, Serialize)]
+struct SubmissionMetadata {
+    submission_id: i64,
+    homework_id: i64,
+    student_id: i64,
+    version: i32,
+    content: Option<String>,
+    max_score: f64,
+    files: Vec<String>,
+}
+
+/// 在后台评测
+pub(crate) fn spawn_auto_grade(storage: Arc<dyn Storage>, run: AutoGradeRun) {
+    if !QUEUED.lock().map(|mut q| q.insert(run.id)).unwrap_or(false) {
+        return;
+    }
+    tokio::spawn(async move {
+        process_run(&storage, &run).await;
+        if let Ok(mut queued) = QUEUED.lock() {
+            queued.remove(&run.id);
+        }
+    });
+}
+
+/// 将等待评测的记录加入评测队列
+pub(crate) async fn process_pending_auto_grade_runs(storage: &Arc<dyn Storage>) {
+    match storage
+        .list_pending_auto_grade_runs(PENDING_BATCH_SIZE)
+        .await
+    {
+        Ok(runs) => {
+            for run in runs {
+                spawn_auto_grade(storage.clone(), run);
+            }
+        }
+        Err(e) => warn!("Failed to list pending auto grade runs: {}", e),
+    }
+}
+
+async fn process_run(storage: &Arc<dyn Storage>, run: &AutoGradeRun) {
+    let Ok(_permit) = SEMAPHORE.acquire().await else {
+        return;
+    };
+
+    match storage.claim_auto_grade_run(run.id).await {
+        Ok(true) => {}
+        Ok(false) => return,
+        Err(e) => {
+            warn!("Failed to claim auto grade run {}: {}", run.id, e);
+            return;
+        }
+    }
+
+    debug!(
+        "Running auto grader {} for submission {}",
+        run.grader, run.submission_id
+    );
+    match grade_submission(storage, run).await {
+        Ok(result) => {
+            if let Err(e) = storage.complete_auto_grade_run(run.id, result).await {
+                warn!("Failed to save auto grade run {}: {}", run.id, e);
+            }
+        }
+        Err(error) => {
+            debug!("Auto grade run {} failed: {}", run.id, error);
+            if let Err(e) = storage.fail_auto_grade_run(run.id, error).await {
+                warn!("Failed to save auto grade run {}: {}", run.id, e);
+            }
+        }
+    }
+}
+
+/// 准备提交数据并调用评测器，返回校验后的评测结果
+async fn grade_submission(
+    storage: &Arc<dyn Storage>,
+    run: &AutoGradeRun,
+) -> Result<AutoGradeResult, String> {
+    let config = AppConfig::get();
+    let Some(grader) = config.auto_grader.find(&run.grader) else {
+        return Err(format!("评测器 {} 未配置", run.grader));
+    };
+
+    let submission = match storage.get_submission_by_id(run.submission_id).await {
+        Ok(Some(submission)) => submission,
+        Ok(None) => return Err("提交不存在".to_string()),
+        Err(e) => return Err(format!("查询提交失败: {e}")),
+    };
+    let homework = match storage.get_homework_by_id(submission.homework_id).await {
+        Ok(Some(homework)) => homework,
+        Ok(None) => return Err("作业不存在".to_string()),
+        Err(e) => return Err(format!("查询作业失败: {e}")),
+    };
+
+    let file_ids = storage
+        .get_submission_file_ids(submission.id)
+        .await
+        .map_err(|e| format!("查询提交附件失败: {e}"))?;
+    let files = storage
+        .get_files_by_ids(&file_ids)
+        .await
+        .map_err(|e| format!("查询提交附件失败: {e}"))?;
+
+    // 附件名去除路径并去重
+    let mut used = HashSet::new();
+    let attachments: Vec<(String, PathBuf)> = file_ids
+        .iter()
+        .filter_map(|id| files.get(id))
+        .map(|file| {
+            let name = unique_file_name(&file.original_name, &mut used);
+            let path = PathBuf::from(format!("{}/{}", config.upload.dir, file.stored_name));
+            (name, path)
+        })
+        .collect();
+
+    let metadata = SubmissionMetadata {
+        submission_id: submission.id,
+        homework_id: homework.id,
+        student_id: submission.creator_id,
+        version: submission.version,
+        content: submission.content,
+        max_score: homework.max_score,
+        files: attachments.iter().map(|(name, _)| name.clone()).collect(),
+    };
+    let metadata =
+        serde_json::to_string(&metadata).map_err(|e| format!("生成提交信息失败: {e}"))?;
+
+    let max_output = config.auto_grader.max_output_size;
+    let output = match grader.grader_type {
+        GraderType::Command => run_command(grader, &metadata, &attachments, max_output).await?,
+        GraderType::Http => run_http(grader, metadata, &attachments, max_output).await?,
+    };
+
+    let result: AutoGradeResult =
+        serde_json::from_slice(&output).map_err(|e| format!("评测结果不是有效的 JSON: {e}"))?;
+    if !result.score.is_finite() || result.score < 0.0 || result.score > homework.max_score {
+        return Err(format!(
+            "评测分数 {} 不在 0 到 {} 之间",
+            result.score, homework.max_score
+        ));
+    }
+
+    Ok(result)
+}
+
+/// 生成安全的附件名（去除路径部分，重名时追加序号）
+fn unique_file_name(original: &str, used: &mut HashSet<String>) -> String {
+    let base = original
+        .rsplit(['/', '\\'])
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .trim_start_matches('.');
+    let base = if base.is_empty() { "file" } else { base };
+
+    let mut name = base.to_string();
+    let mut index = 1;
+    while !used.insert(name.clone()) {
+        name = match base.rsplit_once('.') {
+            Some((stem, ext)) => format!("{stem}_{index}.{ext}"),
+            None => format!("{base}_{index}"),
+        };
+        index += 1;
+    }
+    name
+}
+
+/// 在临时目录中运行评测命令，返回标准输出
+///
+/// 命令的工作目录为临时目录，环境变量仅保留 PATH，另设置：
+/// - `HWSYSTEM_SUBMISSION_DIR`：临时目录
+/// - `HWSYSTEM_SUBMISSION_JSON`：submission.json 路径
+async fn run_command(
+    grader: &GraderConfig,
+    metadata: &str,
+    attachments: &[(String, PathBuf)],
+    max_output: usize,
+) -> Result<Vec<u8>, String> {
+    let workdir = tempfile::Builder::new()
+        .prefix("hwsystem-grade-")
+        .tempdir()
+        .map_err(|e| format!("创建临时目录失败: {e}"))?;
+    let files_dir = workdir.path().join("files");
+    tokio::fs::create_dir(&files_dir)
+        .await
+        .map_err(|e| format!("创建临时目录失败: {e}"))?;
+    for (name, path) in attachments {
+        tokio::fs::copy(path, files_dir.join(name))
+            .await
+            .map_err(|e| format!("复制附件 {name} 失败: {e}"))?;
+    }
+    let metadata_path = workdir.path().join("submission.json");
+    tokio::fs::write(&metadata_path, metadata)
+        .await
+        .map_err(|e| format!("写入提交信息失败: {e}"))?;
+
+    let mut command = build_command(grader);
+    command
+        .current_dir(workdir.path())
+        .env_clear()
+        .env("PATH", std::env::var("PATH").unwrap_or_default())
+        .env("HOME", workdir.path())
+        .env("HWSYSTEM_SUBMISSION_DIR", workdir.path())
+        .env("HWSYSTEM_SUBMISSION_JSON", &metadata_path)
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .kill_on_drop(true);
+    #[cfg(unix)]
+    command.process_group(0);
+
+    let mut child = command
+        .spawn()
+        .map_err(|e| format!("启动评测命令失败: {e}"))?;
+    let stdout = tokio::spawn(read_limited(child.stdout.take(), max_output));
+    let stderr = tokio::spawn(read_limited(child.stderr.take(), MAX_ERROR_LENGTH * 4));
+
+    let status = match tokio::time::timeout(Duration::from_secs(grader.timeout), child.wait()).await
+    {
+        Ok(Ok(status)) => status,
+        Ok(Err(e)) => return Err(format!("等待评测命令失败: {e}")),
+        Err(_) => {
+            kill_process_group(&mut child).await;
+            return Err(format!("评测超时（{} 秒）", grader.timeout));
+        }
+    };
+
+    let (stdout, truncated) = stdout.await.unwrap_or_default();
+    let (stderr, _) = stderr.await.unwrap_or_default();
+
+    if !status.success() {
+        let stderr = String::from_utf8_lossy(&stderr);
+        return Err(format!(
+            "评测命令异常退出（{status}）: {}",
+            truncate_chars(stderr.trim(), MAX_ERROR_LENGTH)
+        ));
+    }
+    if truncated {
+        return Err(format!("评测输出超过 {max_output} 字节"));
+    }
+
+    Ok(stdout)
+}
+
+/// 构造评测命令（设置了内存限制时通过 shell 的 ulimit 限制虚拟内存）
+fn build_command(grader: &GraderConfig) -> tokio::process::Command {
+    if cfg!(unix) && grader.memory_limit > 0 {
+        let mut command = tokio::process::Command::new("sh");
+        command
+            .arg("-c")
+            .arg(format!(
+                "ulimit -v {} && exec \"$@\"",
+                grader.memory_limit * 1024
+            ))
+            .arg("sh")
+            .args(&grader.command);
+        command
+    } else {
+        let mut command = tokio::process::Command::new(&grader.command[0]);
+        command.args(&grader.command[1..]);
+        command
+    }
+}
+
+/// 终止评测命令及其创建的子进程
+async fn kill_process_group(child: &mut tokio::process::Child) {
+    #[cfg(unix)]
+    if let Some(pid) = child.id() {
+        let _ = tokio::process::Command::new("kill")
+            .arg("-KILL")
+            .arg(format!("-{pid}"))
+            .status()
+            .await;
+    }
+    let _ = child.kill().await;
+}
+
+/// 读取输出，最多保留 limit 字节（其余部分读取后丢弃），返回 (内容, 是否截断)
+async fn read_limited<R: AsyncRead + Unpin>(reader: Option<R>, limit: usize) -> (Vec<u8>, bool) {
+    let Some(mut reader) = reader else {
+        return (vec![], false);
+    };
+    let mut output = Vec::new();
+    let mut truncated = false;
+    let mut buffer = [0u8; 8192];
+    loop {
+        match reader.read(&mut buffer).await {
+            Ok(0) | Err(_) => break,
+            Ok(n) => {
+                let room = limit.saturating_sub(output.len());
+                if n > room {
+                    truncated = true;
+                }
+                output.extend_from_slice(&buffer[..n.min(room)]);
+            }
+        }
+    }
+    (output, truncated)
+}
+
+/// 调用 HTTP 评测服务，返回响应体
+///
+/// 以 multipart/form-data 上传：`metadata` 为提交信息 JSON，`files` 为各个附件。
+async fn run_http(
+    grader: &GraderConfig,
+    metadata: String,
+    attachments: &[(String, PathBuf)],
+    max_output: usize,
+) -> Result<Vec<u8>, String> {
+    let mut form = reqwest::multipart::Form::new().text("metadata", metadata);
+    for (name, path) in attachments {
+        let data = tokio::fs::read(path)
+            .await
+            .map_err(|e| format!("读取附件 {name} 失败: {e}"))?;
+        form = form.part(
+            "files",
+            reqwest::multipart::Part::bytes(data).file_name(name.clone()),
+        );
+    }
+
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(grader.timeout))
+        .build()
+        .map_err(|e| format!("创建 HTTP 客户端失败: {e}"))?;
+    let mut response = client
+        .post(&grader.url)
+        .multipart(form)
+        .send()
+        .await
+        .map_err(|e| {
+            if e.is_timeout() {
+                format!("评测超时（{} 秒）", grader.timeout)
+            } else {
+                format!("请求评测服务失败: {e}")
+            }
+        })?;
+
+    let status = response.status();
+    let mut body = Vec::new();
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|e| format!("读取评测结果失败: {e}"))?
+    {
+        body.extend_from_slice(&chunk);
+        if body.len() > max_output {
+            return Err(format!("评测输出超过 {max_output} 字节"));
+        }
+    }
+
+    if !status.is_success() {
+        let body = String::from_utf8_lossy(&body);
+        return Err(format!(
+            "评测服务返回错误（{status}）: {}",
+            truncate_chars(body.trim(), MAX_ERROR_LENGTH)
+        ));
+    }
+
+    Ok(body)
+}
+
+fn truncate_chars(text: &str, max: usize) -> String {
+    match text.char_indices().nth(max) {
+        Some((index, _)) => format!("{}...", &text[..index]),
+        None => text.to_string(),
+    }
+}
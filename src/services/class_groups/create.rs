@@ -0,0 +1,91 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use std::sync::Arc;
+use tracing::error;
+
+use super::ClassGroupService;
+use crate::models::class_groups::requests::CreateClassGroupRequest;
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::{StorageProvider, error_response};
+use crate::storage::Storage;
+
+pub async fn create_class_group(
+    service: &ClassGroupService,
+    request: &HttpRequest,
+    class_id: i64,
+    mut body: CreateClassGroupRequest,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    body.name = body.name.trim().to_string();
+    if body.name.is_empty() {
+        return Ok(HttpResponse::BadRequest().json(ApiResponse::error_empty(
+            ErrorCode::BadRequest,
+            "Group name must not be empty",
+        )));
+    }
+
+    if let Err(resp) = check_group_name_available(&storage, class_id, &body.name, None).await {
+        return Ok(resp);
+    }
+
+    if let Some(ta_id) = body.ta_id
+        && let Err(resp) = check_ta_in_class(&storage, class_id, ta_id).await
+    {
+        return Ok(resp);
+    }
+
+    match storage.create_class_group(class_id, body).await {
+        Ok(group) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            group,
+            "Class group created successfully",
+        ))),
+        Err(e) => {
+            error!("Failed to create class group: {}", e);
+            Ok(error_response(e))
+        }
+    }
+}
+
+/// 校验分组名称在班级内未被占用（`exclude_id` 用于更新时排除自身）
+pub(super) async fn check_group_name_available(
+    storage: &Arc<dyn Storage>,
+    class_id: i64,
+    name: &str,
+    exclude_id: Option<i64>,
+) -> Result<(), HttpResponse> {
+    let groups = storage
+        .list_class_groups(class_id)
+        .await
+        .map_err(error_response)?;
+
+    if groups
+        .iter()
+        .any(|g| g.name == name && Some(g.id) != exclude_id)
+    {
+        return Err(HttpResponse::Conflict().json(ApiResponse::error_empty(
+            ErrorCode::ClassGroupAlreadyExists,
+            "A group with this name already exists in the class",
+        )));
+    }
+
+    Ok(())
+}
+
+/// 校验助教是班级成员
+pub(super) async fn check_ta_in_class(
+    storage: &Arc<dyn Storage>,
+    class_id: i64,
+    ta_id: i64,
+) -> Result<(), HttpResponse> {
+    match storage
+        .get_class_user_by_user_id_and_class_id(ta_id, class_id)
+        .await
+    {
+        Ok(Some(_)) => Ok(()),
+        Ok(None) => Err(HttpResponse::BadRequest().json(ApiResponse::error_empty(
+            ErrorCode::ClassUserNotFound,
+            "The TA must be a member of the class",
+        ))),
+        Err(e) => Err(error_response(e)),
+    }
+}
@@ -0,0 +1,34 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use tracing::error;
+
+use super::ClassGroupService;
+use super::ensure_group_in_class;
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::{StorageProvider, error_response};
+
+pub async fn delete_class_group(
+    service: &ClassGroupService,
+    request: &HttpRequest,
+    class_id: i64,
+    group_id: i64,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    if let Err(resp) = ensure_group_in_class(&storage, class_id, group_id).await {
+        return Ok(resp);
+    }
+
+    match storage.delete_class_group(group_id).await {
+        Ok(true) => Ok(HttpResponse::Ok().json(ApiResponse::success_empty(
+            "Class group deleted successfully",
+        ))),
+        Ok(false) => Ok(HttpResponse::NotFound().json(ApiResponse::error_empty(
+            ErrorCode::ClassGroupNotFound,
+            "Class group not found",
+        ))),
+        Err(e) => {
+            error!("Failed to delete class group: {}", e);
+            Ok(error_response(e))
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use tracing::error;
+
+use super::ClassGroupService;
+use crate::models::ApiResponse;
+use crate::models::class_groups::responses::{ClassGroupDetail, ClassGroupListResponse};
+use crate::models::class_users::responses::UserInfo;
+use crate::services::{StorageProvider, error_response};
+
+pub async fn list_class_groups(
+    service: &ClassGroupService,
+    request: &HttpRequest,
+    class_id: i64,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    let groups = match storage.list_class_groups(class_id).await {
+        Ok(groups) => groups,
+        Err(e) => {
+            error!("Failed to list class groups: {}", e);
+            return Ok(error_response(e));
+        }
+    };
+
+    let member_counts = match storage.count_class_group_members(class_id).await {
+        Ok(counts) => counts,
+        Err(e) => return Ok(error_response(e)),
+    };
+
+    // 批量获取助教信息
+    let ta_ids: Vec<i64> = groups.iter().filter_map(|g| g.ta_id).collect();
+    let ta_map = match storage.get_users_by_ids(&ta_ids).await {
+        Ok(map) => map,
+        Err(e) => return Ok(error_response(e)),
+    };
+
+    let items = groups
+        .into_iter()
+        .map(|group| {
+            let ta = group
+                .ta_id
+                .and_then(|id| ta_map.get(&id))
+                .map(|u| UserInfo {
+                    id: u.id,
+                    username: u.username.clone(),
+                    display_name: u.display_name.clone(),
+                    avatar_url: u.avatar_url.clone(),
+                });
+            ClassGroupDetail {
+                member_count: member_counts.get(&group.id).copied().unwrap_or(0),
+                ta,
+                group,
+            }
+        })
+        .collect();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(
+        ClassGroupListResponse { items },
+        "Class groups retrieved successfully",
+    )))
+}
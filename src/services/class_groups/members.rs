@@ -0,0 +1,100 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use tracing::error;
+
+use super::ClassGroupService;
+use super::ensure_group_in_class;
+use crate::middlewares::require_class_role::class_user_cache_key;
+use crate::models::class_groups::requests::ClassGroupMembersRequest;
+use crate::models::class_groups::responses::ClassGroupMembersResponse;
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::{CacheProvider, StorageProvider, error_response};
+
+/// 单次批量调整的最大成员数量
+const MAX_BATCH_SIZE: usize = 200;
+
+/// 批量加入（`assign` 为 true）或移出分组成员
+///
+/// 教师成员与非班级成员会被跳过，并在 `skipped_ids` 中返回。
+pub async fn set_class_group_members(
+    service: &ClassGroupService,
+    request: &HttpRequest,
+    class_id: i64,
+    group_id: i64,
+    assign: bool,
+    body: ClassGroupMembersRequest,
+) -> ActixResult<HttpResponse> {
+    let mut user_ids = body.user_ids;
+    user_ids.sort_unstable();
+    user_ids.dedup();
+
+    if user_ids.is_empty() {
+        return Ok(HttpResponse::BadRequest().json(ApiResponse::error_empty(
+            ErrorCode::BadRequest,
+            "user_ids must not be empty",
+        )));
+    }
+    if user_ids.len() > MAX_BATCH_SIZE {
+        return Ok(HttpResponse::BadRequest().json(ApiResponse::error_empty(
+            ErrorCode::BadRequest,
+            format!("At most {MAX_BATCH_SIZE} members can be updated at once"),
+        )));
+    }
+
+    let storage = service.get_storage(request)?;
+
+    if let Err(resp) = ensure_group_in_class(&storage, class_id, group_id).await {
+        return Ok(resp);
+    }
+
+    // 移出时仅处理当前属于该分组的成员
+    let candidate_ids = if assign {
+        user_ids.clone()
+    } else {
+        match storage.list_all_class_users(class_id).await {
+            Ok(members) => members
+                .into_iter()
+                .filter(|cu| cu.group_id == Some(group_id) && user_ids.contains(&cu.user_id))
+                .map(|cu| cu.user_id)
+                .collect(),
+            Err(e) => return Ok(error_response(e)),
+        }
+    };
+
+    let target_group = if assign { Some(group_id) } else { None };
+    let updated = match storage
+        .set_class_group_members(class_id, target_group, &candidate_ids)
+        .await
+    {
+        Ok(updated) => updated,
+        Err(e) => {
+            error!("Failed to update class group members: {}", e);
+            return Ok(error_response(e));
+        }
+    };
+
+    // 失效成员缓存
+    if let Some(cache) = service.get_cache(request) {
+        for &user_id in &updated {
+            cache.remove(&class_user_cache_key(user_id, class_id)).await;
+        }
+    }
+
+    let skipped_ids = user_ids
+        .into_iter()
+        .filter(|id| !updated.contains(id))
+        .collect();
+
+    let message = if assign {
+        "Class group members added successfully"
+    } else {
+        "Class group members removed successfully"
+    };
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(
+        ClassGroupMembersResponse {
+            updated: updated.len(),
+            skipped_ids,
+        },
+        message,
+    )))
+}
@@ -0,0 +1,141 @@
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod members;
+pub mod update;
+
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::models::class_groups::requests::{
+    ClassGroupMembersRequest, CreateClassGroupRequest, UpdateClassGroupRequest,
+};
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::{CacheProvider, StorageProvider, error_response};
+use crate::storage::Storage;
+
+pub struct ClassGroupService {
+    storage: Option<Arc<dyn Storage>>,
+}
+
+impl ClassGroupService {
+    pub fn new_lazy() -> Self {
+        Self { storage: None }
+    }
+
+    // 列出班级分组
+    pub async fn list_class_groups(
+        &self,
+        req: &HttpRequest,
+        class_id: i64,
+    ) -> ActixResult<HttpResponse> {
+        list::list_class_groups(self, req, class_id).await
+    }
+
+    // 创建班级分组
+    pub async fn create_class_group(
+        &self,
+        req: &HttpRequest,
+        class_id: i64,
+        body: CreateClassGroupRequest,
+    ) -> ActixResult<HttpResponse> {
+        create::create_class_group(self, req, class_id, body).await
+    }
+
+    // 更新班级分组
+    pub async fn update_class_group(
+        &self,
+        req: &HttpRequest,
+        class_id: i64,
+        group_id: i64,
+        body: UpdateClassGroupRequest,
+    ) -> ActixResult<HttpResponse> {
+        update::update_class_group(self, req, class_id, group_id, body).await
+    }
+
+    // 删除班级分组
+    pub async fn delete_class_group(
+        &self,
+        req: &HttpRequest,
+        class_id: i64,
+        group_id: i64,
+    ) -> ActixResult<HttpResponse> {
+        delete::delete_class_group(self, req, class_id, group_id).await
+    }
+
+    // 批量加入分组成员
+    pub async fn add_class_group_members(
+        &self,
+        req: &HttpRequest,
+        class_id: i64,
+        group_id: i64,
+        body: ClassGroupMembersRequest,
+    ) -> ActixResult<HttpResponse> {
+        members::set_class_group_members(self, req, class_id, group_id, true, body).await
+    }
+
+    // 批量移出分组成员
+    pub async fn remove_class_group_members(
+        &self,
+        req: &HttpRequest,
+        class_id: i64,
+        group_id: i64,
+        body: ClassGroupMembersRequest,
+    ) -> ActixResult<HttpResponse> {
+        members::set_class_group_members(self, req, class_id, group_id, false, body).await
+    }
+}
+
+impl StorageProvider for ClassGroupService {
+    fn storage_ref(&self) -> Option<Arc<dyn Storage>> {
+        self.storage.clone()
+    }
+}
+
+impl CacheProvider for ClassGroupService {}
+
+/// 校验分组存在且属于指定班级
+pub async fn ensure_group_in_class(
+    storage: &Arc<dyn Storage>,
+    class_id: i64,
+    group_id: i64,
+) -> Result<(), HttpResponse> {
+    match storage.get_class_group_by_id(group_id).await {
+        Ok(Some(group)) if group.class_id == class_id => Ok(()),
+        Ok(_) => Err(HttpResponse::NotFound().json(ApiResponse::error_empty(
+            ErrorCode::ClassGroupNotFound,
+            "班级分组不存在",
+        ))),
+        Err(e) => Err(error_response(e)),
+    }
+}
+
+/// 校验并去重作业分配的分组 ID（所有分组都必须属于指定班级）
+pub async fn normalize_group_ids(
+    storage: &Arc<dyn Storage>,
+    class_id: i64,
+    group_ids: Vec<i64>,
+) -> Result<Vec<i64>, HttpResponse> {
+    if group_ids.is_empty() {
+        return Ok(group_ids);
+    }
+
+    let class_group_ids: HashSet<i64> = match storage.list_class_groups(class_id).await {
+        Ok(groups) => groups.into_iter().map(|g| g.id).collect(),
+        Err(e) => return Err(error_response(e)),
+    };
+
+    let mut normalized = group_ids;
+    normalized.sort_unstable();
+    normalized.dedup();
+
+    if let Some(invalid) = normalized.iter().find(|id| !class_group_ids.contains(id)) {
+        return Err(HttpResponse::NotFound().json(ApiResponse::error_empty(
+            ErrorCode::ClassGroupNotFound,
+            format!("班级分组不存在: {invalid}"),
+        )));
+    }
+
+    Ok(normalized)
+}
@@ -0,0 +1,59 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use tracing::error;
+
+use super::ClassGroupService;
+use super::create::{check_group_name_available, check_ta_in_class};
+use super::ensure_group_in_class;
+use crate::models::class_groups::requests::UpdateClassGroupRequest;
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::{StorageProvider, error_response};
+
+pub async fn update_class_group(
+    service: &ClassGroupService,
+    request: &HttpRequest,
+    class_id: i64,
+    group_id: i64,
+    mut body: UpdateClassGroupRequest,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    if let Err(resp) = ensure_group_in_class(&storage, class_id, group_id).await {
+        return Ok(resp);
+    }
+
+    if let Some(name) = body.name.as_mut() {
+        *name = name.trim().to_string();
+        if name.is_empty() {
+            return Ok(HttpResponse::BadRequest().json(ApiResponse::error_empty(
+                ErrorCode::BadRequest,
+                "Group name must not be empty",
+            )));
+        }
+        if let Err(resp) =
+            check_group_name_available(&storage, class_id, name, Some(group_id)).await
+        {
+            return Ok(resp);
+        }
+    }
+
+    if let Some(ta_id) = body.ta_id
+        && let Err(resp) = check_ta_in_class(&storage, class_id, ta_id).await
+    {
+        return Ok(resp);
+    }
+
+    match storage.update_class_group(group_id, body).await {
+        Ok(Some(group)) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            group,
+            "Class group updated successfully",
+        ))),
+        Ok(None) => Ok(HttpResponse::NotFound().json(ApiResponse::error_empty(
+            ErrorCode::ClassGroupNotFound,
+            "Class group not found",
+        ))),
+        Err(e) => {
+            error!("Failed to update class group: {}", e);
+            Ok(error_response(e))
+        }
+    }
+}
@@ -0,0 +1,493 @@
+//! 班级名单导入服务
+
+use actix_multipart::Multipart;
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use tracing::error;
+
+use super::ClassUserService;
+use crate::middlewares::RequireJWT;
+use crate::middlewares::require_class_role::class_user_cache_key;
+use crate::models::class_users::entities::ClassUserRole;
+use crate::models::class_users::requests::{ClassRosterImportParams, UpdateClassUserRequest};
+use crate::models::class_users::responses::{ClassRosterImportResponse, ClassRosterImportedUser};
+use crate::models::notifications::entities::{NotificationType, ReferenceType};
+use crate::models::profile_fields::entities::ProfileField;
+use crate::models::users::entities::{User, UserRole};
+use crate::models::users::requests::CreateUserRequest;
+use crate::models::users::responses::ImportRowError;
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::notifications::trigger::send_notifications;
+use crate::services::profile_fields::resolve_profile_values;
+use crate::services::users::import::{
+    ImportParseError, ImportTableRow, profile_columns, read_import_table,
+};
+use crate::services::{CacheProvider, StorageProvider, error_response};
+use crate::storage::Storage;
+use crate::utils::password::hash_password;
+use crate::utils::validate::{validate_email, validate_password_simple, validate_username};
+
+/// 名单导入列索引
+struct RosterColumns {
+    username: Option<usize>,
+    email: Option<usize>,
+    student_no: Option<usize>,
+    password: Option<usize>,
+    role: Option<usize>,
+    display_name: Option<usize>,
+    /// 文件中存在的资料字段列（字段键 -> 列索引）
+    profile: Vec<(String, usize)>,
+}
+
+/// 单行处理失败的原因（计入 failed 或 skipped）
+enum RowOutcome {
+    Failed(ImportRowError),
+    Skipped(ImportRowError),
+}
+
+fn row_error(row: usize, field: &str, message: impl Into<String>) -> ImportRowError {
+    ImportRowError {
+        row,
+        field: field.to_string(),
+        message: message.into(),
+    }
+}
+
+/// 导入班级名单
+///
+/// 支持按用户名、唯一资料字段（如学号）或邮箱匹配已有用户，可选为不存在的用户创建账号，
+/// 并设置其班级角色。未配置 `student_no` 资料字段时，`student_no` 列按用户名匹配。
+pub async fn import_class_roster(
+    service: &ClassUserService,
+    request: &HttpRequest,
+    class_id: i64,
+    params: ClassRosterImportParams,
+    mut payload: Multipart,
+) -> ActixResult<HttpResponse> {
+    // 创建账号仅限教师或管理员全局角色
+    if params.create_missing {
+        let can_create = RequireJWT::extract_user_role(request)
+            .map(|role| UserRole::teacher_roles().contains(&&role))
+            .unwrap_or(false);
+        if !can_create {
+            return Ok(HttpResponse::Forbidden().json(ApiResponse::error_empty(
+                ErrorCode::PermissionDenied,
+                "Only teachers or admins can create accounts during roster import",
+            )));
+        }
+    }
+
+    let storage = service.get_storage(request)?;
+
+    let class = match storage.get_class_by_id(class_id).await {
+        Ok(Some(class)) => class,
+        Ok(None) => {
+            return Ok(HttpResponse::NotFound().json(ApiResponse::error_empty(
+                ErrorCode::ClassNotFound,
+                "Class not found",
+            )));
+        }
+        Err(e) => return Ok(error_response(e)),
+    };
+
+    let table = match read_import_table(&mut payload).await {
+        Ok(table) => table,
+        Err(resp) => return Ok(resp),
+    };
+
+    let profile_fields = match storage.list_profile_fields().await {
+        Ok(fields) => fields,
+        Err(e) => return Ok(error_response(e)),
+    };
+
+    // 已配置同名资料字段时，student_no 列作为资料字段处理
+    let legacy_student_no = !profile_fields.iter().any(|f| f.key == "student_no");
+
+    let columns = RosterColumns {
+        username: table.column("username"),
+        email: table.column("email"),
+        student_no: table.column("student_no").filter(|_| legacy_student_no),
+        password: table.column("password"),
+        role: table.column("role"),
+        display_name: table.column("display_name"),
+        profile: profile_columns(&table, &profile_fields),
+    };
+
+    let has_unique_profile_column = columns
+        .profile
+        .iter()
+        .any(|(key, _)| profile_fields.iter().any(|f| f.unique && &f.key == key));
+
+    if columns.username.is_none()
+        && columns.email.is_none()
+        && columns.student_no.is_none()
+        && !has_unique_profile_column
+    {
+        let e = ImportParseError::MissingColumn(
+            "username / email / student_no / 唯一资料字段".to_string(),
+        );
+        return Ok(
+            HttpResponse::BadRequest().json(ApiResponse::error_empty(e.error_code(), e.message()))
+        );
+    }
+
+    // 现有成员及其角色
+    let mut members: HashMap<i64, ClassUserRole> =
+        match storage.list_all_class_users(class_id).await {
+            Ok(users) => users.into_iter().map(|cu| (cu.user_id, cu.role)).collect(),
+            Err(e) => return Ok(error_response(e)),
+        };
+
+    let mut imported: Vec<ClassRosterImportedUser> = Vec::new();
+    let mut errors: Vec<ImportRowError> = Vec::new();
+    let mut skipped = 0;
+    let mut failed = 0;
+    let mut seen: HashSet<i64> = HashSet::new();
+    let mut enrolled_ids: Vec<i64> = Vec::new();
+
+    for row in &table.rows {
+        match import_row(
+            &storage,
+            class_id,
+            row,
+            &columns,
+            &profile_fields,
+            params.create_missing,
+            &mut members,
+            &mut seen,
+        )
+        .await
+        {
+            Ok((item, newly_enrolled)) => {
+                if newly_enrolled {
+                    enrolled_ids.push(item.user_id);
+                }
+                imported.push(item);
+            }
+            Err(RowOutcome::Skipped(e)) => {
+                skipped += 1;
+                errors.push(e);
+            }
+            Err(RowOutcome::Failed(e)) => {
+                failed += 1;
+                errors.push(e);
+            }
+        }
+    }
+
+    // 失效成员缓存
+    if let Some(cache) = service.get_cache(request) {
+        for item in &imported {
+            cache
+                .remove(&class_user_cache_key(item.user_id, class_id))
+                .await;
+        }
+    }
+
+    // 异步通知新加入的成员
+    if !enrolled_ids.is_empty() {
+        let storage_clone = storage.clone();
+        let class_name = class.name;
+        tokio::spawn(async move {
+            send_notifications(
+                storage_clone,
+                enrolled_ids,
+                NotificationType::ClassJoined,
+                format!("您已被加入班级：{}", class_name),
+                Some(format!("教师已将您加入班级「{}」", class_name)),
+                Some(ReferenceType::Class),
+                Some(class_id),
+            )
+            .await;
+        });
+    }
+
+    let response = ClassRosterImportResponse {
+        total: table.rows.len(),
+        success: imported.len(),
+        created: imported.iter().filter(|i| i.created).count(),
+        skipped,
+        failed,
+        imported,
+        errors,
+    };
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(response, "导入完成")))
+}
+
+/// 处理单行，返回处理结果以及是否为新加入班级的成员
+#[allow(clippy::too_many_arguments)]
+async fn import_row(
+    storage: &std::sync::Arc<dyn Storage>,
+    class_id: i64,
+    row: &ImportTableRow,
+    columns: &RosterColumns,
+    profile_fields: &[ProfileField],
+    create_missing: bool,
+    members: &mut HashMap<i64, ClassUserRole>,
+    seen: &mut HashSet<i64>,
+) -> Result<(ClassRosterImportedUser, bool), RowOutcome> {
+    let row_num = row.row_num;
+
+    // 未配置学号资料字段时，学号作为用户名匹配
+    let username = row
+        .get_opt(columns.username)
+        .or_else(|| row.get_opt(columns.student_no));
+    let email = row.get_opt(columns.email);
+    let profile = row.profile_values(&columns.profile);
+
+    // 可用于匹配用户的唯一资料字段值
+    let unique_values: Vec<(&ProfileField, &str)> = profile_fields
+        .iter()
+        .filter(|f| f.unique)
+        .filter_map(|f| profile.get(&f.key).map(|v| (f, v.as_str())))
+        .collect();
+
+    if username.is_none() && email.is_none() && unique_values.is_empty() {
+        return Err(RowOutcome::Failed(row_error(
+            row_num,
+            "username",
+            "缺少用户名、邮箱、学号或唯一资料字段",
+        )));
+    }
+
+    // 班级角色：仅允许学生与课代表
+    let role = match row.get_opt(columns.role) {
+        None => None,
+        Some(r) => match r.parse::<ClassUserRole>() {
+            Ok(ClassUserRole::Teacher) | Err(_) => {
+                return Err(RowOutcome::Failed(row_error(
+                    row_num,
+                    "role",
+                    format!("无效的班级角色: {r}，支持: student, class_representative"),
+                )));
+            }
+            Ok(role) => Some(role),
+        },
+    };
+
+    let found = find_user(
+        storage,
+        username.as_deref(),
+        &unique_values,
+        email.as_deref(),
+    )
+    .await;
+    let (user, created) = match found {
+        Ok(Some(user)) => (user, false),
+        Ok(None) if create_missing => {
+            let user = create_user(
+                storage,
+                row,
+                columns,
+                profile_fields,
+                &profile,
+                username,
+                email,
+            )
+            .await?;
+            (user, true)
+        }
+        Ok(None) => {
+            return Err(RowOutcome::Failed(row_error(
+                row_num,
+                "username",
+                "用户不存在",
+            )));
+        }
+        Err(msg) => return Err(RowOutcome::Failed(row_error(row_num, "", msg))),
+    };
+
+    if !seen.insert(user.id) {
+        return Err(RowOutcome::Skipped(row_error(
+            row_num,
+            "username",
+            "与文件中其他行重复",
+        )));
+    }
+
+    let target_role = match members.get(&user.id) {
+        None => {
+            let role = role.unwrap_or(ClassUserRole::Student);
+            if let Err(e) = storage.join_class(user.id, class_id, role.clone()).await {
+                error!("导入名单加入班级失败: {}", e);
+                return Err(RowOutcome::Failed(row_error(
+                    row_num,
+                    "",
+                    format!("加入班级失败: {e}"),
+                )));
+            }
+            members.insert(user.id, role.clone());
+            return Ok((imported_user(row_num, &user, role, created), true));
+        }
+        Some(ClassUserRole::Teacher) => {
+            return Err(RowOutcome::Skipped(row_error(
+                row_num,
+                "role",
+                "教师成员不受名单导入影响",
+            )));
+        }
+        Some(current) => match role {
+            Some(role) if &role != current => role,
+            _ => {
+                return Err(RowOutcome::Skipped(row_error(
+                    row_num,
+                    "username",
+                    "已是班级成员",
+                )));
+            }
+        },
+    };
+
+    let update = UpdateClassUserRequest {
+        role: Some(target_role.clone()),
+    };
+    if let Err(e) = storage.update_class_user(class_id, user.id, update).await {
+        error!("导入名单更新班级角色失败: {}", e);
+        return Err(RowOutcome::Failed(row_error(
+            row_num,
+            "role",
+            format!("更新班级角色失败: {e}"),
+        )));
+    }
+    members.insert(user.id, target_role.clone());
+
+    Ok((imported_user(row_num, &user, target_role, false), false))
+}
+
+fn imported_user(
+    row: usize,
+    user: &User,
+    role: ClassUserRole,
+    created: bool,
+) -> ClassRosterImportedUser {
+    ClassRosterImportedUser {
+        row,
+        user_id: user.id,
+        username: user.username.clone(),
+        role,
+        created,
+    }
+}
+
+/// 按用户名、唯一资料字段、邮箱的顺序查找用户
+async fn find_user(
+    storage: &std::sync::Arc<dyn Storage>,
+    username: Option<&str>,
+    unique_values: &[(&ProfileField, &str)],
+    email: Option<&str>,
+) -> Result<Option<User>, String> {
+    if let Some(username) = username {
+        match storage.get_user_by_username(username).await {
+            Ok(Some(user)) => return Ok(Some(user)),
+            Ok(None) => {}
+            Err(e) => return Err(format!("查询用户失败: {e}")),
+        }
+    }
+    for (field, value) in unique_values {
+        let user_id = match storage
+            .find_user_ids_by_profile_value(field.id, value)
+            .await
+        {
+            Ok(ids) => ids.first().copied(),
+            Err(e) => return Err(format!("查询用户失败: {e}")),
+        };
+        if let Some(user_id) = user_id {
+            return storage
+                .get_user_by_id(user_id)
+                .await
+                .map_err(|e| format!("查询用户失败: {e}"));
+        }
+    }
+    if let Some(email) = email {
+        return storage
+            .get_user_by_email(email)
+            .await
+            .map_err(|e| format!("查询用户失败: {e}"));
+    }
+    Ok(None)
+}
+
+/// 为不存在的用户创建普通用户账号，并写入文件中的资料字段
+async fn create_user(
+    storage: &std::sync::Arc<dyn Storage>,
+    row: &ImportTableRow,
+    columns: &RosterColumns,
+    profile_fields: &[ProfileField],
+    profile: &BTreeMap<String, String>,
+    username: Option<String>,
+    email: Option<String>,
+) -> Result<User, RowOutcome> {
+    let row_num = row.row_num;
+
+    let (Some(username), Some(email)) = (username, email) else {
+        return Err(RowOutcome::Failed(row_error(
+            row_num,
+            "username",
+            "用户不存在，创建账号需要同时提供用户名（或学号）和邮箱",
+        )));
+    };
+    let password = row.get_opt(columns.password).unwrap_or_default();
+
+    if let Err(msg) = validate_username(&username) {
+        return Err(RowOutcome::Failed(row_error(row_num, "username", msg)));
+    }
+    if let Err(msg) = validate_email(&email) {
+        return Err(RowOutcome::Failed(row_error(row_num, "email", msg)));
+    }
+    if let Err(msg) = validate_password_simple(&password) {
+        return Err(RowOutcome::Failed(row_error(row_num, "password", msg)));
+    }
+
+    let profile_values = resolve_profile_values(storage, profile_fields, None, profile, true)
+        .await
+        .map_err(|e| RowOutcome::Failed(row_error(row_num, e.field(), e.message())))?;
+
+    // 哈希密码（使用 spawn_blocking 避免阻塞）
+    let hashed = match tokio::task::spawn_blocking(move || hash_password(&password)).await {
+        Ok(Ok(hash)) => hash,
+        Ok(Err(e)) => {
+            return Err(RowOutcome::Failed(row_error(
+                row_num,
+                "password",
+                format!("密码哈希失败: {e}"),
+            )));
+        }
+        Err(e) => {
+            return Err(RowOutcome::Failed(row_error(
+                row_num,
+                "password",
+                format!("密码处理失败: {e}"),
+            )));
+        }
+    };
+
+    let create_req = CreateUserRequest {
+        username,
+        email,
+        password: hashed,
+        role: UserRole::User,
+        display_name: row.get_opt(columns.display_name),
+        avatar_url: None,
+        profile: None,
+    };
+
+    let user = storage.create_user(create_req).await.map_err(|e| {
+        error!("导入名单创建用户失败: {}", e);
+        RowOutcome::Failed(row_error(row_num, "", format!("创建失败: {e}")))
+    })?;
+
+    storage
+        .set_user_profile_values(user.id, &profile_values)
+        .await
+        .map_err(|e| {
+            error!("导入名单写入资料字段失败: {}", e);
+            RowOutcome::Failed(row_error(
+                row_num,
+                "",
+                format!("用户已创建，但资料字段写入失败: {e}"),
+            ))
+        })?;
+
+    Ok(user)
+}
@@ -3,9 +3,12 @@ use tracing::error;
 
 use super::ClassUserService;
 use crate::middlewares::require_class_role::class_user_cache_key;
+use crate::models::classes::entities::ClassJoinPolicy;
 use crate::models::notifications::entities::{NotificationType, ReferenceType};
-use crate::services::notifications::trigger::send_notification;
-use crate::services::{CacheProvider, StorageProvider};
+use crate::services::notifications::trigger::{
+    get_class_teacher_ids, send_notification, send_notifications,
+};
+use crate::services::{CacheProvider, StorageProvider, error_response};
 use crate::{
     middlewares::RequireJWT,
     models::{
@@ -49,7 +52,7 @@ pub async fn join_class(
         }
     };
 
-    match (class, class_user) {
+    let class = match (class, class_user) {
         (None, _) => {
             return Ok(HttpResponse::NotFound().json(ApiResponse::error_empty(
                 ErrorCode::ClassInviteCodeInvalid,
@@ -63,7 +66,21 @@ pub async fn join_class(
                 "User has already joined the class",
             )));
         }
-        (Some(_), None) => {
+        (Some(c), None) => c,
+    };
+
+    match class.join_policy {
+        ClassJoinPolicy::Closed => {
+            return Ok(HttpResponse::Forbidden().json(ApiResponse::error_empty(
+                ErrorCode::ClassJoinForbidden,
+                "This class is not accepting new members",
+            )));
+        }
+        ClassJoinPolicy::Approval => {
+            return submit_join_request(service, request, class_id, class.name, user_id, join_data)
+                .await;
+        }
+        ClassJoinPolicy::Open => {
             // 继续执行加入逻辑
         }
     }
@@ -112,3 +129,80 @@ pub async fn join_class(
         }
     }
 }
+
+/// 提交加入申请（班级加入策略为需审批时）
+async fn submit_join_request(
+    service: &ClassUserService,
+    request: &HttpRequest,
+    class_id: i64,
+    class_name: String,
+    user_id: i64,
+    join_data: JoinClassRequest,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    match storage
+        .get_pending_class_join_request(class_id, user_id)
+        .await
+    {
+        Ok(Some(pending)) => {
+            return Ok(HttpResponse::Conflict().json(ApiResponse::error(
+                ErrorCode::ClassJoinRequestPending,
+                pending,
+                "A join request for this class is already pending",
+            )));
+        }
+        Ok(None) => {}
+        Err(e) => {
+            error!("Error getting pending join request: {}", e);
+            return Ok(error_response(e));
+        }
+    }
+
+    let message = join_data
+        .message
+        .map(|m| m.trim().to_string())
+        .filter(|m| !m.is_empty());
+
+    match storage
+        .create_class_join_request(class_id, user_id, message)
+        .await
+    {
+        Ok(join_request) => {
+            // 异步通知班级教师
+            let storage_clone = storage.clone();
+
+            tokio::spawn(async move {
+                let teacher_ids = get_class_teacher_ids(&storage_clone, class_id).await;
+                let applicant = match storage_clone.get_user_by_id(user_id).await {
+                    Ok(Some(user)) => user.display_name.unwrap_or(user.username),
+                    _ => user_id.to_string(),
+                };
+                send_notifications(
+                    storage_clone,
+                    teacher_ids,
+                    NotificationType::ClassJoinRequested,
+                    format!("新的加入申请：{}", class_name),
+                    Some(format!("{} 申请加入班级「{}」", applicant, class_name)),
+                    Some(ReferenceType::Class),
+                    Some(class_id),
+                )
+                .await;
+            });
+
+            Ok(HttpResponse::Accepted().json(ApiResponse::success(
+                join_request,
+                "Join request submitted, waiting for teacher approval",
+            )))
+        }
+        Err(e) => {
+            error!("Error creating join request: {}", e);
+            Ok(
+                HttpResponse::InternalServerError().json(ApiResponse::error_empty(
+                    ErrorCode::ClassJoinFailed,
+                    "Failed to submit join request",
+                )),
+            )
+        }
+    }
+}
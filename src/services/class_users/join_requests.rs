@@ -0,0 +1,217 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use tracing::error;
+
+use super::ClassUserService;
+use crate::middlewares::RequireJWT;
+use crate::middlewares::require_class_role::class_user_cache_key;
+use crate::models::notifications::entities::{NotificationType, ReferenceType};
+use crate::services::notifications::trigger::send_notifications;
+use crate::services::{CacheProvider, StorageProvider, error_response};
+use crate::{
+    models::{
+        ApiResponse, ErrorCode,
+        class_users::{
+            requests::{ClassJoinRequestListQuery, HandleClassJoinRequestsRequest},
+            responses::{
+                ClassJoinRequestDetail, ClassJoinRequestDetailListResponse,
+                HandleClassJoinRequestsResponse, UserInfo,
+            },
+        },
+    },
+    services::class_users::ClassJoinRequestAction,
+};
+
+/// 单次批量审批的最大申请数量
+const MAX_BATCH_SIZE: usize = 200;
+
+pub async fn list_class_join_requests(
+    service: &ClassUserService,
+    request: &HttpRequest,
+    class_id: i64,
+    query: ClassJoinRequestListQuery,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    let response = match storage
+        .list_class_join_requests_with_pagination(class_id, query)
+        .await
+    {
+        Ok(response) => response,
+        Err(e) => {
+            error!("Failed to retrieve class join requests: {}", e);
+            return Ok(error_response(e));
+        }
+    };
+
+    // 批量获取申请人信息
+    let user_ids: Vec<i64> = response.items.iter().map(|r| r.user_id).collect();
+    let user_map = match storage.get_users_by_ids(&user_ids).await {
+        Ok(map) => map,
+        Err(e) => return Ok(error_response(e)),
+    };
+
+    let items = response
+        .items
+        .into_iter()
+        .map(|r| {
+            let user = match user_map.get(&r.user_id) {
+                Some(u) => UserInfo {
+                    id: u.id,
+                    username: u.username.clone(),
+                    display_name: u.display_name.clone(),
+                    avatar_url: u.avatar_url.clone(),
+                },
+                None => UserInfo {
+                    id: r.user_id,
+                    username: "未知用户".to_string(),
+                    display_name: None,
+                    avatar_url: None,
+                },
+            };
+
+            ClassJoinRequestDetail {
+                id: r.id,
+                class_id: r.class_id,
+                status: r.status,
+                message: r.message,
+                created_at: r.created_at,
+                handled_at: r.handled_at,
+                user,
+            }
+        })
+        .collect();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(
+        ClassJoinRequestDetailListResponse {
+            pagination: response.pagination,
+            items,
+        },
+        "Class join requests retrieved successfully",
+    )))
+}
+
+pub async fn handle_class_join_requests(
+    service: &ClassUserService,
+    request: &HttpRequest,
+    class_id: i64,
+    body: HandleClassJoinRequestsRequest,
+    action: ClassJoinRequestAction,
+) -> ActixResult<HttpResponse> {
+    let handler_id = match RequireJWT::extract_user_id(request) {
+        Some(id) => id,
+        None => {
+            return Ok(HttpResponse::Unauthorized().json(ApiResponse::error_empty(
+                ErrorCode::Unauthorized,
+                "Unauthorized: missing user id",
+            )));
+        }
+    };
+
+    let mut request_ids = body.request_ids;
+    request_ids.sort_unstable();
+    request_ids.dedup();
+
+    if request_ids.is_empty() {
+        return Ok(HttpResponse::BadRequest().json(ApiResponse::error_empty(
+            ErrorCode::BadRequest,
+            "request_ids must not be empty",
+        )));
+    }
+    if request_ids.len() > MAX_BATCH_SIZE {
+        return Ok(HttpResponse::BadRequest().json(ApiResponse::error_empty(
+            ErrorCode::BadRequest,
+            format!("At most {MAX_BATCH_SIZE} requests can be handled at once"),
+        )));
+    }
+
+    let storage = service.get_storage(request)?;
+
+    let class = match storage.get_class_by_id(class_id).await {
+        Ok(Some(class)) => class,
+        Ok(None) => {
+            return Ok(HttpResponse::NotFound().json(ApiResponse::error_empty(
+                ErrorCode::ClassNotFound,
+                "Class not found",
+            )));
+        }
+        Err(e) => return Ok(error_response(e)),
+    };
+
+    let approve = action == ClassJoinRequestAction::Approve;
+    let handled = match storage
+        .handle_class_join_requests(class_id, &request_ids, approve, handler_id)
+        .await
+    {
+        Ok(handled) => handled,
+        Err(e) => {
+            error!("Failed to handle class join requests: {}", e);
+            return Ok(error_response(e));
+        }
+    };
+
+    if handled.is_empty() {
+        return Ok(HttpResponse::NotFound().json(ApiResponse::error_empty(
+            ErrorCode::ClassJoinRequestNotFound,
+            "No pending join requests found for the given ids",
+        )));
+    }
+
+    let user_ids: Vec<i64> = handled.iter().map(|r| r.user_id).collect();
+
+    // 通过后失效成员缓存
+    if approve && let Some(cache) = service.get_cache(request) {
+        for &user_id in &user_ids {
+            cache.remove(&class_user_cache_key(user_id, class_id)).await;
+        }
+    }
+
+    // 异步通知申请人
+    let storage_clone = storage.clone();
+    let class_name = class.name;
+    tokio::spawn(async move {
+        let (notification_type, title, content) = if approve {
+            (
+                NotificationType::ClassJoinApproved,
+                format!("加入申请已通过：{}", class_name),
+                format!("您加入班级「{}」的申请已通过", class_name),
+            )
+        } else {
+            (
+                NotificationType::ClassJoinRejected,
+                format!("加入申请未通过：{}", class_name),
+                format!("您加入班级「{}」的申请已被拒绝", class_name),
+            )
+        };
+
+        send_notifications(
+            storage_clone,
+            user_ids,
+            notification_type,
+            title,
+            Some(content),
+            Some(ReferenceType::Class),
+            Some(class_id),
+        )
+        .await;
+    });
+
+    let handled_ids: std::collections::HashSet<i64> = handled.iter().map(|r| r.id).collect();
+    let skipped_ids = request_ids
+        .into_iter()
+        .filter(|id| !handled_ids.contains(id))
+        .collect();
+
+    let message = if approve {
+        "Class join requests approved successfully"
+    } else {
+        "Class join requests rejected successfully"
+    };
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(
+        HandleClassJoinRequestsResponse {
+            processed: handled.len(),
+            skipped_ids,
+        },
+        message,
+    )))
+}
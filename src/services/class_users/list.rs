@@ -66,6 +66,7 @@ pub async fn list_class_users_with_pagination(
                         class_id: cu.class_id,
                         user_id: cu.user_id,
                         role: cu.role,
+                        group_id: cu.group_id,
                         joined_at: cu.joined_at,
                         user,
                     }
@@ -1,17 +1,28 @@
 pub mod delete;
 pub mod get;
+pub mod import;
 pub mod join;
+pub mod join_requests;
 pub mod list;
 pub mod update;
 
+use actix_multipart::Multipart;
 use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
 use std::sync::Arc;
 
 use crate::models::class_users::requests::{
-    ClassUserListQuery, JoinClassRequest, UpdateClassUserRequest,
+    ClassJoinRequestListQuery, ClassRosterImportParams, ClassUserListQuery,
+    HandleClassJoinRequestsRequest, JoinClassRequest, UpdateClassUserRequest,
 };
 use crate::storage::Storage;
 
+/// 加入申请审批动作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClassJoinRequestAction {
+    Approve,
+    Reject,
+}
+
 pub struct ClassUserService {
     storage: Option<Arc<dyn Storage>>,
 }
@@ -71,6 +82,38 @@ impl ClassUserService {
     ) -> ActixResult<HttpResponse> {
         delete::delete_class_user(self, req, class_id, user_id).await
     }
+
+    // 列出加入申请
+    pub async fn list_class_join_requests(
+        &self,
+        req: &HttpRequest,
+        class_id: i64,
+        query: ClassJoinRequestListQuery,
+    ) -> ActixResult<HttpResponse> {
+        join_requests::list_class_join_requests(self, req, class_id, query).await
+    }
+
+    // 批量审批加入申请
+    pub async fn handle_class_join_requests(
+        &self,
+        req: &HttpRequest,
+        class_id: i64,
+        body: HandleClassJoinRequestsRequest,
+        action: ClassJoinRequestAction,
+    ) -> ActixResult<HttpResponse> {
+        join_requests::handle_class_join_requests(self, req, class_id, body, action).await
+    }
+
+    // 导入班级名单
+    pub async fn import_class_roster(
+        &self,
+        req: &HttpRequest,
+        class_id: i64,
+        params: ClassRosterImportParams,
+        payload: Multipart,
+    ) -> ActixResult<HttpResponse> {
+        import::import_class_roster(self, req, class_id, params, payload).await
+    }
 }
 
 use crate::services::{CacheProvider, StorageProvider};
@@ -20,8 +20,8 @@ enum StudentHomeworkStatus {
     NotSubmitted,
     /// 已提交待批改
     Submitted,
-    /// 已评分
-    Graded(f64),
+    /// 已评分（最终得分，原始分数）
+    Graded(f64, f64),
 }
 
 /// 作业汇总数据
@@ -38,6 +38,8 @@ struct HomeworkSummary {
 struct StudentDetail {
     display_name: String,
     username: String,
+    /// 自定义资料字段值（顺序与资料字段列表一致）
+    profile: Vec<String>,
     homework_statuses: Vec<StudentHomeworkStatus>,
     total_submitted: i64,
     total_homeworks: i64,
@@ -196,6 +198,14 @@ pub async fn export_class_report(
         }
     };
 
+    // 自定义资料字段（如学号）作为学生明细的附加列
+    let profile_fields = match storage.list_profile_fields().await {
+        Ok(fields) => fields,
+        Err(e) => {
+            return Ok(error_response(e));
+        }
+    };
+
     // 构建 homework_submissions 和 homework_summaries
     let mut homework_submissions: HashMap<i64, HashMap<i64, StudentHomeworkStatus>> =
         HashMap::new();
@@ -214,9 +224,12 @@ pub async fn export_class_report(
             submitted_count = latest_submissions.len() as i64;
             for (&user_id, submission) in latest_submissions {
                 if let Some(grade) = grades_map.get(&submission.id) {
-                    user_statuses.insert(user_id, StudentHomeworkStatus::Graded(grade.score));
+                    user_statuses.insert(
+                        user_id,
+                        StudentHomeworkStatus::Graded(grade.effective_score, grade.score),
+                    );
                     graded_count += 1;
-                    scores.push(grade.score);
+                    scores.push(grade.effective_score);
                 } else {
                     user_statuses.insert(user_id, StudentHomeworkStatus::Submitted);
                 }
@@ -262,7 +275,7 @@ pub async fn export_class_report(
                     StudentHomeworkStatus::Submitted => {
                         total_submitted += 1;
                     }
-                    StudentHomeworkStatus::Graded(score) => {
+                    StudentHomeworkStatus::Graded(score, _) => {
                         total_submitted += 1;
                         score_sum += score;
                         graded_count += 1;
@@ -283,6 +296,10 @@ pub async fn export_class_report(
                     .clone()
                     .unwrap_or_else(|| user.username.clone()),
                 username: user.username.clone(),
+                profile: profile_fields
+                    .iter()
+                    .map(|f| user.profile.get(&f.key).cloned().unwrap_or_default())
+                    .collect(),
                 homework_statuses: statuses,
                 total_submitted,
                 total_homeworks,
@@ -303,6 +320,7 @@ pub async fn export_class_report(
 
     // P15 优化：在线程池中生成 XLSX，避免阻塞 worker 线程
     let homework_titles: Vec<String> = homeworks.iter().map(|h| h.title.clone()).collect();
+    let profile_labels: Vec<String> = profile_fields.into_iter().map(|f| f.label).collect();
     let class_name = class.name.clone();
 
     let xlsx_buffer = match web::block(move || {
@@ -313,6 +331,7 @@ pub async fn export_class_report(
             avg_submission_rate,
             &homework_summaries,
             &student_details,
+            &profile_labels,
             &homework_titles,
             show_scores,
         )
@@ -361,6 +380,7 @@ fn generate_xlsx(
     avg_submission_rate: f64,
     homework_summaries: &[HomeworkSummary],
     student_details: &[StudentDetail],
+    profile_labels: &[String],
     homework_titles: &[String],
     show_scores: bool,
 ) -> Result<Vec<u8>, String> {
@@ -401,6 +421,7 @@ fn generate_xlsx(
         sheet3,
         &header_format,
         student_details,
+        profile_labels,
         homework_titles,
         show_scores,
     )?;
@@ -539,10 +560,11 @@ fn write_student_details_sheet(
     sheet: &mut Worksheet,
     header_format: &Format,
     student_details: &[StudentDetail],
+    profile_labels: &[String],
     homework_titles: &[String],
     show_scores: bool,
 ) -> Result<(), String> {
-    // 表头：姓名 | 用户名 | 作业1 | 作业2 | ... | 总提交数 | 平均分
+    // 表头：姓名 | 用户名 | 资料字段... | 作业1 | 作业2 | ... | 总提交数 | 平均分
     let mut col: u16 = 0;
 
     sheet
@@ -555,6 +577,14 @@ fn write_student_details_sheet(
         .map_err(|e| e.to_string())?;
     col += 1;
 
+    // 资料字段列
+    for label in profile_labels {
+        sheet
+            .write_string_with_format(0, col, label, header_format)
+            .map_err(|e| e.to_string())?;
+        col += 1;
+    }
+
     // 作业列
     for title in homework_titles {
         // 截断过长的标题
@@ -589,13 +619,21 @@ fn write_student_details_sheet(
         sheet.write_string(row, col, &student.username).ok();
         col += 1;
 
+        for value in &student.profile {
+            sheet.write_string(row, col, value).ok();
+            col += 1;
+        }
+
         // 每个作业的状态
         for status in &student.homework_statuses {
             let cell_value = match status {
                 StudentHomeworkStatus::NotSubmitted => "-".to_string(),
                 StudentHomeworkStatus::Submitted => "✓".to_string(),
-                StudentHomeworkStatus::Graded(score) => {
-                    if show_scores {
+                StudentHomeworkStatus::Graded(score, raw_score) => {
+                    if show_scores && score != raw_score {
+                        // 有迟交扣分或教师调整时同时显示原始分数
+                        format!("{score}（原始 {raw_score}）")
+                    } else if show_scores {
                         format!("{score}")
                     } else {
                         "✓".to_string()
@@ -632,13 +670,21 @@ fn write_student_details_sheet(
     sheet.set_column_width(0, 15).ok(); // 姓名
     sheet.set_column_width(1, 15).ok(); // 用户名
 
+    // 资料字段列宽度
+    let first_homework_col = profile_labels.len() + 2;
+    for i in 2..first_homework_col {
+        sheet.set_column_width(i as u16, 15).ok();
+    }
+
     // 作业列宽度
     for i in 0..homework_titles.len() {
-        sheet.set_column_width((i + 2) as u16, 10).ok();
+        sheet
+            .set_column_width((i + first_homework_col) as u16, 10)
+            .ok();
     }
 
     // 总提交数和平均分列
-    let last_col = (homework_titles.len() + 2) as u16;
+    let last_col = (homework_titles.len() + first_homework_col) as u16;
     sheet.set_column_width(last_col, 12).ok();
     sheet.set_column_width(last_col + 1, 10).ok();
 
@@ -0,0 +1,56 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use tracing::error;
+
+use super::{
+    CommentService, CommentTarget, load_target_comment, resolve_comment_context,
+    validate_comment_content,
+};
+use crate::models::ApiResponse;
+use crate::models::comments::requests::CreateCommentRequest;
+use crate::services::{StorageProvider, error_response};
+
+pub async fn create_comment(
+    service: &CommentService,
+    request: &HttpRequest,
+    target: CommentTarget,
+    mut body: CreateCommentRequest,
+) -> ActixResult<HttpResponse> {
+    body.content = match validate_comment_content(&body.content) {
+        Ok(content) => content,
+        Err(resp) => return Ok(resp),
+    };
+
+    let storage = service.get_storage(request)?;
+
+    let ctx = match resolve_comment_context(&storage, request, target).await {
+        Ok(ctx) => ctx,
+        Err(resp) => return Ok(resp),
+    };
+
+    // 回复的评论必须属于同一目标
+    if let Some(parent_id) = body.parent_id
+        && let Err(resp) = load_target_comment(&storage, &ctx, parent_id).await
+    {
+        return Ok(resp);
+    }
+
+    match storage
+        .create_comment(
+            ctx.target_type,
+            ctx.target_id,
+            ctx.class_id,
+            ctx.user_id,
+            body,
+        )
+        .await
+    {
+        Ok(comment) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            comment,
+            "Comment created successfully",
+        ))),
+        Err(e) => {
+            error!("Failed to create comment: {}", e);
+            Ok(error_response(e))
+        }
+    }
+}
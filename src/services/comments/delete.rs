@@ -0,0 +1,47 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use tracing::error;
+
+use super::{CommentService, CommentTarget, load_target_comment, resolve_comment_context};
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::{StorageProvider, error_response};
+
+/// 删除评论及其所有回复（作者本人、班级教师或管理员）
+pub async fn delete_comment(
+    service: &CommentService,
+    request: &HttpRequest,
+    target: CommentTarget,
+    comment_id: i64,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    let ctx = match resolve_comment_context(&storage, request, target).await {
+        Ok(ctx) => ctx,
+        Err(resp) => return Ok(resp),
+    };
+
+    let comment = match load_target_comment(&storage, &ctx, comment_id).await {
+        Ok(comment) => comment,
+        Err(resp) => return Ok(resp),
+    };
+
+    if comment.user_id != ctx.user_id && !ctx.is_moderator {
+        return Ok(HttpResponse::Forbidden().json(ApiResponse::error_empty(
+            ErrorCode::PermissionDenied,
+            "You do not have permission to delete this comment",
+        )));
+    }
+
+    match storage.delete_comment(comment_id).await {
+        Ok(0) => Ok(HttpResponse::NotFound().json(ApiResponse::error_empty(
+            ErrorCode::CommentNotFound,
+            "Comment not found",
+        ))),
+        Ok(_) => {
+            Ok(HttpResponse::Ok().json(ApiResponse::success_empty("Comment deleted successfully")))
+        }
+        Err(e) => {
+            error!("Failed to delete comment: {}", e);
+            Ok(error_response(e))
+        }
+    }
+}
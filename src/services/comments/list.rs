@@ -0,0 +1,87 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use std::collections::HashMap;
+use tracing::error;
+
+use super::{CommentService, CommentTarget, resolve_comment_context};
+use crate::models::ApiResponse;
+use crate::models::comments::entities::Comment;
+use crate::models::comments::responses::{CommentListResponse, CommentNode};
+use crate::models::users::entities::User;
+use crate::services::announcements::user_info_from_map;
+use crate::services::{StorageProvider, error_response};
+
+pub async fn list_comments(
+    service: &CommentService,
+    request: &HttpRequest,
+    target: CommentTarget,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    let ctx = match resolve_comment_context(&storage, request, target).await {
+        Ok(ctx) => ctx,
+        Err(resp) => return Ok(resp),
+    };
+
+    let comments = match storage.list_comments(ctx.target_type, ctx.target_id).await {
+        Ok(comments) => comments,
+        Err(e) => {
+            error!("Failed to list comments: {}", e);
+            return Ok(error_response(e));
+        }
+    };
+
+    let mut author_ids: Vec<i64> = comments.iter().map(|c| c.user_id).collect();
+    author_ids.sort_unstable();
+    author_ids.dedup();
+    let user_map = match storage.get_users_by_ids(&author_ids).await {
+        Ok(map) => map,
+        Err(e) => return Ok(error_response(e)),
+    };
+
+    let total = comments.len();
+    let items = build_comment_tree(comments, &user_map);
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(
+        CommentListResponse { total, items },
+        "Comments retrieved successfully",
+    )))
+}
+
+/// 将按时间排序的评论列表组装为评论树（父评论缺失的回复提升为顶层评论）
+fn build_comment_tree(comments: Vec<Comment>, user_map: &HashMap<i64, User>) -> Vec<CommentNode> {
+    let ids: std::collections::HashSet<i64> = comments.iter().map(|c| c.id).collect();
+    let mut children: HashMap<i64, Vec<Comment>> = HashMap::new();
+    let mut roots = Vec::new();
+
+    for comment in comments {
+        match comment.parent_id {
+            Some(parent_id) if ids.contains(&parent_id) => {
+                children.entry(parent_id).or_default().push(comment)
+            }
+            _ => roots.push(comment),
+        }
+    }
+
+    fn attach(
+        comment: Comment,
+        children: &mut HashMap<i64, Vec<Comment>>,
+        user_map: &HashMap<i64, User>,
+    ) -> CommentNode {
+        let replies = children
+            .remove(&comment.id)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|child| attach(child, children, user_map))
+            .collect();
+        CommentNode {
+            author: user_info_from_map(comment.user_id, user_map),
+            comment,
+            replies,
+        }
+    }
+
+    roots
+        .into_iter()
+        .map(|root| attach(root, &mut children, user_map))
+        .collect()
+}
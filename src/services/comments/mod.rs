@@ -0,0 +1,213 @@
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod update;
+
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use std::sync::Arc;
+
+use crate::middlewares::RequireJWT;
+use crate::models::class_users::entities::ClassUserRole;
+use crate::models::comments::entities::{Comment, CommentTargetType};
+use crate::models::comments::requests::{CreateCommentRequest, UpdateCommentRequest};
+use crate::models::users::entities::UserRole;
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::announcements::load_class_announcement;
+use crate::services::{CacheProvider, StorageProvider, error_response};
+use crate::storage::Storage;
+
+/// 评论内容最大长度（字符数）
+pub(crate) const MAX_COMMENT_LENGTH: usize = 5000;
+
+/// 评论所属目标（公告需校验所属班级，作业的班级由作业本身确定）
+#[derive(Debug, Clone, Copy)]
+pub enum CommentTarget {
+    Announcement { class_id: i64, announcement_id: i64 },
+    Homework { homework_id: i64 },
+}
+
+/// 已解析的评论上下文
+pub(crate) struct CommentContext {
+    pub target_type: CommentTargetType,
+    pub target_id: i64,
+    pub class_id: i64,
+    pub user_id: i64,
+    /// 管理员或班级教师，可删除任意评论
+    pub is_moderator: bool,
+}
+
+pub struct CommentService {
+    storage: Option<Arc<dyn Storage>>,
+}
+
+impl CommentService {
+    pub fn new_lazy() -> Self {
+        Self { storage: None }
+    }
+
+    // 列出评论树
+    pub async fn list_comments(
+        &self,
+        req: &HttpRequest,
+        target: CommentTarget,
+    ) -> ActixResult<HttpResponse> {
+        list::list_comments(self, req, target).await
+    }
+
+    // 发表评论或回复
+    pub async fn create_comment(
+        &self,
+        req: &HttpRequest,
+        target: CommentTarget,
+        body: CreateCommentRequest,
+    ) -> ActixResult<HttpResponse> {
+        create::create_comment(self, req, target, body).await
+    }
+
+    // 编辑评论
+    pub async fn update_comment(
+        &self,
+        req: &HttpRequest,
+        target: CommentTarget,
+        comment_id: i64,
+        body: UpdateCommentRequest,
+    ) -> ActixResult<HttpResponse> {
+        update::update_comment(self, req, target, comment_id, body).await
+    }
+
+    // 删除评论
+    pub async fn delete_comment(
+        &self,
+        req: &HttpRequest,
+        target: CommentTarget,
+        comment_id: i64,
+    ) -> ActixResult<HttpResponse> {
+        delete::delete_comment(self, req, target, comment_id).await
+    }
+}
+
+impl StorageProvider for CommentService {
+    fn storage_ref(&self) -> Option<Arc<dyn Storage>> {
+        self.storage.clone()
+    }
+}
+
+impl CacheProvider for CommentService {}
+
+/// 解析评论目标并校验当前用户为该班级成员（管理员除外）
+pub(crate) async fn resolve_comment_context(
+    storage: &Arc<dyn Storage>,
+    request: &HttpRequest,
+    target: CommentTarget,
+) -> Result<CommentContext, HttpResponse> {
+    let user_id = RequireJWT::extract_user_id(request).ok_or_else(|| {
+        HttpResponse::Unauthorized().json(ApiResponse::error_empty(
+            ErrorCode::Unauthorized,
+            "Unauthorized: missing user id",
+        ))
+    })?;
+
+    let (target_type, target_id, class_id, hidden) = match target {
+        CommentTarget::Announcement {
+            class_id,
+            announcement_id,
+        } => {
+            load_class_announcement(storage, class_id, announcement_id).await?;
+            (
+                CommentTargetType::Announcement,
+                announcement_id,
+                class_id,
+                false,
+            )
+        }
+        CommentTarget::Homework { homework_id } => {
+            match storage.get_homework_by_id(homework_id).await {
+                Ok(Some(homework)) => (
+                    CommentTargetType::Homework,
+                    homework_id,
+                    homework.class_id,
+                    !homework.is_published(),
+                ),
+                Ok(None) => {
+                    return Err(HttpResponse::NotFound().json(ApiResponse::error_empty(
+                        ErrorCode::HomeworkNotFound,
+                        "Homework not found",
+                    )));
+                }
+                Err(e) => return Err(error_response(e)),
+            }
+        }
+    };
+
+    let is_moderator = if RequireJWT::extract_user_role(request) == Some(UserRole::Admin) {
+        true
+    } else {
+        match storage
+            .get_class_user_by_user_id_and_class_id(user_id, class_id)
+            .await
+        {
+            Ok(Some(class_user)) => class_user.role == ClassUserRole::Teacher,
+            Ok(None) => {
+                return Err(HttpResponse::Forbidden().json(ApiResponse::error_empty(
+                    ErrorCode::ClassPermissionDenied,
+                    "You are not a member of this class",
+                )));
+            }
+            Err(e) => return Err(error_response(e)),
+        }
+    };
+
+    // 未发布的作业对学生不可见
+    if hidden && !is_moderator {
+        return Err(HttpResponse::NotFound().json(ApiResponse::error_empty(
+            ErrorCode::HomeworkNotFound,
+            "Homework not found",
+        )));
+    }
+
+    Ok(CommentContext {
+        target_type,
+        target_id,
+        class_id,
+        user_id,
+        is_moderator,
+    })
+}
+
+/// 获取属于目标的评论，不存在或不属于该目标时返回 404
+pub(crate) async fn load_target_comment(
+    storage: &Arc<dyn Storage>,
+    ctx: &CommentContext,
+    comment_id: i64,
+) -> Result<Comment, HttpResponse> {
+    match storage.get_comment_by_id(comment_id).await {
+        Ok(Some(comment))
+            if comment.target_type == ctx.target_type && comment.target_id == ctx.target_id =>
+        {
+            Ok(comment)
+        }
+        Ok(_) => Err(HttpResponse::NotFound().json(ApiResponse::error_empty(
+            ErrorCode::CommentNotFound,
+            "Comment not found",
+        ))),
+        Err(e) => Err(error_response(e)),
+    }
+}
+
+/// 校验评论内容，返回去除首尾空白后的内容
+pub(crate) fn validate_comment_content(content: &str) -> Result<String, HttpResponse> {
+    let content = content.trim();
+    if content.is_empty() {
+        return Err(HttpResponse::BadRequest().json(ApiResponse::error_empty(
+            ErrorCode::BadRequest,
+            "Comment content must not be empty",
+        )));
+    }
+    if content.chars().count() > MAX_COMMENT_LENGTH {
+        return Err(HttpResponse::BadRequest().json(ApiResponse::error_empty(
+            ErrorCode::BadRequest,
+            format!("Comment content must be at most {MAX_COMMENT_LENGTH} characters"),
+        )));
+    }
+    Ok(content.to_string())
+}
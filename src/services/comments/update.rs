@@ -0,0 +1,58 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use tracing::error;
+
+use super::{
+    CommentService, CommentTarget, load_target_comment, resolve_comment_context,
+    validate_comment_content,
+};
+use crate::models::comments::requests::UpdateCommentRequest;
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::{StorageProvider, error_response};
+
+pub async fn update_comment(
+    service: &CommentService,
+    request: &HttpRequest,
+    target: CommentTarget,
+    comment_id: i64,
+    body: UpdateCommentRequest,
+) -> ActixResult<HttpResponse> {
+    let content = match validate_comment_content(&body.content) {
+        Ok(content) => content,
+        Err(resp) => return Ok(resp),
+    };
+
+    let storage = service.get_storage(request)?;
+
+    let ctx = match resolve_comment_context(&storage, request, target).await {
+        Ok(ctx) => ctx,
+        Err(resp) => return Ok(resp),
+    };
+
+    let comment = match load_target_comment(&storage, &ctx, comment_id).await {
+        Ok(comment) => comment,
+        Err(resp) => return Ok(resp),
+    };
+
+    // 仅作者本人可以编辑评论
+    if comment.user_id != ctx.user_id {
+        return Ok(HttpResponse::Forbidden().json(ApiResponse::error_empty(
+            ErrorCode::PermissionDenied,
+            "Only the author can edit this comment",
+        )));
+    }
+
+    match storage.update_comment(comment_id, content).await {
+        Ok(Some(comment)) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            comment,
+            "Comment updated successfully",
+        ))),
+        Ok(None) => Ok(HttpResponse::NotFound().json(ApiResponse::error_empty(
+            ErrorCode::CommentNotFound,
+            "Comment not found",
+        ))),
+        Err(e) => {
+            error!("Failed to update comment: {}", e);
+            Ok(error_response(e))
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+
+use super::GradeService;
+use crate::middlewares::RequireJWT;
+use crate::models::notifications::entities::{NotificationType, ReferenceType};
+use crate::models::users::entities::UserRole;
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::notifications::trigger::send_notification;
+use crate::services::{StorageProvider, error_response};
+
+/// 确认待批阅的评分（如自动评测生成的评分），确认后通知学生
+pub async fn confirm_grade(
+    service: &GradeService,
+    request: &HttpRequest,
+    grade_id: i64,
+    user_id: i64,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    let grade = match storage.get_grade_by_id(grade_id).await {
+        Ok(Some(g)) => g,
+        Ok(None) => {
+            return Ok(HttpResponse::NotFound().json(ApiResponse::error_empty(
+                ErrorCode::GradeNotFound,
+                "评分不存在",
+            )));
+        }
+        Err(e) => return Ok(error_response(e)),
+    };
+
+    // 权限检查：只有评分者或管理员才能确认
+    if RequireJWT::extract_user_role(request) != Some(UserRole::Admin) && grade.grader_id != user_id
+    {
+        return Ok(HttpResponse::Forbidden().json(ApiResponse::error_empty(
+            ErrorCode::Forbidden,
+            "只能确认自己负责的评分",
+        )));
+    }
+
+    if !grade.pending_review {
+        return Ok(HttpResponse::Conflict().json(ApiResponse::error_empty(
+            ErrorCode::Conflict,
+            "该评分已确认",
+        )));
+    }
+
+    match storage.confirm_grade(grade_id).await {
+        Ok(Some(confirmed)) => {
+            // 异步通知学生
+            let storage_clone = storage.clone();
+            let g_id = confirmed.id;
+            let score = confirmed.effective_score;
+            let submission_id = confirmed.submission_id;
+
+            tokio::spawn(async move {
+                if let Ok(Some(submission)) =
+                    storage_clone.get_submission_by_id(submission_id).await
+                    && let Ok(Some(homework)) = storage_clone
+                        .get_homework_by_id(submission.homework_id)
+                        .await
+                {
+                    send_notification(
+                        storage_clone,
+                        submission.creator_id,
+                        NotificationType::GradeReceived,
+                        format!("作业已评分：{}", homework.title),
+                        Some(format!(
+                            "您的作业「{}」已评分，得分：{}",
+                            homework.title, score
+                        )),
+                        Some(ReferenceType::Grade),
+                        Some(g_id),
+                    )
+                    .await;
+                }
+            });
+
+            Ok(HttpResponse::Ok().json(ApiResponse::success(confirmed, "确认成功")))
+        }
+        Ok(None) => Ok(HttpResponse::NotFound().json(ApiResponse::error_empty(
+            ErrorCode::GradeNotFound,
+            "评分不存在",
+        ))),
+        Err(e) => Ok(error_response(e)),
+    }
+}
@@ -1,6 +1,6 @@
 use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
 
-use super::GradeService;
+use super::{GradeService, resolve_rubric_scores, validate_score};
 use crate::middlewares::RequireJWT;
 use crate::models::grades::requests::CreateGradeRequest;
 use crate::models::notifications::entities::{NotificationType, ReferenceType};
@@ -13,7 +13,7 @@ pub async fn create_grade(
     service: &GradeService,
     request: &HttpRequest,
     grader_id: i64,
-    req: CreateGradeRequest,
+    mut req: CreateGradeRequest,
 ) -> ActixResult<HttpResponse> {
     let storage = service.get_storage(request)?;
     let user_role = RequireJWT::extract_user_role(request);
@@ -79,6 +79,37 @@ pub async fn create_grade(
         }
     }
 
+    // 教师调整分不能为负数
+    if req
+        .score_override
+        .is_some_and(|s| !s.is_finite() || s < 0.0)
+    {
+        return Ok(HttpResponse::BadRequest().json(ApiResponse::error_empty(
+            ErrorCode::BadRequest,
+            "调整后的得分不能为负数",
+        )));
+    }
+
+    // 按评分细则评分时合计分由各评分项得出，否则校验直接给出的分数
+    let rubric_scores = match req.rubric_scores.take() {
+        Some(selections) => match resolve_rubric_scores(&storage, &homework, selections).await {
+            Ok(scores) => scores,
+            Err(resp) => return Ok(resp),
+        },
+        None => {
+            let Some(score) = req.score else {
+                return Ok(HttpResponse::BadRequest().json(ApiResponse::error_empty(
+                    ErrorCode::GradeScoreInvalid,
+                    "必须提供分数或评分细则选择",
+                )));
+            };
+            if let Err(resp) = validate_score(score, homework.max_score) {
+                return Ok(resp);
+            }
+            vec![]
+        }
+    };
+
     // 检查是否已评分
     match storage.get_grade_by_submission_id(req.submission_id).await {
         Ok(Some(_)) => {
@@ -93,13 +124,13 @@ pub async fn create_grade(
         _ => {}
     }
 
-    match storage.create_grade(grader_id, req).await {
+    match storage.create_grade(grader_id, req, rubric_scores).await {
         Ok(grade) => {
             // 异步通知学生
             let storage_clone = storage.clone();
             let grade_id = grade.id;
             let student_id = submission.creator_id;
-            let score = grade.score;
+            let score = grade.effective_score;
             let hw_title = homework.title.clone();
 
             tokio::spawn(async move {
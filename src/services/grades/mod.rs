@@ -1,12 +1,20 @@
+pub mod confirm;
 pub mod create;
 pub mod detail;
 pub mod list;
 pub mod update;
 
 use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use crate::models::grades::requests::{CreateGradeRequest, GradeListQuery, UpdateGradeRequest};
+use crate::models::homework_questions::requests::QuestionScoreInput;
+use crate::models::homeworks::entities::Homework;
+use crate::models::rubrics::entities::RubricScore;
+use crate::models::rubrics::requests::RubricSelection;
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::error_response;
 use crate::storage::Storage;
 
 pub struct GradeService {
@@ -48,6 +56,16 @@ impl GradeService {
         update::update_grade(self, request, grade_id, req, user_id).await
     }
 
+    /// 确认待批阅的评分
+    pub async fn confirm_grade(
+        &self,
+        request: &HttpRequest,
+        grade_id: i64,
+        user_id: i64,
+    ) -> ActixResult<HttpResponse> {
+        confirm::confirm_grade(self, request, grade_id, user_id).await
+    }
+
     /// 列出评分
     pub async fn list_grades(
         &self,
@@ -65,3 +83,131 @@ impl StorageProvider for GradeService {
         self.storage.clone()
     }
 }
+
+fn score_invalid(message: impl Into<String>) -> HttpResponse {
+    HttpResponse::BadRequest().json(ApiResponse::error_empty(
+        ErrorCode::GradeScoreInvalid,
+        message,
+    ))
+}
+
+/// 校验原始分数在 0 到作业满分之间
+pub(crate) fn validate_score(score: f64, max_score: f64) -> Result<(), HttpResponse> {
+    if !score.is_finite() || score < 0.0 || score > max_score {
+        return Err(score_invalid(format!("分数必须在 0 到 {max_score} 之间")));
+    }
+    Ok(())
+}
+
+/// 按作业关联的评分细则解析评分项选择，生成评分明细快照
+///
+/// 每个评分项必须且只能选择一个档位，合计分不能超过作业满分。
+pub(crate) async fn resolve_rubric_scores(
+    storage: &Arc<dyn Storage>,
+    homework: &Homework,
+    selections: Vec<RubricSelection>,
+) -> Result<Vec<RubricScore>, HttpResponse> {
+    let Some(rubric_id) = homework.rubric_id else {
+        return Err(score_invalid("该作业未关联评分细则"));
+    };
+    let rubric = match storage.get_rubric_by_id(rubric_id).await {
+        Ok(Some(rubric)) => rubric,
+        Ok(None) => return Err(score_invalid("该作业关联的评分细则已被删除")),
+        Err(e) => return Err(error_response(e)),
+    };
+
+    let mut selected: HashMap<i64, RubricSelection> = HashMap::new();
+    for selection in selections {
+        if selected.insert(selection.criterion_id, selection).is_some() {
+            return Err(score_invalid("同一评分项不能重复选择"));
+        }
+    }
+
+    let mut scores = Vec::with_capacity(rubric.criteria.len());
+    for criterion in &rubric.criteria {
+        let Some(selection) = selected.remove(&criterion.id) else {
+            return Err(score_invalid(format!(
+                "评分项「{}」未选择档位",
+                criterion.title
+            )));
+        };
+        let Some(level) = criterion.levels.get(selection.level) else {
+            return Err(score_invalid(format!(
+                "评分项「{}」的档位不存在",
+                criterion.title
+            )));
+        };
+        scores.push(RubricScore {
+            criterion_id: criterion.id,
+            criterion_title: criterion.title.clone(),
+            level_title: level.title.clone(),
+            points: level.points,
+            max_points: criterion.max_points(),
+            comment: selection
+                .comment
+                .map(|c| c.trim().to_string())
+                .filter(|c| !c.is_empty()),
+        });
+    }
+    if !selected.is_empty() {
+        return Err(score_invalid("包含不属于该评分细则的评分项"));
+    }
+
+    let total: f64 = scores.iter().map(|s| s.points).sum();
+    validate_score(total, homework.max_score)?;
+
+    Ok(scores)
+}
+
+/// 校验按题目给分：题目须已作答，得分在 0 到该题分值之间
+///
+/// 返回 (question_id, points) 列表。
+pub(crate) async fn resolve_question_scores(
+    storage: &Arc<dyn Storage>,
+    submission_id: i64,
+    inputs: Vec<QuestionScoreInput>,
+) -> Result<Vec<(i64, f64)>, HttpResponse> {
+    let submission = match storage.get_submission_by_id(submission_id).await {
+        Ok(Some(submission)) => submission,
+        Ok(None) => {
+            return Err(HttpResponse::NotFound().json(ApiResponse::error_empty(
+                ErrorCode::SubmissionNotFound,
+                "提交不存在",
+            )));
+        }
+        Err(e) => return Err(error_response(e)),
+    };
+    let questions = match storage
+        .list_homework_questions(submission.homework_id)
+        .await
+    {
+        Ok(questions) => questions,
+        Err(e) => return Err(error_response(e)),
+    };
+    let answered = match storage.list_submission_answers(&[submission_id]).await {
+        Ok(mut map) => map.remove(&submission_id).unwrap_or_default(),
+        Err(e) => return Err(error_response(e)),
+    };
+
+    let mut scores: HashMap<i64, f64> = HashMap::new();
+    for input in inputs {
+        let Some(question) = questions.iter().find(|q| q.id == input.question_id) else {
+            return Err(score_invalid("包含不属于该作业的题目"));
+        };
+        let number = question.position + 1;
+        if !answered.iter().any(|a| a.question_id == question.id) {
+            return Err(score_invalid(format!("第 {number} 题未作答，不能给分")));
+        }
+        if !input.points.is_finite() || input.points < 0.0 || input.points > question.points {
+            return Err(score_invalid(format!(
+                "第 {number} 题得分必须在 0 到 {} 之间",
+                question.points
+            )));
+        }
+        if scores.insert(question.id, input.points).is_some() {
+            return Err(score_invalid(format!("第 {number} 题重复给分")));
+        }
+    }
+
+    Ok(scores.into_iter().collect())
+}
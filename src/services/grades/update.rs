@@ -1,6 +1,6 @@
 use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
 
-use super::GradeService;
+use super::{GradeService, resolve_question_scores, resolve_rubric_scores, validate_score};
 use crate::middlewares::RequireJWT;
 use crate::models::grades::requests::UpdateGradeRequest;
 use crate::models::notifications::entities::{NotificationType, ReferenceType};
@@ -13,7 +13,7 @@ pub async fn update_grade(
     service: &GradeService,
     request: &HttpRequest,
     grade_id: i64,
-    req: UpdateGradeRequest,
+    mut req: UpdateGradeRequest,
     user_id: i64,
 ) -> ActixResult<HttpResponse> {
     let storage = service.get_storage(request)?;
@@ -52,12 +52,90 @@ pub async fn update_grade(
         }
     }
 
-    match storage.update_grade(grade_id, req).await {
+    // 教师调整分不能为负数
+    if req
+        .score_override
+        .flatten()
+        .is_some_and(|s| !s.is_finite() || s < 0.0)
+    {
+        return Ok(HttpResponse::BadRequest().json(ApiResponse::error_empty(
+            ErrorCode::BadRequest,
+            "调整后的得分不能为负数",
+        )));
+    }
+
+    // 按题目给分：更新作答得分后按各题得分合计重新计算原始分数
+    if let Some(question_scores) = req.question_scores.take() {
+        if req.score.is_some() || req.rubric_scores.is_some() {
+            return Ok(HttpResponse::BadRequest().json(ApiResponse::error_empty(
+                ErrorCode::GradeScoreInvalid,
+                "按题目给分时不能同时修改分数或评分细则",
+            )));
+        }
+        let scores =
+            match resolve_question_scores(&storage, grade.submission_id, question_scores).await {
+                Ok(scores) => scores,
+                Err(resp) => return Ok(resp),
+            };
+        if let Err(e) = storage
+            .set_submission_answer_points(grade.submission_id, &scores)
+            .await
+        {
+            return Ok(error_response(e));
+        }
+        if let Err(e) = storage.grade_submission_answers(grade.submission_id).await {
+            return Ok(error_response(e));
+        }
+    }
+
+    // 按评分细则重新评分，或直接修改分数（同时清除原有评分明细）
+    let rubric_scores = if req.rubric_scores.is_some() || req.score.is_some() {
+        let homework = match storage.get_submission_by_id(grade.submission_id).await {
+            Ok(Some(submission)) => {
+                match storage.get_homework_by_id(submission.homework_id).await {
+                    Ok(Some(hw)) => hw,
+                    Ok(None) => {
+                        return Ok(HttpResponse::NotFound().json(ApiResponse::error_empty(
+                            ErrorCode::HomeworkNotFound,
+                            "作业不存在",
+                        )));
+                    }
+                    Err(e) => return Ok(error_response(e)),
+                }
+            }
+            Ok(None) => {
+                return Ok(HttpResponse::NotFound().json(ApiResponse::error_empty(
+                    ErrorCode::SubmissionNotFound,
+                    "提交不存在",
+                )));
+            }
+            Err(e) => return Ok(error_response(e)),
+        };
+
+        match req.rubric_scores.take() {
+            Some(selections) => {
+                match resolve_rubric_scores(&storage, &homework, selections).await {
+                    Ok(scores) => Some(scores),
+                    Err(resp) => return Ok(resp),
+                }
+            }
+            None => {
+                if let Some(Err(resp)) = req.score.map(|s| validate_score(s, homework.max_score)) {
+                    return Ok(resp);
+                }
+                Some(vec![])
+            }
+        }
+    } else {
+        None
+    };
+
+    match storage.update_grade(grade_id, req, rubric_scores).await {
         Ok(Some(updated_grade)) => {
             // 异步通知学生
             let storage_clone = storage.clone();
             let g_id = updated_grade.id;
-            let new_score = updated_grade.score;
+            let new_score = updated_grade.effective_score;
             let submission_id = grade.submission_id;
 
             tokio::spawn(async move {
@@ -0,0 +1,119 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+
+use super::{HomeworkExtensionService, load_managed_homework};
+use crate::middlewares::RequireJWT;
+use crate::models::class_users::entities::ClassUserRole;
+use crate::models::homework_extensions::requests::SetHomeworkExtensionRequest;
+use crate::models::notifications::entities::{NotificationType, ReferenceType};
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::class_groups::ensure_group_in_class;
+use crate::services::notifications::trigger::{get_class_student_ids, send_notifications};
+use crate::services::{StorageProvider, error_response};
+
+/// 延期原因最大长度（字符数）
+const MAX_REASON_LENGTH: usize = 500;
+
+fn invalid(message: &str) -> HttpResponse {
+    HttpResponse::BadRequest().json(ApiResponse::error_empty(
+        ErrorCode::HomeworkExtensionInvalid,
+        message,
+    ))
+}
+
+pub async fn set_homework_extension(
+    service: &HomeworkExtensionService,
+    request: &HttpRequest,
+    homework_id: i64,
+    mut req: SetHomeworkExtensionRequest,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    let homework = match load_managed_homework(&storage, request, homework_id).await {
+        Ok(hw) => hw,
+        Err(resp) => return Ok(resp),
+    };
+    let user_id = RequireJWT::extract_user_id(request).unwrap_or(homework.created_by);
+
+    // 校验延期目标：学生与分组必须且只能指定一个
+    if req.user_id.is_some() == req.group_id.is_some() {
+        return Ok(invalid("必须且只能指定一个学生或一个分组"));
+    }
+
+    // 只有设置了截止时间的作业才能延期，且延期后的截止时间必须晚于原截止时间
+    let Some(deadline) = homework.deadline else {
+        return Ok(invalid("作业未设置截止时间，无需延期"));
+    };
+    if req.deadline <= deadline {
+        return Ok(invalid("延期后的截止时间必须晚于作业截止时间"));
+    }
+
+    req.reason = req
+        .reason
+        .map(|r| r.trim().to_string())
+        .filter(|r| !r.is_empty());
+    if req
+        .reason
+        .as_ref()
+        .is_some_and(|r| r.chars().count() > MAX_REASON_LENGTH)
+    {
+        return Ok(invalid("延期原因不能超过 500 个字符"));
+    }
+
+    // 延期目标必须属于作业所在班级
+    if let Some(target_user_id) = req.user_id {
+        match storage
+            .get_class_user_by_user_id_and_class_id(target_user_id, homework.class_id)
+            .await
+        {
+            Ok(Some(cu)) if cu.role != ClassUserRole::Teacher => {}
+            Ok(_) => return Ok(invalid("只能为该班级的学生设置延期")),
+            Err(e) => return Ok(error_response(e)),
+        }
+    }
+    if let Some(group_id) = req.group_id
+        && let Err(resp) = ensure_group_in_class(&storage, homework.class_id, group_id).await
+    {
+        return Ok(resp);
+    }
+
+    match storage
+        .set_homework_extension(homework_id, req, user_id)
+        .await
+    {
+        Ok(extension) => {
+            // 异步通知获得延期的学生
+            let storage_clone = storage.clone();
+            let class_id = homework.class_id;
+            let title = homework.title.clone();
+            let target_user = extension.user_id;
+            let target_group = extension.group_id;
+            let new_deadline = extension.deadline;
+            tokio::spawn(async move {
+                let user_ids = match (target_user, target_group) {
+                    (Some(id), _) => vec![id],
+                    (None, Some(group_id)) => {
+                        get_class_student_ids(&storage_clone, class_id, &[group_id]).await
+                    }
+                    (None, None) => vec![],
+                };
+                send_notifications(
+                    storage_clone,
+                    user_ids,
+                    NotificationType::HomeworkUpdated,
+                    format!("作业截止时间已延长：{title}"),
+                    Some(format!(
+                        "作业「{}」对你的截止时间已延长至 {}",
+                        title,
+                        new_deadline.format("%Y-%m-%d %H:%M UTC")
+                    )),
+                    Some(ReferenceType::Homework),
+                    Some(homework_id),
+                )
+                .await;
+            });
+
+            Ok(HttpResponse::Ok().json(ApiResponse::success(extension, "设置成功")))
+        }
+        Err(e) => Ok(error_response(e)),
+    }
+}
@@ -0,0 +1,39 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+
+use super::{HomeworkExtensionService, load_managed_homework};
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::{StorageProvider, error_response};
+
+pub async fn delete_homework_extension(
+    service: &HomeworkExtensionService,
+    request: &HttpRequest,
+    homework_id: i64,
+    extension_id: i64,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    if let Err(resp) = load_managed_homework(&storage, request, homework_id).await {
+        return Ok(resp);
+    }
+
+    // 延期必须属于该作业
+    match storage.get_homework_extension_by_id(extension_id).await {
+        Ok(Some(ext)) if ext.homework_id == homework_id => {}
+        Ok(_) => {
+            return Ok(HttpResponse::NotFound().json(ApiResponse::error_empty(
+                ErrorCode::HomeworkExtensionNotFound,
+                "延期不存在",
+            )));
+        }
+        Err(e) => return Ok(error_response(e)),
+    }
+
+    match storage.delete_homework_extension(extension_id).await {
+        Ok(true) => Ok(HttpResponse::Ok().json(ApiResponse::success_empty("删除成功"))),
+        Ok(false) => Ok(HttpResponse::NotFound().json(ApiResponse::error_empty(
+            ErrorCode::HomeworkExtensionNotFound,
+            "延期不存在",
+        ))),
+        Err(e) => Ok(error_response(e)),
+    }
+}
@@ -0,0 +1,64 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use std::collections::HashMap;
+
+use super::{HomeworkExtensionService, load_managed_homework};
+use crate::models::ApiResponse;
+use crate::models::class_users::responses::UserInfo;
+use crate::models::homework_extensions::responses::{
+    HomeworkExtensionDetail, HomeworkExtensionListResponse,
+};
+use crate::services::{StorageProvider, error_response};
+
+pub async fn list_homework_extensions(
+    service: &HomeworkExtensionService,
+    request: &HttpRequest,
+    homework_id: i64,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    let homework = match load_managed_homework(&storage, request, homework_id).await {
+        Ok(hw) => hw,
+        Err(resp) => return Ok(resp),
+    };
+
+    let extensions = match storage.list_homework_extensions(homework_id).await {
+        Ok(extensions) => extensions,
+        Err(e) => return Ok(error_response(e)),
+    };
+
+    let user_ids: Vec<i64> = extensions.iter().filter_map(|e| e.user_id).collect();
+    let users = match storage.get_users_by_ids(&user_ids).await {
+        Ok(users) => users,
+        Err(e) => return Ok(error_response(e)),
+    };
+
+    let group_names: HashMap<i64, String> = match storage.list_class_groups(homework.class_id).await
+    {
+        Ok(groups) => groups.into_iter().map(|g| (g.id, g.name)).collect(),
+        Err(e) => return Ok(error_response(e)),
+    };
+
+    let items = extensions
+        .into_iter()
+        .map(|extension| HomeworkExtensionDetail {
+            user: extension
+                .user_id
+                .and_then(|id| users.get(&id))
+                .map(|u| UserInfo {
+                    id: u.id,
+                    username: u.username.clone(),
+                    display_name: u.display_name.clone(),
+                    avatar_url: u.avatar_url.clone(),
+                }),
+            group_name: extension
+                .group_id
+                .and_then(|id| group_names.get(&id).cloned()),
+            extension,
+        })
+        .collect();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(
+        HomeworkExtensionListResponse { items },
+        "查询成功",
+    )))
+}
@@ -0,0 +1,132 @@
+pub mod create;
+pub mod delete;
+pub mod list;
+
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::middlewares::RequireJWT;
+use crate::models::class_users::entities::{ClassUser, ClassUserRole};
+use crate::models::homework_extensions::entities::HomeworkExtension;
+use crate::models::homework_extensions::requests::SetHomeworkExtensionRequest;
+use crate::models::homeworks::entities::Homework;
+use crate::models::users::entities::UserRole;
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::{CacheProvider, StorageProvider, error_response};
+use crate::storage::Storage;
+
+pub struct HomeworkExtensionService {
+    storage: Option<Arc<dyn Storage>>,
+}
+
+impl HomeworkExtensionService {
+    pub fn new_lazy() -> Self {
+        Self { storage: None }
+    }
+
+    // 列出作业延期
+    pub async fn list_homework_extensions(
+        &self,
+        req: &HttpRequest,
+        homework_id: i64,
+    ) -> ActixResult<HttpResponse> {
+        list::list_homework_extensions(self, req, homework_id).await
+    }
+
+    // 设置作业延期
+    pub async fn set_homework_extension(
+        &self,
+        req: &HttpRequest,
+        homework_id: i64,
+        body: SetHomeworkExtensionRequest,
+    ) -> ActixResult<HttpResponse> {
+        create::set_homework_extension(self, req, homework_id, body).await
+    }
+
+    // 删除作业延期
+    pub async fn delete_homework_extension(
+        &self,
+        req: &HttpRequest,
+        homework_id: i64,
+        extension_id: i64,
+    ) -> ActixResult<HttpResponse> {
+        delete::delete_homework_extension(self, req, homework_id, extension_id).await
+    }
+}
+
+impl StorageProvider for HomeworkExtensionService {
+    fn storage_ref(&self) -> Option<Arc<dyn Storage>> {
+        self.storage.clone()
+    }
+}
+
+impl CacheProvider for HomeworkExtensionService {}
+
+/// 获取作业并校验当前用户可管理延期（作业创建者或管理员）
+pub(crate) async fn load_managed_homework(
+    storage: &Arc<dyn Storage>,
+    request: &HttpRequest,
+    homework_id: i64,
+) -> Result<Homework, HttpResponse> {
+    let user_id = RequireJWT::extract_user_id(request).ok_or_else(|| {
+        HttpResponse::Unauthorized().json(ApiResponse::error_empty(
+            ErrorCode::Unauthorized,
+            "无法获取用户信息",
+        ))
+    })?;
+
+    let homework = match storage.get_homework_by_id(homework_id).await {
+        Ok(Some(hw)) => hw,
+        Ok(None) => {
+            return Err(HttpResponse::NotFound().json(ApiResponse::error_empty(
+                ErrorCode::HomeworkNotFound,
+                "作业不存在",
+            )));
+        }
+        Err(e) => return Err(error_response(e)),
+    };
+
+    if RequireJWT::extract_user_role(request) != Some(UserRole::Admin)
+        && homework.created_by != user_id
+    {
+        return Err(HttpResponse::Forbidden().json(ApiResponse::error_empty(
+            ErrorCode::Forbidden,
+            "只能管理自己创建的作业的延期",
+        )));
+    }
+
+    Ok(homework)
+}
+
+/// 计算班级学生的延期截止时间（个人延期优先于分组延期）
+///
+/// 返回 user_id -> 延期后的截止时间，没有延期的学生不在结果中。
+pub(crate) fn resolve_student_deadlines(
+    extensions: &[HomeworkExtension],
+    class_users: &[ClassUser],
+) -> HashMap<i64, DateTime<Utc>> {
+    let group_deadlines: HashMap<i64, DateTime<Utc>> = extensions
+        .iter()
+        .filter_map(|ext| ext.group_id.map(|g| (g, ext.deadline)))
+        .collect();
+
+    let mut deadlines: HashMap<i64, DateTime<Utc>> = class_users
+        .iter()
+        .filter(|cu| cu.role != ClassUserRole::Teacher)
+        .filter_map(|cu| {
+            cu.group_id
+                .and_then(|g| group_deadlines.get(&g))
+                .map(|deadline| (cu.user_id, *deadline))
+        })
+        .collect();
+
+    for ext in extensions {
+        if let Some(user_id) = ext.user_id {
+            deadlines.insert(user_id, ext.deadline);
+        }
+    }
+
+    deadlines
+}
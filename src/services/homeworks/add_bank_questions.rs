@@ -0,0 +1,288 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use super::{HomeworkService, MAX_HOMEWORK_QUESTIONS, question_invalid, validate_question_total};
+use crate::middlewares::RequireJWT;
+use crate::models::homework_questions::entities::{QuestionType, question_total};
+use crate::models::homework_questions::responses::HomeworkQuestionsResponse;
+use crate::models::question_bank::entities::BankQuestionScope;
+use crate::models::question_bank::requests::{
+    AddBankQuestionsRequest, BankQuestionDraw, BankQuestionFilter, QuestionPoolDraft,
+};
+use crate::models::users::entities::UserRole;
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::question_bank::viewer_filter;
+use crate::services::{StorageProvider, error_response};
+use crate::storage::Storage;
+
+/// 单个抽题条件的候选题目数量上限
+const MAX_POOL_CANDIDATES: u64 = 200;
+
+pub async fn add_bank_questions(
+    service: &HomeworkService,
+    request: &HttpRequest,
+    homework_id: i64,
+    req: AddBankQuestionsRequest,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    let Some(user_id) = RequireJWT::extract_user_id(request) else {
+        return Ok(HttpResponse::Unauthorized().json(ApiResponse::error_empty(
+            ErrorCode::Unauthorized,
+            "无法获取用户信息",
+        )));
+    };
+
+    let homework = match storage.get_homework_by_id(homework_id).await {
+        Ok(Some(hw)) => hw,
+        Ok(None) => {
+            return Ok(HttpResponse::NotFound().json(ApiResponse::error_empty(
+                ErrorCode::HomeworkNotFound,
+                "作业不存在",
+            )));
+        }
+        Err(e) => return Ok(error_response(e)),
+    };
+
+    // 权限检查：只有作业创建者或管理员才能修改题目
+    if RequireJWT::extract_user_role(request) != Some(UserRole::Admin)
+        && homework.created_by != user_id
+    {
+        return Ok(HttpResponse::Forbidden().json(ApiResponse::error_empty(
+            ErrorCode::Forbidden,
+            "只能修改自己创建的作业",
+        )));
+    }
+
+    if homework.auto_grader.is_some() {
+        return Ok(HttpResponse::BadRequest().json(ApiResponse::error_empty(
+            ErrorCode::HomeworkAutoGraderInvalid,
+            "自动评测不能与作业题目同时使用",
+        )));
+    }
+
+    match storage.has_homework_submissions(homework_id).await {
+        Ok(true) => {
+            return Ok(HttpResponse::Conflict().json(ApiResponse::error_empty(
+                ErrorCode::HomeworkQuestionsLocked,
+                "作业已有提交，不能修改题目",
+            )));
+        }
+        Ok(false) => {}
+        Err(e) => return Ok(error_response(e)),
+    }
+
+    // 手动选择的题目：去重并保持顺序
+    let mut seen = HashSet::new();
+    let question_ids: Vec<i64> = req
+        .question_ids
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|id| seen.insert(*id))
+        .collect();
+    let draws = req.draws.unwrap_or_default();
+    if question_ids.is_empty() && draws.is_empty() {
+        return Ok(question_invalid("请选择题目或设置抽题条件"));
+    }
+
+    let base_filter = match viewer_filter(request) {
+        Ok(filter) => filter,
+        Err(resp) => return Ok(resp),
+    };
+
+    let mut picked = Vec::with_capacity(question_ids.len());
+    if !question_ids.is_empty() {
+        let filter = BankQuestionFilter {
+            ids: Some(question_ids.clone()),
+            ..base_filter.clone()
+        };
+        let found = match storage
+            .list_bank_questions(filter, question_ids.len() as u64)
+            .await
+        {
+            Ok(found) => found,
+            Err(e) => return Ok(error_response(e)),
+        };
+        for id in &question_ids {
+            match found.iter().find(|q| q.id == *id) {
+                Some(question) => picked.push(question.clone()),
+                None => {
+                    return Ok(HttpResponse::NotFound().json(ApiResponse::error_empty(
+                        ErrorCode::BankQuestionNotFound,
+                        format!("题库题目 {id} 不存在或无权使用"),
+                    )));
+                }
+            }
+        }
+    }
+
+    // 随机抽题：候选题目不与手动选择的题目及其他抽题池重复
+    let mut pools = Vec::with_capacity(draws.len());
+    for (index, draw) in draws.into_iter().enumerate() {
+        let number = index + 1;
+        match build_pool(&storage, &base_filter, draw, &seen).await {
+            Ok(Ok(pool)) => {
+                seen.extend(pool.candidates.iter().map(|q| q.id));
+                pools.push(pool);
+            }
+            Ok(Err(msg)) => return Ok(question_invalid(format!("第 {number} 个抽题条件：{msg}"))),
+            Err(resp) => return Ok(resp),
+        }
+    }
+
+    let existing = match storage.list_homework_questions(homework_id).await {
+        Ok(questions) => questions,
+        Err(e) => return Ok(error_response(e)),
+    };
+    let existing_pools = match storage.list_homework_question_pools(homework_id).await {
+        Ok(pools) => pools,
+        Err(e) => return Ok(error_response(e)),
+    };
+
+    let stored =
+        existing.len() + picked.len() + pools.iter().map(|p| p.candidates.len()).sum::<usize>();
+    if stored > MAX_HOMEWORK_QUESTIONS {
+        return Ok(question_invalid(format!(
+            "题目数量（含抽题候选）不能超过 {MAX_HOMEWORK_QUESTIONS} 道"
+        )));
+    }
+
+    let total = question_total(&existing, &existing_pools)
+        + picked.iter().map(|q| q.points).sum::<f64>()
+        + pools
+            .iter()
+            .map(|p| p.draw_count as f64 * p.points)
+            .sum::<f64>();
+    if let Err(resp) = validate_question_total(total, homework.max_score) {
+        return Ok(resp);
+    }
+
+    if let Err(e) = storage
+        .add_bank_questions_to_homework(homework_id, picked, pools)
+        .await
+    {
+        return Ok(error_response(e));
+    }
+
+    let (questions, question_pools) = match (
+        storage.list_homework_questions(homework_id).await,
+        storage.list_homework_question_pools(homework_id).await,
+    ) {
+        (Ok(questions), Ok(pools)) => (questions, pools),
+        (Err(e), _) | (_, Err(e)) => return Ok(error_response(e)),
+    };
+    let total_points = question_total(&questions, &question_pools);
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(
+        HomeworkQuestionsResponse {
+            questions,
+            question_pools,
+            total_points,
+        },
+        "添加成功",
+    )))
+}
+
+/// 按抽题条件选出候选题目（外层错误为存储错误，内层为条件错误）
+async fn build_pool(
+    storage: &Arc<dyn Storage>,
+    base_filter: &BankQuestionFilter,
+    draw: BankQuestionDraw,
+    excluded: &HashSet<i64>,
+) -> Result<Result<QuestionPoolDraft, String>, HttpResponse> {
+    if draw.count < 1 {
+        return Ok(Err("抽题数量至少为 1".to_string()));
+    }
+    if let Some(points) = draw.points
+        && (!points.is_finite() || points < 0.0)
+    {
+        return Ok(Err("分值不能为负数".to_string()));
+    }
+
+    let tag = draw
+        .tag
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty());
+    let knowledge_point = draw
+        .knowledge_point
+        .map(|k| k.trim().to_string())
+        .filter(|k| !k.is_empty());
+
+    // 抽题池名称由抽题条件生成
+    let mut criteria = Vec::new();
+    if let Some(tag) = &tag {
+        criteria.push(format!("标签：{tag}"));
+    }
+    if let Some(difficulty) = draw.difficulty {
+        criteria.push(format!("难度：{difficulty}"));
+    }
+    if let Some(knowledge_point) = &knowledge_point {
+        criteria.push(format!("知识点：{knowledge_point}"));
+    }
+    if let Some(question_type) = draw.question_type {
+        criteria.push(format!("题型：{}", question_type_name(question_type)));
+    }
+    let label = if criteria.is_empty() {
+        "全部题目".to_string()
+    } else {
+        criteria.join("，")
+    };
+
+    let filter = BankQuestionFilter {
+        scope: BankQuestionScope::All,
+        tag,
+        difficulty: draw.difficulty,
+        knowledge_point,
+        question_type: draw.question_type,
+        ..base_filter.clone()
+    };
+    let candidates: Vec<_> = match storage
+        .list_bank_questions(filter, MAX_POOL_CANDIDATES + excluded.len() as u64)
+        .await
+    {
+        Ok(found) => found
+            .into_iter()
+            .filter(|q| !excluded.contains(&q.id))
+            .take(MAX_POOL_CANDIDATES as usize)
+            .collect(),
+        Err(e) => return Err(error_response(e)),
+    };
+
+    if candidates.len() < draw.count as usize {
+        return Ok(Err(format!(
+            "符合条件的题目只有 {} 道，不足 {} 道",
+            candidates.len(),
+            draw.count
+        )));
+    }
+
+    let points = match draw.points {
+        Some(points) => points,
+        None => {
+            let first = candidates[0].points;
+            if candidates.iter().any(|q| q.points != first) {
+                return Ok(Err("候选题目分值不一致，请指定每道题的分值".to_string()));
+            }
+            first
+        }
+    };
+
+    Ok(Ok(QuestionPoolDraft {
+        label,
+        draw_count: draw.count,
+        points,
+        candidates,
+    }))
+}
+
+fn question_type_name(question_type: QuestionType) -> &'static str {
+    match question_type {
+        QuestionType::SingleChoice => "单选题",
+        QuestionType::MultipleChoice => "多选题",
+        QuestionType::TrueFalse => "判断题",
+        QuestionType::FillBlank => "填空题",
+        QuestionType::Numeric => "数值题",
+        QuestionType::Essay => "简答题",
+    }
+}
@@ -3,17 +3,21 @@ use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
 use super::HomeworkService;
 use crate::middlewares::RequireJWT;
 use crate::models::homeworks::requests::CreateHomeworkRequest;
-use crate::models::notifications::entities::{NotificationType, ReferenceType};
 use crate::models::users::entities::UserRole;
 use crate::models::{ApiResponse, ErrorCode};
-use crate::services::notifications::trigger::{get_class_student_ids, send_notifications};
+use crate::services::class_groups::normalize_group_ids;
+use crate::services::homeworks::{
+    notify_homework_published, resolve_publish_state, validate_auto_grader, validate_late_policy,
+    validate_questions,
+};
+use crate::services::rubrics::load_owned_rubric;
 use crate::services::{StorageProvider, error_response};
 
 pub async fn create_homework(
     service: &HomeworkService,
     request: &HttpRequest,
     created_by: i64,
-    req: CreateHomeworkRequest,
+    mut req: CreateHomeworkRequest,
 ) -> ActixResult<HttpResponse> {
     let storage = service.get_storage(request)?;
     let user_role = RequireJWT::extract_user_role(request);
@@ -51,27 +55,65 @@ pub async fn create_homework(
         }
     }
 
+    // 校验分配的分组均属于该班级
+    if let Some(group_ids) = req.group_ids.take() {
+        match normalize_group_ids(&storage, req.class_id, group_ids).await {
+            Ok(group_ids) => req.group_ids = Some(group_ids),
+            Err(resp) => return Ok(resp),
+        }
+    }
+
+    // 关联的评分细则须为自己评分细则库中的（管理员不限）
+    if let Some(rubric_id) = req.rubric_id
+        && let Err(resp) = load_owned_rubric(&storage, request, rubric_id).await
+    {
+        return Ok(resp);
+    }
+
+    // 校验题目
+    if let Some(questions) = req.questions.as_mut()
+        && let Err(resp) = validate_questions(questions, req.max_score.unwrap_or(100.0))
+    {
+        return Ok(resp);
+    }
+
+    // 校验自动评测器
+    req.auto_grader = req
+        .auto_grader
+        .take()
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty());
+    let has_questions = req.questions.as_ref().is_some_and(|q| !q.is_empty());
+    if let Err(resp) = validate_auto_grader(req.auto_grader.as_deref(), has_questions) {
+        return Ok(resp);
+    }
+
+    // 校验迟交策略
+    if let Err(resp) =
+        validate_late_policy(req.deadline, req.late_cutoff, req.late_penalty.as_ref())
+    {
+        return Ok(resp);
+    }
+
+    // 确定发布状态（草稿、定时发布或立即发布）
+    match resolve_publish_state(req.status, req.publish_at, req.deadline, chrono::Utc::now()) {
+        Ok((status, publish_at)) => {
+            req.status = Some(status);
+            req.publish_at = publish_at;
+        }
+        Err(resp) => return Ok(resp),
+    }
+
     match storage.create_homework(created_by, req).await {
         Ok(homework) => {
-            // 异步发送通知给班级学生
-            let storage_clone = storage.clone();
-            let homework_id = homework.id;
-            let class_id = homework.class_id;
-            let title = homework.title.clone();
-
-            tokio::spawn(async move {
-                let student_ids = get_class_student_ids(&storage_clone, class_id).await;
-                send_notifications(
-                    storage_clone,
-                    student_ids,
-                    NotificationType::HomeworkCreated,
-                    format!("新作业发布：{}", title),
-                    Some(format!("作业「{}」已发布，请及时查看", title)),
-                    Some(ReferenceType::Homework),
-                    Some(homework_id),
-                )
-                .await;
-            });
+            // 仅立即发布时通知学生，定时发布由调度器在发布时通知
+            if homework.is_published() {
+                let storage_clone = storage.clone();
+                let homework_clone = homework.clone();
+                tokio::spawn(async move {
+                    notify_homework_published(storage_clone, &homework_clone).await;
+                });
+            }
 
             Ok(HttpResponse::Created().json(ApiResponse::success(homework, "创建成功")))
         }
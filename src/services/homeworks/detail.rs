@@ -2,7 +2,9 @@ use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
 
 use super::HomeworkService;
 use crate::middlewares::RequireJWT;
+use crate::models::class_users::entities::ClassUserRole;
 use crate::models::files::responses::FileInfo;
+use crate::models::homework_questions::entities::select_question_variant;
 use crate::models::homeworks::responses::HomeworkCreator;
 use crate::models::users::entities::UserRole;
 use crate::models::{ApiResponse, ErrorCode, homeworks::responses::HomeworkDetail};
@@ -26,14 +28,35 @@ pub async fn get_homework(
 
     match storage.get_homework_by_id(homework_id).await {
         Ok(Some(homework)) => {
+            // 学生视角的延期截止时间（教师和管理员不适用）
+            let mut extended_deadline = None;
+            // 班级教师和管理员可查看题目标准答案
+            let mut can_view_answers = current_user.role == UserRole::Admin;
+
             // 权限验证：管理员直接放行，否则验证班级成员资格
             if current_user.role != UserRole::Admin {
                 match storage
                     .get_class_user_by_user_id_and_class_id(current_user.id, homework.class_id)
                     .await
                 {
-                    Ok(Some(_)) => {
-                        // 用户是班级成员，允许访问
+                    Ok(Some(class_user)) => {
+                        can_view_answers = class_user.role == ClassUserRole::Teacher;
+                        // 未发布的作业仅班级教师可见
+                        if !homework.is_published() && class_user.role != ClassUserRole::Teacher {
+                            return Ok(HttpResponse::NotFound().json(ApiResponse::error_empty(
+                                ErrorCode::HomeworkNotFound,
+                                "作业不存在",
+                            )));
+                        }
+                        if class_user.role != ClassUserRole::Teacher {
+                            extended_deadline = match storage
+                                .get_user_extended_deadlines(current_user.id, &[homework_id])
+                                .await
+                            {
+                                Ok(mut deadlines) => deadlines.remove(&homework_id),
+                                Err(e) => return Ok(error_response(e)),
+                            };
+                        }
                     }
                     Ok(None) => {
                         return Ok(HttpResponse::Forbidden().json(ApiResponse::error_empty(
@@ -81,10 +104,40 @@ pub async fn get_homework(
                 _ => None,
             };
 
+            // 获取关联的评分细则
+            let rubric = match homework.rubric_id {
+                Some(rubric_id) => storage.get_rubric_by_id(rubric_id).await.ok().flatten(),
+                None => None,
+            };
+
+            let questions = match storage.list_homework_questions(homework_id).await {
+                Ok(questions) => questions,
+                Err(e) => return Ok(error_response(e)),
+            };
+            let pools = match storage.list_homework_question_pools(homework_id).await {
+                Ok(pools) => pools,
+                Err(e) => return Ok(error_response(e)),
+            };
+
+            // 学生只能看到本人抽到的题目，且不含标准答案
+            let (questions, question_pools) = if can_view_answers {
+                (questions, pools)
+            } else {
+                let questions = select_question_variant(questions, &pools, current_user.id)
+                    .into_iter()
+                    .map(|q| q.redacted())
+                    .collect();
+                (questions, vec![])
+            };
+
             let detail = HomeworkDetail {
                 homework,
                 attachments,
                 creator,
+                extended_deadline,
+                rubric,
+                questions,
+                question_pools,
             };
             Ok(HttpResponse::Ok().json(ApiResponse::success(detail, "查询成功")))
         }
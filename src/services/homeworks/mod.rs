@@ -1,3 +1,4 @@
+pub mod add_bank_questions;
 pub mod create;
 pub mod delete;
 pub mod detail;
@@ -10,11 +11,23 @@ pub mod teacher_stats;
 pub mod update;
 
 use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use chrono::{DateTime, Utc};
+use std::collections::HashSet;
 use std::sync::Arc;
+use tracing::error;
 
+use crate::config::AppConfig;
+use crate::models::homework_questions::requests::HomeworkQuestionInput;
+use crate::models::homeworks::entities::{Homework, HomeworkStatus, LatePenalty};
 use crate::models::homeworks::requests::{
-    AllHomeworksQuery, CreateHomeworkRequest, HomeworkListQuery, UpdateHomeworkRequest,
+    AllHomeworksQuery, CreateHomeworkRequest, HomeworkListQuery, HomeworkStatsQuery,
+    UpdateHomeworkRequest,
 };
+use crate::models::notifications::entities::{NotificationType, ReferenceType};
+use crate::models::question_bank::requests::AddBankQuestionsRequest;
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::homework_extensions::resolve_student_deadlines;
+use crate::services::notifications::trigger::{get_class_student_ids, send_notifications};
 use crate::storage::Storage;
 
 pub struct HomeworkService {
@@ -70,20 +83,31 @@ impl HomeworkService {
         delete::delete_homework(self, request, homework_id, user_id).await
     }
 
+    pub async fn add_bank_questions(
+        &self,
+        request: &HttpRequest,
+        homework_id: i64,
+        req: AddBankQuestionsRequest,
+    ) -> ActixResult<HttpResponse> {
+        add_bank_questions::add_bank_questions(self, request, homework_id, req).await
+    }
+
     pub async fn get_homework_stats(
         &self,
         request: &HttpRequest,
         homework_id: i64,
+        query: HomeworkStatsQuery,
     ) -> ActixResult<HttpResponse> {
-        stats::get_homework_stats(self, request, homework_id).await
+        stats::get_homework_stats(self, request, homework_id, query).await
     }
 
     pub async fn export_homework_stats(
         &self,
         request: &HttpRequest,
         homework_id: i64,
+        query: HomeworkStatsQuery,
     ) -> ActixResult<HttpResponse> {
-        stats_export::export_homework_stats(self, request, homework_id).await
+        stats_export::export_homework_stats(self, request, homework_id, query).await
     }
 
     pub async fn get_my_homework_stats(&self, request: &HttpRequest) -> ActixResult<HttpResponse> {
@@ -113,3 +137,225 @@ impl StorageProvider for HomeworkService {
         self.storage.clone()
     }
 }
+
+/// 通知作业面向的学生：作业已发布
+///
+/// 立即发布、手动发布和定时发布共用。
+pub(crate) async fn notify_homework_published(storage: Arc<dyn Storage>, homework: &Homework) {
+    let student_ids = get_class_student_ids(&storage, homework.class_id, &homework.group_ids).await;
+    send_notifications(
+        storage,
+        student_ids,
+        NotificationType::HomeworkCreated,
+        format!("新作业发布：{}", homework.title),
+        Some(format!("作业「{}」已发布，请及时查看", homework.title)),
+        Some(ReferenceType::Homework),
+        Some(homework.id),
+    )
+    .await;
+}
+
+/// 通知尚未提交的学生：作业即将截止
+pub(crate) async fn notify_homework_deadline(
+    storage: Arc<dyn Storage>,
+    homework: &Homework,
+    remaining_minutes: i64,
+) {
+    let student_ids = get_class_student_ids(&storage, homework.class_id, &homework.group_ids).await;
+    let submitted: HashSet<i64> = match storage.list_all_submissions_by_homework(homework.id).await
+    {
+        Ok(submissions) => submissions.into_iter().map(|s| s.creator_id).collect(),
+        Err(e) => {
+            error!(
+                "Failed to list submissions for homework {}: {}",
+                homework.id, e
+            );
+            return;
+        }
+    };
+    // 已获得延期的学生不按原截止时间提醒
+    let extended: HashSet<i64> = match (
+        storage.list_homework_extensions(homework.id).await,
+        storage.list_all_class_users(homework.class_id).await,
+    ) {
+        (Ok(extensions), Ok(class_users)) => resolve_student_deadlines(&extensions, &class_users)
+            .into_keys()
+            .collect(),
+        (Err(e), _) | (_, Err(e)) => {
+            error!(
+                "Failed to load extensions for homework {}: {}",
+                homework.id, e
+            );
+            return;
+        }
+    };
+    let pending: Vec<i64> = student_ids
+        .into_iter()
+        .filter(|id| !submitted.contains(id) && !extended.contains(id))
+        .collect();
+
+    let remaining = if remaining_minutes >= 60 {
+        format!("{} 小时", remaining_minutes / 60)
+    } else {
+        format!("{} 分钟", remaining_minutes.max(1))
+    };
+    send_notifications(
+        storage,
+        pending,
+        NotificationType::HomeworkDeadline,
+        format!("作业即将截止：{}", homework.title),
+        Some(format!(
+            "作业「{}」将在约 {} 后截止，你还没有提交",
+            homework.title, remaining
+        )),
+        Some(ReferenceType::Homework),
+        Some(homework.id),
+    )
+    .await;
+}
+
+/// 计算作业的目标发布状态与发布时间
+///
+/// - 未指定状态时，有晚于当前的 `publish_at` 视为定时发布，否则立即发布；
+/// - 定时发布要求 `publish_at` 晚于当前时间且不晚于截止时间；
+/// - 立即发布时 `publish_at` 记录为当前时间。
+pub(crate) fn resolve_publish_state(
+    status: Option<HomeworkStatus>,
+    publish_at: Option<DateTime<Utc>>,
+    deadline: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> Result<(HomeworkStatus, Option<DateTime<Utc>>), HttpResponse> {
+    let status = status.unwrap_or(match publish_at {
+        Some(at) if at > now => HomeworkStatus::Scheduled,
+        _ => HomeworkStatus::Published,
+    });
+
+    match status {
+        HomeworkStatus::Published => Ok((status, Some(now))),
+        HomeworkStatus::Draft => Ok((status, publish_at)),
+        HomeworkStatus::Scheduled => {
+            let Some(at) = publish_at.filter(|at| *at > now) else {
+                return Err(HttpResponse::BadRequest().json(ApiResponse::error_empty(
+                    ErrorCode::HomeworkStatusInvalid,
+                    "定时发布需要指定晚于当前时间的发布时间",
+                )));
+            };
+            if deadline.is_some_and(|deadline| at >= deadline) {
+                return Err(HttpResponse::BadRequest().json(ApiResponse::error_empty(
+                    ErrorCode::HomeworkStatusInvalid,
+                    "发布时间必须早于截止时间",
+                )));
+            }
+            Ok((status, Some(at)))
+        }
+    }
+}
+
+/// 校验作业迟交策略
+///
+/// 迟交截止时间须晚于作业截止时间；扣分规则参数须合法。
+pub(crate) fn validate_late_policy(
+    deadline: Option<DateTime<Utc>>,
+    late_cutoff: Option<DateTime<Utc>>,
+    late_penalty: Option<&LatePenalty>,
+) -> Result<(), HttpResponse> {
+    let invalid = |message: String| {
+        HttpResponse::BadRequest().json(ApiResponse::error_empty(
+            ErrorCode::HomeworkLatePolicyInvalid,
+            message,
+        ))
+    };
+
+    if let Some(cutoff) = late_cutoff {
+        match deadline {
+            None => return Err(invalid("设置迟交截止时间前需要先设置截止时间".to_string())),
+            Some(deadline) if cutoff <= deadline => {
+                return Err(invalid("迟交截止时间必须晚于截止时间".to_string()));
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(penalty) = late_penalty {
+        penalty.validate().map_err(invalid)?;
+    }
+
+    Ok(())
+}
+
+/// 单个作业的题目数量上限
+const MAX_HOMEWORK_QUESTIONS: usize = 200;
+
+fn question_invalid(message: impl Into<String>) -> HttpResponse {
+    HttpResponse::BadRequest().json(ApiResponse::error_empty(
+        ErrorCode::HomeworkQuestionInvalid,
+        message,
+    ))
+}
+
+/// 校验作业题目（题干去除首尾空白）
+///
+/// 题干不能为空，分值不能为负数，各题分值之和不能超过作业满分。
+pub(crate) fn validate_questions(
+    questions: &mut [HomeworkQuestionInput],
+    max_score: f64,
+) -> Result<(), HttpResponse> {
+    if questions.len() > MAX_HOMEWORK_QUESTIONS {
+        return Err(question_invalid(format!(
+            "题目数量不能超过 {MAX_HOMEWORK_QUESTIONS} 道"
+        )));
+    }
+
+    for (index, question) in questions.iter_mut().enumerate() {
+        let number = index + 1;
+        question.prompt = question.prompt.trim().to_string();
+        if question.prompt.is_empty() {
+            return Err(question_invalid(format!("第 {number} 题的题干不能为空")));
+        }
+        if !question.points.is_finite() || question.points < 0.0 {
+            return Err(question_invalid(format!("第 {number} 题的分值不能为负数")));
+        }
+        question
+            .spec
+            .validate()
+            .map_err(|msg| question_invalid(format!("第 {number} 题：{msg}")))?;
+    }
+
+    validate_question_total(questions.iter().map(|q| q.points).sum(), max_score)
+}
+
+/// 校验题目总分不超过作业满分
+pub(crate) fn validate_question_total(total: f64, max_score: f64) -> Result<(), HttpResponse> {
+    if total > max_score {
+        return Err(question_invalid(format!(
+            "题目总分 {total} 超过作业满分 {max_score}"
+        )));
+    }
+    Ok(())
+}
+
+/// 校验作业的自动评测器
+///
+/// 评测器须在服务器配置中定义；自动评测与客观题自动判分不能同时使用。
+pub(crate) fn validate_auto_grader(
+    auto_grader: Option<&str>,
+    has_questions: bool,
+) -> Result<(), HttpResponse> {
+    let Some(name) = auto_grader else {
+        return Ok(());
+    };
+    let invalid = |message: String| {
+        HttpResponse::BadRequest().json(ApiResponse::error_empty(
+            ErrorCode::HomeworkAutoGraderInvalid,
+            message,
+        ))
+    };
+
+    if AppConfig::get().auto_grader.find(name).is_none() {
+        return Err(invalid(format!("自动评测器 {name} 不存在")));
+    }
+    if has_questions {
+        return Err(invalid("自动评测不能与作业题目同时使用".to_string()));
+    }
+    Ok(())
+}
@@ -4,17 +4,23 @@ use std::collections::{HashMap, HashSet};
 use super::HomeworkService;
 use crate::middlewares::RequireJWT;
 use crate::models::class_users::entities::ClassUserRole;
+use crate::models::homework_questions::entities::{HomeworkQuestion, SubmissionAnswer};
+use crate::models::homework_questions::responses::QuestionStat;
+use crate::models::homeworks::requests::HomeworkStatsQuery;
 use crate::models::homeworks::stats_responses::{
-    HomeworkStatsResponse, ScoreRange, ScoreStats, UnsubmittedStudent,
+    ExtendedStudent, HomeworkStatsResponse, ScoreRange, ScoreStats, UnsubmittedStudent,
 };
 use crate::models::users::entities::UserRole;
 use crate::models::{ApiResponse, ErrorCode};
+use crate::services::class_groups::ensure_group_in_class;
+use crate::services::homework_extensions::resolve_student_deadlines;
 use crate::services::{StorageProvider, error_response};
 
 pub async fn get_homework_stats(
     service: &HomeworkService,
     request: &HttpRequest,
     homework_id: i64,
+    query: HomeworkStatsQuery,
 ) -> ActixResult<HttpResponse> {
     let storage = service.get_storage(request)?;
 
@@ -78,6 +84,13 @@ pub async fn get_homework_stats(
         }
     }
 
+    // 校验分组属于作业所在班级
+    if let Some(group_id) = query.group_id
+        && let Err(resp) = ensure_group_in_class(&storage, class_id, group_id).await
+    {
+        return Ok(resp);
+    }
+
     // 获取班级所有成员（不分页）
     let class_users = match storage.list_all_class_users(class_id).await {
         Ok(users) => users,
@@ -86,10 +99,11 @@ pub async fn get_homework_stats(
         }
     };
 
-    // 统计需要提交作业的成员（排除教师）
+    // 统计需要提交作业的成员（排除教师，按分组筛选）
     let students: Vec<_> = class_users
         .iter()
         .filter(|cu| cu.role != ClassUserRole::Teacher)
+        .filter(|cu| query.group_id.is_none() || cu.group_id == query.group_id)
         .collect();
     let total_students = students.len() as i64;
     let student_ids: HashSet<i64> = students.iter().map(|cu| cu.user_id).collect();
@@ -136,12 +150,20 @@ pub async fn get_homework_stats(
         .unwrap_or_default();
 
     let mut graded_count = 0i64;
+    let mut penalized_count = 0i64;
     let mut scores: Vec<f64> = Vec::new();
 
+    // 分数统计按最终得分（扣除迟交扣分或教师调整后）计算
+    // 仍有主观题待批阅的评分不计入已批改
     for submission in &student_submissions {
-        if let Some(grade) = grades_map.get(&submission.id) {
+        if let Some(grade) = grades_map.get(&submission.id)
+            && !grade.pending_review
+        {
             graded_count += 1;
-            scores.push(grade.score);
+            if grade.penalty > 0.0 {
+                penalized_count += 1;
+            }
+            scores.push(grade.effective_score);
         }
     }
 
@@ -178,40 +200,129 @@ pub async fn get_homework_stats(
         .map(|s| s.user_id)
         .collect();
 
-    let unsubmitted_users_map = storage
-        .get_users_by_ids(&unsubmitted_user_ids)
+    // 获取统计范围内获得延期的学生
+    let extensions = match storage.list_homework_extensions(homework_id).await {
+        Ok(exts) => exts,
+        Err(e) => {
+            return Ok(error_response(e));
+        }
+    };
+    let mut extended_deadlines: Vec<_> = resolve_student_deadlines(&extensions, &class_users)
+        .into_iter()
+        .filter(|(user_id, _)| student_ids.contains(user_id))
+        .collect();
+    extended_deadlines.sort_by_key(|(user_id, deadline)| (*deadline, *user_id));
+    let extended_count = extended_deadlines.len() as i64;
+
+    let mut lookup_ids = unsubmitted_user_ids.clone();
+    lookup_ids.extend(extended_deadlines.iter().map(|(user_id, _)| *user_id));
+    let users_map = storage
+        .get_users_by_ids(&lookup_ids)
         .await
         .unwrap_or_default();
 
     let unsubmitted_students: Vec<UnsubmittedStudent> = unsubmitted_user_ids
         .iter()
         .filter_map(|user_id| {
-            unsubmitted_users_map
-                .get(user_id)
-                .map(|user| UnsubmittedStudent {
-                    id: user.id,
-                    username: user.username.clone(),
-                    display_name: user.display_name.clone(),
-                    avatar_url: user.avatar_url.clone(),
-                })
+            users_map.get(user_id).map(|user| UnsubmittedStudent {
+                id: user.id,
+                username: user.username.clone(),
+                display_name: user.display_name.clone(),
+                avatar_url: user.avatar_url.clone(),
+            })
+        })
+        .collect();
+
+    let extended_students: Vec<ExtendedStudent> = extended_deadlines
+        .iter()
+        .filter_map(|(user_id, deadline)| {
+            users_map.get(user_id).map(|user| ExtendedStudent {
+                id: user.id,
+                username: user.username.clone(),
+                display_name: user.display_name.clone(),
+                extended_deadline: *deadline,
+                submitted: submitted_student_ids.contains(user_id),
+            })
         })
         .collect();
 
+    // 各题作答统计（按每个学生的最新提交计算）
+    let questions = match storage.list_homework_questions(homework_id).await {
+        Ok(questions) => questions,
+        Err(e) => return Ok(error_response(e)),
+    };
+    let question_stats = if questions.is_empty() {
+        vec![]
+    } else {
+        match storage.list_submission_answers(&submission_ids).await {
+            Ok(answers_map) => calculate_question_stats(&questions, answers_map.values().flatten()),
+            Err(e) => return Ok(error_response(e)),
+        }
+    };
+
     let response = HomeworkStatsResponse {
         homework_id,
+        group_id: query.group_id,
         total_students,
         submitted_count,
         graded_count,
         late_count,
+        penalized_count,
+        extended_count,
         submission_rate,
         score_stats,
         score_distribution,
         unsubmitted_students,
+        extended_students,
+        question_stats,
     };
 
     Ok(HttpResponse::Ok().json(ApiResponse::success(response, "查询成功")))
 }
 
+/// 计算各题作答统计
+fn calculate_question_stats<'a>(
+    questions: &[HomeworkQuestion],
+    answers: impl Iterator<Item = &'a SubmissionAnswer>,
+) -> Vec<QuestionStat> {
+    // question_id -> (作答人数, 答对人数, 已给分人数, 得分合计)
+    let mut tally: HashMap<i64, (i64, i64, i64, f64)> = HashMap::new();
+    for answer in answers {
+        let entry = tally.entry(answer.question_id).or_default();
+        entry.0 += 1;
+        if answer.is_correct == Some(true) {
+            entry.1 += 1;
+        }
+        if let Some(points) = answer.points {
+            entry.2 += 1;
+            entry.3 += points;
+        }
+    }
+
+    let round2 = |v: f64| (v * 100.0).round() / 100.0;
+    questions
+        .iter()
+        .map(|question| {
+            let (answered, correct, scored, points_sum) =
+                tally.get(&question.id).copied().unwrap_or_default();
+            let correct_rate = (!question.spec.is_manual() && answered > 0)
+                .then(|| round2(correct as f64 / answered as f64 * 100.0));
+            let average_points = (scored > 0).then(|| round2(points_sum / scored as f64));
+            QuestionStat {
+                question_id: question.id,
+                position: question.position,
+                prompt: question.prompt.clone(),
+                question_type: question.spec.question_type(),
+                points: question.points,
+                answered_count: answered,
+                correct_count: correct,
+                correct_rate,
+                average_points,
+            }
+        })
+        .collect()
+}
+
 /// 计算分数分布
 fn calculate_score_distribution(scores: &[f64], max_score: f64) -> Vec<ScoreRange> {
     if max_score <= 0.0 {
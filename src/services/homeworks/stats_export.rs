@@ -9,18 +9,32 @@ use tracing::error;
 use super::HomeworkService;
 use crate::middlewares::RequireJWT;
 use crate::models::class_users::entities::ClassUserRole;
+use crate::models::grades::entities::Grade;
+use crate::models::homeworks::requests::HomeworkStatsQuery;
+use crate::models::rubrics::entities::RubricScore;
 use crate::models::users::entities::UserRole;
 use crate::models::{ApiResponse, ErrorCode};
+use crate::services::class_groups::ensure_group_in_class;
+use crate::services::homework_extensions::resolve_student_deadlines;
 use crate::services::{StorageProvider, error_response};
 
 /// 学生明细信息
 struct StudentDetail {
     display_name: String,
     username: String,
+    /// 自定义资料字段值（顺序与资料字段列表一致）
+    profile: Vec<String>,
     submitted: bool,
+    /// 最终得分（扣除迟交扣分或教师调整后）
     score: Option<f64>,
+    /// 原始分数
+    raw_score: Option<f64>,
     submitted_at: Option<String>,
     is_late: bool,
+    /// 延期后的截止时间
+    extended_deadline: Option<String>,
+    /// 评分细则得分明细
+    rubric_scores: Vec<RubricScore>,
 }
 
 /// 导出作业统计报表
@@ -28,6 +42,7 @@ pub async fn export_homework_stats(
     service: &HomeworkService,
     request: &HttpRequest,
     homework_id: i64,
+    query: HomeworkStatsQuery,
 ) -> ActixResult<HttpResponse> {
     let storage = service.get_storage(request)?;
 
@@ -97,6 +112,13 @@ pub async fn export_homework_stats(
         }
     }
 
+    // 校验分组属于作业所在班级
+    if let Some(group_id) = query.group_id
+        && let Err(resp) = ensure_group_in_class(&storage, class_id, group_id).await
+    {
+        return Ok(resp);
+    }
+
     // 获取班级所有成员（不分页）
     let class_users = match storage.list_all_class_users(class_id).await {
         Ok(users) => users,
@@ -105,10 +127,11 @@ pub async fn export_homework_stats(
         }
     };
 
-    // 统计需要提交作业的成员（排除教师）
+    // 统计需要提交作业的成员（排除教师，按分组筛选）
     let students: Vec<_> = class_users
         .iter()
         .filter(|cu| cu.role != ClassUserRole::Teacher)
+        .filter(|cu| query.group_id.is_none() || cu.group_id == query.group_id)
         .collect();
     let total_students = students.len() as i64;
     let student_ids: HashSet<i64> = students.iter().map(|cu| cu.user_id).collect();
@@ -149,13 +172,16 @@ pub async fn export_homework_stats(
     // 获取所有提交的评分
     let mut graded_count = 0i64;
     let mut scores: Vec<f64> = Vec::new();
-    let mut submission_grades: HashMap<i64, f64> = HashMap::new(); // submission_id -> score
+    let mut submission_grades: HashMap<i64, Grade> = HashMap::new(); // submission_id -> grade
 
     for submission in &student_submissions {
         if let Ok(Some(grade)) = storage.get_grade_by_submission_id(submission.id).await {
-            graded_count += 1;
-            scores.push(grade.score);
-            submission_grades.insert(submission.id, grade.score);
+            // 仍有主观题待批阅的评分不计入已批改
+            if !grade.pending_review {
+                graded_count += 1;
+                scores.push(grade.effective_score);
+            }
+            submission_grades.insert(submission.id, grade);
         }
     }
 
@@ -181,29 +207,57 @@ pub async fn export_homework_stats(
         0.0
     };
 
+    // 获取统计范围内获得延期的学生
+    let extensions = match storage.list_homework_extensions(homework_id).await {
+        Ok(exts) => exts,
+        Err(e) => return Ok(error_response(e)),
+    };
+    let extended_deadlines: HashMap<i64, _> = resolve_student_deadlines(&extensions, &class_users)
+        .into_iter()
+        .filter(|(user_id, _)| student_ids.contains(user_id))
+        .collect();
+    let extended_count = extended_deadlines.len() as i64;
+
+    // 自定义资料字段（如学号）作为学生明细的附加列
+    let profile_fields = match storage.list_profile_fields().await {
+        Ok(fields) => fields,
+        Err(e) => return Ok(error_response(e)),
+    };
+
     // 构建学生明细数据
     let mut student_details: Vec<StudentDetail> = Vec::new();
     for student in &students {
         if let Ok(Some(user)) = storage.get_user_by_id(student.user_id).await {
             let submission = latest_submissions.get(&student.user_id);
-            let (submitted, score, submitted_at, is_late) = if let Some(sub) = submission {
-                let score = submission_grades.get(&sub.id).copied();
-                (true, score, Some(sub.submitted_at.clone()), sub.is_late)
+            let (submitted, grade, submitted_at, is_late) = if let Some(sub) = submission {
+                let grade = submission_grades.remove(&sub.id);
+                (true, grade, Some(sub.submitted_at.clone()), sub.is_late)
             } else {
                 (false, None, None, false)
             };
 
             student_details.push(StudentDetail {
+                profile: profile_fields
+                    .iter()
+                    .map(|f| user.profile.get(&f.key).cloned().unwrap_or_default())
+                    .collect(),
                 display_name: user.display_name.unwrap_or_else(|| user.username.clone()),
                 username: user.username,
                 submitted,
-                score,
+                score: grade.as_ref().map(|g| g.effective_score),
+                raw_score: grade.as_ref().map(|g| g.score),
                 submitted_at,
                 is_late,
+                extended_deadline: extended_deadlines
+                    .get(&student.user_id)
+                    .map(|d| d.to_rfc3339()),
+                rubric_scores: grade.map(|g| g.rubric_scores).unwrap_or_default(),
             });
         }
     }
 
+    let profile_labels: Vec<String> = profile_fields.into_iter().map(|f| f.label).collect();
+
     // 生成 XLSX
     let xlsx_result = generate_xlsx(
         &homework.title,
@@ -211,6 +265,7 @@ pub async fn export_homework_stats(
         submitted_count,
         graded_count,
         late_count,
+        extended_count,
         submission_rate,
         avg_score,
         max_score_val,
@@ -218,6 +273,7 @@ pub async fn export_homework_stats(
         max_score,
         &score_distribution,
         &student_details,
+        &profile_labels,
         show_scores,
     );
 
@@ -292,6 +348,7 @@ fn generate_xlsx(
     submitted_count: i64,
     graded_count: i64,
     late_count: i64,
+    extended_count: i64,
     submission_rate: f64,
     avg_score: Option<f64>,
     max_score: Option<f64>,
@@ -299,6 +356,7 @@ fn generate_xlsx(
     homework_max_score: f64,
     score_distribution: &[(String, i64)],
     student_details: &[StudentDetail],
+    profile_labels: &[String],
     show_scores: bool,
 ) -> Result<Vec<u8>, String> {
     let mut workbook = Workbook::new();
@@ -321,6 +379,7 @@ fn generate_xlsx(
         submitted_count,
         graded_count,
         late_count,
+        extended_count,
         submission_rate,
         avg_score,
         max_score,
@@ -347,7 +406,22 @@ fn generate_xlsx(
         .add_worksheet()
         .set_name("学生明细")
         .map_err(|e| e.to_string())?;
-    write_details_sheet(sheet3, &header_format, student_details, show_scores)?;
+    write_details_sheet(
+        sheet3,
+        &header_format,
+        student_details,
+        profile_labels,
+        show_scores,
+    )?;
+
+    // Sheet 4: 评分细则（仅在有按评分细则评分的学生时生成）
+    if student_details.iter().any(|s| !s.rubric_scores.is_empty()) {
+        let sheet4 = workbook
+            .add_worksheet()
+            .set_name("评分细则")
+            .map_err(|e| e.to_string())?;
+        write_rubric_sheet(sheet4, &header_format, student_details, show_scores)?;
+    }
 
     // 生成二进制数据
     workbook.save_to_buffer().map_err(|e| e.to_string())
@@ -364,6 +438,7 @@ fn write_summary_sheet(
     submitted_count: i64,
     graded_count: i64,
     late_count: i64,
+    extended_count: i64,
     submission_rate: f64,
     avg_score: Option<f64>,
     max_score: Option<f64>,
@@ -411,6 +486,10 @@ fn write_summary_sheet(
     sheet.write_number(row, 1, late_count as f64).ok();
     row += 1;
 
+    sheet.write_string(row, 0, "延期人数").ok();
+    sheet.write_number(row, 1, extended_count as f64).ok();
+    row += 1;
+
     sheet.write_string(row, 0, "提交率").ok();
     sheet
         .write_string(row, 1, format!("{submission_rate}%"))
@@ -513,22 +592,32 @@ fn write_details_sheet(
     sheet: &mut Worksheet,
     header_format: &Format,
     student_details: &[StudentDetail],
+    profile_labels: &[String],
     show_scores: bool,
 ) -> Result<(), String> {
-    // 表头
-    let headers = ["姓名", "用户名", "提交状态", "分数", "提交时间", "迟交"];
-    for (col, header) in headers.iter().enumerate() {
+    // 表头：姓名 | 用户名 | 资料字段... | 提交状态 | 分数 | 原始分数 | 提交时间 | 迟交 | 延期至
+    let headers = ["姓名", "用户名"]
+        .into_iter()
+        .chain(profile_labels.iter().map(String::as_str))
+        .chain(["提交状态", "分数", "原始分数", "提交时间", "迟交", "延期至"]);
+    for (col, header) in headers.enumerate() {
         sheet
-            .write_string_with_format(0, col as u16, *header, header_format)
+            .write_string_with_format(0, col as u16, header, header_format)
             .map_err(|e| e.to_string())?;
     }
 
+    // 资料字段之后的列偏移
+    let offset = profile_labels.len() as u16;
+
     // 数据
     for (row, student) in student_details.iter().enumerate() {
         let row = (row + 1) as u32;
 
         sheet.write_string(row, 0, &student.display_name).ok();
         sheet.write_string(row, 1, &student.username).ok();
+        for (i, value) in student.profile.iter().enumerate() {
+            sheet.write_string(row, 2 + i as u16, value).ok();
+        }
 
         // 提交状态
         let status = if student.submitted {
@@ -536,40 +625,135 @@ fn write_details_sheet(
         } else {
             "未提交"
         };
-        sheet.write_string(row, 2, status).ok();
+        sheet.write_string(row, offset + 2, status).ok();
 
         // 分数（根据权限显示）
         if show_scores {
             if let Some(score) = student.score {
-                sheet.write_number(row, 3, score).ok();
+                sheet.write_number(row, offset + 3, score).ok();
             } else if student.submitted {
-                sheet.write_string(row, 3, "待批改").ok();
+                sheet.write_string(row, offset + 3, "待批改").ok();
             } else {
-                sheet.write_string(row, 3, "-").ok();
+                sheet.write_string(row, offset + 3, "-").ok();
             }
         } else {
-            sheet.write_string(row, 3, "***").ok();
+            sheet.write_string(row, offset + 3, "***").ok();
+        }
+
+        // 原始分数（迟交扣分前）
+        match student.raw_score {
+            Some(raw_score) if show_scores => {
+                sheet.write_number(row, offset + 4, raw_score).ok();
+            }
+            Some(_) => {
+                sheet.write_string(row, offset + 4, "***").ok();
+            }
+            None => {
+                sheet.write_string(row, offset + 4, "-").ok();
+            }
         }
 
         // 提交时间
         if let Some(ref time) = student.submitted_at {
-            sheet.write_string(row, 4, time).ok();
+            sheet.write_string(row, offset + 5, time).ok();
         } else {
-            sheet.write_string(row, 4, "-").ok();
+            sheet.write_string(row, offset + 5, "-").ok();
         }
 
         // 迟交
         let late = if student.is_late { "是" } else { "-" };
-        sheet.write_string(row, 5, late).ok();
+        sheet.write_string(row, offset + 6, late).ok();
+
+        // 延期至
+        let extended = student.extended_deadline.as_deref().unwrap_or("-");
+        sheet.write_string(row, offset + 7, extended).ok();
     }
 
     // 设置列宽
     sheet.set_column_width(0, 15).ok();
     sheet.set_column_width(1, 15).ok();
-    sheet.set_column_width(2, 10).ok();
-    sheet.set_column_width(3, 10).ok();
-    sheet.set_column_width(4, 20).ok();
-    sheet.set_column_width(5, 8).ok();
+    for i in 0..offset {
+        sheet.set_column_width(2 + i, 15).ok();
+    }
+    sheet.set_column_width(offset + 2, 10).ok();
+    sheet.set_column_width(offset + 3, 10).ok();
+    sheet.set_column_width(offset + 4, 10).ok();
+    sheet.set_column_width(offset + 5, 20).ok();
+    sheet.set_column_width(offset + 6, 8).ok();
+    sheet.set_column_width(offset + 7, 20).ok();
+
+    Ok(())
+}
+
+/// 写入评分细则 Sheet
+fn write_rubric_sheet(
+    sheet: &mut Worksheet,
+    header_format: &Format,
+    student_details: &[StudentDetail],
+    show_scores: bool,
+) -> Result<(), String> {
+    // 评分项列按首次出现的顺序排列（评分细则修改后新旧评分项并列）
+    let mut criteria: Vec<(i64, &str)> = Vec::new();
+    for score in student_details.iter().flat_map(|s| &s.rubric_scores) {
+        if !criteria.iter().any(|(id, _)| *id == score.criterion_id) {
+            criteria.push((score.criterion_id, &score.criterion_title));
+        }
+    }
+
+    // 表头：姓名 | 用户名 | 评分项... | 合计
+    let headers = ["姓名", "用户名"]
+        .into_iter()
+        .chain(criteria.iter().map(|(_, title)| *title))
+        .chain(["合计"]);
+    for (col, header) in headers.enumerate() {
+        sheet
+            .write_string_with_format(0, col as u16, header, header_format)
+            .map_err(|e| e.to_string())?;
+    }
+
+    let total_col = criteria.len() as u16 + 2;
+
+    // 数据：仅列出按评分细则评分的学生
+    let rows = student_details
+        .iter()
+        .filter(|s| !s.rubric_scores.is_empty());
+    for (row, student) in rows.enumerate() {
+        let row = (row + 1) as u32;
+
+        sheet.write_string(row, 0, &student.display_name).ok();
+        sheet.write_string(row, 1, &student.username).ok();
+
+        for (i, (criterion_id, _)) in criteria.iter().enumerate() {
+            let col = 2 + i as u16;
+            let cell = match student
+                .rubric_scores
+                .iter()
+                .find(|s| s.criterion_id == *criterion_id)
+            {
+                Some(_) if !show_scores => "***".to_string(),
+                Some(score) => format!("{}（{}）", score.points, score.level_title),
+                None => "-".to_string(),
+            };
+            sheet.write_string(row, col, cell).ok();
+        }
+
+        if show_scores {
+            let total: f64 = student.rubric_scores.iter().map(|s| s.points).sum();
+            sheet
+                .write_number(row, total_col, (total * 100.0).round() / 100.0)
+                .ok();
+        } else {
+            sheet.write_string(row, total_col, "***").ok();
+        }
+    }
+
+    // 设置列宽
+    sheet.set_column_width(0, 15).ok();
+    sheet.set_column_width(1, 15).ok();
+    for i in 0..criteria.len() as u16 {
+        sheet.set_column_width(2 + i, 18).ok();
+    }
+    sheet.set_column_width(total_col, 10).ok();
 
     Ok(())
 }
@@ -2,18 +2,26 @@ use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
 
 use super::HomeworkService;
 use crate::middlewares::RequireJWT;
+use crate::models::homework_questions::entities::question_total;
+use crate::models::homeworks::entities::HomeworkStatus;
 use crate::models::homeworks::requests::UpdateHomeworkRequest;
 use crate::models::notifications::entities::{NotificationType, ReferenceType};
 use crate::models::users::entities::UserRole;
 use crate::models::{ApiResponse, ErrorCode};
+use crate::services::class_groups::normalize_group_ids;
+use crate::services::homeworks::{
+    notify_homework_published, resolve_publish_state, validate_auto_grader, validate_late_policy,
+    validate_question_total, validate_questions,
+};
 use crate::services::notifications::trigger::{get_class_student_ids, send_notifications};
+use crate::services::rubrics::load_owned_rubric;
 use crate::services::{StorageProvider, error_response};
 
 pub async fn update_homework(
     service: &HomeworkService,
     request: &HttpRequest,
     homework_id: i64,
-    req: UpdateHomeworkRequest,
+    mut req: UpdateHomeworkRequest,
     user_id: i64,
 ) -> ActixResult<HttpResponse> {
     let storage = service.get_storage(request)?;
@@ -52,16 +60,143 @@ pub async fn update_homework(
         }
     }
 
+    // 校验分配的分组均属于该班级
+    if let Some(group_ids) = req.group_ids.take() {
+        match normalize_group_ids(&storage, homework.class_id, group_ids).await {
+            Ok(group_ids) => req.group_ids = Some(group_ids),
+            Err(resp) => return Ok(resp),
+        }
+    }
+
+    // 关联的评分细则须为自己评分细则库中的（管理员不限）
+    if let Some(Some(rubric_id)) = req.rubric_id
+        && let Err(resp) = load_owned_rubric(&storage, request, rubric_id).await
+    {
+        return Ok(resp);
+    }
+
+    // 校验题目：已有提交的作业不能修改题目，修改满分时题目总分不能超过新的满分
+    let max_score = req.max_score.unwrap_or(homework.max_score);
+    if let Some(questions) = req.questions.as_mut() {
+        match storage.has_homework_submissions(homework_id).await {
+            Ok(true) => {
+                return Ok(HttpResponse::Conflict().json(ApiResponse::error_empty(
+                    ErrorCode::HomeworkQuestionsLocked,
+                    "作业已有提交，不能修改题目",
+                )));
+            }
+            Ok(false) => {}
+            Err(e) => return Ok(error_response(e)),
+        }
+        if let Err(resp) = validate_questions(questions, max_score) {
+            return Ok(resp);
+        }
+    } else if req.max_score.is_some() {
+        let questions = match storage.list_homework_questions(homework_id).await {
+            Ok(questions) => questions,
+            Err(e) => return Ok(error_response(e)),
+        };
+        let pools = match storage.list_homework_question_pools(homework_id).await {
+            Ok(pools) => pools,
+            Err(e) => return Ok(error_response(e)),
+        };
+        let total = question_total(&questions, &pools);
+        if let Err(resp) = validate_question_total(total, max_score) {
+            return Ok(resp);
+        }
+    }
+
+    // 校验更新后的自动评测器（不能与题目同时使用）
+    if let Some(auto_grader) = req.auto_grader.as_mut() {
+        *auto_grader = auto_grader
+            .take()
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty());
+    }
+    if req.auto_grader.is_some() || req.questions.is_some() {
+        let auto_grader = match &req.auto_grader {
+            Some(auto_grader) => auto_grader.as_deref(),
+            None => homework.auto_grader.as_deref(),
+        };
+        let has_questions = match &req.questions {
+            Some(questions) => !questions.is_empty(),
+            None if auto_grader.is_some() => {
+                match (
+                    storage.list_homework_questions(homework_id).await,
+                    storage.list_homework_question_pools(homework_id).await,
+                ) {
+                    (Ok(questions), Ok(pools)) => !questions.is_empty() || !pools.is_empty(),
+                    (Err(e), _) | (_, Err(e)) => return Ok(error_response(e)),
+                }
+            }
+            None => false,
+        };
+        if let Err(resp) = validate_auto_grader(auto_grader, has_questions) {
+            return Ok(resp);
+        }
+    }
+
+    // 校验更新后的迟交策略
+    if req.deadline.is_some() || req.late_cutoff.is_some() || req.late_penalty.is_some() {
+        let late_cutoff = req.late_cutoff.unwrap_or(homework.late_cutoff);
+        let late_penalty = match &req.late_penalty {
+            Some(penalty) => penalty.as_ref(),
+            None => homework.late_penalty.as_ref(),
+        };
+        if let Err(resp) = validate_late_policy(
+            req.deadline.or(homework.deadline),
+            late_cutoff,
+            late_penalty,
+        ) {
+            return Ok(resp);
+        }
+    }
+
+    // 处理发布状态变更：已发布的作业不能撤回
+    if homework.is_published() {
+        if req.status.is_some_and(|s| s != HomeworkStatus::Published) {
+            return Ok(HttpResponse::BadRequest().json(ApiResponse::error_empty(
+                ErrorCode::HomeworkStatusInvalid,
+                "已发布的作业不能改回草稿或定时发布",
+            )));
+        }
+        req.status = None;
+        req.publish_at = None;
+    } else if req.status.is_some() || req.publish_at.is_some() || req.deadline.is_some() {
+        let status = req.status.unwrap_or(homework.status);
+        let publish_at = req.publish_at.or(homework.publish_at);
+        let deadline = req.deadline.or(homework.deadline);
+        match resolve_publish_state(Some(status), publish_at, deadline, chrono::Utc::now()) {
+            Ok((status, publish_at)) => {
+                req.status = Some(status);
+                req.publish_at = publish_at;
+            }
+            Err(resp) => return Ok(resp),
+        }
+    }
+
     match storage.update_homework(homework_id, req, user_id).await {
+        Ok(Some(updated_homework)) if !homework.is_published() => {
+            // 草稿或定时作业：仅在本次手动发布时通知学生
+            if updated_homework.is_published() {
+                let storage_clone = storage.clone();
+                let homework_clone = updated_homework.clone();
+                tokio::spawn(async move {
+                    notify_homework_published(storage_clone, &homework_clone).await;
+                });
+            }
+            Ok(HttpResponse::Ok().json(ApiResponse::success(updated_homework, "更新成功")))
+        }
         Ok(Some(updated_homework)) => {
             // 异步发送通知给班级学生
             let storage_clone = storage.clone();
             let hw_id = updated_homework.id;
             let class_id = homework.class_id;
             let title = updated_homework.title.clone();
+            let group_ids = updated_homework.group_ids.clone();
 
             tokio::spawn(async move {
-                let student_ids = get_class_student_ids(&storage_clone, class_id).await;
+                let student_ids = get_class_student_ids(&storage_clone, class_id, &group_ids).await;
                 send_notifications(
                     storage_clone,
                     student_ids,
@@ -1,10 +1,18 @@
+pub mod announcements;
 pub mod auth;
+pub mod auto_graders;
+pub mod class_groups;
 pub mod class_users;
 pub mod classes;
+pub mod comments;
 pub mod files;
 pub mod grades;
+pub mod homework_extensions;
 pub mod homeworks;
 pub mod notifications;
+pub mod profile_fields;
+pub mod question_bank;
+pub mod rubrics;
 pub mod submissions;
 pub mod system;
 pub mod users;
@@ -67,13 +75,21 @@ pub trait CacheProvider {
     }
 }
 
+pub use announcements::AnnouncementService;
 pub use auth::AuthService;
+pub use auto_graders::AutoGraderService;
+pub use class_groups::ClassGroupService;
 pub use class_users::ClassUserService;
 pub use classes::ClassService;
+pub use comments::CommentService;
 pub use files::FileService;
 pub use grades::GradeService;
+pub use homework_extensions::HomeworkExtensionService;
 pub use homeworks::HomeworkService;
 pub use notifications::NotificationService;
+pub use profile_fields::ProfileFieldService;
+pub use question_bank::QuestionBankService;
+pub use rubrics::RubricService;
 pub use submissions::SubmissionService;
 pub use system::SystemService;
 pub use users::UserService;
@@ -84,12 +84,21 @@ pub async fn send_notification(
     .await;
 }
 
-/// 获取班级所有学生的 user_id 列表（排除教师角色）
-pub async fn get_class_student_ids(storage: &Arc<dyn Storage>, class_id: i64) -> Vec<i64> {
+/// 获取班级学生的 user_id 列表（排除教师角色）
+///
+/// `group_ids` 非空时仅返回这些分组中的学生
+pub async fn get_class_student_ids(
+    storage: &Arc<dyn Storage>,
+    class_id: i64,
+    group_ids: &[i64],
+) -> Vec<i64> {
     match storage.list_all_class_users(class_id).await {
         Ok(class_users) => class_users
             .into_iter()
             .filter(|cu| cu.role != ClassUserRole::Teacher)
+            .filter(|cu| {
+                group_ids.is_empty() || cu.group_id.is_some_and(|g| group_ids.contains(&g))
+            })
             .map(|cu| cu.user_id)
             .collect(),
         Err(e) => {
@@ -98,3 +107,18 @@ pub async fn get_class_student_ids(storage: &Arc<dyn Storage>, class_id: i64) ->
         }
     }
 }
+
+/// 获取班级所有教师的 user_id 列表
+pub async fn get_class_teacher_ids(storage: &Arc<dyn Storage>, class_id: i64) -> Vec<i64> {
+    match storage.list_all_class_users(class_id).await {
+        Ok(class_users) => class_users
+            .into_iter()
+            .filter(|cu| cu.role == ClassUserRole::Teacher)
+            .map(|cu| cu.user_id)
+            .collect(),
+        Err(e) => {
+            error!("Failed to get class teachers for class {}: {}", class_id, e);
+            vec![]
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use tracing::error;
+
+use super::ProfileFieldService;
+use crate::models::profile_fields::entities::ProfileFieldType;
+use crate::models::profile_fields::requests::CreateProfileFieldRequest;
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::{StorageProvider, error_response};
+
+static FIELD_KEY_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^[a-z][a-z0-9_]{0,31}$").expect("Invalid profile field key regex"));
+
+/// 与用户导入导出内置列冲突的字段键
+const RESERVED_KEYS: &[&str] = &[
+    "id",
+    "username",
+    "email",
+    "password",
+    "role",
+    "status",
+    "display_name",
+    "avatar_url",
+    "created_at",
+    "updated_at",
+    "last_login",
+];
+
+pub async fn create_profile_field(
+    service: &ProfileFieldService,
+    request: &HttpRequest,
+    mut body: CreateProfileFieldRequest,
+) -> ActixResult<HttpResponse> {
+    body.key = body.key.trim().to_string();
+    body.label = body.label.trim().to_string();
+
+    if !FIELD_KEY_RE.is_match(&body.key) || RESERVED_KEYS.contains(&body.key.as_str()) {
+        return Ok(HttpResponse::BadRequest().json(ApiResponse::error_empty(
+            ErrorCode::ProfileFieldInvalid,
+            "Field key must be 1-32 lowercase letters, digits or underscores, start with a letter and not be a built-in column",
+        )));
+    }
+
+    if let Err(msg) = validate_definition(
+        &body.label,
+        body.field_type.unwrap_or(ProfileFieldType::Text),
+        body.pattern.as_deref(),
+        body.options.as_deref(),
+    ) {
+        return Ok(HttpResponse::BadRequest().json(ApiResponse::error_empty(
+            ErrorCode::ProfileFieldInvalid,
+            msg,
+        )));
+    }
+
+    let storage = service.get_storage(request)?;
+
+    match storage.get_profile_field_by_key(&body.key).await {
+        Ok(Some(_)) => {
+            return Ok(HttpResponse::Conflict().json(ApiResponse::error_empty(
+                ErrorCode::ProfileFieldKeyExists,
+                "Profile field key already exists",
+            )));
+        }
+        Ok(None) => {}
+        Err(e) => return Ok(error_response(e)),
+    }
+
+    match storage.create_profile_field(body).await {
+        Ok(field) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            field,
+            "Profile field created successfully",
+        ))),
+        Err(e) => {
+            error!("Failed to create profile field: {}", e);
+            Ok(error_response(e))
+        }
+    }
+}
+
+/// 校验字段定义：显示名称非空、正则可编译、单选类型提供可选值
+pub(super) fn validate_definition(
+    label: &str,
+    field_type: ProfileFieldType,
+    pattern: Option<&str>,
+    options: Option<&[String]>,
+) -> Result<(), String> {
+    if label.trim().is_empty() {
+        return Err("Field label must not be empty".to_string());
+    }
+
+    if let Some(pattern) = pattern.filter(|p| !p.is_empty())
+        && let Err(e) = Regex::new(pattern)
+    {
+        return Err(format!("Invalid pattern: {e}"));
+    }
+
+    if field_type == ProfileFieldType::Select && options.is_none_or(|o| o.is_empty()) {
+        return Err("Select fields require at least one option".to_string());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use tracing::error;
+
+use super::ProfileFieldService;
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::{StorageProvider, error_response};
+
+/// 删除资料字段，同时删除所有用户的该字段值
+pub async fn delete_profile_field(
+    service: &ProfileFieldService,
+    request: &HttpRequest,
+    field_id: i64,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    match storage.delete_profile_field(field_id).await {
+        Ok(true) => Ok(HttpResponse::Ok().json(ApiResponse::success_empty(
+            "Profile field deleted successfully",
+        ))),
+        Ok(false) => Ok(HttpResponse::NotFound().json(ApiResponse::error_empty(
+            ErrorCode::ProfileFieldNotFound,
+            "Profile field not found",
+        ))),
+        Err(e) => {
+            error!("Failed to delete profile field: {}", e);
+            Ok(error_response(e))
+        }
+    }
+}
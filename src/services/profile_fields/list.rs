@@ -0,0 +1,21 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+
+use super::ProfileFieldService;
+use crate::models::ApiResponse;
+use crate::models::profile_fields::responses::ProfileFieldListResponse;
+use crate::services::{StorageProvider, error_response};
+
+pub async fn list_profile_fields(
+    service: &ProfileFieldService,
+    request: &HttpRequest,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    match storage.list_profile_fields().await {
+        Ok(items) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ProfileFieldListResponse { items },
+            "Profile fields retrieved successfully",
+        ))),
+        Err(e) => Ok(error_response(e)),
+    }
+}
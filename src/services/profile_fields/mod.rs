@@ -0,0 +1,172 @@
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod update;
+
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::errors::HWSystemError;
+use crate::models::profile_fields::entities::ProfileField;
+use crate::models::profile_fields::requests::{
+    CreateProfileFieldRequest, UpdateProfileFieldRequest,
+};
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::{StorageProvider, error_response};
+use crate::storage::Storage;
+
+pub struct ProfileFieldService {
+    storage: Option<Arc<dyn Storage>>,
+}
+
+impl ProfileFieldService {
+    pub fn new_lazy() -> Self {
+        Self { storage: None }
+    }
+
+    // 列出资料字段
+    pub async fn list_profile_fields(&self, req: &HttpRequest) -> ActixResult<HttpResponse> {
+        list::list_profile_fields(self, req).await
+    }
+
+    // 创建资料字段
+    pub async fn create_profile_field(
+        &self,
+        req: &HttpRequest,
+        body: CreateProfileFieldRequest,
+    ) -> ActixResult<HttpResponse> {
+        create::create_profile_field(self, req, body).await
+    }
+
+    // 更新资料字段
+    pub async fn update_profile_field(
+        &self,
+        req: &HttpRequest,
+        field_id: i64,
+        body: UpdateProfileFieldRequest,
+    ) -> ActixResult<HttpResponse> {
+        update::update_profile_field(self, req, field_id, body).await
+    }
+
+    // 删除资料字段
+    pub async fn delete_profile_field(
+        &self,
+        req: &HttpRequest,
+        field_id: i64,
+    ) -> ActixResult<HttpResponse> {
+        delete::delete_profile_field(self, req, field_id).await
+    }
+}
+
+impl StorageProvider for ProfileFieldService {
+    fn storage_ref(&self) -> Option<Arc<dyn Storage>> {
+        self.storage.clone()
+    }
+}
+
+/// 待写入的资料字段值（field_id, 值），值为 None 表示清除
+pub(crate) type ProfileValues = Vec<(i64, Option<String>)>;
+
+/// 资料字段值校验失败
+pub(crate) enum ProfileValueError {
+    /// 值不合法（未知字段、必填缺失、格式不符）
+    Invalid { field: String, message: String },
+    /// 唯一字段的值已被其他用户使用
+    Conflict { field: String, message: String },
+    /// 查询失败
+    Storage(HWSystemError),
+}
+
+impl ProfileValueError {
+    /// 出错的字段键
+    pub(crate) fn field(&self) -> &str {
+        match self {
+            Self::Invalid { field, .. } | Self::Conflict { field, .. } => field,
+            Self::Storage(_) => "",
+        }
+    }
+
+    pub(crate) fn message(&self) -> String {
+        match self {
+            Self::Invalid { message, .. } | Self::Conflict { message, .. } => message.clone(),
+            Self::Storage(e) => e.to_string(),
+        }
+    }
+
+    pub(crate) fn into_response(self) -> HttpResponse {
+        match self {
+            Self::Invalid { message, .. } => HttpResponse::BadRequest().json(
+                ApiResponse::error_empty(ErrorCode::ProfileValueInvalid, message),
+            ),
+            Self::Conflict { message, .. } => HttpResponse::Conflict().json(
+                ApiResponse::error_empty(ErrorCode::ProfileValueConflict, message),
+            ),
+            Self::Storage(e) => error_response(e),
+        }
+    }
+}
+
+/// 校验用户提交的资料字段值，返回待写入的 (field_id, 值) 列表
+///
+/// - `user_id` 为当前用户（新建用户时为 None），唯一性校验时排除自身；
+/// - `require_all` 为 true 时（新建用户）所有必填字段都必须提供；
+/// - 空字符串表示清除该字段的值。
+pub(crate) async fn resolve_profile_values(
+    storage: &Arc<dyn Storage>,
+    fields: &[ProfileField],
+    user_id: Option<i64>,
+    input: &BTreeMap<String, String>,
+    require_all: bool,
+) -> Result<ProfileValues, ProfileValueError> {
+    if let Some(key) = input.keys().find(|k| !fields.iter().any(|f| &f.key == *k)) {
+        return Err(ProfileValueError::Invalid {
+            field: key.clone(),
+            message: format!("未知的资料字段: {key}"),
+        });
+    }
+
+    let mut values = Vec::new();
+
+    for field in fields {
+        let value = match input.get(&field.key).map(|v| v.trim()) {
+            Some(v) if !v.is_empty() => v,
+            provided => {
+                if field.required && (require_all || provided.is_some()) {
+                    return Err(ProfileValueError::Invalid {
+                        field: field.key.clone(),
+                        message: format!("{}为必填项", field.label),
+                    });
+                }
+                if provided.is_some() {
+                    values.push((field.id, None));
+                }
+                continue;
+            }
+        };
+
+        let value = field
+            .validate_value(value)
+            .map_err(|message| ProfileValueError::Invalid {
+                field: field.key.clone(),
+                message,
+            })?;
+
+        if field.unique {
+            let owners = storage
+                .find_user_ids_by_profile_value(field.id, &value)
+                .await
+                .map_err(ProfileValueError::Storage)?;
+            if owners.iter().any(|&id| Some(id) != user_id) {
+                return Err(ProfileValueError::Conflict {
+                    field: field.key.clone(),
+                    message: format!("{}「{}」已被其他用户使用", field.label, value),
+                });
+            }
+        }
+
+        values.push((field.id, Some(value)));
+    }
+
+    Ok(values)
+}
@@ -0,0 +1,75 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use tracing::error;
+
+use super::ProfileFieldService;
+use super::create::validate_definition;
+use crate::models::profile_fields::requests::UpdateProfileFieldRequest;
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::{StorageProvider, error_response};
+
+pub async fn update_profile_field(
+    service: &ProfileFieldService,
+    request: &HttpRequest,
+    field_id: i64,
+    mut body: UpdateProfileFieldRequest,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    let field = match storage.get_profile_field_by_id(field_id).await {
+        Ok(Some(field)) => field,
+        Ok(None) => {
+            return Ok(HttpResponse::NotFound().json(ApiResponse::error_empty(
+                ErrorCode::ProfileFieldNotFound,
+                "Profile field not found",
+            )));
+        }
+        Err(e) => return Ok(error_response(e)),
+    };
+
+    if let Some(label) = body.label.as_mut() {
+        *label = label.trim().to_string();
+    }
+
+    // 按更新后的完整定义校验
+    let options = body.options.as_deref().unwrap_or(&field.options);
+    if let Err(msg) = validate_definition(
+        body.label.as_deref().unwrap_or(&field.label),
+        body.field_type.unwrap_or(field.field_type),
+        body.pattern.as_deref().or(field.pattern.as_deref()),
+        Some(options),
+    ) {
+        return Ok(HttpResponse::BadRequest().json(ApiResponse::error_empty(
+            ErrorCode::ProfileFieldInvalid,
+            msg,
+        )));
+    }
+
+    // 改为唯一字段前确认现有值没有重复
+    if body.unique == Some(true) && !field.unique {
+        match storage.has_duplicate_profile_values(field_id).await {
+            Ok(true) => {
+                return Ok(HttpResponse::Conflict().json(ApiResponse::error_empty(
+                    ErrorCode::ProfileValueConflict,
+                    "Existing values of this field are not unique",
+                )));
+            }
+            Ok(false) => {}
+            Err(e) => return Ok(error_response(e)),
+        }
+    }
+
+    match storage.update_profile_field(field_id, body).await {
+        Ok(Some(field)) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            field,
+            "Profile field updated successfully",
+        ))),
+        Ok(None) => Ok(HttpResponse::NotFound().json(ApiResponse::error_empty(
+            ErrorCode::ProfileFieldNotFound,
+            "Profile field not found",
+        ))),
+        Err(e) => {
+            error!("Failed to update profile field: {}", e);
+            Ok(error_response(e))
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+
+use super::{QuestionBankService, invalid, normalize_new_question};
+use crate::middlewares::RequireJWT;
+use crate::models::question_bank::requests::CreateBankQuestionRequest;
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::{StorageProvider, error_response};
+
+pub async fn create_bank_question(
+    service: &QuestionBankService,
+    request: &HttpRequest,
+    req: CreateBankQuestionRequest,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    let Some(user_id) = RequireJWT::extract_user_id(request) else {
+        return Ok(HttpResponse::Unauthorized().json(ApiResponse::error_empty(
+            ErrorCode::Unauthorized,
+            "无法获取用户信息",
+        )));
+    };
+
+    let req = match normalize_new_question(req) {
+        Ok(req) => req,
+        Err(msg) => return Ok(invalid(msg)),
+    };
+
+    match storage.create_bank_question(user_id, req).await {
+        Ok(question) => {
+            Ok(HttpResponse::Created().json(ApiResponse::success(question, "创建成功")))
+        }
+        Err(e) => Ok(error_response(e)),
+    }
+}
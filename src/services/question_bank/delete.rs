@@ -0,0 +1,26 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+
+use super::{QuestionBankService, load_bank_question};
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::{StorageProvider, error_response};
+
+pub async fn delete_bank_question(
+    service: &QuestionBankService,
+    request: &HttpRequest,
+    question_id: i64,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    if let Err(resp) = load_bank_question(&storage, request, question_id, true).await {
+        return Ok(resp);
+    }
+
+    match storage.delete_bank_question(question_id).await {
+        Ok(true) => Ok(HttpResponse::Ok().json(ApiResponse::success_empty("删除成功"))),
+        Ok(false) => Ok(HttpResponse::NotFound().json(ApiResponse::error_empty(
+            ErrorCode::BankQuestionNotFound,
+            "题库题目不存在",
+        ))),
+        Err(e) => Ok(error_response(e)),
+    }
+}
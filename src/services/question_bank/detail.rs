@@ -0,0 +1,18 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+
+use super::{QuestionBankService, load_bank_question};
+use crate::models::ApiResponse;
+use crate::services::StorageProvider;
+
+pub async fn get_bank_question(
+    service: &QuestionBankService,
+    request: &HttpRequest,
+    question_id: i64,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    match load_bank_question(&storage, request, question_id, false).await {
+        Ok(question) => Ok(HttpResponse::Ok().json(ApiResponse::success(question, "获取成功"))),
+        Err(resp) => Ok(resp),
+    }
+}
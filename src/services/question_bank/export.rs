@@ -0,0 +1,289 @@
+//! 题库导出服务
+
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use rust_xlsxwriter::{Format, Workbook};
+use tracing::error;
+
+use super::gift::format_gift;
+use super::{QuestionBankService, viewer_filter};
+use crate::models::homework_questions::entities::QuestionSpec;
+use crate::models::question_bank::entities::BankQuestion;
+use crate::models::question_bank::requests::BankQuestionExportParams;
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::{StorageProvider, error_response};
+
+/// 单次导出的最大题目数量
+const MAX_EXPORT_QUESTIONS: u64 = 10000;
+
+/// 导出题库题目（xlsx / gift / moodle_xml）
+pub async fn export_bank_questions(
+    service: &QuestionBankService,
+    request: &HttpRequest,
+    params: BankQuestionExportParams,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    let mut filter = match viewer_filter(request) {
+        Ok(filter) => filter,
+        Err(resp) => return Ok(resp),
+    };
+    filter.scope = params.scope.unwrap_or_default();
+    filter.tag = params.tag;
+    filter.difficulty = params.difficulty;
+    filter.knowledge_point = params.knowledge_point;
+    filter.question_type = params.question_type;
+    filter.search = params.search;
+
+    let questions = match storage
+        .list_bank_questions(filter, MAX_EXPORT_QUESTIONS)
+        .await
+    {
+        Ok(questions) => questions,
+        Err(e) => {
+            error!("导出题库失败: {}", e);
+            return Ok(error_response(e));
+        }
+    };
+
+    match params.format.as_str() {
+        "gift" => Ok(HttpResponse::Ok()
+            .content_type("text/plain; charset=utf-8")
+            .insert_header((
+                "Content-Disposition",
+                "attachment; filename=\"questions.gift.txt\"",
+            ))
+            .body(format_gift(&questions))),
+        "moodle_xml" => Ok(HttpResponse::Ok()
+            .content_type("application/xml; charset=utf-8")
+            .insert_header((
+                "Content-Disposition",
+                "attachment; filename=\"questions.xml\"",
+            ))
+            .body(format_moodle_xml(&questions))),
+        _ => export_xlsx(&questions),
+    }
+}
+
+/// 选项下标转字母（0 -> A）
+fn choice_letter(index: usize) -> String {
+    char::from(b'A' + (index % 26) as u8).to_string()
+}
+
+/// 表格中的题型与答案列（与导入格式一致）
+fn spec_columns(spec: &QuestionSpec) -> (&'static str, String, String) {
+    match spec {
+        QuestionSpec::SingleChoice { options, answer } => (
+            "single_choice",
+            options.join("\n"),
+            answer.map(choice_letter).unwrap_or_default(),
+        ),
+        QuestionSpec::MultipleChoice { options, answers } => (
+            "multiple_choice",
+            options.join("\n"),
+            answers
+                .iter()
+                .flatten()
+                .map(|a| choice_letter(*a))
+                .collect::<Vec<_>>()
+                .join(","),
+        ),
+        QuestionSpec::TrueFalse { answer } => (
+            "true_false",
+            String::new(),
+            answer.map(|a| a.to_string()).unwrap_or_default(),
+        ),
+        QuestionSpec::FillBlank {
+            accepted_answers, ..
+        } => (
+            "fill_blank",
+            String::new(),
+            accepted_answers.clone().unwrap_or_default().join("\n"),
+        ),
+        QuestionSpec::Numeric { answer, tolerance } => {
+            let answer = match (answer, tolerance) {
+                (Some(answer), Some(tolerance)) if *tolerance > 0.0 => {
+                    format!("{answer}:{tolerance}")
+                }
+                (Some(answer), _) => answer.to_string(),
+                _ => String::new(),
+            };
+            ("numeric", String::new(), answer)
+        }
+        QuestionSpec::Essay => ("essay", String::new(), String::new()),
+    }
+}
+
+fn export_xlsx(questions: &[BankQuestion]) -> ActixResult<HttpResponse> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // 表头格式
+    let header_format = Format::new().set_bold();
+
+    // 写入表头（与导入列名一致，可直接重新导入）
+    let headers = [
+        "type",
+        "prompt",
+        "points",
+        "options",
+        "answer",
+        "tags",
+        "difficulty",
+        "knowledge_point",
+    ];
+    for (col, header) in headers.iter().enumerate() {
+        if let Err(e) = worksheet.write_string_with_format(0, col as u16, *header, &header_format) {
+            error!("XLSX 写入失败: {}", e);
+            return Ok(
+                HttpResponse::InternalServerError().json(ApiResponse::<()>::error_empty(
+                    ErrorCode::ExportFailed,
+                    format!("XLSX 写入失败: {e}"),
+                )),
+            );
+        }
+    }
+
+    // 写入数据
+    for (row, question) in questions.iter().enumerate() {
+        let row = (row + 1) as u32;
+        let (question_type, options, answer) = spec_columns(&question.spec);
+        worksheet.write_string(row, 0, question_type).ok();
+        worksheet.write_string(row, 1, &question.prompt).ok();
+        worksheet.write_number(row, 2, question.points).ok();
+        worksheet.write_string(row, 3, options).ok();
+        worksheet.write_string(row, 4, answer).ok();
+        worksheet
+            .write_string(row, 5, question.tags.join(", "))
+            .ok();
+        worksheet
+            .write_number(row, 6, question.difficulty as f64)
+            .ok();
+        worksheet
+            .write_string(row, 7, question.knowledge_point.as_deref().unwrap_or(""))
+            .ok();
+    }
+
+    // 生成二进制数据
+    let buffer = match workbook.save_to_buffer() {
+        Ok(b) => b,
+        Err(e) => {
+            error!("XLSX 生成失败: {}", e);
+            return Ok(
+                HttpResponse::InternalServerError().json(ApiResponse::<()>::error_empty(
+                    ErrorCode::ExportFailed,
+                    format!("XLSX 生成失败: {e}"),
+                )),
+            );
+        }
+    };
+
+    Ok(HttpResponse::Ok()
+        .content_type("application/vnd.openxmlformats-officedocument.spreadsheetml.sheet")
+        .insert_header((
+            "Content-Disposition",
+            "attachment; filename=\"questions.xlsx\"",
+        ))
+        .body(buffer))
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Moodle XML 中的 <answer> 元素
+fn xml_answer(fraction: f64, text: &str) -> String {
+    format!(
+        "    <answer fraction=\"{}\" format=\"moodle_auto_format\"><text>{}</text></answer>\n",
+        fraction,
+        xml_escape(text)
+    )
+}
+
+/// 导出为 Moodle XML（难度与知识点以标签形式保存）
+fn format_moodle_xml(questions: &[BankQuestion]) -> String {
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<quiz>\n");
+    for (index, question) in questions.iter().enumerate() {
+        let (question_type, body) = match &question.spec {
+            QuestionSpec::SingleChoice { options, answer } => {
+                let mut body = String::from("    <single>true</single>\n");
+                for (i, option) in options.iter().enumerate() {
+                    let fraction = if *answer == Some(i) { 100.0 } else { 0.0 };
+                    body.push_str(&xml_answer(fraction, option));
+                }
+                ("multichoice", body)
+            }
+            QuestionSpec::MultipleChoice { options, answers } => {
+                let answers = answers.clone().unwrap_or_default();
+                let weight = 100.0 / answers.len().max(1) as f64;
+                let mut body = String::from("    <single>false</single>\n");
+                for (i, option) in options.iter().enumerate() {
+                    let fraction = if answers.contains(&i) { weight } else { -100.0 };
+                    body.push_str(&xml_answer((fraction * 1e5).round() / 1e5, option));
+                }
+                ("multichoice", body)
+            }
+            QuestionSpec::TrueFalse { answer } => {
+                let answer = answer.unwrap_or(false);
+                let body = format!(
+                    "{}{}",
+                    xml_answer(if answer { 100.0 } else { 0.0 }, "true"),
+                    xml_answer(if answer { 0.0 } else { 100.0 }, "false")
+                );
+                ("truefalse", body)
+            }
+            QuestionSpec::FillBlank {
+                accepted_answers,
+                case_sensitive,
+            } => {
+                let mut body = format!(
+                    "    <usecase>{}</usecase>\n",
+                    if *case_sensitive { 1 } else { 0 }
+                );
+                for answer in accepted_answers.iter().flatten() {
+                    body.push_str(&xml_answer(100.0, answer));
+                }
+                ("shortanswer", body)
+            }
+            QuestionSpec::Numeric { answer, tolerance } => {
+                let body = format!(
+                    "    <answer fraction=\"100\"><text>{}</text><tolerance>{}</tolerance></answer>\n",
+                    answer.unwrap_or(0.0),
+                    tolerance.unwrap_or(0.0)
+                );
+                ("numerical", body)
+            }
+            QuestionSpec::Essay => ("essay", String::new()),
+        };
+
+        let mut tags: Vec<String> = question.tags.clone();
+        tags.push(format!("difficulty:{}", question.difficulty));
+        if let Some(knowledge_point) = &question.knowledge_point {
+            tags.push(format!("knowledge_point:{knowledge_point}"));
+        }
+
+        out.push_str(&format!("  <question type=\"{question_type}\">\n"));
+        out.push_str(&format!("    <name><text>Q{}</text></name>\n", index + 1));
+        out.push_str(&format!(
+            "    <questiontext format=\"html\"><text>{}</text></questiontext>\n",
+            xml_escape(&question.prompt)
+        ));
+        out.push_str(&format!(
+            "    <defaultgrade>{}</defaultgrade>\n",
+            question.points
+        ));
+        out.push_str(&body);
+        out.push_str("    <tags>\n");
+        for tag in tags {
+            out.push_str(&format!(
+                "      <tag><text>{}</text></tag>\n",
+                xml_escape(&tag)
+            ));
+        }
+        out.push_str("    </tags>\n  </question>\n");
+    }
+    out.push_str("</quiz>\n");
+    out
+}
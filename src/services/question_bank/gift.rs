@@ -0,0 +1,457 @@
+//! GIFT 格式（Moodle 纯文本题目格式）读写
+//!
+//! 分值、难度、标签与知识点写在题目前的注释行中，例如：
+//!
+//! ```text
+//! // points: 5
+//! // difficulty: 3
+//! // tags: 函数, 极限
+//! // knowledge_point: 导数的定义
+//! ::Q1::下列哪个函数是奇函数？{=sin x ~cos x ~e^x}
+//! ```
+
+use std::collections::HashMap;
+
+use crate::models::homework_questions::entities::QuestionSpec;
+use crate::models::question_bank::entities::BankQuestion;
+use crate::models::question_bank::requests::CreateBankQuestionRequest;
+
+/// GIFT 中需要转义的特殊字符
+const SPECIAL_CHARS: [char; 6] = ['~', '=', '#', '{', '}', ':'];
+
+/// 未指定分值时的默认分值（与 Moodle 一致）
+const DEFAULT_POINTS: f64 = 1.0;
+
+/// 将题目导出为 GIFT 文本
+pub(crate) fn format_gift(questions: &[BankQuestion]) -> String {
+    let mut out = String::new();
+    for (index, question) in questions.iter().enumerate() {
+        out.push_str(&format!("// points: {}\n", question.points));
+        out.push_str(&format!("// difficulty: {}\n", question.difficulty));
+        if !question.tags.is_empty() {
+            out.push_str(&format!("// tags: {}\n", question.tags.join(", ")));
+        }
+        if let Some(knowledge_point) = &question.knowledge_point {
+            out.push_str(&format!("// knowledge_point: {knowledge_point}\n"));
+        }
+
+        out.push_str(&format!("::Q{}::", index + 1));
+        if question.prompt.contains('<') {
+            out.push_str("[html]");
+        }
+        out.push_str(&escape(&question.prompt));
+        out.push_str(&format_answer(&question.spec));
+        out.push_str("\n\n");
+    }
+    out
+}
+
+fn format_answer(spec: &QuestionSpec) -> String {
+    match spec {
+        QuestionSpec::SingleChoice { options, answer } => {
+            let lines: Vec<String> = options
+                .iter()
+                .enumerate()
+                .map(|(i, option)| {
+                    let marker = if *answer == Some(i) { '=' } else { '~' };
+                    format!("\t{marker}{}", escape(option))
+                })
+                .collect();
+            format!("{{\n{}\n}}", lines.join("\n"))
+        }
+        QuestionSpec::MultipleChoice { options, answers } => {
+            let answers = answers.clone().unwrap_or_default();
+            let weight = format_weight(100.0 / answers.len().max(1) as f64);
+            let lines: Vec<String> = options
+                .iter()
+                .enumerate()
+                .map(|(i, option)| {
+                    let weight = if answers.contains(&i) {
+                        weight.as_str()
+                    } else {
+                        "-100"
+                    };
+                    format!("\t~%{weight}%{}", escape(option))
+                })
+                .collect();
+            format!("{{\n{}\n}}", lines.join("\n"))
+        }
+        QuestionSpec::TrueFalse { answer } => {
+            if answer.unwrap_or(false) {
+                "{T}".to_string()
+            } else {
+                "{F}".to_string()
+            }
+        }
+        QuestionSpec::FillBlank {
+            accepted_answers, ..
+        } => {
+            let lines: Vec<String> = accepted_answers
+                .iter()
+                .flatten()
+                .map(|a| format!("\t={}", escape(a)))
+                .collect();
+            format!("{{\n{}\n}}", lines.join("\n"))
+        }
+        QuestionSpec::Numeric { answer, tolerance } => match tolerance {
+            Some(tolerance) if *tolerance > 0.0 => {
+                format!("{{#{}:{tolerance}}}", answer.unwrap_or(0.0))
+            }
+            _ => format!("{{#{}}}", answer.unwrap_or(0.0)),
+        },
+        QuestionSpec::Essay => "{}".to_string(),
+    }
+}
+
+/// 百分比权重（最多保留 5 位小数）
+fn format_weight(weight: f64) -> String {
+    let text = format!("{weight:.5}");
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\n' => out.push_str("\\n"),
+            '\r' => {}
+            c if SPECIAL_CHARS.contains(&c) => {
+                out.push('\\');
+                out.push(c);
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn unescape(chars: &[char]) -> String {
+    let mut out = String::with_capacity(chars.len());
+    let mut iter = chars.iter().peekable();
+    while let Some(&c) = iter.next() {
+        if c == '\\'
+            && let Some(&&next) = iter.peek()
+        {
+            if next == 'n' {
+                out.push('\n');
+                iter.next();
+                continue;
+            }
+            if SPECIAL_CHARS.contains(&next) || next == '\\' {
+                out.push(next);
+                iter.next();
+                continue;
+            }
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// 查找未转义的字符位置
+fn find_unescaped(chars: &[char], start: usize, target: char) -> Option<usize> {
+    let mut i = start;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 2,
+            c if c == target => return Some(i),
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+/// 解析 GIFT 文本，返回 (起始行号, 解析结果) 列表
+///
+/// 题目之间以空行分隔；$CATEGORY 行被忽略。
+pub(crate) fn parse_gift(text: &str) -> Vec<(usize, Result<CreateBankQuestionRequest, String>)> {
+    let text = text.trim_start_matches('\u{feff}');
+    let mut results = Vec::new();
+
+    let mut block: Vec<&str> = Vec::new();
+    let mut meta: HashMap<String, String> = HashMap::new();
+    let mut start_line = 0;
+
+    let mut flush = |block: &mut Vec<&str>, meta: &mut HashMap<String, String>, line: usize| {
+        if !block.is_empty() {
+            let joined = block.join("\n");
+            if !joined.trim_start().starts_with("$CATEGORY") {
+                results.push((line, parse_question(&joined, meta)));
+            }
+        }
+        block.clear();
+        meta.clear();
+    };
+
+    for (index, line) in text.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            flush(&mut block, &mut meta, start_line);
+            continue;
+        }
+        if let Some(comment) = trimmed.strip_prefix("//") {
+            if let Some((key, value)) = comment.split_once(':') {
+                meta.insert(key.trim().to_lowercase(), value.trim().to_string());
+            }
+            continue;
+        }
+        if block.is_empty() {
+            start_line = index + 1;
+        }
+        block.push(line);
+    }
+    flush(&mut block, &mut meta, start_line);
+
+    results
+}
+
+fn parse_question(
+    text: &str,
+    meta: &HashMap<String, String>,
+) -> Result<CreateBankQuestionRequest, String> {
+    let chars: Vec<char> = text.trim().chars().collect();
+
+    // 跳过 ::标题::
+    let mut start = 0;
+    if chars.starts_with(&[':', ':']) {
+        let mut i = 2;
+        loop {
+            let Some(pos) = find_unescaped(&chars, i, ':') else {
+                return Err("题目标题缺少结束的 ::".to_string());
+            };
+            if chars.get(pos + 1) == Some(&':') {
+                start = pos + 2;
+                break;
+            }
+            i = pos + 1;
+        }
+    }
+
+    let open = find_unescaped(&chars, start, '{').ok_or("缺少答案部分 {...}")?;
+    let close = find_unescaped(&chars, open + 1, '}').ok_or("答案部分缺少结束的 }")?;
+
+    let before = unescape(&chars[start..open]);
+    let after = unescape(&chars[close + 1..]);
+    let mut prompt = before.trim().to_string();
+    for prefix in ["[html]", "[moodle]", "[plain]", "[markdown]"] {
+        if let Some(rest) = prompt.strip_prefix(prefix) {
+            prompt = rest.trim().to_string();
+        }
+    }
+    // 完形填空格式：答案位于题干中间
+    if !after.trim().is_empty() {
+        prompt = format!("{prompt} _____ {}", after.trim());
+    }
+
+    let spec = parse_answer(&chars[open + 1..close])?;
+
+    let points = match meta.get("points") {
+        Some(value) => value
+            .parse::<f64>()
+            .map_err(|_| format!("分值格式错误: {value}"))?,
+        None => DEFAULT_POINTS,
+    };
+    let difficulty = match meta.get("difficulty") {
+        Some(value) => Some(
+            value
+                .parse::<i32>()
+                .map_err(|_| format!("难度格式错误: {value}"))?,
+        ),
+        None => None,
+    };
+    let tags: Vec<String> = meta
+        .get("tags")
+        .map(|tags| {
+            tags.split([',', '，'])
+                .map(|t| t.trim().to_string())
+                .filter(|t| !t.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(CreateBankQuestionRequest {
+        prompt,
+        points,
+        spec,
+        tags: Some(tags).filter(|t| !t.is_empty()),
+        difficulty,
+        knowledge_point: meta.get("knowledge_point").cloned(),
+        is_shared: None,
+        attachments: None,
+    })
+}
+
+/// 去掉答案后的反馈（# 之后的内容）
+fn strip_feedback(chars: &[char]) -> &[char] {
+    match find_unescaped(chars, 0, '#') {
+        Some(pos) => &chars[..pos],
+        None => chars,
+    }
+}
+
+fn parse_answer(chars: &[char]) -> Result<QuestionSpec, String> {
+    let text: String = chars.iter().collect();
+    let trimmed = text.trim();
+
+    if trimmed.is_empty() {
+        return Ok(QuestionSpec::Essay);
+    }
+
+    if let Some(numeric) = trimmed.strip_prefix('#') {
+        return parse_numeric(numeric);
+    }
+
+    let keyword: String = strip_feedback(trimmed.chars().collect::<Vec<_>>().as_slice())
+        .iter()
+        .collect();
+    match keyword.trim().to_uppercase().as_str() {
+        "T" | "TRUE" => return Ok(QuestionSpec::TrueFalse { answer: Some(true) }),
+        "F" | "FALSE" => {
+            return Ok(QuestionSpec::TrueFalse {
+                answer: Some(false),
+            });
+        }
+        _ => {}
+    }
+
+    // 按未转义的 = 和 ~ 切分选项
+    let mut tokens: Vec<(char, Vec<char>)> = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            '\\' => {
+                if let Some((_, token)) = tokens.last_mut() {
+                    token.push(c);
+                    if let Some(&next) = chars.get(i + 1) {
+                        token.push(next);
+                    }
+                }
+                i += 2;
+                continue;
+            }
+            '=' | '~' => tokens.push((c, Vec::new())),
+            c if tokens.is_empty() => {
+                if !c.is_whitespace() {
+                    return Err("无法识别的答案格式".to_string());
+                }
+            }
+            c => {
+                if let Some((_, token)) = tokens.last_mut() {
+                    token.push(c);
+                }
+            }
+        }
+        i += 1;
+    }
+
+    let mut options: Vec<(bool, String)> = Vec::with_capacity(tokens.len());
+    let mut has_wrong_marker = false;
+    for (marker, token) in &tokens {
+        let mut body: &[char] = token;
+        let mut weight = None;
+        if body.first() == Some(&'%') {
+            let end = body[1..]
+                .iter()
+                .position(|c| *c == '%')
+                .ok_or("选项权重缺少结束的 %")?;
+            let value: String = body[1..end + 1].iter().collect();
+            weight = Some(
+                value
+                    .trim()
+                    .parse::<f64>()
+                    .map_err(|_| format!("选项权重格式错误: {value}"))?,
+            );
+            body = &body[end + 2..];
+        }
+        let option = unescape(strip_feedback(body)).trim().to_string();
+        if *marker == '=' && option.contains("->") {
+            return Err("不支持匹配题".to_string());
+        }
+        if option.is_empty() {
+            return Err("选项内容不能为空".to_string());
+        }
+        if *marker == '~' {
+            has_wrong_marker = true;
+        }
+        let correct = *marker == '=' || weight.is_some_and(|w| w > 0.0);
+        options.push((correct, option));
+    }
+
+    if options.is_empty() {
+        return Err("无法识别的答案格式".to_string());
+    }
+
+    // 只有 = 答案时为填空题
+    if !has_wrong_marker {
+        return Ok(QuestionSpec::FillBlank {
+            accepted_answers: Some(options.into_iter().map(|(_, o)| o).collect()),
+            case_sensitive: false,
+        });
+    }
+
+    let correct: Vec<usize> = options
+        .iter()
+        .enumerate()
+        .filter(|(_, (correct, _))| *correct)
+        .map(|(i, _)| i)
+        .collect();
+    let single =
+        tokens.iter().filter(|(marker, _)| *marker == '=').count() == 1 && correct.len() == 1;
+    let options: Vec<String> = options.into_iter().map(|(_, o)| o).collect();
+
+    if correct.is_empty() {
+        return Err("选择题须至少有一个正确选项".to_string());
+    }
+    if single {
+        Ok(QuestionSpec::SingleChoice {
+            options,
+            answer: correct.first().copied(),
+        })
+    } else {
+        Ok(QuestionSpec::MultipleChoice {
+            options,
+            answers: Some(correct),
+        })
+    }
+}
+
+/// 解析数值题答案：`3.14`、`3.14:0.01` 或区间 `3.1..3.2`（多个答案时取第一个）
+fn parse_numeric(text: &str) -> Result<QuestionSpec, String> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut body: Vec<char> = strip_feedback(&chars).to_vec();
+    if let Some(pos) = body.iter().position(|c| *c == '=') {
+        body = body[pos + 1..].to_vec();
+        if let Some(end) = body.iter().position(|c| *c == '=') {
+            body.truncate(end);
+        }
+        if body.first() == Some(&'%')
+            && let Some(end) = body[1..].iter().position(|c| *c == '%')
+        {
+            body = body[end + 2..].to_vec();
+        }
+    }
+    let body: String = body.iter().collect();
+    let body = body.trim();
+
+    let parse = |value: &str| {
+        value
+            .trim()
+            .parse::<f64>()
+            .map_err(|_| format!("数值答案格式错误: {body}"))
+    };
+
+    let (answer, tolerance) = if let Some((min, max)) = body.split_once("..") {
+        let (min, max) = (parse(min)?, parse(max)?);
+        ((min + max) / 2.0, Some((max - min).abs() / 2.0))
+    } else if let Some((answer, tolerance)) = body.split_once(':') {
+        (parse(answer)?, Some(parse(tolerance)?))
+    } else {
+        (parse(body)?, None)
+    };
+
+    Ok(QuestionSpec::Numeric {
+        answer: Some(answer),
+        tolerance,
+    })
+}
@@ -0,0 +1,336 @@
+//! 题库导入服务
+//!
+//! 支持 XLSX/CSV 表格（列：type, prompt, points, options, answer, tags, difficulty,
+//! knowledge_point）与 GIFT 文本（.gift / .txt）。
+
+use actix_multipart::Multipart;
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use tracing::error;
+
+use super::gift::parse_gift;
+use super::{QuestionBankService, normalize_new_question};
+use crate::middlewares::RequireJWT;
+use crate::models::homework_questions::entities::{QuestionSpec, QuestionType};
+use crate::models::question_bank::requests::CreateBankQuestionRequest;
+use crate::models::question_bank::responses::BankQuestionImportResponse;
+use crate::models::users::responses::ImportRowError;
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::StorageProvider;
+use crate::services::users::import::{
+    ImportParseError, ImportTable, ImportTableRow, MAX_IMPORT_ROWS, parse_import_table,
+    read_import_file,
+};
+
+/// 单行解析结果：成功为创建请求，失败为 (字段, 错误信息)
+type ParsedRow = (usize, Result<CreateBankQuestionRequest, (String, String)>);
+
+/// 导入题库题目
+pub async fn import_bank_questions(
+    service: &QuestionBankService,
+    request: &HttpRequest,
+    mut payload: Multipart,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    let Some(user_id) = RequireJWT::extract_user_id(request) else {
+        return Ok(HttpResponse::Unauthorized().json(ApiResponse::error_empty(
+            ErrorCode::Unauthorized,
+            "无法获取用户信息",
+        )));
+    };
+
+    let (file_bytes, file_name) = match read_import_file(&mut payload).await {
+        Ok(result) => result,
+        Err(resp) => return Ok(resp),
+    };
+
+    let file_name = file_name.to_lowercase();
+    let rows = if file_name.ends_with(".gift") || file_name.ends_with(".txt") {
+        match parse_gift_file(&file_bytes) {
+            Ok(rows) => rows,
+            Err(resp) => return Ok(resp),
+        }
+    } else {
+        let table = match parse_import_table(&file_bytes, &file_name) {
+            Ok(table) => table,
+            Err(resp) => return Ok(resp),
+        };
+        match parse_table_rows(&table) {
+            Ok(rows) => rows,
+            Err(e) => {
+                return Ok(HttpResponse::BadRequest()
+                    .json(ApiResponse::error_empty(e.error_code(), e.message())));
+            }
+        }
+    };
+
+    let total = rows.len();
+    let mut success = 0;
+    let mut failed = 0;
+    let mut errors = Vec::new();
+
+    for (row, parsed) in rows {
+        let req =
+            parsed.and_then(|req| normalize_new_question(req).map_err(|e| (String::new(), e)));
+        let req = match req {
+            Ok(req) => req,
+            Err((field, message)) => {
+                failed += 1;
+                errors.push(ImportRowError {
+                    row,
+                    field,
+                    message,
+                });
+                continue;
+            }
+        };
+
+        match storage.create_bank_question(user_id, req).await {
+            Ok(_) => success += 1,
+            Err(e) => {
+                failed += 1;
+                error!("导入题库题目失败: {}", e);
+                errors.push(ImportRowError {
+                    row,
+                    field: String::new(),
+                    message: format!("创建失败: {e}"),
+                });
+            }
+        }
+    }
+
+    let response = BankQuestionImportResponse {
+        total,
+        success,
+        failed,
+        errors,
+    };
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(response, "导入完成")))
+}
+
+/// 解析 GIFT 文件（行号为题目起始行）
+fn parse_gift_file(file_bytes: &[u8]) -> Result<Vec<ParsedRow>, HttpResponse> {
+    let text = std::str::from_utf8(file_bytes).map_err(|_| {
+        HttpResponse::BadRequest().json(ApiResponse::error_empty(
+            ErrorCode::ImportFileParseFailed,
+            "GIFT 文件须为 UTF-8 编码",
+        ))
+    })?;
+
+    let rows: Vec<ParsedRow> = parse_gift(text)
+        .into_iter()
+        .map(|(line, result)| (line, result.map_err(|e| (String::new(), e))))
+        .collect();
+
+    if rows.is_empty() {
+        return Err(HttpResponse::BadRequest().json(ApiResponse::error_empty(
+            ErrorCode::ImportFileDataInvalid,
+            "文件中没有题目",
+        )));
+    }
+    if rows.len() > MAX_IMPORT_ROWS {
+        return Err(HttpResponse::BadRequest().json(ApiResponse::error_empty(
+            ErrorCode::ImportFileDataInvalid,
+            format!("单次导入最多支持 {MAX_IMPORT_ROWS} 道题目"),
+        )));
+    }
+    Ok(rows)
+}
+
+/// 表格列索引
+struct BankColumns {
+    question_type: usize,
+    prompt: usize,
+    points: Option<usize>,
+    options: Option<usize>,
+    answer: Option<usize>,
+    tags: Option<usize>,
+    difficulty: Option<usize>,
+    knowledge_point: Option<usize>,
+}
+
+/// 将表格解析为题目创建请求
+fn parse_table_rows(table: &ImportTable) -> Result<Vec<ParsedRow>, ImportParseError> {
+    let columns = BankColumns {
+        question_type: table.require_column("type")?,
+        prompt: table.require_column("prompt")?,
+        points: table.column("points"),
+        options: table.column("options"),
+        answer: table.column("answer"),
+        tags: table.column("tags"),
+        difficulty: table.column("difficulty"),
+        knowledge_point: table.column("knowledge_point"),
+    };
+
+    Ok(table
+        .rows
+        .iter()
+        // 跳过空行
+        .filter(|row| {
+            !row.get(columns.question_type).is_empty() || !row.get(columns.prompt).is_empty()
+        })
+        .map(|row| (row.row_num, parse_table_row(row, &columns)))
+        .collect())
+}
+
+fn parse_table_row(
+    row: &ImportTableRow,
+    columns: &BankColumns,
+) -> Result<CreateBankQuestionRequest, (String, String)> {
+    let question_type = parse_question_type(&row.get(columns.question_type))
+        .ok_or_else(|| ("type".to_string(), "无法识别的题型".to_string()))?;
+    let options: Vec<String> = row
+        .get_opt(columns.options)
+        .map(|o| split_lines(&o))
+        .unwrap_or_default();
+    let answer = row.get_opt(columns.answer).unwrap_or_default();
+    let spec =
+        parse_spec(question_type, options, &answer).map_err(|e| ("answer".to_string(), e))?;
+
+    let points = match row.get_opt(columns.points) {
+        Some(value) => value
+            .parse::<f64>()
+            .map_err(|_| ("points".to_string(), format!("分值格式错误: {value}")))?,
+        None => 1.0,
+    };
+    let difficulty = row
+        .get_opt(columns.difficulty)
+        .map(|value| {
+            parse_integer(&value)
+                .ok_or_else(|| ("difficulty".to_string(), format!("难度格式错误: {value}")))
+        })
+        .transpose()?;
+    let tags: Vec<String> = row
+        .get_opt(columns.tags)
+        .map(|tags| {
+            tags.split([',', '，', '\n'])
+                .map(|t| t.trim().to_string())
+                .filter(|t| !t.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(CreateBankQuestionRequest {
+        prompt: row.get(columns.prompt),
+        points,
+        spec,
+        tags: Some(tags).filter(|t| !t.is_empty()),
+        difficulty,
+        knowledge_point: row.get_opt(columns.knowledge_point),
+        is_shared: None,
+        attachments: None,
+    })
+}
+
+/// 整数单元格（XLSX 中的数字可能带有 .0）
+fn parse_integer(value: &str) -> Option<i32> {
+    let number = value.parse::<f64>().ok()?;
+    (number.fract() == 0.0).then_some(number as i32)
+}
+
+/// 按行拆分单元格内容（忽略空行）
+fn split_lines(value: &str) -> Vec<String> {
+    value
+        .lines()
+        .map(|l| l.trim().to_string())
+        .filter(|l| !l.is_empty())
+        .collect()
+}
+
+/// 题型名称（支持英文标识与中文名称）
+fn parse_question_type(value: &str) -> Option<QuestionType> {
+    let value = value.trim().to_lowercase();
+    let question_type = match value.trim_end_matches('题') {
+        "single_choice" | "单选" => QuestionType::SingleChoice,
+        "multiple_choice" | "多选" => QuestionType::MultipleChoice,
+        "true_false" | "判断" => QuestionType::TrueFalse,
+        "fill_blank" | "填空" => QuestionType::FillBlank,
+        "numeric" | "数值" => QuestionType::Numeric,
+        "essay" | "简答" => QuestionType::Essay,
+        _ => return None,
+    };
+    Some(question_type)
+}
+
+/// 选项字母（A、B、C...）或从 1 开始的序号转为下标
+fn parse_choice(value: &str) -> Option<usize> {
+    let value = value.trim();
+    let mut chars = value.chars();
+    if let (Some(c), None) = (chars.next(), chars.next())
+        && c.is_ascii_alphabetic()
+    {
+        return Some((c.to_ascii_uppercase() as u8 - b'A') as usize);
+    }
+    parse_integer(value)
+        .filter(|n| *n >= 1)
+        .map(|n| n as usize - 1)
+}
+
+/// 按题型解析答案列
+fn parse_spec(
+    question_type: QuestionType,
+    options: Vec<String>,
+    answer: &str,
+) -> Result<QuestionSpec, String> {
+    let answer = answer.trim();
+    let spec = match question_type {
+        QuestionType::SingleChoice => QuestionSpec::SingleChoice {
+            options,
+            answer: Some(parse_choice(answer).ok_or("单选题答案须为选项字母")?),
+        },
+        QuestionType::MultipleChoice => {
+            // 支持 "A,C"、"A C" 与 "AC"
+            let parts: Vec<&str> = if answer.contains([',', '，', ' ', ';']) {
+                answer
+                    .split([',', '，', ' ', ';'])
+                    .filter(|p| !p.is_empty())
+                    .collect()
+            } else {
+                answer
+                    .char_indices()
+                    .map(|(i, c)| &answer[i..i + c.len_utf8()])
+                    .collect()
+            };
+            let answers = parts
+                .into_iter()
+                .map(|p| parse_choice(p).ok_or("多选题答案须为选项字母"))
+                .collect::<Result<Vec<_>, _>>()?;
+            QuestionSpec::MultipleChoice {
+                options,
+                answers: Some(answers),
+            }
+        }
+        QuestionType::TrueFalse => {
+            let value = match answer.to_lowercase().as_str() {
+                "true" | "t" | "对" | "正确" | "是" | "√" => true,
+                "false" | "f" | "错" | "错误" | "否" | "×" => false,
+                _ => return Err("判断题答案须为 对/错 或 true/false".to_string()),
+            };
+            QuestionSpec::TrueFalse {
+                answer: Some(value),
+            }
+        }
+        QuestionType::FillBlank => QuestionSpec::FillBlank {
+            accepted_answers: Some(split_lines(answer)),
+            case_sensitive: false,
+        },
+        QuestionType::Numeric => {
+            let parse = |v: &str| {
+                v.trim()
+                    .parse::<f64>()
+                    .map_err(|_| format!("数值答案格式错误: {answer}"))
+            };
+            let (value, tolerance) = match answer.split_once(':') {
+                Some((value, tolerance)) => (parse(value)?, Some(parse(tolerance)?)),
+                None => (parse(answer)?, None),
+            };
+            QuestionSpec::Numeric {
+                answer: Some(value),
+                tolerance,
+            }
+        }
+        QuestionType::Essay => QuestionSpec::Essay,
+    };
+    Ok(spec)
+}
@@ -0,0 +1,35 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+
+use super::{QuestionBankService, viewer_filter};
+use crate::models::ApiResponse;
+use crate::models::question_bank::requests::BankQuestionListQuery;
+use crate::services::{StorageProvider, error_response};
+
+pub async fn list_bank_questions(
+    service: &QuestionBankService,
+    request: &HttpRequest,
+    query: BankQuestionListQuery,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    let mut filter = match viewer_filter(request) {
+        Ok(filter) => filter,
+        Err(resp) => return Ok(resp),
+    };
+    filter.scope = query.scope.unwrap_or_default();
+    filter.tag = query.tag;
+    filter.difficulty = query
+        .difficulty
+        .map(|d| i32::try_from(d).unwrap_or(i32::MAX));
+    filter.knowledge_point = query.knowledge_point;
+    filter.question_type = query.question_type;
+    filter.search = query.search;
+
+    match storage
+        .list_bank_questions_with_pagination(filter, query.pagination)
+        .await
+    {
+        Ok(response) => Ok(HttpResponse::Ok().json(ApiResponse::success(response, "获取成功"))),
+        Err(e) => Ok(error_response(e)),
+    }
+}
@@ -0,0 +1,269 @@
+pub mod create;
+pub mod delete;
+pub mod detail;
+pub mod export;
+pub mod gift;
+pub mod import;
+pub mod list;
+pub mod update;
+
+use actix_multipart::Multipart;
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use std::sync::Arc;
+
+use crate::middlewares::RequireJWT;
+use crate::models::question_bank::entities::BankQuestion;
+use crate::models::question_bank::requests::{
+    BankQuestionExportParams, BankQuestionFilter, BankQuestionListQuery, CreateBankQuestionRequest,
+    UpdateBankQuestionRequest,
+};
+use crate::models::users::entities::UserRole;
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::{StorageProvider, error_response};
+use crate::storage::Storage;
+
+/// 单道题目的标签数量上限
+const MAX_TAGS: usize = 20;
+/// 标签最大长度（字符数）
+const MAX_TAG_LENGTH: usize = 30;
+/// 知识点最大长度（字符数）
+const MAX_KNOWLEDGE_POINT_LENGTH: usize = 100;
+/// 难度范围
+const MIN_DIFFICULTY: i32 = 1;
+const MAX_DIFFICULTY: i32 = 5;
+
+pub struct QuestionBankService {
+    storage: Option<Arc<dyn Storage>>,
+}
+
+impl QuestionBankService {
+    pub fn new_lazy() -> Self {
+        Self { storage: None }
+    }
+
+    // 列出题库题目
+    pub async fn list_bank_questions(
+        &self,
+        req: &HttpRequest,
+        query: BankQuestionListQuery,
+    ) -> ActixResult<HttpResponse> {
+        list::list_bank_questions(self, req, query).await
+    }
+
+    // 创建题库题目
+    pub async fn create_bank_question(
+        &self,
+        req: &HttpRequest,
+        body: CreateBankQuestionRequest,
+    ) -> ActixResult<HttpResponse> {
+        create::create_bank_question(self, req, body).await
+    }
+
+    // 获取题库题目详情
+    pub async fn get_bank_question(
+        &self,
+        req: &HttpRequest,
+        question_id: i64,
+    ) -> ActixResult<HttpResponse> {
+        detail::get_bank_question(self, req, question_id).await
+    }
+
+    // 更新题库题目
+    pub async fn update_bank_question(
+        &self,
+        req: &HttpRequest,
+        question_id: i64,
+        body: UpdateBankQuestionRequest,
+    ) -> ActixResult<HttpResponse> {
+        update::update_bank_question(self, req, question_id, body).await
+    }
+
+    // 删除题库题目
+    pub async fn delete_bank_question(
+        &self,
+        req: &HttpRequest,
+        question_id: i64,
+    ) -> ActixResult<HttpResponse> {
+        delete::delete_bank_question(self, req, question_id).await
+    }
+
+    // 导出题库题目
+    pub async fn export_bank_questions(
+        &self,
+        req: &HttpRequest,
+        params: BankQuestionExportParams,
+    ) -> ActixResult<HttpResponse> {
+        export::export_bank_questions(self, req, params).await
+    }
+
+    // 导入题库题目
+    pub async fn import_bank_questions(
+        &self,
+        req: &HttpRequest,
+        payload: Multipart,
+    ) -> ActixResult<HttpResponse> {
+        import::import_bank_questions(self, req, payload).await
+    }
+}
+
+impl StorageProvider for QuestionBankService {
+    fn storage_ref(&self) -> Option<Arc<dyn Storage>> {
+        self.storage.clone()
+    }
+}
+
+fn invalid(message: impl Into<String>) -> HttpResponse {
+    HttpResponse::BadRequest().json(ApiResponse::error_empty(
+        ErrorCode::BankQuestionInvalid,
+        message,
+    ))
+}
+
+/// 当前用户的题库查询条件（默认范围：自己的和共享的题目）
+pub(crate) fn viewer_filter(request: &HttpRequest) -> Result<BankQuestionFilter, HttpResponse> {
+    let viewer_id = RequireJWT::extract_user_id(request).ok_or_else(|| {
+        HttpResponse::Unauthorized().json(ApiResponse::error_empty(
+            ErrorCode::Unauthorized,
+            "无法获取用户信息",
+        ))
+    })?;
+
+    Ok(BankQuestionFilter {
+        viewer_id,
+        is_admin: RequireJWT::extract_user_role(request) == Some(UserRole::Admin),
+        ..Default::default()
+    })
+}
+
+/// 获取题库题目并校验访问权限
+///
+/// 查看：创建者、共享题目或管理员；修改：创建者或管理员。
+pub(crate) async fn load_bank_question(
+    storage: &Arc<dyn Storage>,
+    request: &HttpRequest,
+    question_id: i64,
+    for_write: bool,
+) -> Result<BankQuestion, HttpResponse> {
+    let filter = viewer_filter(request)?;
+
+    let question = match storage.get_bank_question_by_id(question_id).await {
+        Ok(Some(question)) => question,
+        Ok(None) => {
+            return Err(HttpResponse::NotFound().json(ApiResponse::error_empty(
+                ErrorCode::BankQuestionNotFound,
+                "题库题目不存在",
+            )));
+        }
+        Err(e) => return Err(error_response(e)),
+    };
+
+    let is_owner = filter.is_admin || question.created_by == filter.viewer_id;
+    if for_write && !is_owner {
+        return Err(HttpResponse::Forbidden().json(ApiResponse::error_empty(
+            ErrorCode::Forbidden,
+            "只能修改自己创建的题目",
+        )));
+    }
+    if !is_owner && !question.is_shared {
+        return Err(HttpResponse::Forbidden().json(ApiResponse::error_empty(
+            ErrorCode::Forbidden,
+            "无权查看该题目",
+        )));
+    }
+
+    Ok(question)
+}
+
+/// 规范化并校验新建题目（导入时复用，错误以文本返回）
+pub(crate) fn normalize_new_question(
+    mut req: CreateBankQuestionRequest,
+) -> Result<CreateBankQuestionRequest, String> {
+    req.prompt = normalize_prompt(&req.prompt)?;
+    validate_points(req.points)?;
+    req.spec.validate()?;
+    req.tags = req.tags.map(normalize_tags).transpose()?;
+    if let Some(difficulty) = req.difficulty {
+        validate_difficulty(difficulty)?;
+    }
+    req.knowledge_point = normalize_knowledge_point(req.knowledge_point)?;
+    Ok(req)
+}
+
+/// 规范化并校验题目更新
+fn normalize_update(
+    mut req: UpdateBankQuestionRequest,
+) -> Result<UpdateBankQuestionRequest, String> {
+    req.prompt = req.prompt.as_deref().map(normalize_prompt).transpose()?;
+    if let Some(points) = req.points {
+        validate_points(points)?;
+    }
+    if let Some(spec) = &req.spec {
+        spec.validate()?;
+    }
+    req.tags = req.tags.map(normalize_tags).transpose()?;
+    if let Some(difficulty) = req.difficulty {
+        validate_difficulty(difficulty)?;
+    }
+    if let Some(knowledge_point) = req.knowledge_point.take() {
+        req.knowledge_point = Some(normalize_knowledge_point(knowledge_point)?);
+    }
+    Ok(req)
+}
+
+fn normalize_prompt(prompt: &str) -> Result<String, String> {
+    let prompt = prompt.trim();
+    if prompt.is_empty() {
+        return Err("题干不能为空".to_string());
+    }
+    Ok(prompt.to_string())
+}
+
+fn validate_points(points: f64) -> Result<(), String> {
+    if !points.is_finite() || points < 0.0 {
+        return Err("分值不能为负数".to_string());
+    }
+    Ok(())
+}
+
+fn validate_difficulty(difficulty: i32) -> Result<(), String> {
+    if !(MIN_DIFFICULTY..=MAX_DIFFICULTY).contains(&difficulty) {
+        return Err(format!(
+            "难度须在 {MIN_DIFFICULTY} 到 {MAX_DIFFICULTY} 之间"
+        ));
+    }
+    Ok(())
+}
+
+/// 标签去除首尾空白并去重（保持顺序）
+fn normalize_tags(tags: Vec<String>) -> Result<Vec<String>, String> {
+    let mut normalized: Vec<String> = Vec::with_capacity(tags.len());
+    for tag in tags {
+        let tag = tag.trim();
+        if tag.is_empty() {
+            return Err("标签不能为空".to_string());
+        }
+        if tag.chars().count() > MAX_TAG_LENGTH {
+            return Err(format!("标签不能超过 {MAX_TAG_LENGTH} 个字符"));
+        }
+        if !normalized.iter().any(|t| t == tag) {
+            normalized.push(tag.to_string());
+        }
+    }
+    if normalized.len() > MAX_TAGS {
+        return Err(format!("标签不能超过 {MAX_TAGS} 个"));
+    }
+    Ok(normalized)
+}
+
+fn normalize_knowledge_point(knowledge_point: Option<String>) -> Result<Option<String>, String> {
+    let Some(knowledge_point) = knowledge_point else {
+        return Ok(None);
+    };
+    let knowledge_point = knowledge_point.trim();
+    if knowledge_point.chars().count() > MAX_KNOWLEDGE_POINT_LENGTH {
+        return Err(format!(
+            "知识点不能超过 {MAX_KNOWLEDGE_POINT_LENGTH} 个字符"
+        ));
+    }
+    Ok(Some(knowledge_point.to_string()).filter(|k| !k.is_empty()))
+}
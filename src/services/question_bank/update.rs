@@ -0,0 +1,47 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+
+use super::{QuestionBankService, invalid, load_bank_question, normalize_update};
+use crate::middlewares::RequireJWT;
+use crate::models::question_bank::requests::UpdateBankQuestionRequest;
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::{StorageProvider, error_response};
+
+pub async fn update_bank_question(
+    service: &QuestionBankService,
+    request: &HttpRequest,
+    question_id: i64,
+    req: UpdateBankQuestionRequest,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    let Some(user_id) = RequireJWT::extract_user_id(request) else {
+        return Ok(HttpResponse::Unauthorized().json(ApiResponse::error_empty(
+            ErrorCode::Unauthorized,
+            "无法获取用户信息",
+        )));
+    };
+
+    if let Err(resp) = load_bank_question(&storage, request, question_id, true).await {
+        return Ok(resp);
+    }
+
+    // 已添加到作业的题目为添加时的快照，不受影响
+    let req = match normalize_update(req) {
+        Ok(req) => req,
+        Err(msg) => return Ok(invalid(msg)),
+    };
+
+    match storage
+        .update_bank_question(question_id, user_id, req)
+        .await
+    {
+        Ok(Some(question)) => {
+            Ok(HttpResponse::Ok().json(ApiResponse::success(question, "更新成功")))
+        }
+        Ok(None) => Ok(HttpResponse::NotFound().json(ApiResponse::error_empty(
+            ErrorCode::BankQuestionNotFound,
+            "题库题目不存在",
+        ))),
+        Err(e) => Ok(error_response(e)),
+    }
+}
@@ -0,0 +1,36 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+
+use super::{RubricService, normalize_criteria, normalize_title};
+use crate::middlewares::RequireJWT;
+use crate::models::rubrics::requests::CreateRubricRequest;
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::{StorageProvider, error_response};
+
+pub async fn create_rubric(
+    service: &RubricService,
+    request: &HttpRequest,
+    mut req: CreateRubricRequest,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    let Some(user_id) = RequireJWT::extract_user_id(request) else {
+        return Ok(HttpResponse::Unauthorized().json(ApiResponse::error_empty(
+            ErrorCode::Unauthorized,
+            "无法获取用户信息",
+        )));
+    };
+
+    req.title = match normalize_title(&req.title) {
+        Ok(title) => title,
+        Err(resp) => return Ok(resp),
+    };
+    req.criteria = match normalize_criteria(req.criteria) {
+        Ok(criteria) => criteria,
+        Err(resp) => return Ok(resp),
+    };
+
+    match storage.create_rubric(user_id, req).await {
+        Ok(rubric) => Ok(HttpResponse::Created().json(ApiResponse::success(rubric, "创建成功"))),
+        Err(e) => Ok(error_response(e)),
+    }
+}
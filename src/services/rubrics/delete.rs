@@ -0,0 +1,26 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+
+use super::{RubricService, load_owned_rubric};
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::{StorageProvider, error_response};
+
+pub async fn delete_rubric(
+    service: &RubricService,
+    request: &HttpRequest,
+    rubric_id: i64,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    if let Err(resp) = load_owned_rubric(&storage, request, rubric_id).await {
+        return Ok(resp);
+    }
+
+    match storage.delete_rubric(rubric_id).await {
+        Ok(true) => Ok(HttpResponse::Ok().json(ApiResponse::success_empty("删除成功"))),
+        Ok(false) => Ok(HttpResponse::NotFound().json(ApiResponse::error_empty(
+            ErrorCode::RubricNotFound,
+            "评分细则不存在",
+        ))),
+        Err(e) => Ok(error_response(e)),
+    }
+}
@@ -0,0 +1,18 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+
+use super::{RubricService, load_owned_rubric};
+use crate::models::ApiResponse;
+use crate::services::StorageProvider;
+
+pub async fn get_rubric(
+    service: &RubricService,
+    request: &HttpRequest,
+    rubric_id: i64,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    match load_owned_rubric(&storage, request, rubric_id).await {
+        Ok(rubric) => Ok(HttpResponse::Ok().json(ApiResponse::success(rubric, "获取成功"))),
+        Err(resp) => Ok(resp),
+    }
+}
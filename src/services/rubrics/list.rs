@@ -0,0 +1,36 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+
+use super::RubricService;
+use crate::middlewares::RequireJWT;
+use crate::models::rubrics::responses::RubricListResponse;
+use crate::models::users::entities::UserRole;
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::{StorageProvider, error_response};
+
+pub async fn list_rubrics(
+    service: &RubricService,
+    request: &HttpRequest,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    let Some(user_id) = RequireJWT::extract_user_id(request) else {
+        return Ok(HttpResponse::Unauthorized().json(ApiResponse::error_empty(
+            ErrorCode::Unauthorized,
+            "无法获取用户信息",
+        )));
+    };
+
+    // 教师只能看到自己的评分细则库，管理员可以看到全部
+    let created_by = match RequireJWT::extract_user_role(request) {
+        Some(UserRole::Admin) => None,
+        _ => Some(user_id),
+    };
+
+    match storage.list_rubrics(created_by).await {
+        Ok(items) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            RubricListResponse { items },
+            "获取成功",
+        ))),
+        Err(e) => Ok(error_response(e)),
+    }
+}
@@ -0,0 +1,177 @@
+pub mod create;
+pub mod delete;
+pub mod detail;
+pub mod list;
+pub mod update;
+
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use std::sync::Arc;
+
+use crate::middlewares::RequireJWT;
+use crate::models::rubrics::entities::Rubric;
+use crate::models::rubrics::requests::{
+    CreateRubricRequest, RubricCriterionInput, UpdateRubricRequest,
+};
+use crate::models::users::entities::UserRole;
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::{StorageProvider, error_response};
+use crate::storage::Storage;
+
+/// 评分细则标题最大长度（字符数）
+const MAX_TITLE_LENGTH: usize = 100;
+/// 单个评分细则的评分项数量上限
+const MAX_CRITERIA: usize = 50;
+/// 单个评分项的档位数量上限
+const MAX_LEVELS: usize = 10;
+
+pub struct RubricService {
+    storage: Option<Arc<dyn Storage>>,
+}
+
+impl RubricService {
+    pub fn new_lazy() -> Self {
+        Self { storage: None }
+    }
+
+    // 列出评分细则
+    pub async fn list_rubrics(&self, req: &HttpRequest) -> ActixResult<HttpResponse> {
+        list::list_rubrics(self, req).await
+    }
+
+    // 创建评分细则
+    pub async fn create_rubric(
+        &self,
+        req: &HttpRequest,
+        body: CreateRubricRequest,
+    ) -> ActixResult<HttpResponse> {
+        create::create_rubric(self, req, body).await
+    }
+
+    // 获取评分细则详情
+    pub async fn get_rubric(&self, req: &HttpRequest, rubric_id: i64) -> ActixResult<HttpResponse> {
+        detail::get_rubric(self, req, rubric_id).await
+    }
+
+    // 更新评分细则
+    pub async fn update_rubric(
+        &self,
+        req: &HttpRequest,
+        rubric_id: i64,
+        body: UpdateRubricRequest,
+    ) -> ActixResult<HttpResponse> {
+        update::update_rubric(self, req, rubric_id, body).await
+    }
+
+    // 删除评分细则
+    pub async fn delete_rubric(
+        &self,
+        req: &HttpRequest,
+        rubric_id: i64,
+    ) -> ActixResult<HttpResponse> {
+        delete::delete_rubric(self, req, rubric_id).await
+    }
+}
+
+impl StorageProvider for RubricService {
+    fn storage_ref(&self) -> Option<Arc<dyn Storage>> {
+        self.storage.clone()
+    }
+}
+
+fn invalid(message: impl Into<String>) -> HttpResponse {
+    HttpResponse::BadRequest().json(ApiResponse::error_empty(ErrorCode::RubricInvalid, message))
+}
+
+/// 获取评分细则并校验当前用户可使用（创建者或管理员）
+pub(crate) async fn load_owned_rubric(
+    storage: &Arc<dyn Storage>,
+    request: &HttpRequest,
+    rubric_id: i64,
+) -> Result<Rubric, HttpResponse> {
+    let user_id = RequireJWT::extract_user_id(request).ok_or_else(|| {
+        HttpResponse::Unauthorized().json(ApiResponse::error_empty(
+            ErrorCode::Unauthorized,
+            "无法获取用户信息",
+        ))
+    })?;
+
+    let rubric = match storage.get_rubric_by_id(rubric_id).await {
+        Ok(Some(rubric)) => rubric,
+        Ok(None) => {
+            return Err(HttpResponse::NotFound().json(ApiResponse::error_empty(
+                ErrorCode::RubricNotFound,
+                "评分细则不存在",
+            )));
+        }
+        Err(e) => return Err(error_response(e)),
+    };
+
+    if RequireJWT::extract_user_role(request) != Some(UserRole::Admin)
+        && rubric.created_by != user_id
+    {
+        return Err(HttpResponse::Forbidden().json(ApiResponse::error_empty(
+            ErrorCode::Forbidden,
+            "只能使用自己创建的评分细则",
+        )));
+    }
+
+    Ok(rubric)
+}
+
+/// 规范化并校验评分细则标题
+fn normalize_title(title: &str) -> Result<String, HttpResponse> {
+    let title = title.trim();
+    if title.is_empty() {
+        return Err(invalid("评分细则标题不能为空"));
+    }
+    if title.chars().count() > MAX_TITLE_LENGTH {
+        return Err(invalid("评分细则标题不能超过 100 个字符"));
+    }
+    Ok(title.to_string())
+}
+
+/// 规范化并校验评分项
+///
+/// 评分项与档位标题不能为空，分值须为非负数。
+fn normalize_criteria(
+    criteria: Vec<RubricCriterionInput>,
+) -> Result<Vec<RubricCriterionInput>, HttpResponse> {
+    if criteria.is_empty() {
+        return Err(invalid("评分细则至少需要一个评分项"));
+    }
+    if criteria.len() > MAX_CRITERIA {
+        return Err(invalid(format!("评分项不能超过 {MAX_CRITERIA} 个")));
+    }
+
+    criteria
+        .into_iter()
+        .map(|mut criterion| {
+            criterion.title = criterion.title.trim().to_string();
+            if criterion.title.is_empty() {
+                return Err(invalid("评分项标题不能为空"));
+            }
+            if criterion.levels.is_empty() || criterion.levels.len() > MAX_LEVELS {
+                return Err(invalid(format!(
+                    "评分项「{}」的档位数量须在 1 到 {MAX_LEVELS} 之间",
+                    criterion.title
+                )));
+            }
+            for level in &mut criterion.levels {
+                level.title = level.title.trim().to_string();
+                if level.title.is_empty() {
+                    return Err(invalid(format!(
+                        "评分项「{}」的档位标题不能为空",
+                        criterion.title
+                    )));
+                }
+                if !level.points.is_finite() || level.points < 0.0 {
+                    return Err(invalid(format!(
+                        "评分项「{}」的档位分值必须为非负数",
+                        criterion.title
+                    )));
+                }
+            }
+            Ok(criterion)
+        })
+        .collect()
+}
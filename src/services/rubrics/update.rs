@@ -0,0 +1,42 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+
+use super::{RubricService, load_owned_rubric, normalize_criteria, normalize_title};
+use crate::models::rubrics::requests::UpdateRubricRequest;
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::{StorageProvider, error_response};
+
+pub async fn update_rubric(
+    service: &RubricService,
+    request: &HttpRequest,
+    rubric_id: i64,
+    mut req: UpdateRubricRequest,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    if let Err(resp) = load_owned_rubric(&storage, request, rubric_id).await {
+        return Ok(resp);
+    }
+
+    if let Some(title) = req.title.as_deref() {
+        req.title = match normalize_title(title) {
+            Ok(title) => Some(title),
+            Err(resp) => return Ok(resp),
+        };
+    }
+    // 评分项整体替换，已有评分的明细为评分时的快照，不受影响
+    if let Some(criteria) = req.criteria.take() {
+        req.criteria = match normalize_criteria(criteria) {
+            Ok(criteria) => Some(criteria),
+            Err(resp) => return Ok(resp),
+        };
+    }
+
+    match storage.update_rubric(rubric_id, req).await {
+        Ok(Some(rubric)) => Ok(HttpResponse::Ok().json(ApiResponse::success(rubric, "更新成功"))),
+        Ok(None) => Ok(HttpResponse::NotFound().json(ApiResponse::error_empty(
+            ErrorCode::RubricNotFound,
+            "评分细则不存在",
+        ))),
+        Err(e) => Ok(error_response(e)),
+    }
+}
@@ -0,0 +1,165 @@
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use std::sync::Arc;
+
+use super::SubmissionService;
+use crate::middlewares::RequireJWT;
+use crate::models::auto_graders::entities::AutoGradeRunStatus;
+use crate::models::class_users::entities::ClassUserRole;
+use crate::models::homeworks::entities::Homework;
+use crate::models::submissions::entities::Submission;
+use crate::models::users::entities::UserRole;
+use crate::models::{ApiResponse, ErrorCode};
+use crate::services::auto_graders::spawn_auto_grade;
+use crate::services::{StorageProvider, error_response};
+use crate::storage::Storage;
+
+/// 获取提交及其作业
+async fn load_submission_homework(
+    storage: &Arc<dyn Storage>,
+    submission_id: i64,
+) -> Result<(Submission, Homework), HttpResponse> {
+    let submission = match storage.get_submission_by_id(submission_id).await {
+        Ok(Some(sub)) => sub,
+        Ok(None) => {
+            return Err(HttpResponse::NotFound().json(ApiResponse::error_empty(
+                ErrorCode::SubmissionNotFound,
+                "提交不存在",
+            )));
+        }
+        Err(e) => return Err(error_response(e)),
+    };
+    let homework = match storage.get_homework_by_id(submission.homework_id).await {
+        Ok(Some(hw)) => hw,
+        Ok(None) => {
+            return Err(HttpResponse::NotFound().json(ApiResponse::error_empty(
+                ErrorCode::HomeworkNotFound,
+                "作业不存在",
+            )));
+        }
+        Err(e) => return Err(error_response(e)),
+    };
+    Ok((submission, homework))
+}
+
+/// 获取提交的自动评测结果（班级教师或管理员）
+/// GET /submissions/{id}/auto-grade
+pub async fn get_submission_auto_grade(
+    service: &SubmissionService,
+    request: &HttpRequest,
+    submission_id: i64,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    let Some(current_user) = RequireJWT::extract_user_claims(request) else {
+        return Ok(HttpResponse::Unauthorized()
+            .json(ApiResponse::error_empty(ErrorCode::Unauthorized, "未登录")));
+    };
+
+    let (_, homework) = match load_submission_homework(&storage, submission_id).await {
+        Ok(loaded) => loaded,
+        Err(resp) => return Ok(resp),
+    };
+
+    // 权限检查：管理员或班级教师
+    if current_user.role != UserRole::Admin {
+        match storage
+            .get_class_user_by_user_id_and_class_id(current_user.id, homework.class_id)
+            .await
+        {
+            Ok(Some(cu)) if cu.role == ClassUserRole::Teacher => {}
+            Ok(_) => {
+                return Ok(HttpResponse::Forbidden().json(ApiResponse::error_empty(
+                    ErrorCode::Forbidden,
+                    "没有查看自动评测结果的权限",
+                )));
+            }
+            Err(e) => return Ok(error_response(e)),
+        }
+    }
+
+    match storage
+        .get_auto_grade_run_by_submission_id(submission_id)
+        .await
+    {
+        Ok(Some(run)) => Ok(HttpResponse::Ok().json(ApiResponse::success(run, "查询成功"))),
+        Ok(None) => Ok(HttpResponse::NotFound().json(ApiResponse::error_empty(
+            ErrorCode::NotFound,
+            "该提交没有自动评测记录",
+        ))),
+        Err(e) => Ok(error_response(e)),
+    }
+}
+
+/// 重新自动评测（作业创建者或管理员）
+/// POST /submissions/{id}/auto-grade/rerun
+///
+/// 使用作业当前配置的评测器；已确认的评分不会被新结果覆盖。
+pub async fn rerun_submission_auto_grade(
+    service: &SubmissionService,
+    request: &HttpRequest,
+    submission_id: i64,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    let Some(user_id) = RequireJWT::extract_user_id(request) else {
+        return Ok(HttpResponse::Unauthorized().json(ApiResponse::error_empty(
+            ErrorCode::Unauthorized,
+            "无法获取用户信息",
+        )));
+    };
+
+    let (_, homework) = match load_submission_homework(&storage, submission_id).await {
+        Ok(loaded) => loaded,
+        Err(resp) => return Ok(resp),
+    };
+
+    // 权限检查：只有作业创建者或管理员才能重新评测
+    if RequireJWT::extract_user_role(request) != Some(UserRole::Admin)
+        && homework.created_by != user_id
+    {
+        return Ok(HttpResponse::Forbidden().json(ApiResponse::error_empty(
+            ErrorCode::Forbidden,
+            "只能重新评测自己创建的作业的提交",
+        )));
+    }
+
+    let Some(grader) = homework.auto_grader else {
+        return Ok(HttpResponse::BadRequest().json(ApiResponse::error_empty(
+            ErrorCode::HomeworkAutoGraderInvalid,
+            "该作业未配置自动评测",
+        )));
+    };
+
+    let existing = match storage
+        .get_auto_grade_run_by_submission_id(submission_id)
+        .await
+    {
+        Ok(existing) => existing,
+        Err(e) => return Ok(error_response(e)),
+    };
+    let result = match existing {
+        Some(run) if run.status == AutoGradeRunStatus::Running => {
+            return Ok(HttpResponse::Conflict().json(ApiResponse::error_empty(
+                ErrorCode::Conflict,
+                "正在评测中，请稍后再试",
+            )));
+        }
+        Some(run) => storage.requeue_auto_grade_run(run.id, &grader).await,
+        None => storage
+            .create_auto_grade_run(submission_id, &grader)
+            .await
+            .map(Some),
+    };
+
+    match result {
+        Ok(Some(run)) => {
+            spawn_auto_grade(storage.clone(), run.clone());
+            Ok(HttpResponse::Ok().json(ApiResponse::success(run, "已重新提交评测")))
+        }
+        Ok(None) => Ok(HttpResponse::NotFound().json(ApiResponse::error_empty(
+            ErrorCode::NotFound,
+            "该提交没有自动评测记录",
+        ))),
+        Err(e) => Ok(error_response(e)),
+    }
+}
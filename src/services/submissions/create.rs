@@ -1,10 +1,17 @@
 use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
+use std::collections::HashMap;
+use tracing::error;
 
 use super::SubmissionService;
+use crate::models::homework_questions::entities::{
+    HomeworkQuestion, SubmissionAnswer, select_question_variant,
+};
+use crate::models::homework_questions::requests::SubmissionAnswerInput;
 use crate::models::notifications::entities::{NotificationType, ReferenceType};
 use crate::models::submissions::requests::CreateSubmissionRequest;
 use crate::models::users::entities::UserRole;
 use crate::models::{ApiResponse, ErrorCode};
+use crate::services::auto_graders::spawn_auto_grade;
 use crate::services::notifications::trigger::send_notification;
 use crate::services::{StorageProvider, error_response};
 
@@ -13,14 +20,15 @@ pub async fn create_submission(
     request: &HttpRequest,
     creator_id: i64,
     creator_role: UserRole,
-    req: CreateSubmissionRequest,
+    mut req: CreateSubmissionRequest,
 ) -> ActixResult<HttpResponse> {
     let storage = service.get_storage(request)?;
 
     // 检查作业是否存在并获取班级信息
+    // 未发布的作业视为不存在
     let homework = match storage.get_homework_by_id(req.homework_id).await {
-        Ok(Some(hw)) => hw,
-        Ok(None) => {
+        Ok(Some(hw)) if hw.is_published() => hw,
+        Ok(_) => {
             return Ok(HttpResponse::NotFound().json(ApiResponse::error_empty(
                 ErrorCode::HomeworkNotFound,
                 "作业不存在",
@@ -52,8 +60,77 @@ pub async fn create_submission(
         }
     }
 
-    match storage.create_submission(creator_id, req).await {
+    // 超过迟交截止时间后不再接受提交（获得延期的学生至少可提交到延期截止时间）
+    if let Some(cutoff) = homework.late_cutoff {
+        let extended = match storage
+            .get_user_extended_deadlines(creator_id, &[homework.id])
+            .await
+        {
+            Ok(mut deadlines) => deadlines.remove(&homework.id),
+            Err(e) => return Ok(error_response(e)),
+        };
+        let cutoff = extended.map_or(cutoff, |deadline| deadline.max(cutoff));
+        if chrono::Utc::now() > cutoff {
+            return Ok(HttpResponse::Forbidden().json(ApiResponse::error_empty(
+                ErrorCode::SubmissionPastCutoff,
+                "已超过迟交截止时间，无法提交",
+            )));
+        }
+    }
+
+    // 校验题目作答并自动判分
+    let questions = match storage.list_homework_questions(homework.id).await {
+        Ok(questions) => questions,
+        Err(e) => return Ok(error_response(e)),
+    };
+    let pools = match storage.list_homework_question_pools(homework.id).await {
+        Ok(pools) => pools,
+        Err(e) => return Ok(error_response(e)),
+    };
+    // 只能作答本人抽到的题目
+    let questions = select_question_variant(questions, &pools, creator_id);
+    let answers = match grade_answers(&questions, req.answers.take().unwrap_or_default()) {
+        Ok(answers) => answers,
+        Err(resp) => return Ok(resp),
+    };
+
+    match storage.create_submission(creator_id, req, answers).await {
         Ok(submission) => {
+            // 包含题目的作业提交后自动生成评分（有简答题时待教师批阅）
+            if !questions.is_empty() {
+                match storage.grade_submission_answers(submission.id).await {
+                    Ok(Some(grade)) if !grade.pending_review => {
+                        let storage_clone = storage.clone();
+                        let hw_title = homework.title.clone();
+                        tokio::spawn(async move {
+                            send_notification(
+                                storage_clone,
+                                creator_id,
+                                NotificationType::GradeReceived,
+                                format!("作业已评分：{}", hw_title),
+                                Some(format!(
+                                    "您的作业「{}」已自动评分，得分：{}",
+                                    hw_title, grade.effective_score
+                                )),
+                                Some(ReferenceType::Grade),
+                                Some(grade.id),
+                            )
+                            .await;
+                        });
+                    }
+                    Ok(_) => {}
+                    Err(e) => return Ok(error_response(e)),
+                }
+            }
+
+            // 配置了自动评测的作业：在后台评测，结果生成待教师确认的评分
+            if let Some(grader) = &homework.auto_grader {
+                match storage.create_auto_grade_run(submission.id, grader).await {
+                    Ok(run) => spawn_auto_grade(storage.clone(), run),
+                    Err(e) => error!("创建自动评测记录失败: {}", e),
+                }
+            }
+
             // 异步通知教师（作业创建者）
             let storage_clone = storage.clone();
             let submission_id = submission.id;
@@ -84,3 +161,56 @@ pub async fn create_submission(
         Err(e) => Ok(error_response(e)),
     }
 }
+
+fn answer_invalid(message: impl Into<String>) -> HttpResponse {
+    HttpResponse::BadRequest().json(ApiResponse::error_empty(
+        ErrorCode::SubmissionAnswerInvalid,
+        message,
+    ))
+}
+
+/// 校验作答并按标准答案判分
+///
+/// 客观题答对得满分、答错得 0 分；简答题得分留空，等待教师批阅。
+/// 未作答的题目不生成作答记录，按 0 分计。
+fn grade_answers(
+    questions: &[HomeworkQuestion],
+    inputs: Vec<SubmissionAnswerInput>,
+) -> Result<Vec<SubmissionAnswer>, HttpResponse> {
+    if inputs.is_empty() {
+        return Ok(vec![]);
+    }
+    if questions.is_empty() {
+        return Err(answer_invalid("该作业没有题目，无需作答"));
+    }
+
+    let by_id: HashMap<i64, &HomeworkQuestion> = questions.iter().map(|q| (q.id, q)).collect();
+    let mut answered: HashMap<i64, SubmissionAnswer> = HashMap::new();
+    for input in inputs {
+        let Some(question) = by_id.get(&input.question_id) else {
+            return Err(answer_invalid("包含不属于该作业的题目"));
+        };
+        let number = question.position + 1;
+        let answer = question
+            .spec
+            .normalize_answer(input.answer)
+            .map_err(|msg| answer_invalid(format!("第 {number} 题：{msg}")))?;
+        let is_correct = question.spec.check(&answer);
+        let points = is_correct.map(|correct| if correct { question.points } else { 0.0 });
+        let record = SubmissionAnswer {
+            question_id: question.id,
+            answer,
+            is_correct,
+            points,
+        };
+        if answered.insert(question.id, record).is_some() {
+            return Err(answer_invalid(format!("第 {number} 题重复作答")));
+        }
+    }
+
+    // 按题目顺序保存
+    Ok(questions
+        .iter()
+        .filter_map(|q| answered.remove(&q.id))
+        .collect())
+}
@@ -1,3 +1,4 @@
+pub mod auto_grade;
 pub mod create;
 pub mod delete;
 pub mod detail;
@@ -89,8 +90,18 @@ impl SubmissionService {
         page: Option<i64>,
         page_size: Option<i64>,
         graded: Option<bool>,
+        group_id: Option<i64>,
     ) -> ActixResult<HttpResponse> {
-        summary::get_submission_summary(self, request, homework_id, page, page_size, graded).await
+        summary::get_submission_summary(
+            self,
+            request,
+            homework_id,
+            page,
+            page_size,
+            graded,
+            group_id,
+        )
+        .await
     }
 
     /// 获取某学生某作业的所有版本（教师视角）
@@ -111,6 +122,24 @@ impl SubmissionService {
     ) -> ActixResult<HttpResponse> {
         grade::get_submission_grade(self, request, submission_id).await
     }
+
+    /// 获取提交的自动评测结果
+    pub async fn get_submission_auto_grade(
+        &self,
+        request: &HttpRequest,
+        submission_id: i64,
+    ) -> ActixResult<HttpResponse> {
+        auto_grade::get_submission_auto_grade(self, request, submission_id).await
+    }
+
+    /// 重新自动评测
+    pub async fn rerun_submission_auto_grade(
+        &self,
+        request: &HttpRequest,
+        submission_id: i64,
+    ) -> ActixResult<HttpResponse> {
+        auto_grade::rerun_submission_auto_grade(self, request, submission_id).await
+    }
 }
 
 use crate::services::StorageProvider;
@@ -7,6 +7,7 @@ use crate::models::class_users::entities::ClassUserRole;
 use crate::models::users::entities::UserRole;
 use crate::models::{ApiResponse, ErrorCode};
 use crate::services::StorageProvider;
+use crate::services::class_groups::ensure_group_in_class;
 use crate::services::error_response;
 use crate::services::submissions::SubmissionService;
 
@@ -18,6 +19,7 @@ use crate::services::submissions::SubmissionService;
 ///
 /// 参数：
 /// - `graded`: 筛选是否已批改，true=已批改，false=待批改，None=全部
+/// - `group_id`: 仅统计该班级分组成员的提交
 pub async fn get_submission_summary(
     service: &SubmissionService,
     request: &HttpRequest,
@@ -25,6 +27,7 @@ pub async fn get_submission_summary(
     page: Option<i64>,
     page_size: Option<i64>,
     graded: Option<bool>,
+    group_id: Option<i64>,
 ) -> ActixResult<HttpResponse> {
     let storage = service.get_storage(request)?;
 
@@ -93,12 +96,26 @@ pub async fn get_submission_summary(
         class_user.role == ClassUserRole::Teacher
     };
 
+    // 校验分组属于作业所在班级
+    if let Some(group_id) = group_id
+        && let Err(resp) = ensure_group_in_class(&storage, homework.class_id, group_id).await
+    {
+        return Ok(resp);
+    }
+
     // 获取提交概览
     let page = page.unwrap_or(1);
     let page_size = page_size.unwrap_or(20);
 
     let summary = match storage
-        .get_submission_summary(homework_id, page, page_size, include_grades, graded)
+        .get_submission_summary(
+            homework_id,
+            page,
+            page_size,
+            include_grades,
+            graded,
+            group_id,
+        )
         .await
     {
         Ok(s) => s,
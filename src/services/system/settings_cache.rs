@@ -223,4 +223,25 @@ impl DynamicConfig {
             .map(|v| v as u8)
             .unwrap_or_else(|| AppConfig::get().upload.thumbnail.quality)
     }
+
+    // ============================================
+    // 通知配置访问方法
+    // ============================================
+
+    /// 获取作业截止提醒提前量（分钟，降序），默认截止前 24 小时和 1 小时
+    pub async fn deadline_reminder_offsets() -> Vec<i64> {
+        let mut offsets: Vec<i64> = Self::get_json_array("notification.deadline_reminder_offsets")
+            .await
+            .map(|values| {
+                values
+                    .iter()
+                    .filter_map(|v| v.trim().parse().ok())
+                    .filter(|&v: &i64| v > 0)
+                    .collect()
+            })
+            .unwrap_or_else(|| vec![1440, 60]);
+        offsets.sort_unstable_by(|a, b| b.cmp(a));
+        offsets.dedup();
+        offsets
+    }
 }
@@ -6,6 +6,7 @@ use crate::models::{
     ApiResponse, ErrorCode,
     users::{requests::CreateUserRequest, responses::UserResponse},
 };
+use crate::services::profile_fields::resolve_profile_values;
 use crate::services::{StorageProvider, error_response};
 use crate::utils::password::hash_password;
 use crate::utils::validate::{validate_email, validate_password_simple, validate_username};
@@ -44,9 +45,39 @@ pub async fn create_user(
 
     let storage = service.get_storage(request)?;
 
+    // 校验自定义资料字段（新建用户需提供所有必填字段）
+    let profile_fields = match storage.list_profile_fields().await {
+        Ok(fields) => fields,
+        Err(e) => return Ok(error_response(e)),
+    };
+    let profile = user_data.profile.take().unwrap_or_default();
+    let profile_values =
+        match resolve_profile_values(&storage, &profile_fields, None, &profile, true).await {
+            Ok(values) => values,
+            Err(e) => return Ok(e.into_response()),
+        };
+
     match storage.create_user(user_data).await {
-        Ok(user) => Ok(HttpResponse::Created()
-            .json(ApiResponse::success(UserResponse { user }, "用户创建成功"))),
+        Ok(mut user) => {
+            if !profile_values.is_empty() {
+                if let Err(e) = storage
+                    .set_user_profile_values(user.id, &profile_values)
+                    .await
+                {
+                    error!("写入用户资料字段失败: {}", e);
+                    return Ok(error_response(e));
+                }
+                user.profile = profile_values
+                    .iter()
+                    .filter_map(|(id, v)| {
+                        let key = profile_fields.iter().find(|f| f.id == *id)?.key.clone();
+                        Some((key, v.clone()?))
+                    })
+                    .collect();
+            }
+            Ok(HttpResponse::Created()
+                .json(ApiResponse::success(UserResponse { user }, "用户创建成功")))
+        }
         Err(e) => {
             let msg = format!("User creation failed: {e}");
             error!("{}", msg);
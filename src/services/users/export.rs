@@ -5,6 +5,8 @@ use rust_xlsxwriter::{Format, Workbook};
 use tracing::error;
 
 use super::UserService;
+use crate::models::profile_fields::entities::ProfileField;
+use crate::models::users::entities::User;
 use crate::models::users::requests::UserExportParams;
 use crate::models::{ApiResponse, ErrorCode};
 use crate::services::{StorageProvider, error_response};
@@ -29,25 +31,54 @@ pub async fn export_users(
         }
     };
 
+    let profile_fields = match storage.list_profile_fields().await {
+        Ok(fields) => fields,
+        Err(e) => return Ok(error_response(e)),
+    };
+
     match params.format.as_str() {
-        "xlsx" => export_xlsx(&users),
-        _ => export_csv(&users),
+        "xlsx" => export_xlsx(&users, &profile_fields),
+        _ => export_csv(&users, &profile_fields),
     }
 }
 
-/// 下载导入模板
-pub async fn download_template(format: &str) -> ActixResult<HttpResponse> {
+/// 下载导入模板（包含所有自定义资料字段列）
+pub async fn download_template(
+    service: &UserService,
+    format: &str,
+    request: &HttpRequest,
+) -> ActixResult<HttpResponse> {
+    let storage = service.get_storage(request)?;
+
+    let profile_fields = match storage.list_profile_fields().await {
+        Ok(fields) => fields,
+        Err(e) => return Ok(error_response(e)),
+    };
+
     match format {
-        "xlsx" => generate_template_xlsx(),
-        _ => generate_template_csv(),
+        "xlsx" => generate_template_xlsx(&profile_fields),
+        _ => generate_template_csv(&profile_fields),
     }
 }
 
-fn export_csv(users: &[crate::models::users::entities::User]) -> ActixResult<HttpResponse> {
+/// 用户某个资料字段的值
+fn profile_value<'a>(user: &'a User, field: &ProfileField) -> &'a str {
+    user.profile
+        .get(&field.key)
+        .map(String::as_str)
+        .unwrap_or("")
+}
+
+/// 资料字段在导入模板示例行中的值
+fn template_example(field: &ProfileField) -> String {
+    field.options.first().cloned().unwrap_or_default()
+}
+
+fn export_csv(users: &[User], profile_fields: &[ProfileField]) -> ActixResult<HttpResponse> {
     let mut wtr = csv::Writer::from_writer(vec![]);
 
-    // 写入表头
-    if let Err(e) = wtr.write_record([
+    // 写入表头（资料字段使用字段键，便于重新导入）
+    let mut headers = vec![
         "id",
         "username",
         "email",
@@ -55,7 +86,9 @@ fn export_csv(users: &[crate::models::users::entities::User]) -> ActixResult<Htt
         "status",
         "display_name",
         "created_at",
-    ]) {
+    ];
+    headers.extend(profile_fields.iter().map(|f| f.key.as_str()));
+    if let Err(e) = wtr.write_record(&headers) {
         error!("CSV 写入失败: {}", e);
         return Ok(
             HttpResponse::InternalServerError().json(ApiResponse::<()>::error_empty(
@@ -67,7 +100,7 @@ fn export_csv(users: &[crate::models::users::entities::User]) -> ActixResult<Htt
 
     // 写入数据
     for user in users {
-        if let Err(e) = wtr.write_record([
+        let mut record = vec![
             user.id.to_string(),
             user.username.clone(),
             user.email.clone(),
@@ -75,7 +108,13 @@ fn export_csv(users: &[crate::models::users::entities::User]) -> ActixResult<Htt
             user.status.to_string(),
             user.display_name.clone().unwrap_or_default(),
             user.created_at.to_rfc3339(),
-        ]) {
+        ];
+        record.extend(
+            profile_fields
+                .iter()
+                .map(|f| profile_value(user, f).to_string()),
+        );
+        if let Err(e) = wtr.write_record(&record) {
             error!("CSV 写入失败: {}", e);
             return Ok(
                 HttpResponse::InternalServerError().json(ApiResponse::<()>::error_empty(
@@ -105,7 +144,7 @@ fn export_csv(users: &[crate::models::users::entities::User]) -> ActixResult<Htt
         .body(data))
 }
 
-fn export_xlsx(users: &[crate::models::users::entities::User]) -> ActixResult<HttpResponse> {
+fn export_xlsx(users: &[User], profile_fields: &[ProfileField]) -> ActixResult<HttpResponse> {
     let mut workbook = Workbook::new();
     let worksheet = workbook.add_worksheet();
 
@@ -113,7 +152,7 @@ fn export_xlsx(users: &[crate::models::users::entities::User]) -> ActixResult<Ht
     let header_format = Format::new().set_bold();
 
     // 写入表头
-    let headers = [
+    let mut headers = vec![
         "ID",
         "用户名",
         "邮箱",
@@ -122,6 +161,7 @@ fn export_xlsx(users: &[crate::models::users::entities::User]) -> ActixResult<Ht
         "显示名称",
         "创建时间",
     ];
+    headers.extend(profile_fields.iter().map(|f| f.label.as_str()));
     for (col, header) in headers.iter().enumerate() {
         if let Err(e) = worksheet.write_string_with_format(0, col as u16, *header, &header_format) {
             error!("XLSX 写入失败: {}", e);
@@ -148,6 +188,11 @@ fn export_xlsx(users: &[crate::models::users::entities::User]) -> ActixResult<Ht
         worksheet
             .write_string(row, 6, user.created_at.to_rfc3339())
             .ok();
+        for (i, field) in profile_fields.iter().enumerate() {
+            worksheet
+                .write_string(row, (7 + i) as u16, profile_value(user, field))
+                .ok();
+        }
     }
 
     // 生成二进制数据
@@ -170,11 +215,13 @@ fn export_xlsx(users: &[crate::models::users::entities::User]) -> ActixResult<Ht
         .body(buffer))
 }
 
-fn generate_template_csv() -> ActixResult<HttpResponse> {
+fn generate_template_csv(profile_fields: &[ProfileField]) -> ActixResult<HttpResponse> {
     let mut wtr = csv::Writer::from_writer(vec![]);
 
     // 写入表头
-    if let Err(e) = wtr.write_record(["username", "email", "password", "role", "display_name"]) {
+    let mut headers = vec!["username", "email", "password", "role", "display_name"];
+    headers.extend(profile_fields.iter().map(|f| f.key.as_str()));
+    if let Err(e) = wtr.write_record(&headers) {
         error!("CSV 写入失败: {}", e);
         return Ok(
             HttpResponse::InternalServerError().json(ApiResponse::<()>::error_empty(
@@ -185,13 +232,15 @@ fn generate_template_csv() -> ActixResult<HttpResponse> {
     }
 
     // 写入示例行
-    if let Err(e) = wtr.write_record([
-        "example_user",
-        "user@example.com",
-        "password123",
-        "user",
-        "示例用户",
-    ]) {
+    let mut example = vec![
+        "example_user".to_string(),
+        "user@example.com".to_string(),
+        "password123".to_string(),
+        "user".to_string(),
+        "示例用户".to_string(),
+    ];
+    example.extend(profile_fields.iter().map(template_example));
+    if let Err(e) = wtr.write_record(&example) {
         error!("CSV 写入失败: {}", e);
         return Ok(
             HttpResponse::InternalServerError().json(ApiResponse::<()>::error_empty(
@@ -223,7 +272,7 @@ fn generate_template_csv() -> ActixResult<HttpResponse> {
         .body(data))
 }
 
-fn generate_template_xlsx() -> ActixResult<HttpResponse> {
+fn generate_template_xlsx(profile_fields: &[ProfileField]) -> ActixResult<HttpResponse> {
     let mut workbook = Workbook::new();
     let worksheet = workbook.add_worksheet();
 
@@ -231,7 +280,8 @@ fn generate_template_xlsx() -> ActixResult<HttpResponse> {
     let header_format = Format::new().set_bold();
 
     // 写入表头
-    let headers = ["username", "email", "password", "role", "display_name"];
+    let mut headers = vec!["username", "email", "password", "role", "display_name"];
+    headers.extend(profile_fields.iter().map(|f| f.key.as_str()));
     for (col, header) in headers.iter().enumerate() {
         if let Err(e) = worksheet.write_string_with_format(0, col as u16, *header, &header_format) {
             error!("XLSX 写入失败: {}", e);
@@ -250,6 +300,11 @@ fn generate_template_xlsx() -> ActixResult<HttpResponse> {
     worksheet.write_string(1, 2, "password123").ok();
     worksheet.write_string(1, 3, "user").ok();
     worksheet.write_string(1, 4, "示例用户").ok();
+    for (i, field) in profile_fields.iter().enumerate() {
+        worksheet
+            .write_string(1, (5 + i) as u16, template_example(field))
+            .ok();
+    }
 
     // 生成二进制数据
     let buffer = match workbook.save_to_buffer() {
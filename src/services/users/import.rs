@@ -4,28 +4,30 @@ use actix_multipart::Multipart;
 use actix_web::{HttpRequest, HttpResponse, Result as ActixResult};
 use calamine::{Reader, Xlsx};
 use futures_util::StreamExt;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::io::Cursor;
 use tracing::error;
 
 use super::UserService;
+use crate::models::profile_fields::entities::ProfileField;
 use crate::models::users::entities::UserRole;
 use crate::models::users::requests::CreateUserRequest;
 use crate::models::users::responses::{ImportRowError, UserImportResponse};
 use crate::models::{ApiResponse, ErrorCode};
-use crate::services::StorageProvider;
+use crate::services::profile_fields::{ProfileValues, resolve_profile_values};
+use crate::services::{StorageProvider, error_response};
 use crate::utils::password::hash_password;
 use crate::utils::validate::{validate_email, validate_password_simple, validate_username};
 
 /// 导入解析错误
-enum ImportParseError {
+pub(crate) enum ImportParseError {
     MissingColumn(String),
     ParseFailed(String),
     EmptyFile,
 }
 
 impl ImportParseError {
-    fn error_code(&self) -> ErrorCode {
+    pub(crate) fn error_code(&self) -> ErrorCode {
         match self {
             Self::MissingColumn(_) => ErrorCode::ImportFileMissingColumn,
             Self::ParseFailed(_) => ErrorCode::ImportFileParseFailed,
@@ -33,7 +35,7 @@ impl ImportParseError {
         }
     }
 
-    fn message(&self) -> String {
+    pub(crate) fn message(&self) -> String {
         match self {
             Self::MissingColumn(col) => format!("缺少必需列: {col}"),
             Self::ParseFailed(msg) => msg.clone(),
@@ -42,6 +44,56 @@ impl ImportParseError {
     }
 }
 
+/// 单次导入的最大行数
+pub(crate) const MAX_IMPORT_ROWS: usize = 1000;
+
+/// 解析后的导入表格（表头统一转为小写）
+pub(crate) struct ImportTable {
+    headers: HashMap<String, usize>,
+    pub rows: Vec<ImportTableRow>,
+}
+
+/// 导入表格中的一行
+pub(crate) struct ImportTableRow {
+    /// 文件中的行号（1-based，含表头）
+    pub row_num: usize,
+    cells: Vec<String>,
+}
+
+impl ImportTable {
+    /// 获取可选列的索引
+    pub(crate) fn column(&self, name: &str) -> Option<usize> {
+        self.headers.get(name).copied()
+    }
+
+    /// 获取必需列的索引
+    pub(crate) fn require_column(&self, name: &str) -> Result<usize, ImportParseError> {
+        self.column(name)
+            .ok_or_else(|| ImportParseError::MissingColumn(name.to_string()))
+    }
+}
+
+impl ImportTableRow {
+    /// 获取单元格内容（已去除首尾空白，列不存在时为空字符串）
+    pub(crate) fn get(&self, idx: usize) -> String {
+        self.cells.get(idx).cloned().unwrap_or_default()
+    }
+
+    /// 获取可选列的非空单元格内容
+    pub(crate) fn get_opt(&self, idx: Option<usize>) -> Option<String> {
+        idx.map(|i| self.get(i)).filter(|s| !s.is_empty())
+    }
+
+    /// 获取资料字段列中的非空值
+    pub(crate) fn profile_values(&self, columns: &[(String, usize)]) -> BTreeMap<String, String> {
+        columns
+            .iter()
+            .map(|(key, idx)| (key.clone(), self.get(*idx)))
+            .filter(|(_, v)| !v.is_empty())
+            .collect()
+    }
+}
+
 /// 导入行数据
 #[derive(Debug, Clone)]
 struct ImportRow {
@@ -51,6 +103,8 @@ struct ImportRow {
     password: String,
     role: String,
     display_name: Option<String>,
+    /// 自定义资料字段值（字段键 -> 值），仅包含文件中存在的字段列
+    profile: BTreeMap<String, String>,
 }
 
 /// 导入用户
@@ -61,57 +115,25 @@ pub async fn import_users(
 ) -> ActixResult<HttpResponse> {
     let storage = service.get_storage(request)?;
 
-    // 读取文件内容
-    let (file_bytes, file_name) = match read_file_from_multipart(&mut payload).await {
-        Ok(result) => result,
-        Err(e) => {
-            return Ok(HttpResponse::BadRequest().json(ApiResponse::error_empty(
-                ErrorCode::FileUploadFailed,
-                format!("文件读取失败: {e}"),
-            )));
-        }
+    // 读取并解析文件
+    let table = match read_import_table(&mut payload).await {
+        Ok(table) => table,
+        Err(resp) => return Ok(resp),
     };
 
-    if file_bytes.is_empty() {
-        return Ok(HttpResponse::BadRequest().json(ApiResponse::error_empty(
-            ErrorCode::FileUploadFailed,
-            "文件内容为空",
-        )));
-    }
+    let profile_fields = match storage.list_profile_fields().await {
+        Ok(fields) => fields,
+        Err(e) => return Ok(error_response(e)),
+    };
 
-    // 根据文件扩展名解析
-    let rows = if file_name.ends_with(".xlsx") {
-        match parse_xlsx(&file_bytes) {
-            Ok(rows) => rows,
-            Err(e) => {
-                return Ok(HttpResponse::BadRequest()
-                    .json(ApiResponse::error_empty(e.error_code(), e.message())));
-            }
-        }
-    } else {
-        match parse_csv(&file_bytes) {
-            Ok(rows) => rows,
-            Err(e) => {
-                return Ok(HttpResponse::BadRequest()
-                    .json(ApiResponse::error_empty(e.error_code(), e.message())));
-            }
+    let rows = match parse_user_rows(&table, &profile_fields) {
+        Ok(rows) => rows,
+        Err(e) => {
+            return Ok(HttpResponse::BadRequest()
+                .json(ApiResponse::error_empty(e.error_code(), e.message())));
         }
     };
 
-    if rows.is_empty() {
-        return Ok(HttpResponse::BadRequest().json(ApiResponse::error_empty(
-            ErrorCode::ImportFileDataInvalid,
-            "文件中没有数据行",
-        )));
-    }
-
-    if rows.len() > 1000 {
-        return Ok(HttpResponse::BadRequest().json(ApiResponse::error_empty(
-            ErrorCode::ImportFileDataInvalid,
-            "单次导入最多支持 1000 行",
-        )));
-    }
-
     // 验证并过滤数据
     let mut errors: Vec<ImportRowError> = Vec::new();
     let mut valid_rows: Vec<ImportRow> = Vec::new();
@@ -143,7 +165,10 @@ pub async fn import_users(
 
     // 过滤冲突行
     let mut skipped = 0;
-    let mut to_create: Vec<ImportRow> = Vec::new();
+    let mut to_create: Vec<(ImportRow, ProfileValues)> = Vec::new();
+
+    // 同一文件内唯一资料字段已出现的值（字段 ID -> 值集合）
+    let mut seen_unique: HashMap<i64, HashSet<String>> = HashMap::new();
 
     for row in valid_rows {
         if existing_usernames_set.contains(&row.username) {
@@ -161,7 +186,43 @@ pub async fn import_users(
                 message: "邮箱已存在".to_string(),
             });
         } else {
-            to_create.push(row);
+            // 校验资料字段（含必填与数据库中的唯一性）
+            let values =
+                match resolve_profile_values(&storage, &profile_fields, None, &row.profile, true)
+                    .await
+                {
+                    Ok(values) => values,
+                    Err(e) => {
+                        skipped += 1;
+                        errors.push(ImportRowError {
+                            row: row.row_num,
+                            field: e.field().to_string(),
+                            message: e.message(),
+                        });
+                        continue;
+                    }
+                };
+
+            // 校验文件内的唯一性
+            let duplicate = profile_fields.iter().filter(|f| f.unique).find(|f| {
+                values.iter().any(|(id, v)| {
+                    *id == f.id
+                        && v.as_ref().is_some_and(|v| {
+                            !seen_unique.entry(f.id).or_default().insert(v.clone())
+                        })
+                })
+            });
+            if let Some(field) = duplicate {
+                skipped += 1;
+                errors.push(ImportRowError {
+                    row: row.row_num,
+                    field: field.key.clone(),
+                    message: format!("{}与文件中其他行重复", field.label),
+                });
+                continue;
+            }
+
+            to_create.push((row, values));
         }
     }
 
@@ -169,7 +230,7 @@ pub async fn import_users(
     let mut success = 0;
     let mut failed = 0;
 
-    for row in to_create {
+    for (row, profile_values) in to_create {
         // 哈希密码（使用 spawn_blocking 避免阻塞）
         let password_clone = row.password.clone();
         let hashed = match tokio::task::spawn_blocking(move || hash_password(&password_clone)).await
@@ -215,10 +276,11 @@ pub async fn import_users(
             role,
             display_name: row.display_name,
             avatar_url: None,
+            profile: None,
         };
 
-        match storage.create_user(create_req).await {
-            Ok(_) => success += 1,
+        let created = match storage.create_user(create_req).await {
+            Ok(user) => user,
             Err(e) => {
                 failed += 1;
                 error!("创建用户失败: {}", e);
@@ -227,6 +289,23 @@ pub async fn import_users(
                     field: "".to_string(),
                     message: format!("创建失败: {e}"),
                 });
+                continue;
+            }
+        };
+
+        match storage
+            .set_user_profile_values(created.id, &profile_values)
+            .await
+        {
+            Ok(()) => success += 1,
+            Err(e) => {
+                failed += 1;
+                error!("写入用户资料字段失败: {}", e);
+                errors.push(ImportRowError {
+                    row: row.row_num,
+                    field: "".to_string(),
+                    message: format!("用户已创建，但资料字段写入失败: {e}"),
+                });
             }
         }
     }
@@ -273,37 +352,90 @@ async fn read_file_from_multipart(payload: &mut Multipart) -> Result<(Vec<u8>, S
     Ok((file_bytes, file_name))
 }
 
-fn parse_csv(data: &[u8]) -> Result<Vec<ImportRow>, ImportParseError> {
+/// 从 multipart 读取导入文件，返回文件内容与文件名
+///
+/// 失败时直接返回可用于响应的 HttpResponse
+pub(crate) async fn read_import_file(
+    payload: &mut Multipart,
+) -> Result<(Vec<u8>, String), HttpResponse> {
+    let (file_bytes, file_name) = match read_file_from_multipart(payload).await {
+        Ok(result) => result,
+        Err(e) => {
+            return Err(HttpResponse::BadRequest().json(ApiResponse::error_empty(
+                ErrorCode::FileUploadFailed,
+                format!("文件读取失败: {e}"),
+            )));
+        }
+    };
+
+    if file_bytes.is_empty() {
+        return Err(HttpResponse::BadRequest().json(ApiResponse::error_empty(
+            ErrorCode::FileUploadFailed,
+            "文件内容为空",
+        )));
+    }
+
+    Ok((file_bytes, file_name))
+}
+
+/// 从 multipart 读取导入文件并解析为表格
+///
+/// 失败时直接返回可用于响应的 HttpResponse
+pub(crate) async fn read_import_table(
+    payload: &mut Multipart,
+) -> Result<ImportTable, HttpResponse> {
+    let (file_bytes, file_name) = read_import_file(payload).await?;
+    parse_import_table(&file_bytes, &file_name)
+}
+
+/// 按文件扩展名将导入文件解析为表格（xlsx 或 csv）
+pub(crate) fn parse_import_table(
+    file_bytes: &[u8],
+    file_name: &str,
+) -> Result<ImportTable, HttpResponse> {
+    let parsed = if file_name.ends_with(".xlsx") {
+        parse_xlsx(file_bytes)
+    } else {
+        parse_csv(file_bytes)
+    };
+
+    let table = parsed.map_err(|e| {
+        HttpResponse::BadRequest().json(ApiResponse::error_empty(e.error_code(), e.message()))
+    })?;
+
+    if table.rows.is_empty() {
+        return Err(HttpResponse::BadRequest().json(ApiResponse::error_empty(
+            ErrorCode::ImportFileDataInvalid,
+            "文件中没有数据行",
+        )));
+    }
+
+    if table.rows.len() > MAX_IMPORT_ROWS {
+        return Err(HttpResponse::BadRequest().json(ApiResponse::error_empty(
+            ErrorCode::ImportFileDataInvalid,
+            format!("单次导入最多支持 {MAX_IMPORT_ROWS} 行"),
+        )));
+    }
+
+    Ok(table)
+}
+
+fn parse_csv(data: &[u8]) -> Result<ImportTable, ImportParseError> {
     let mut rdr = csv::ReaderBuilder::new()
         .has_headers(true)
         .flexible(true)
         .from_reader(Cursor::new(data));
 
-    // 检查表头
+    // 读取表头
     let headers = rdr
         .headers()
         .map_err(|e| ImportParseError::ParseFailed(format!("读取表头失败: {e}")))?;
-    let header_map: std::collections::HashMap<_, _> = headers
+    let headers: HashMap<_, _> = headers
         .iter()
         .enumerate()
-        .map(|(i, h)| (h.to_lowercase(), i))
+        .map(|(i, h)| (h.trim().to_lowercase(), i))
         .collect();
 
-    // 必需列
-    let username_idx = header_map
-        .get("username")
-        .ok_or_else(|| ImportParseError::MissingColumn("username".to_string()))?;
-    let email_idx = header_map
-        .get("email")
-        .ok_or_else(|| ImportParseError::MissingColumn("email".to_string()))?;
-    let password_idx = header_map
-        .get("password")
-        .ok_or_else(|| ImportParseError::MissingColumn("password".to_string()))?;
-    let role_idx = header_map
-        .get("role")
-        .ok_or_else(|| ImportParseError::MissingColumn("role".to_string()))?;
-    let display_name_idx = header_map.get("display_name");
-
     let mut rows = Vec::new();
 
     for (row_num, result) in rdr.records().enumerate() {
@@ -311,29 +443,16 @@ fn parse_csv(data: &[u8]) -> Result<Vec<ImportRow>, ImportParseError> {
             ImportParseError::ParseFailed(format!("第 {} 行解析失败: {e}", row_num + 2))
         })?;
 
-        let username = record.get(*username_idx).unwrap_or("").trim().to_string();
-        let email = record.get(*email_idx).unwrap_or("").trim().to_string();
-        let password = record.get(*password_idx).unwrap_or("").trim().to_string();
-        let role = record.get(*role_idx).unwrap_or("").trim().to_string();
-        let display_name = display_name_idx
-            .and_then(|i| record.get(*i))
-            .map(|s| s.trim().to_string())
-            .filter(|s| !s.is_empty());
-
-        rows.push(ImportRow {
+        rows.push(ImportTableRow {
             row_num: row_num + 2, // 1-based, skip header
-            username,
-            email,
-            password,
-            role,
-            display_name,
+            cells: record.iter().map(|c| c.trim().to_string()).collect(),
         });
     }
 
-    Ok(rows)
+    Ok(ImportTable { headers, rows })
 }
 
-fn parse_xlsx(data: &[u8]) -> Result<Vec<ImportRow>, ImportParseError> {
+fn parse_xlsx(data: &[u8]) -> Result<ImportTable, ImportParseError> {
     let cursor = Cursor::new(data);
     let mut workbook: Xlsx<_> = Xlsx::new(cursor)
         .map_err(|e| ImportParseError::ParseFailed(format!("打开 XLSX 失败: {e}")))?;
@@ -352,53 +471,65 @@ fn parse_xlsx(data: &[u8]) -> Result<Vec<ImportRow>, ImportParseError> {
 
     // 读取表头
     let header_row = rows_iter.next().ok_or(ImportParseError::EmptyFile)?;
-    let header_map: std::collections::HashMap<_, _> = header_row
+    let headers: HashMap<_, _> = header_row
         .iter()
         .enumerate()
-        .map(|(i, cell)| (cell.to_string().to_lowercase(), i))
+        .map(|(i, cell)| (cell.to_string().trim().to_lowercase(), i))
         .collect();
 
-    // 必需列
-    let username_idx = *header_map
-        .get("username")
-        .ok_or_else(|| ImportParseError::MissingColumn("username".to_string()))?;
-    let email_idx = *header_map
-        .get("email")
-        .ok_or_else(|| ImportParseError::MissingColumn("email".to_string()))?;
-    let password_idx = *header_map
-        .get("password")
-        .ok_or_else(|| ImportParseError::MissingColumn("password".to_string()))?;
-    let role_idx = *header_map
-        .get("role")
-        .ok_or_else(|| ImportParseError::MissingColumn("role".to_string()))?;
-    let display_name_idx = header_map.get("display_name").copied();
-
-    let mut rows = Vec::new();
-
-    for (row_num, row) in rows_iter.enumerate() {
-        let get_cell = |idx: usize| -> String {
-            row.get(idx)
+    let rows = rows_iter
+        .enumerate()
+        .map(|(row_num, row)| ImportTableRow {
+            row_num: row_num + 2, // 1-based, skip header
+            cells: row
+                .iter()
                 .map(|c| c.to_string().trim().to_string())
-                .unwrap_or_default()
-        };
+                .collect(),
+        })
+        .collect();
 
-        let username = get_cell(username_idx);
-        let email = get_cell(email_idx);
-        let password = get_cell(password_idx);
-        let role = get_cell(role_idx);
-        let display_name = display_name_idx.map(get_cell).filter(|s| !s.is_empty());
+    Ok(ImportTable { headers, rows })
+}
 
-        rows.push(ImportRow {
-            row_num: row_num + 2, // 1-based, skip header
-            username,
-            email,
-            password,
-            role,
-            display_name,
-        });
-    }
+/// 将表格解析为用户导入行
+///
+/// 与资料字段键同名的列作为该字段的值导入。
+fn parse_user_rows(
+    table: &ImportTable,
+    profile_fields: &[ProfileField],
+) -> Result<Vec<ImportRow>, ImportParseError> {
+    // 必需列
+    let username_idx = table.require_column("username")?;
+    let email_idx = table.require_column("email")?;
+    let password_idx = table.require_column("password")?;
+    let role_idx = table.require_column("role")?;
+    let display_name_idx = table.column("display_name");
+    let profile_columns = profile_columns(table, profile_fields);
+
+    Ok(table
+        .rows
+        .iter()
+        .map(|row| ImportRow {
+            row_num: row.row_num,
+            username: row.get(username_idx),
+            email: row.get(email_idx),
+            password: row.get(password_idx),
+            role: row.get(role_idx),
+            display_name: row.get_opt(display_name_idx),
+            profile: row.profile_values(&profile_columns),
+        })
+        .collect())
+}
 
-    Ok(rows)
+/// 查找文件中存在的资料字段列（字段键 -> 列索引）
+pub(crate) fn profile_columns(
+    table: &ImportTable,
+    profile_fields: &[ProfileField],
+) -> Vec<(String, usize)> {
+    profile_fields
+        .iter()
+        .filter_map(|f| table.column(&f.key).map(|idx| (f.key.clone(), idx)))
+        .collect()
 }
 
 fn validate_row(row: &ImportRow) -> Vec<ImportRowError> {
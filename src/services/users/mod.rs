@@ -86,8 +86,12 @@ impl UserService {
     }
 
     // 下载导入模板
-    pub async fn download_import_template(&self, format: &str) -> ActixResult<HttpResponse> {
-        export::download_template(format).await
+    pub async fn download_import_template(
+        &self,
+        format: &str,
+        request: &HttpRequest,
+    ) -> ActixResult<HttpResponse> {
+        export::download_template(self, format, request).await
     }
 
     // 获取当前用户统计
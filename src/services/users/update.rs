@@ -6,6 +6,7 @@ use crate::models::{
     ApiResponse, ErrorCode,
     users::{entities::UserRole, requests::UpdateUserRequest, responses::UserResponse},
 };
+use crate::services::profile_fields::resolve_profile_values;
 use crate::services::{StorageProvider, error_response};
 use crate::utils::validate::validate_password_simple;
 
@@ -76,6 +77,24 @@ pub async fn update_user(
         }
     }
 
+    // 仅校验并更新请求中提供的资料字段，空字符串表示清除
+    if let Some(profile) = update_data.profile.take() {
+        let profile_fields = match storage.list_profile_fields().await {
+            Ok(fields) => fields,
+            Err(e) => return Ok(error_response(e)),
+        };
+        let values =
+            match resolve_profile_values(&storage, &profile_fields, Some(user_id), &profile, false)
+                .await
+            {
+                Ok(values) => values,
+                Err(e) => return Ok(e.into_response()),
+            };
+        if let Err(e) = storage.set_user_profile_values(user_id, &values).await {
+            return Ok(error_response(e));
+        }
+    }
+
     match storage.update_user(user_id, update_data).await {
         Ok(Some(user)) => Ok(HttpResponse::Ok().json(ApiResponse::success(
             UserResponse { user },
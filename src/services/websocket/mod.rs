@@ -213,11 +213,10 @@ impl WebSocketService {
                                 }
                             }
                         }
-                        Some(Ok(Message::Ping(data))) => {
-                            if session.pong(&data).await.is_err() {
+                        Some(Ok(Message::Ping(data)))
+                            if session.pong(&data).await.is_err() => {
                                 break;
                             }
-                        }
                         Some(Ok(Message::Close(_))) | None => {
                             info!("WebSocket closed for user: {}", user_id);
                             break;
@@ -1,23 +1,40 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::sync::Arc;
 
 use crate::models::{
+    announcements::{
+        entities::{Announcement, AnnouncementRead},
+        requests::{AnnouncementListQuery, CreateAnnouncementRequest, UpdateAnnouncementRequest},
+        responses::AnnouncementListResponse,
+    },
+    auto_graders::entities::{AutoGradeResult, AutoGradeRun},
+    class_groups::{
+        entities::ClassGroup,
+        requests::{CreateClassGroupRequest, UpdateClassGroupRequest},
+    },
     class_users::{
-        entities::{ClassUser, ClassUserRole},
-        requests::{ClassUserListQuery, UpdateClassUserRequest},
-        responses::ClassUserListResponse,
+        entities::{ClassJoinRequest, ClassUser, ClassUserRole},
+        requests::{ClassJoinRequestListQuery, ClassUserListQuery, UpdateClassUserRequest},
+        responses::{ClassJoinRequestListResponse, ClassUserListResponse},
     },
     classes::{
         entities::Class,
         requests::{ClassListQuery, CreateClassRequest, UpdateClassRequest},
         responses::ClassListResponse,
     },
+    comments::{
+        entities::{Comment, CommentTargetType},
+        requests::CreateCommentRequest,
+    },
+    common::PaginationQuery,
     files::entities::File,
     grades::{
         entities::Grade,
         requests::{CreateGradeRequest, GradeListQuery, UpdateGradeRequest},
         responses::GradeListResponse,
     },
+    homework_extensions::{entities::HomeworkExtension, requests::SetHomeworkExtensionRequest},
+    homework_questions::entities::{HomeworkQuestion, HomeworkQuestionPool, SubmissionAnswer},
     homeworks::{
         entities::Homework,
         requests::{
@@ -30,6 +47,22 @@ use crate::models::{
         requests::{CreateNotificationRequest, NotificationListQuery},
         responses::NotificationListResponse,
     },
+    profile_fields::{
+        entities::ProfileField,
+        requests::{CreateProfileFieldRequest, UpdateProfileFieldRequest},
+    },
+    question_bank::{
+        entities::BankQuestion,
+        requests::{
+            BankQuestionFilter, CreateBankQuestionRequest, QuestionPoolDraft,
+            UpdateBankQuestionRequest,
+        },
+        responses::BankQuestionListResponse,
+    },
+    rubrics::{
+        entities::{Rubric, RubricScore},
+        requests::{CreateRubricRequest, UpdateRubricRequest},
+    },
     submissions::{
         entities::Submission,
         requests::{CreateSubmissionRequest, SubmissionListQuery},
@@ -97,6 +130,42 @@ pub trait Storage: Send + Sync {
     /// 批量获取用户信息
     async fn get_users_by_ids(&self, ids: &[i64]) -> Result<HashMap<i64, User>>;
 
+    // ============================================
+    // 自定义资料字段方法
+    // ============================================
+
+    /// 创建资料字段
+    async fn create_profile_field(&self, req: CreateProfileFieldRequest) -> Result<ProfileField>;
+    /// 通过 ID 获取资料字段
+    async fn get_profile_field_by_id(&self, field_id: i64) -> Result<Option<ProfileField>>;
+    /// 通过字段键获取资料字段
+    async fn get_profile_field_by_key(&self, key: &str) -> Result<Option<ProfileField>>;
+    /// 列出所有资料字段
+    async fn list_profile_fields(&self) -> Result<Vec<ProfileField>>;
+    /// 更新资料字段
+    async fn update_profile_field(
+        &self,
+        field_id: i64,
+        update: UpdateProfileFieldRequest,
+    ) -> Result<Option<ProfileField>>;
+    /// 删除资料字段及其所有值
+    async fn delete_profile_field(&self, field_id: i64) -> Result<bool>;
+    /// 批量获取用户资料字段值（user_id -> 字段键 -> 值）
+    async fn get_user_profiles(
+        &self,
+        user_ids: &[i64],
+    ) -> Result<HashMap<i64, BTreeMap<String, String>>>;
+    /// 设置用户资料字段值（值为 None 时删除）
+    async fn set_user_profile_values(
+        &self,
+        user_id: i64,
+        values: &[(i64, Option<String>)],
+    ) -> Result<()>;
+    /// 查找资料字段值为指定值的用户
+    async fn find_user_ids_by_profile_value(&self, field_id: i64, value: &str) -> Result<Vec<i64>>;
+    /// 检查资料字段是否存在重复值
+    async fn has_duplicate_profile_values(&self, field_id: i64) -> Result<bool>;
+
     // ============================================
     // 文件管理方法
     // ============================================
@@ -199,6 +268,71 @@ pub trait Storage: Send + Sync {
     /// 获取班级所有成员（不分页，用于内部统计/导出）
     async fn list_all_class_users(&self, class_id: i64) -> Result<Vec<ClassUser>>;
 
+    // ============================================
+    // 加入班级申请方法
+    // ============================================
+
+    /// 创建加入班级申请
+    async fn create_class_join_request(
+        &self,
+        class_id: i64,
+        user_id: i64,
+        message: Option<String>,
+    ) -> Result<ClassJoinRequest>;
+    /// 获取用户在班级中待审批的申请
+    async fn get_pending_class_join_request(
+        &self,
+        class_id: i64,
+        user_id: i64,
+    ) -> Result<Option<ClassJoinRequest>>;
+    /// 列出班级的加入申请（分页）
+    async fn list_class_join_requests_with_pagination(
+        &self,
+        class_id: i64,
+        query: ClassJoinRequestListQuery,
+    ) -> Result<ClassJoinRequestListResponse>;
+    /// 批量审批加入申请，返回实际处理的申请
+    /// - `approve`: true=通过（同时加入班级），false=拒绝
+    async fn handle_class_join_requests(
+        &self,
+        class_id: i64,
+        request_ids: &[i64],
+        approve: bool,
+        handler_id: i64,
+    ) -> Result<Vec<ClassJoinRequest>>;
+
+    // ============================================
+    // 班级分组方法
+    // ============================================
+
+    /// 创建班级分组
+    async fn create_class_group(
+        &self,
+        class_id: i64,
+        req: CreateClassGroupRequest,
+    ) -> Result<ClassGroup>;
+    /// 通过 ID 获取班级分组
+    async fn get_class_group_by_id(&self, group_id: i64) -> Result<Option<ClassGroup>>;
+    /// 列出班级所有分组
+    async fn list_class_groups(&self, class_id: i64) -> Result<Vec<ClassGroup>>;
+    /// 更新班级分组
+    async fn update_class_group(
+        &self,
+        group_id: i64,
+        update: UpdateClassGroupRequest,
+    ) -> Result<Option<ClassGroup>>;
+    /// 删除班级分组（成员归属清空）
+    async fn delete_class_group(&self, group_id: i64) -> Result<bool>;
+    /// 统计班级各分组成员数量（group_id -> count）
+    async fn count_class_group_members(&self, class_id: i64) -> Result<HashMap<i64, i64>>;
+    /// 批量设置成员分组，`group_id` 为 None 表示移出分组，返回实际更新的用户 ID
+    async fn set_class_group_members(
+        &self,
+        class_id: i64,
+        group_id: Option<i64>,
+        user_ids: &[i64],
+    ) -> Result<Vec<i64>>;
+
     // ============================================
     // 作业管理方法
     // ============================================
@@ -212,7 +346,8 @@ pub trait Storage: Send + Sync {
     /// 通过 ID 获取作业
     async fn get_homework_by_id(&self, homework_id: i64) -> Result<Option<Homework>>;
     /// 列出作业
-    /// - current_user_id: 当前用户 ID，如果提供则查询该用户对这些作业的提交状态
+    /// - current_user_id: 学生视角的当前用户 ID，如果提供则查询该用户对这些作业的提交状态，
+    ///   且只返回已发布的作业
     async fn list_homeworks_with_pagination(
         &self,
         query: HomeworkListQuery,
@@ -242,7 +377,7 @@ pub trait Storage: Send + Sync {
     async fn get_teacher_homework_stats(&self, user_id: i64) -> Result<(i64, i64, i64, i64)>;
     /// 列出用户所有班级的作业（跨班级）
     /// - user_id: 当前用户 ID
-    /// - is_teacher: 是否为教师视角
+    /// - is_teacher: 是否为教师视角（非教师视角只返回已发布的作业）
     async fn list_all_homeworks(
         &self,
         user_id: i64,
@@ -251,16 +386,222 @@ pub trait Storage: Send + Sync {
     ) -> Result<AllHomeworksResponse>;
     /// 获取班级所有作业（不分页，用于内部统计/导出）
     async fn list_all_homeworks_by_class(&self, class_id: i64) -> Result<Vec<Homework>>;
+    /// 发布已到发布时间的定时作业，返回本次发布的作业
+    async fn publish_due_homeworks(&self, now: i64) -> Result<Vec<Homework>>;
+    /// 列出截止时间在 (from, to] 范围内的已发布作业
+    async fn list_published_homeworks_due_between(
+        &self,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<Homework>>;
+    /// 占用一次截止提醒（作业 + 提前量 + 截止时间唯一），返回是否为首次占用
+    async fn claim_deadline_reminder(
+        &self,
+        homework_id: i64,
+        offset_minutes: i64,
+        deadline: i64,
+    ) -> Result<bool>;
+
+    // ============================================
+    // 作业延期方法
+    // ============================================
+
+    /// 设置作业延期（同一学生或分组已有延期时覆盖）
+    async fn set_homework_extension(
+        &self,
+        homework_id: i64,
+        req: SetHomeworkExtensionRequest,
+        created_by: i64,
+    ) -> Result<HomeworkExtension>;
+    /// 通过 ID 获取作业延期
+    async fn get_homework_extension_by_id(
+        &self,
+        extension_id: i64,
+    ) -> Result<Option<HomeworkExtension>>;
+    /// 列出作业的所有延期
+    async fn list_homework_extensions(&self, homework_id: i64) -> Result<Vec<HomeworkExtension>>;
+    /// 删除作业延期
+    async fn delete_homework_extension(&self, extension_id: i64) -> Result<bool>;
+    /// 获取学生在指定作业上的延期截止时间（个人延期优先于分组延期）
+    async fn get_user_extended_deadlines(
+        &self,
+        user_id: i64,
+        homework_ids: &[i64],
+    ) -> Result<HashMap<i64, chrono::DateTime<chrono::Utc>>>;
+
+    // ============================================
+    // 评分细则方法
+    // ============================================
+
+    /// 创建评分细则
+    async fn create_rubric(&self, created_by: i64, req: CreateRubricRequest) -> Result<Rubric>;
+    /// 通过 ID 获取评分细则
+    async fn get_rubric_by_id(&self, rubric_id: i64) -> Result<Option<Rubric>>;
+    /// 列出评分细则（created_by 为 None 时列出全部）
+    async fn list_rubrics(&self, created_by: Option<i64>) -> Result<Vec<Rubric>>;
+    /// 更新评分细则
+    async fn update_rubric(
+        &self,
+        rubric_id: i64,
+        update: UpdateRubricRequest,
+    ) -> Result<Option<Rubric>>;
+    /// 删除评分细则（解除作业关联）
+    async fn delete_rubric(&self, rubric_id: i64) -> Result<bool>;
+
+    // ============================================
+    // 作业题目方法
+    // ============================================
+
+    /// 列出作业题目（按顺序排列，包含标准答案）
+    async fn list_homework_questions(&self, homework_id: i64) -> Result<Vec<HomeworkQuestion>>;
+    /// 作业是否已有提交
+    async fn has_homework_submissions(&self, homework_id: i64) -> Result<bool>;
+    /// 批量获取提交的题目作答，返回 submission_id -> 作答列表 的映射
+    async fn list_submission_answers(
+        &self,
+        submission_ids: &[i64],
+    ) -> Result<HashMap<i64, Vec<SubmissionAnswer>>>;
+    /// 设置题目作答得分（question_id, points）
+    async fn set_submission_answer_points(
+        &self,
+        submission_id: i64,
+        scores: &[(i64, f64)],
+    ) -> Result<()>;
+    /// 按题目作答得分生成或刷新评分（仍有未给分作答时标记为待批阅）
+    async fn grade_submission_answers(&self, submission_id: i64) -> Result<Option<Grade>>;
+    /// 列出作业的随机抽题池
+    async fn list_homework_question_pools(
+        &self,
+        homework_id: i64,
+    ) -> Result<Vec<HomeworkQuestionPool>>;
+
+    // ============================================
+    // 题库方法
+    // ============================================
+
+    /// 创建题库题目（附件通过 download_token 关联，须为创建者上传）
+    async fn create_bank_question(
+        &self,
+        created_by: i64,
+        req: CreateBankQuestionRequest,
+    ) -> Result<BankQuestion>;
+    /// 通过 ID 获取题库题目
+    async fn get_bank_question_by_id(&self, question_id: i64) -> Result<Option<BankQuestion>>;
+    /// 分页列出题库题目
+    async fn list_bank_questions_with_pagination(
+        &self,
+        filter: BankQuestionFilter,
+        pagination: PaginationQuery,
+    ) -> Result<BankQuestionListResponse>;
+    /// 列出符合条件的全部题库题目（按 ID 排序，最多 limit 条）
+    async fn list_bank_questions(
+        &self,
+        filter: BankQuestionFilter,
+        limit: u64,
+    ) -> Result<Vec<BankQuestion>>;
+    /// 更新题库题目（附件须为 user_id 上传）
+    async fn update_bank_question(
+        &self,
+        question_id: i64,
+        user_id: i64,
+        update: UpdateBankQuestionRequest,
+    ) -> Result<Option<BankQuestion>>;
+    /// 删除题库题目（已添加到作业的题目不受影响）
+    async fn delete_bank_question(&self, question_id: i64) -> Result<bool>;
+    /// 将题库题目追加到作业（固定题目与随机抽题池，复制题目快照与附件）
+    async fn add_bank_questions_to_homework(
+        &self,
+        homework_id: i64,
+        picked: Vec<BankQuestion>,
+        pools: Vec<QuestionPoolDraft>,
+    ) -> Result<()>;
+
+    // ============================================
+    // 班级公告方法
+    // ============================================
+
+    /// 创建公告（带附件）
+    async fn create_announcement(
+        &self,
+        class_id: i64,
+        created_by: i64,
+        req: CreateAnnouncementRequest,
+    ) -> Result<Announcement>;
+    /// 通过 ID 获取公告
+    async fn get_announcement_by_id(&self, announcement_id: i64) -> Result<Option<Announcement>>;
+    /// 分页列出班级公告（置顶优先）
+    async fn list_announcements_with_pagination(
+        &self,
+        class_id: i64,
+        query: AnnouncementListQuery,
+    ) -> Result<AnnouncementListResponse>;
+    /// 更新公告
+    async fn update_announcement(
+        &self,
+        announcement_id: i64,
+        update: UpdateAnnouncementRequest,
+        user_id: i64,
+    ) -> Result<Option<Announcement>>;
+    /// 删除公告（同时删除附件关联、已读回执和讨论）
+    async fn delete_announcement(&self, announcement_id: i64) -> Result<bool>;
+    /// 获取公告附件 ID 列表
+    async fn get_announcement_file_ids(&self, announcement_id: i64) -> Result<Vec<i64>>;
+    /// 标记公告已读，返回是否为首次阅读
+    async fn mark_announcement_read(&self, announcement_id: i64, user_id: i64) -> Result<bool>;
+    /// 列出公告的已读回执
+    async fn list_announcement_reads(&self, announcement_id: i64) -> Result<Vec<AnnouncementRead>>;
+    /// 批量统计公告已读人数（announcement_id -> count）
+    async fn count_announcement_reads(&self, announcement_ids: &[i64])
+    -> Result<HashMap<i64, i64>>;
+    /// 获取用户在给定公告中已读的公告 ID
+    async fn list_read_announcement_ids(
+        &self,
+        user_id: i64,
+        announcement_ids: &[i64],
+    ) -> Result<HashSet<i64>>;
+
+    // ============================================
+    // 讨论评论方法
+    // ============================================
+
+    /// 发表评论
+    async fn create_comment(
+        &self,
+        target_type: CommentTargetType,
+        target_id: i64,
+        class_id: i64,
+        user_id: i64,
+        req: CreateCommentRequest,
+    ) -> Result<Comment>;
+    /// 通过 ID 获取评论
+    async fn get_comment_by_id(&self, comment_id: i64) -> Result<Option<Comment>>;
+    /// 列出目标下的所有评论（按发表时间排序）
+    async fn list_comments(
+        &self,
+        target_type: CommentTargetType,
+        target_id: i64,
+    ) -> Result<Vec<Comment>>;
+    /// 编辑评论内容
+    async fn update_comment(&self, comment_id: i64, content: String) -> Result<Option<Comment>>;
+    /// 删除评论及其所有回复，返回删除数量
+    async fn delete_comment(&self, comment_id: i64) -> Result<u64>;
+    /// 批量统计评论数量（target_id -> count）
+    async fn count_comments(
+        &self,
+        target_type: CommentTargetType,
+        target_ids: &[i64],
+    ) -> Result<HashMap<i64, i64>>;
 
     // ============================================
     // 提交管理方法
     // ============================================
 
-    /// 创建提交（自动计算版本号）
+    /// 创建提交（自动计算版本号，同时保存题目作答）
     async fn create_submission(
         &self,
         creator_id: i64,
         req: CreateSubmissionRequest,
+        answers: Vec<SubmissionAnswer>,
     ) -> Result<Submission>;
     /// 通过 ID 获取提交
     async fn get_submission_by_id(&self, submission_id: i64) -> Result<Option<Submission>>;
@@ -319,6 +660,7 @@ pub trait Storage: Send + Sync {
         page_size: i64,
         include_grades: bool,
         graded: Option<bool>,
+        group_id: Option<i64>,
     ) -> Result<SubmissionSummaryResponse>;
     /// 获取某学生某作业的所有提交版本（教师视角，包含评分和附件）
     /// - `include_grades`: 是否包含成绩信息（课代表不可见成绩）
@@ -333,17 +675,23 @@ pub trait Storage: Send + Sync {
     // 评分管理方法
     // ============================================
 
-    /// 创建评分
-    async fn create_grade(&self, grader_id: i64, req: CreateGradeRequest) -> Result<Grade>;
+    /// 创建评分（rubric_scores 非空时原始分数取各评分项得分之和）
+    async fn create_grade(
+        &self,
+        grader_id: i64,
+        req: CreateGradeRequest,
+        rubric_scores: Vec<RubricScore>,
+    ) -> Result<Grade>;
     /// 通过 ID 获取评分
     async fn get_grade_by_id(&self, grade_id: i64) -> Result<Option<Grade>>;
     /// 通过提交 ID 获取评分
     async fn get_grade_by_submission_id(&self, submission_id: i64) -> Result<Option<Grade>>;
-    /// 更新评分
+    /// 更新评分（rubric_scores 为 Some 时替换评分明细，非空时原始分数取各评分项得分之和）
     async fn update_grade(
         &self,
         grade_id: i64,
         update: UpdateGradeRequest,
+        rubric_scores: Option<Vec<RubricScore>>,
     ) -> Result<Option<Grade>>;
     /// 列出评分（分页）
     async fn list_grades_with_pagination(&self, query: GradeListQuery)
@@ -353,6 +701,41 @@ pub trait Storage: Send + Sync {
         &self,
         submission_ids: &[i64],
     ) -> Result<HashMap<i64, Grade>>;
+    /// 确认待批阅的评分，并将提交标记为已评分
+    async fn confirm_grade(&self, grade_id: i64) -> Result<Option<Grade>>;
+
+    // ============================================
+    // 自动评测方法
+    // ============================================
+
+    /// 创建自动评测记录（等待评测）
+    async fn create_auto_grade_run(&self, submission_id: i64, grader: &str)
+    -> Result<AutoGradeRun>;
+    /// 通过提交 ID 获取自动评测记录
+    async fn get_auto_grade_run_by_submission_id(
+        &self,
+        submission_id: i64,
+    ) -> Result<Option<AutoGradeRun>>;
+    /// 列出等待评测的记录
+    async fn list_pending_auto_grade_runs(&self, limit: u64) -> Result<Vec<AutoGradeRun>>;
+    /// 领取评测任务（等待中改为运行中），返回是否领取成功
+    async fn claim_auto_grade_run(&self, run_id: i64) -> Result<bool>;
+    /// 将运行中的评测重置为等待状态，返回重置数量
+    async fn reset_running_auto_grade_runs(&self) -> Result<u64>;
+    /// 重新评测：清除上次结果并改为等待状态
+    async fn requeue_auto_grade_run(
+        &self,
+        run_id: i64,
+        grader: &str,
+    ) -> Result<Option<AutoGradeRun>>;
+    /// 标记评测失败
+    async fn fail_auto_grade_run(&self, run_id: i64, error: String) -> Result<bool>;
+    /// 保存评测结果并生成待确认的评分（已确认的评分不覆盖，返回 None）
+    async fn complete_auto_grade_run(
+        &self,
+        run_id: i64,
+        result: AutoGradeResult,
+    ) -> Result<Option<Grade>>;
 
     // ============================================
     // 通知管理方法
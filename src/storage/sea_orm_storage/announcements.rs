@@ -0,0 +1,371 @@
+//! 班级公告存储操作
+
+use std::collections::{HashMap, HashSet};
+
+use super::SeaOrmStorage;
+use crate::entity::announcement_files::{
+    ActiveModel as AnnouncementFileActiveModel, Column as AnnouncementFileColumn,
+    Entity as AnnouncementFiles,
+};
+use crate::entity::announcement_reads::{
+    ActiveModel as AnnouncementReadActiveModel, Column as AnnouncementReadColumn,
+    Entity as AnnouncementReads,
+};
+use crate::entity::announcements::{ActiveModel, Column, Entity as Announcements};
+use crate::errors::{HWSystemError, Result};
+use crate::models::{
+    PaginationInfo,
+    announcements::{
+        entities::{Announcement, AnnouncementRead},
+        requests::{AnnouncementListQuery, CreateAnnouncementRequest, UpdateAnnouncementRequest},
+        responses::AnnouncementListResponse,
+    },
+    comments::entities::CommentTargetType,
+};
+use crate::utils::escape_like_pattern;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+
+impl SeaOrmStorage {
+    /// 创建公告（使用事务保护）
+    pub async fn create_announcement_impl(
+        &self,
+        class_id: i64,
+        created_by: i64,
+        req: CreateAnnouncementRequest,
+    ) -> Result<Announcement> {
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("开启事务失败: {e}")))?;
+
+        let now = chrono::Utc::now().timestamp();
+
+        let model = ActiveModel {
+            class_id: Set(class_id),
+            title: Set(req.title),
+            content: Set(req.content),
+            pinned: Set(req.pinned.unwrap_or(false)),
+            created_by: Set(created_by),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        let result = model
+            .insert(&txn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("创建公告失败: {e}")))?;
+
+        // 处理附件
+        if let Some(tokens) = req.attachments {
+            self.set_announcement_files_txn(&txn, result.id, tokens, created_by)
+                .await?;
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("提交事务失败: {e}")))?;
+
+        Ok(result.into_announcement())
+    }
+
+    /// 通过 ID 获取公告
+    pub async fn get_announcement_by_id_impl(
+        &self,
+        announcement_id: i64,
+    ) -> Result<Option<Announcement>> {
+        let result = Announcements::find_by_id(announcement_id)
+            .one(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询公告失败: {e}")))?;
+
+        Ok(result.map(|m| m.into_announcement()))
+    }
+
+    /// 分页列出班级公告（置顶优先，其次按发布时间倒序）
+    pub async fn list_announcements_with_pagination_impl(
+        &self,
+        class_id: i64,
+        query: AnnouncementListQuery,
+    ) -> Result<AnnouncementListResponse> {
+        let (page, page_size) = query.pagination.normalized();
+
+        let mut select = Announcements::find().filter(Column::ClassId.eq(class_id));
+
+        if let Some(ref search) = query.search
+            && !search.trim().is_empty()
+        {
+            let escaped = escape_like_pattern(search.trim());
+            select = select.filter(Column::Title.contains(&escaped));
+        }
+
+        let select = select
+            .order_by_desc(Column::Pinned)
+            .order_by_desc(Column::CreatedAt)
+            .order_by_desc(Column::Id);
+
+        let paginator = select.paginate(&self.db, page_size);
+        let total = paginator
+            .num_items()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询公告总数失败: {e}")))?;
+
+        let pages = paginator
+            .num_pages()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询公告页数失败: {e}")))?;
+
+        let announcements = paginator
+            .fetch_page(page - 1)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询公告列表失败: {e}")))?;
+
+        Ok(AnnouncementListResponse {
+            items: announcements
+                .into_iter()
+                .map(|m| m.into_announcement())
+                .collect(),
+            pagination: PaginationInfo {
+                page: page as i64,
+                page_size: page_size as i64,
+                total: total as i64,
+                total_pages: pages as i64,
+            },
+        })
+    }
+
+    /// 更新公告
+    pub async fn update_announcement_impl(
+        &self,
+        announcement_id: i64,
+        update: UpdateAnnouncementRequest,
+        user_id: i64,
+    ) -> Result<Option<Announcement>> {
+        if self
+            .get_announcement_by_id_impl(announcement_id)
+            .await?
+            .is_none()
+        {
+            return Ok(None);
+        }
+
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("开启事务失败: {e}")))?;
+
+        let mut model = ActiveModel {
+            id: Set(announcement_id),
+            updated_at: Set(chrono::Utc::now().timestamp()),
+            ..Default::default()
+        };
+
+        if let Some(title) = update.title {
+            model.title = Set(title);
+        }
+
+        if let Some(content) = update.content {
+            model.content = Set(content);
+        }
+
+        if let Some(pinned) = update.pinned {
+            model.pinned = Set(pinned);
+        }
+
+        model
+            .update(&txn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("更新公告失败: {e}")))?;
+
+        // 处理附件
+        if let Some(tokens) = update.attachments {
+            self.set_announcement_files_txn(&txn, announcement_id, tokens, user_id)
+                .await?;
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("提交事务失败: {e}")))?;
+
+        self.get_announcement_by_id_impl(announcement_id).await
+    }
+
+    /// 删除公告（使用事务保护，同时删除附件关联、已读回执和讨论）
+    pub async fn delete_announcement_impl(&self, announcement_id: i64) -> Result<bool> {
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("开启事务失败: {e}")))?;
+
+        AnnouncementFiles::delete_many()
+            .filter(AnnouncementFileColumn::AnnouncementId.eq(announcement_id))
+            .exec(&txn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("删除公告附件关联失败: {e}")))?;
+
+        AnnouncementReads::delete_many()
+            .filter(AnnouncementReadColumn::AnnouncementId.eq(announcement_id))
+            .exec(&txn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("删除公告已读回执失败: {e}")))?;
+
+        self.delete_comments_by_target_txn(&txn, CommentTargetType::Announcement, announcement_id)
+            .await?;
+
+        let result = Announcements::delete_by_id(announcement_id)
+            .exec(&txn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("删除公告失败: {e}")))?;
+
+        txn.commit()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("提交事务失败: {e}")))?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    /// 获取公告附件 ID 列表
+    pub async fn get_announcement_file_ids_impl(&self, announcement_id: i64) -> Result<Vec<i64>> {
+        let results = AnnouncementFiles::find()
+            .filter(AnnouncementFileColumn::AnnouncementId.eq(announcement_id))
+            .all(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询公告附件失败: {e}")))?;
+
+        Ok(results.into_iter().map(|m| m.file_id).collect())
+    }
+
+    /// 设置公告附件（事务版本，通过 download_token，带所有权校验）
+    async fn set_announcement_files_txn<C: ConnectionTrait>(
+        &self,
+        conn: &C,
+        announcement_id: i64,
+        tokens: Vec<String>,
+        user_id: i64,
+    ) -> Result<()> {
+        // 先删除旧的关联
+        AnnouncementFiles::delete_many()
+            .filter(AnnouncementFileColumn::AnnouncementId.eq(announcement_id))
+            .exec(conn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("删除旧附件关联失败: {e}")))?;
+
+        for token in tokens {
+            let file = self
+                .get_file_by_token_impl(&token)
+                .await?
+                .ok_or_else(|| HWSystemError::not_found(format!("文件不存在: {token}")))?;
+
+            // 校验文件所有权
+            if file.user_id != Some(user_id) {
+                return Err(HWSystemError::authorization(format!(
+                    "无权使用此文件: {token}"
+                )));
+            }
+
+            AnnouncementFileActiveModel {
+                announcement_id: Set(announcement_id),
+                file_id: Set(file.id),
+            }
+            .insert(conn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("创建附件关联失败: {e}")))?;
+
+            // 增加文件引用计数
+            self.increment_file_citation_txn(conn, file.id).await?;
+        }
+
+        Ok(())
+    }
+
+    /// 标记公告已读（重复标记不会更新首次阅读时间），返回是否为首次阅读
+    pub async fn mark_announcement_read_impl(
+        &self,
+        announcement_id: i64,
+        user_id: i64,
+    ) -> Result<bool> {
+        let model = AnnouncementReadActiveModel {
+            announcement_id: Set(announcement_id),
+            user_id: Set(user_id),
+            read_at: Set(chrono::Utc::now().timestamp()),
+        };
+
+        let result = AnnouncementReads::insert(model)
+            .on_conflict_do_nothing()
+            .exec(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("标记公告已读失败: {e}")))?;
+
+        Ok(matches!(result, sea_orm::TryInsertResult::Inserted(_)))
+    }
+
+    /// 列出公告的已读回执（按阅读时间排序）
+    pub async fn list_announcement_reads_impl(
+        &self,
+        announcement_id: i64,
+    ) -> Result<Vec<AnnouncementRead>> {
+        let results = AnnouncementReads::find()
+            .filter(AnnouncementReadColumn::AnnouncementId.eq(announcement_id))
+            .order_by_asc(AnnouncementReadColumn::ReadAt)
+            .all(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询已读回执失败: {e}")))?;
+
+        Ok(results
+            .into_iter()
+            .map(|m| m.into_announcement_read())
+            .collect())
+    }
+
+    /// 批量统计公告已读人数（announcement_id -> count）
+    pub async fn count_announcement_reads_impl(
+        &self,
+        announcement_ids: &[i64],
+    ) -> Result<HashMap<i64, i64>> {
+        if announcement_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let rows: Vec<(i64, i64)> = AnnouncementReads::find()
+            .filter(AnnouncementReadColumn::AnnouncementId.is_in(announcement_ids.to_vec()))
+            .select_only()
+            .column(AnnouncementReadColumn::AnnouncementId)
+            .column_as(AnnouncementReadColumn::UserId.count(), "read_count")
+            .group_by(AnnouncementReadColumn::AnnouncementId)
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("统计已读人数失败: {e}")))?;
+
+        Ok(rows.into_iter().collect())
+    }
+
+    /// 获取用户已读的公告 ID（限定在给定公告范围内）
+    pub async fn list_read_announcement_ids_impl(
+        &self,
+        user_id: i64,
+        announcement_ids: &[i64],
+    ) -> Result<HashSet<i64>> {
+        if announcement_ids.is_empty() {
+            return Ok(HashSet::new());
+        }
+
+        let ids: Vec<i64> = AnnouncementReads::find()
+            .filter(AnnouncementReadColumn::UserId.eq(user_id))
+            .filter(AnnouncementReadColumn::AnnouncementId.is_in(announcement_ids.to_vec()))
+            .select_only()
+            .column(AnnouncementReadColumn::AnnouncementId)
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询已读公告失败: {e}")))?;
+
+        Ok(ids.into_iter().collect())
+    }
+}
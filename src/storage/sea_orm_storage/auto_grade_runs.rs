@@ -0,0 +1,272 @@
+//! 自动评测记录存储操作
+
+use super::SeaOrmStorage;
+use crate::entity::auto_grade_runs::{ActiveModel, Column, Entity as AutoGradeRuns};
+use crate::entity::grades::{
+    ActiveModel as GradeActiveModel, Column as GradeColumn, Entity as Grades,
+};
+use crate::entity::homeworks::Entity as Homeworks;
+use crate::entity::submissions::Entity as Submissions;
+use crate::errors::{HWSystemError, Result};
+use crate::models::{
+    auto_graders::entities::{AutoGradeResult, AutoGradeRun, AutoGradeRunStatus},
+    grades::entities::Grade,
+};
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect, Set,
+    TransactionTrait,
+};
+
+impl SeaOrmStorage {
+    /// 创建自动评测记录（等待评测）
+    pub async fn create_auto_grade_run_impl(
+        &self,
+        submission_id: i64,
+        grader: &str,
+    ) -> Result<AutoGradeRun> {
+        let model = ActiveModel {
+            submission_id: Set(submission_id),
+            grader: Set(grader.to_string()),
+            status: Set(AutoGradeRunStatus::PENDING.to_string()),
+            created_at: Set(chrono::Utc::now().timestamp()),
+            ..Default::default()
+        };
+
+        let result = model.insert(&self.db).await.map_err(|e| {
+            HWSystemError::database_operation(format!("创建自动评测记录失败: {e}"))
+        })?;
+
+        Ok(result.into_auto_grade_run())
+    }
+
+    /// 通过提交 ID 获取自动评测记录
+    pub async fn get_auto_grade_run_by_submission_id_impl(
+        &self,
+        submission_id: i64,
+    ) -> Result<Option<AutoGradeRun>> {
+        let result = AutoGradeRuns::find()
+            .filter(Column::SubmissionId.eq(submission_id))
+            .one(&self.db)
+            .await
+            .map_err(|e| {
+                HWSystemError::database_operation(format!("查询自动评测记录失败: {e}"))
+            })?;
+
+        Ok(result.map(|m| m.into_auto_grade_run()))
+    }
+
+    /// 列出等待评测的记录（按创建时间排序）
+    pub async fn list_pending_auto_grade_runs_impl(&self, limit: u64) -> Result<Vec<AutoGradeRun>> {
+        let results = AutoGradeRuns::find()
+            .filter(Column::Status.eq(AutoGradeRunStatus::PENDING))
+            .order_by_asc(Column::Id)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(|e| {
+                HWSystemError::database_operation(format!("查询自动评测记录失败: {e}"))
+            })?;
+
+        Ok(results
+            .into_iter()
+            .map(|m| m.into_auto_grade_run())
+            .collect())
+    }
+
+    /// 领取评测任务：仅当记录仍为等待状态时改为运行中，返回是否领取成功
+    pub async fn claim_auto_grade_run_impl(&self, run_id: i64) -> Result<bool> {
+        let result = AutoGradeRuns::update_many()
+            .col_expr(Column::Status, Expr::value(AutoGradeRunStatus::RUNNING))
+            .col_expr(
+                Column::StartedAt,
+                Expr::value(chrono::Utc::now().timestamp()),
+            )
+            .filter(Column::Id.eq(run_id))
+            .filter(Column::Status.eq(AutoGradeRunStatus::PENDING))
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                HWSystemError::database_operation(format!("更新自动评测记录失败: {e}"))
+            })?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    /// 将运行中的评测重置为等待状态（服务重启后重新评测）
+    pub async fn reset_running_auto_grade_runs_impl(&self) -> Result<u64> {
+        let result = AutoGradeRuns::update_many()
+            .col_expr(Column::Status, Expr::value(AutoGradeRunStatus::PENDING))
+            .col_expr(Column::StartedAt, Expr::value(Option::<i64>::None))
+            .filter(Column::Status.eq(AutoGradeRunStatus::RUNNING))
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                HWSystemError::database_operation(format!("更新自动评测记录失败: {e}"))
+            })?;
+
+        Ok(result.rows_affected)
+    }
+
+    /// 重新评测：清除上次结果并改为等待状态
+    pub async fn requeue_auto_grade_run_impl(
+        &self,
+        run_id: i64,
+        grader: &str,
+    ) -> Result<Option<AutoGradeRun>> {
+        let model = ActiveModel {
+            id: Set(run_id),
+            grader: Set(grader.to_string()),
+            status: Set(AutoGradeRunStatus::PENDING.to_string()),
+            score: Set(None),
+            feedback: Set(None),
+            tests: Set(None),
+            error: Set(None),
+            created_at: Set(chrono::Utc::now().timestamp()),
+            started_at: Set(None),
+            finished_at: Set(None),
+            ..Default::default()
+        };
+
+        match model.update(&self.db).await {
+            Ok(updated) => Ok(Some(updated.into_auto_grade_run())),
+            Err(e) => {
+                // SeaORM 的 RecordNotUpdated 错误表示记录不存在
+                if e.to_string().contains("RecordNotUpdated") {
+                    Ok(None)
+                } else {
+                    Err(HWSystemError::database_operation(format!(
+                        "更新自动评测记录失败: {e}"
+                    )))
+                }
+            }
+        }
+    }
+
+    /// 评测失败
+    pub async fn fail_auto_grade_run_impl(&self, run_id: i64, error: String) -> Result<bool> {
+        let model = ActiveModel {
+            id: Set(run_id),
+            status: Set(AutoGradeRunStatus::FAILED.to_string()),
+            error: Set(Some(error)),
+            finished_at: Set(Some(chrono::Utc::now().timestamp())),
+            ..Default::default()
+        };
+
+        match model.update(&self.db).await {
+            Ok(_) => Ok(true),
+            Err(e) if e.to_string().contains("RecordNotUpdated") => Ok(false),
+            Err(e) => Err(HWSystemError::database_operation(format!(
+                "更新自动评测记录失败: {e}"
+            ))),
+        }
+    }
+
+    /// 评测完成：保存结果并生成待确认的评分（使用事务保护）
+    ///
+    /// 已有评分且已确认（或由教师批阅）时不覆盖，返回 None。
+    pub async fn complete_auto_grade_run_impl(
+        &self,
+        run_id: i64,
+        result: AutoGradeResult,
+    ) -> Result<Option<Grade>> {
+        let Some(run) = AutoGradeRuns::find_by_id(run_id)
+            .one(&self.db)
+            .await
+            .map_err(|e| {
+                HWSystemError::database_operation(format!("查询自动评测记录失败: {e}"))
+            })?
+        else {
+            return Ok(None);
+        };
+        let submission_id = run.submission_id;
+
+        let Some(submission) = Submissions::find_by_id(submission_id)
+            .one(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询提交失败: {e}")))?
+        else {
+            return Ok(None);
+        };
+        let Some(homework) = Homeworks::find_by_id(submission.homework_id)
+            .one(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询作业失败: {e}")))?
+        else {
+            return Ok(None);
+        };
+
+        let penalty = self
+            .calculate_late_penalty(submission_id, result.score)
+            .await?;
+
+        let existing = Grades::find()
+            .filter(GradeColumn::SubmissionId.eq(submission_id))
+            .one(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询评分失败: {e}")))?;
+
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("开启事务失败: {e}")))?;
+
+        let now = chrono::Utc::now().timestamp();
+
+        let model = ActiveModel {
+            id: Set(run_id),
+            status: Set(AutoGradeRunStatus::SUCCEEDED.to_string()),
+            score: Set(Some(result.score)),
+            feedback: Set(result.feedback.clone()),
+            tests: Set(serde_json::to_string(&result.tests).ok()),
+            error: Set(None),
+            finished_at: Set(Some(now)),
+            ..Default::default()
+        };
+        model.update(&txn).await.map_err(|e| {
+            HWSystemError::database_operation(format!("更新自动评测记录失败: {e}"))
+        })?;
+
+        let grade = match existing {
+            // 教师已确认或已批阅的评分保持不变
+            Some(existing) if !existing.pending_review => None,
+            Some(existing) => {
+                let model = GradeActiveModel {
+                    id: Set(existing.id),
+                    score: Set(result.score),
+                    penalty: Set(penalty),
+                    comment: Set(result.feedback),
+                    rubric_scores: Set(None),
+                    updated_at: Set(now),
+                    ..Default::default()
+                };
+                Some(model.update(&txn).await.map_err(|e| {
+                    HWSystemError::database_operation(format!("更新评分失败: {e}"))
+                })?)
+            }
+            None => {
+                let model = GradeActiveModel {
+                    submission_id: Set(submission_id),
+                    grader_id: Set(homework.created_by),
+                    score: Set(result.score),
+                    penalty: Set(penalty),
+                    comment: Set(result.feedback),
+                    pending_review: Set(true),
+                    graded_at: Set(now),
+                    updated_at: Set(now),
+                    ..Default::default()
+                };
+                Some(model.insert(&txn).await.map_err(|e| {
+                    HWSystemError::database_operation(format!("创建评分失败: {e}"))
+                })?)
+            }
+        };
+
+        txn.commit()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("提交事务失败: {e}")))?;
+
+        Ok(grade.map(|g| g.into_grade()))
+    }
+}
@@ -0,0 +1,274 @@
+//! 班级分组存储操作
+
+use std::collections::HashMap;
+
+use super::SeaOrmStorage;
+use crate::entity::class_groups::{ActiveModel, Column, Entity as ClassGroups};
+use crate::entity::class_users::{Column as ClassUserColumn, Entity as ClassUsers};
+use crate::entity::homework_groups::{
+    ActiveModel as HomeworkGroupActiveModel, Column as HomeworkGroupColumn,
+    Entity as HomeworkGroups,
+};
+use crate::errors::{HWSystemError, Result};
+use crate::models::class_groups::{
+    entities::ClassGroup,
+    requests::{CreateClassGroupRequest, UpdateClassGroupRequest},
+};
+use crate::models::class_users::entities::ClassUserRole;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set, TransactionTrait,
+    sea_query::{Expr, Value},
+};
+
+impl SeaOrmStorage {
+    /// 创建班级分组
+    pub async fn create_class_group_impl(
+        &self,
+        class_id: i64,
+        req: CreateClassGroupRequest,
+    ) -> Result<ClassGroup> {
+        let now = chrono::Utc::now().timestamp();
+
+        let model = ActiveModel {
+            class_id: Set(class_id),
+            name: Set(req.name),
+            description: Set(req.description),
+            ta_id: Set(req.ta_id),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        let result = model
+            .insert(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("创建分组失败: {e}")))?;
+
+        Ok(result.into_class_group())
+    }
+
+    /// 通过 ID 获取班级分组
+    pub async fn get_class_group_by_id_impl(&self, group_id: i64) -> Result<Option<ClassGroup>> {
+        let result = ClassGroups::find_by_id(group_id)
+            .one(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询分组失败: {e}")))?;
+
+        Ok(result.map(|m| m.into_class_group()))
+    }
+
+    /// 列出班级所有分组
+    pub async fn list_class_groups_impl(&self, class_id: i64) -> Result<Vec<ClassGroup>> {
+        let result = ClassGroups::find()
+            .filter(Column::ClassId.eq(class_id))
+            .order_by_asc(Column::Name)
+            .all(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询分组列表失败: {e}")))?;
+
+        Ok(result.into_iter().map(|m| m.into_class_group()).collect())
+    }
+
+    /// 更新班级分组
+    pub async fn update_class_group_impl(
+        &self,
+        group_id: i64,
+        update: UpdateClassGroupRequest,
+    ) -> Result<Option<ClassGroup>> {
+        if self.get_class_group_by_id_impl(group_id).await?.is_none() {
+            return Ok(None);
+        }
+
+        let mut model = ActiveModel {
+            id: Set(group_id),
+            updated_at: Set(chrono::Utc::now().timestamp()),
+            ..Default::default()
+        };
+
+        if let Some(name) = update.name {
+            model.name = Set(name);
+        }
+
+        if let Some(description) = update.description {
+            model.description = Set(Some(description));
+        }
+
+        if let Some(ta_id) = update.ta_id {
+            model.ta_id = Set(Some(ta_id));
+        }
+
+        let result = model
+            .update(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("更新分组失败: {e}")))?;
+
+        Ok(Some(result.into_class_group()))
+    }
+
+    /// 删除班级分组（使用事务保护，同时清空成员的分组归属）
+    pub async fn delete_class_group_impl(&self, group_id: i64) -> Result<bool> {
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("开启事务失败: {e}")))?;
+
+        ClassUsers::update_many()
+            .col_expr(ClassUserColumn::GroupId, Expr::value(Value::BigInt(None)))
+            .filter(ClassUserColumn::GroupId.eq(group_id))
+            .exec(&txn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("清空分组成员失败: {e}")))?;
+
+        HomeworkGroups::delete_many()
+            .filter(HomeworkGroupColumn::GroupId.eq(group_id))
+            .exec(&txn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("删除作业分组关联失败: {e}")))?;
+
+        let result = ClassGroups::delete_by_id(group_id)
+            .exec(&txn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("删除分组失败: {e}")))?;
+
+        txn.commit()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("提交事务失败: {e}")))?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    /// 统计班级各分组的成员数量（group_id -> count）
+    pub async fn count_class_group_members_impl(&self, class_id: i64) -> Result<HashMap<i64, i64>> {
+        let rows: Vec<(Option<i64>, i64)> = ClassUsers::find()
+            .filter(ClassUserColumn::ClassId.eq(class_id))
+            .filter(ClassUserColumn::GroupId.is_not_null())
+            .select_only()
+            .column(ClassUserColumn::GroupId)
+            .column_as(ClassUserColumn::Id.count(), "member_count")
+            .group_by(ClassUserColumn::GroupId)
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("统计分组成员失败: {e}")))?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(group_id, count)| group_id.map(|id| (id, count)))
+            .collect())
+    }
+
+    /// 批量设置班级成员的分组（group_id 为 None 表示移出分组）
+    ///
+    /// 仅处理该班级中的非教师成员，返回实际更新的用户 ID 列表。
+    pub async fn set_class_group_members_impl(
+        &self,
+        class_id: i64,
+        group_id: Option<i64>,
+        user_ids: &[i64],
+    ) -> Result<Vec<i64>> {
+        if user_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("开启事务失败: {e}")))?;
+
+        let target_user_ids: Vec<i64> = ClassUsers::find()
+            .filter(ClassUserColumn::ClassId.eq(class_id))
+            .filter(ClassUserColumn::UserId.is_in(user_ids.to_vec()))
+            .filter(ClassUserColumn::Role.ne(ClassUserRole::TEACHER))
+            .select_only()
+            .column(ClassUserColumn::UserId)
+            .into_tuple()
+            .all(&txn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询班级成员失败: {e}")))?;
+
+        if !target_user_ids.is_empty() {
+            ClassUsers::update_many()
+                .col_expr(ClassUserColumn::GroupId, Expr::value(group_id))
+                .filter(ClassUserColumn::ClassId.eq(class_id))
+                .filter(ClassUserColumn::UserId.is_in(target_user_ids.clone()))
+                .exec(&txn)
+                .await
+                .map_err(|e| HWSystemError::database_operation(format!("更新成员分组失败: {e}")))?;
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("提交事务失败: {e}")))?;
+
+        Ok(target_user_ids)
+    }
+
+    /// 获取指定分组中的学生 user_id 列表（排除教师角色）
+    pub async fn list_class_group_student_ids_impl(&self, group_ids: &[i64]) -> Result<Vec<i64>> {
+        if group_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        ClassUsers::find()
+            .filter(ClassUserColumn::GroupId.is_in(group_ids.to_vec()))
+            .filter(ClassUserColumn::Role.ne(ClassUserRole::TEACHER))
+            .select_only()
+            .column(ClassUserColumn::UserId)
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询分组成员失败: {e}")))
+    }
+
+    /// 批量获取作业分配的分组 ID（homework_id -> group_ids）
+    pub async fn get_homework_group_ids_map(
+        &self,
+        homework_ids: &[i64],
+    ) -> Result<HashMap<i64, Vec<i64>>> {
+        if homework_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let rows = HomeworkGroups::find()
+            .filter(HomeworkGroupColumn::HomeworkId.is_in(homework_ids.to_vec()))
+            .order_by_asc(HomeworkGroupColumn::GroupId)
+            .all(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询作业分组失败: {e}")))?;
+
+        let mut map: HashMap<i64, Vec<i64>> = HashMap::new();
+        for row in rows {
+            map.entry(row.homework_id).or_default().push(row.group_id);
+        }
+        Ok(map)
+    }
+
+    /// 设置作业分配的分组（事务版本，空列表表示面向全班）
+    pub async fn set_homework_groups_txn<C: ConnectionTrait>(
+        &self,
+        conn: &C,
+        homework_id: i64,
+        group_ids: &[i64],
+    ) -> Result<()> {
+        HomeworkGroups::delete_many()
+            .filter(HomeworkGroupColumn::HomeworkId.eq(homework_id))
+            .exec(conn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("删除旧作业分组失败: {e}")))?;
+
+        for &group_id in group_ids {
+            HomeworkGroupActiveModel {
+                homework_id: Set(homework_id),
+                group_id: Set(group_id),
+            }
+            .insert(conn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("创建作业分组关联失败: {e}")))?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,193 @@
+//! 加入班级申请存储操作
+
+use super::SeaOrmStorage;
+use crate::entity::class_join_requests::{ActiveModel, Column, Entity as ClassJoinRequests};
+use crate::entity::class_users::{
+    ActiveModel as ClassUserActiveModel, Column as ClassUserColumn, Entity as ClassUsers,
+};
+use crate::errors::{HWSystemError, Result};
+use crate::models::{
+    PaginationInfo,
+    class_users::{
+        entities::{ClassJoinRequest, ClassJoinRequestStatus, ClassUserRole},
+        requests::ClassJoinRequestListQuery,
+        responses::ClassJoinRequestListResponse,
+    },
+};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, Set,
+    TransactionTrait,
+};
+
+impl SeaOrmStorage {
+    /// 创建加入班级申请
+    pub async fn create_class_join_request_impl(
+        &self,
+        class_id: i64,
+        user_id: i64,
+        message: Option<String>,
+    ) -> Result<ClassJoinRequest> {
+        let now = chrono::Utc::now().timestamp();
+
+        let model = ActiveModel {
+            class_id: Set(class_id),
+            user_id: Set(user_id),
+            status: Set(ClassJoinRequestStatus::PENDING.to_string()),
+            message: Set(message),
+            created_at: Set(now),
+            ..Default::default()
+        };
+
+        let result = model
+            .insert(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("创建加入申请失败: {e}")))?;
+
+        Ok(result.into_class_join_request())
+    }
+
+    /// 获取用户在班级中待审批的申请
+    pub async fn get_pending_class_join_request_impl(
+        &self,
+        class_id: i64,
+        user_id: i64,
+    ) -> Result<Option<ClassJoinRequest>> {
+        let result = ClassJoinRequests::find()
+            .filter(Column::ClassId.eq(class_id))
+            .filter(Column::UserId.eq(user_id))
+            .filter(Column::Status.eq(ClassJoinRequestStatus::PENDING))
+            .one(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询加入申请失败: {e}")))?;
+
+        Ok(result.map(|m| m.into_class_join_request()))
+    }
+
+    /// 分页列出班级的加入申请
+    pub async fn list_class_join_requests_with_pagination_impl(
+        &self,
+        class_id: i64,
+        query: ClassJoinRequestListQuery,
+    ) -> Result<ClassJoinRequestListResponse> {
+        let (page, page_size) = query.pagination.normalized();
+        let status = query.status.unwrap_or(ClassJoinRequestStatus::Pending);
+
+        let select = ClassJoinRequests::find()
+            .filter(Column::ClassId.eq(class_id))
+            .filter(Column::Status.eq(status.to_string()))
+            .order_by_desc(Column::CreatedAt);
+
+        let paginator = select.paginate(&self.db, page_size);
+        let total = paginator
+            .num_items()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询加入申请总数失败: {e}")))?;
+
+        let pages = paginator
+            .num_pages()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询加入申请页数失败: {e}")))?;
+
+        let requests = paginator
+            .fetch_page(page - 1)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询加入申请列表失败: {e}")))?;
+
+        Ok(ClassJoinRequestListResponse {
+            items: requests
+                .into_iter()
+                .map(|m| m.into_class_join_request())
+                .collect(),
+            pagination: PaginationInfo {
+                page: page as i64,
+                page_size: page_size as i64,
+                total: total as i64,
+                total_pages: pages as i64,
+            },
+        })
+    }
+
+    /// 批量审批加入申请（使用事务保护）
+    ///
+    /// 仅处理属于该班级且仍处于待审批状态的申请，返回实际处理的申请列表。
+    /// 通过时同时创建学生角色的班级成员记录（已是成员则跳过创建）。
+    pub async fn handle_class_join_requests_impl(
+        &self,
+        class_id: i64,
+        request_ids: &[i64],
+        approve: bool,
+        handler_id: i64,
+    ) -> Result<Vec<ClassJoinRequest>> {
+        if request_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("开启事务失败: {e}")))?;
+
+        let pending = ClassJoinRequests::find()
+            .filter(Column::ClassId.eq(class_id))
+            .filter(Column::Id.is_in(request_ids.to_vec()))
+            .filter(Column::Status.eq(ClassJoinRequestStatus::PENDING))
+            .all(&txn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询加入申请失败: {e}")))?;
+
+        let now = chrono::Utc::now().timestamp();
+        let new_status = if approve {
+            ClassJoinRequestStatus::Approved
+        } else {
+            ClassJoinRequestStatus::Rejected
+        };
+
+        let mut handled = Vec::with_capacity(pending.len());
+        for request in pending {
+            if approve {
+                let exists = ClassUsers::find()
+                    .filter(ClassUserColumn::ClassId.eq(class_id))
+                    .filter(ClassUserColumn::UserId.eq(request.user_id))
+                    .one(&txn)
+                    .await
+                    .map_err(|e| {
+                        HWSystemError::database_operation(format!("查询班级用户失败: {e}"))
+                    })?
+                    .is_some();
+
+                if !exists {
+                    ClassUserActiveModel {
+                        class_id: Set(class_id),
+                        user_id: Set(request.user_id),
+                        role: Set(ClassUserRole::Student.to_string()),
+                        joined_at: Set(now),
+                        ..Default::default()
+                    }
+                    .insert(&txn)
+                    .await
+                    .map_err(|e| HWSystemError::database_operation(format!("加入班级失败: {e}")))?;
+                }
+            }
+
+            let updated = ActiveModel {
+                id: Set(request.id),
+                status: Set(new_status.to_string()),
+                handled_by: Set(Some(handler_id)),
+                handled_at: Set(Some(now)),
+                ..Default::default()
+            }
+            .update(&txn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("更新加入申请失败: {e}")))?;
+
+            handled.push(updated.into_class_join_request());
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("提交事务失败: {e}")))?;
+
+        Ok(handled)
+    }
+}
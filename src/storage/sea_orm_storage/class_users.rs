@@ -126,6 +126,11 @@ impl SeaOrmStorage {
             select = select.filter(Column::Role.eq(role.to_string()));
         }
 
+        // 分组过滤
+        if let Some(group_id) = query.group_id {
+            select = select.filter(Column::GroupId.eq(group_id));
+        }
+
         // search 过滤 - 查 users 表的 username/display_name
         if let Some(ref search) = query.search
             && !search.trim().is_empty()
@@ -6,7 +6,7 @@ use crate::errors::{HWSystemError, Result};
 use crate::models::{
     PaginationInfo,
     classes::{
-        entities::Class,
+        entities::{Class, ClassJoinPolicy},
         requests::{ClassListQuery, CreateClassRequest, UpdateClassRequest},
         responses::ClassListResponse,
     },
@@ -32,6 +32,7 @@ impl SeaOrmStorage {
             name: Set(req.name),
             description: Set(req.description),
             invite_code: Set(invite_code),
+            join_policy: Set(req.join_policy.unwrap_or(ClassJoinPolicy::Open).to_string()),
             created_at: Set(now),
             updated_at: Set(now),
             ..Default::default()
@@ -141,6 +142,10 @@ impl SeaOrmStorage {
             model.description = Set(Some(description));
         }
 
+        if let Some(join_policy) = update.join_policy {
+            model.join_policy = Set(join_policy.to_string());
+        }
+
         match model.update(&self.db).await {
             Ok(updated) => Ok(Some(updated.into_class())),
             Err(e) => {
@@ -0,0 +1,180 @@
+//! 讨论评论存储操作
+
+use std::collections::HashMap;
+
+use super::SeaOrmStorage;
+use crate::entity::comments::{ActiveModel, Column, Entity as Comments};
+use crate::errors::{HWSystemError, Result};
+use crate::models::comments::{
+    entities::{Comment, CommentTargetType},
+    requests::CreateCommentRequest,
+};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set, TransactionTrait,
+};
+
+impl SeaOrmStorage {
+    /// 发表评论
+    pub async fn create_comment_impl(
+        &self,
+        target_type: CommentTargetType,
+        target_id: i64,
+        class_id: i64,
+        user_id: i64,
+        req: CreateCommentRequest,
+    ) -> Result<Comment> {
+        let now = chrono::Utc::now().timestamp();
+
+        let model = ActiveModel {
+            target_type: Set(target_type.to_string()),
+            target_id: Set(target_id),
+            class_id: Set(class_id),
+            parent_id: Set(req.parent_id),
+            user_id: Set(user_id),
+            content: Set(req.content),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        let result = model
+            .insert(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("发表评论失败: {e}")))?;
+
+        Ok(result.into_comment())
+    }
+
+    /// 通过 ID 获取评论
+    pub async fn get_comment_by_id_impl(&self, comment_id: i64) -> Result<Option<Comment>> {
+        let result = Comments::find_by_id(comment_id)
+            .one(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询评论失败: {e}")))?;
+
+        Ok(result.map(|m| m.into_comment()))
+    }
+
+    /// 列出目标下的所有评论（按发表时间排序）
+    pub async fn list_comments_impl(
+        &self,
+        target_type: CommentTargetType,
+        target_id: i64,
+    ) -> Result<Vec<Comment>> {
+        let results = Comments::find()
+            .filter(Column::TargetType.eq(target_type.to_string()))
+            .filter(Column::TargetId.eq(target_id))
+            .order_by_asc(Column::CreatedAt)
+            .order_by_asc(Column::Id)
+            .all(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询评论列表失败: {e}")))?;
+
+        Ok(results.into_iter().map(|m| m.into_comment()).collect())
+    }
+
+    /// 编辑评论内容
+    pub async fn update_comment_impl(
+        &self,
+        comment_id: i64,
+        content: String,
+    ) -> Result<Option<Comment>> {
+        if self.get_comment_by_id_impl(comment_id).await?.is_none() {
+            return Ok(None);
+        }
+
+        let model = ActiveModel {
+            id: Set(comment_id),
+            content: Set(content),
+            updated_at: Set(chrono::Utc::now().timestamp()),
+            ..Default::default()
+        };
+
+        let result = model
+            .update(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("编辑评论失败: {e}")))?;
+
+        Ok(Some(result.into_comment()))
+    }
+
+    /// 删除评论及其所有回复（使用事务保护），返回删除的评论数量
+    pub async fn delete_comment_impl(&self, comment_id: i64) -> Result<u64> {
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("开启事务失败: {e}")))?;
+
+        // 逐层收集回复
+        let mut to_delete = vec![comment_id];
+        let mut frontier = vec![comment_id];
+        while !frontier.is_empty() {
+            let children: Vec<i64> = Comments::find()
+                .filter(Column::ParentId.is_in(frontier))
+                .select_only()
+                .column(Column::Id)
+                .into_tuple()
+                .all(&txn)
+                .await
+                .map_err(|e| HWSystemError::database_operation(format!("查询评论回复失败: {e}")))?;
+            to_delete.extend(&children);
+            frontier = children;
+        }
+
+        let result = Comments::delete_many()
+            .filter(Column::Id.is_in(to_delete))
+            .exec(&txn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("删除评论失败: {e}")))?;
+
+        txn.commit()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("提交事务失败: {e}")))?;
+
+        Ok(result.rows_affected)
+    }
+
+    /// 批量统计目标的评论数量（target_id -> count）
+    pub async fn count_comments_impl(
+        &self,
+        target_type: CommentTargetType,
+        target_ids: &[i64],
+    ) -> Result<HashMap<i64, i64>> {
+        if target_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let rows: Vec<(i64, i64)> = Comments::find()
+            .filter(Column::TargetType.eq(target_type.to_string()))
+            .filter(Column::TargetId.is_in(target_ids.to_vec()))
+            .select_only()
+            .column(Column::TargetId)
+            .column_as(Column::Id.count(), "comment_count")
+            .group_by(Column::TargetId)
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("统计评论数量失败: {e}")))?;
+
+        Ok(rows.into_iter().collect())
+    }
+
+    /// 删除目标下的所有评论（事务版本，删除公告或作业时调用）
+    pub async fn delete_comments_by_target_txn<C: ConnectionTrait>(
+        &self,
+        conn: &C,
+        target_type: CommentTargetType,
+        target_id: i64,
+    ) -> Result<()> {
+        Comments::delete_many()
+            .filter(Column::TargetType.eq(target_type.to_string()))
+            .filter(Column::TargetId.eq(target_id))
+            .exec(conn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("删除讨论失败: {e}")))?;
+
+        Ok(())
+    }
+}
@@ -4,7 +4,8 @@ use std::collections::HashMap;
 
 use super::SeaOrmStorage;
 use crate::entity::grades::{ActiveModel, Column, Entity as Grades};
-use crate::entity::submissions::Column as SubmissionColumn;
+use crate::entity::homeworks::Entity as Homeworks;
+use crate::entity::submissions::{Column as SubmissionColumn, Entity as Submissions};
 use crate::errors::{HWSystemError, Result};
 use crate::models::{
     PaginationInfo,
@@ -13,6 +14,8 @@ use crate::models::{
         requests::{CreateGradeRequest, GradeListQuery, UpdateGradeRequest},
         responses::GradeListResponse,
     },
+    homeworks::entities::LatePenalty,
+    rubrics::entities::RubricScore,
     submissions::entities::SubmissionStatus,
 };
 use sea_orm::{
@@ -26,7 +29,17 @@ impl SeaOrmStorage {
         &self,
         grader_id: i64,
         req: CreateGradeRequest,
+        rubric_scores: Vec<RubricScore>,
     ) -> Result<Grade> {
+        let score = if rubric_scores.is_empty() {
+            req.score.unwrap_or(0.0)
+        } else {
+            rubric_total(&rubric_scores)
+        };
+        let penalty = self
+            .calculate_late_penalty(req.submission_id, score)
+            .await?;
+
         let txn = self
             .db
             .begin()
@@ -38,8 +51,11 @@ impl SeaOrmStorage {
         let model = ActiveModel {
             submission_id: Set(req.submission_id),
             grader_id: Set(grader_id),
-            score: Set(req.score),
+            score: Set(score),
+            penalty: Set(penalty),
+            score_override: Set(req.score_override),
             comment: Set(req.comment),
+            rubric_scores: Set(encode_rubric_scores(&rubric_scores)),
             graded_at: Set(now),
             updated_at: Set(now),
             ..Default::default()
@@ -90,7 +106,16 @@ impl SeaOrmStorage {
         &self,
         grade_id: i64,
         update: UpdateGradeRequest,
+        rubric_scores: Option<Vec<RubricScore>>,
     ) -> Result<Option<Grade>> {
+        let Some(existing) = Grades::find_by_id(grade_id)
+            .one(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询评分失败: {e}")))?
+        else {
+            return Ok(None);
+        };
+
         let now = chrono::Utc::now().timestamp();
 
         let mut model = ActiveModel {
@@ -99,8 +124,35 @@ impl SeaOrmStorage {
             ..Default::default()
         };
 
-        if let Some(score) = update.score {
+        // 按评分细则评分时原始分数取各评分项得分之和
+        let mut score = update.score;
+        if let Some(rubric_scores) = rubric_scores {
+            if !rubric_scores.is_empty() {
+                score = Some(rubric_total(&rubric_scores));
+            }
+            model.rubric_scores = Set(encode_rubric_scores(&rubric_scores));
+        }
+
+        // 原始分数变化时按迟交规则重新计算扣分
+        if let Some(score) = score {
             model.score = Set(score);
+            model.penalty = Set(self
+                .calculate_late_penalty(existing.submission_id, score)
+                .await?);
+            // 教师直接给出总分视为批阅完成
+            if existing.pending_review {
+                model.pending_review = Set(false);
+                self.update_submission_status_txn(
+                    &self.db,
+                    existing.submission_id,
+                    SubmissionStatus::GRADED,
+                )
+                .await?;
+            }
+        }
+
+        if let Some(score_override) = update.score_override {
+            model.score_override = Set(score_override);
         }
 
         if let Some(comment) = update.comment {
@@ -122,6 +174,43 @@ impl SeaOrmStorage {
         }
     }
 
+    /// 确认待批阅的评分（使用事务保护）
+    pub async fn confirm_grade_impl(&self, grade_id: i64) -> Result<Option<Grade>> {
+        let Some(existing) = Grades::find_by_id(grade_id)
+            .one(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询评分失败: {e}")))?
+        else {
+            return Ok(None);
+        };
+
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("开启事务失败: {e}")))?;
+
+        let model = ActiveModel {
+            id: Set(grade_id),
+            pending_review: Set(false),
+            updated_at: Set(chrono::Utc::now().timestamp()),
+            ..Default::default()
+        };
+        let updated = model
+            .update(&txn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("更新评分失败: {e}")))?;
+
+        self.update_submission_status_txn(&txn, existing.submission_id, SubmissionStatus::GRADED)
+            .await?;
+
+        txn.commit()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("提交事务失败: {e}")))?;
+
+        Ok(Some(updated.into_grade()))
+    }
+
     /// 列出评分（分页）
     pub async fn list_grades_with_pagination_impl(
         &self,
@@ -202,4 +291,96 @@ impl SeaOrmStorage {
             .map(|g| (g.submission_id, g.into_grade()))
             .collect())
     }
+
+    /// 按作业的迟交扣分规则计算提交的扣分
+    ///
+    /// 迟交时长按学生的有效截止时间（含延期）计算。
+    pub(super) async fn calculate_late_penalty(
+        &self,
+        submission_id: i64,
+        score: f64,
+    ) -> Result<f64> {
+        let Some(submission) = Submissions::find_by_id(submission_id)
+            .one(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询提交失败: {e}")))?
+        else {
+            return Ok(0.0);
+        };
+
+        let Some(homework) = Homeworks::find_by_id(submission.homework_id)
+            .one(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询作业失败: {e}")))?
+        else {
+            return Ok(0.0);
+        };
+
+        let Some(penalty) = homework
+            .late_penalty
+            .as_deref()
+            .and_then(|s| serde_json::from_str::<LatePenalty>(s).ok())
+        else {
+            return Ok(0.0);
+        };
+
+        let extended = self
+            .load_user_extended_deadlines(submission.creator_id, Some(&[homework.id]))
+            .await?
+            .remove(&homework.id)
+            .map(|d| d.timestamp());
+        let Some(deadline) = extended.or(homework.deadline) else {
+            return Ok(0.0);
+        };
+
+        Ok(penalty.penalty(score, submission.submitted_at - deadline))
+    }
+
+    /// 重新计算作业下所有评分的迟交扣分（迟交规则、截止时间或延期变化后调用）
+    pub(super) async fn refresh_grade_penalties(&self, homework_id: i64) -> Result<()> {
+        let grades = Grades::find()
+            .join(
+                JoinType::InnerJoin,
+                crate::entity::grades::Relation::Submission.def(),
+            )
+            .filter(SubmissionColumn::HomeworkId.eq(homework_id))
+            .all(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询评分列表失败: {e}")))?;
+
+        for grade in grades {
+            let penalty = self
+                .calculate_late_penalty(grade.submission_id, grade.score)
+                .await?;
+            if penalty == grade.penalty {
+                continue;
+            }
+
+            ActiveModel {
+                id: Set(grade.id),
+                penalty: Set(penalty),
+                ..Default::default()
+            }
+            .update(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("更新迟交扣分失败: {e}")))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// 评分细则各评分项得分之和
+fn rubric_total(rubric_scores: &[RubricScore]) -> f64 {
+    let total: f64 = rubric_scores.iter().map(|s| s.points).sum();
+    (total * 100.0).round() / 100.0
+}
+
+/// 序列化评分明细，为空时存储 NULL
+fn encode_rubric_scores(rubric_scores: &[RubricScore]) -> Option<String> {
+    if rubric_scores.is_empty() {
+        None
+    } else {
+        serde_json::to_string(rubric_scores).ok()
+    }
 }
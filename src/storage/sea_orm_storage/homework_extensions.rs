@@ -0,0 +1,194 @@
+//! 作业延期存储操作
+
+use std::collections::HashMap;
+
+use super::SeaOrmStorage;
+use crate::entity::class_users::{Column as ClassUserColumn, Entity as ClassUsers};
+use crate::entity::homework_extensions::{ActiveModel, Column, Entity as HomeworkExtensions};
+use crate::errors::{HWSystemError, Result};
+use crate::models::homework_extensions::{
+    entities::HomeworkExtension, requests::SetHomeworkExtensionRequest,
+};
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+    Set,
+};
+
+impl SeaOrmStorage {
+    /// 设置作业延期（同一学生或分组已有延期时覆盖）
+    pub async fn set_homework_extension_impl(
+        &self,
+        homework_id: i64,
+        req: SetHomeworkExtensionRequest,
+        created_by: i64,
+    ) -> Result<HomeworkExtension> {
+        let now = chrono::Utc::now().timestamp();
+
+        let mut select = HomeworkExtensions::find().filter(Column::HomeworkId.eq(homework_id));
+        select = match (req.user_id, req.group_id) {
+            (Some(user_id), _) => select.filter(Column::UserId.eq(user_id)),
+            (None, Some(group_id)) => select.filter(Column::GroupId.eq(group_id)),
+            (None, None) => {
+                return Err(HWSystemError::validation("延期必须指定学生或分组"));
+            }
+        };
+
+        let existing = select
+            .one(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询作业延期失败: {e}")))?;
+
+        let result = match existing {
+            Some(model) => {
+                let mut model: ActiveModel = model.into();
+                model.deadline = Set(req.deadline.timestamp());
+                model.reason = Set(req.reason);
+                model.created_by = Set(created_by);
+                model.updated_at = Set(now);
+                model.update(&self.db).await.map_err(|e| {
+                    HWSystemError::database_operation(format!("更新作业延期失败: {e}"))
+                })?
+            }
+            None => {
+                let model = ActiveModel {
+                    homework_id: Set(homework_id),
+                    user_id: Set(req.user_id),
+                    group_id: Set(req.group_id),
+                    deadline: Set(req.deadline.timestamp()),
+                    reason: Set(req.reason),
+                    created_by: Set(created_by),
+                    created_at: Set(now),
+                    updated_at: Set(now),
+                    ..Default::default()
+                };
+                model.insert(&self.db).await.map_err(|e| {
+                    HWSystemError::database_operation(format!("创建作业延期失败: {e}"))
+                })?
+            }
+        };
+
+        // 有效截止时间变化，重新计算迟交扣分
+        self.refresh_grade_penalties(homework_id).await?;
+
+        Ok(result.into_homework_extension())
+    }
+
+    /// 通过 ID 获取作业延期
+    pub async fn get_homework_extension_by_id_impl(
+        &self,
+        extension_id: i64,
+    ) -> Result<Option<HomeworkExtension>> {
+        let result = HomeworkExtensions::find_by_id(extension_id)
+            .one(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询作业延期失败: {e}")))?;
+
+        Ok(result.map(|m| m.into_homework_extension()))
+    }
+
+    /// 列出作业的所有延期（按截止时间排序）
+    pub async fn list_homework_extensions_impl(
+        &self,
+        homework_id: i64,
+    ) -> Result<Vec<HomeworkExtension>> {
+        let results = HomeworkExtensions::find()
+            .filter(Column::HomeworkId.eq(homework_id))
+            .order_by_asc(Column::Deadline)
+            .all(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询作业延期失败: {e}")))?;
+
+        Ok(results
+            .into_iter()
+            .map(|m| m.into_homework_extension())
+            .collect())
+    }
+
+    /// 删除作业延期
+    pub async fn delete_homework_extension_impl(&self, extension_id: i64) -> Result<bool> {
+        let Some(extension) = HomeworkExtensions::find_by_id(extension_id)
+            .one(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询作业延期失败: {e}")))?
+        else {
+            return Ok(false);
+        };
+
+        HomeworkExtensions::delete_by_id(extension_id)
+            .exec(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("删除作业延期失败: {e}")))?;
+
+        // 有效截止时间变化，重新计算迟交扣分
+        self.refresh_grade_penalties(extension.homework_id).await?;
+
+        Ok(true)
+    }
+
+    /// 获取学生在指定作业上的延期截止时间（个人延期优先于分组延期）
+    ///
+    /// 返回 homework_id -> 延期后的截止时间，没有延期的作业不在结果中。
+    pub async fn get_user_extended_deadlines_impl(
+        &self,
+        user_id: i64,
+        homework_ids: &[i64],
+    ) -> Result<HashMap<i64, DateTime<Utc>>> {
+        if homework_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+        self.load_user_extended_deadlines(user_id, Some(homework_ids))
+            .await
+    }
+
+    /// 加载学生的延期截止时间，`homework_ids` 为空时加载该学生的全部延期
+    pub(super) async fn load_user_extended_deadlines(
+        &self,
+        user_id: i64,
+        homework_ids: Option<&[i64]>,
+    ) -> Result<HashMap<i64, DateTime<Utc>>> {
+        // 学生所在的分组（每个班级至多一个）
+        let group_ids: Vec<i64> = ClassUsers::find()
+            .filter(ClassUserColumn::UserId.eq(user_id))
+            .filter(ClassUserColumn::GroupId.is_not_null())
+            .select_only()
+            .column(ClassUserColumn::GroupId)
+            .into_tuple::<Option<i64>>()
+            .all(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询学生分组失败: {e}")))?
+            .into_iter()
+            .flatten()
+            .collect();
+
+        let mut target = Condition::any().add(Column::UserId.eq(user_id));
+        if !group_ids.is_empty() {
+            target = target.add(Column::GroupId.is_in(group_ids));
+        }
+
+        let mut select = HomeworkExtensions::find().filter(target);
+        if let Some(homework_ids) = homework_ids {
+            select = select.filter(Column::HomeworkId.is_in(homework_ids.to_vec()));
+        }
+
+        let extensions = select
+            .all(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询作业延期失败: {e}")))?;
+
+        let mut deadlines: HashMap<i64, DateTime<Utc>> = HashMap::new();
+        // 先写入分组延期，再由个人延期覆盖
+        for ext in extensions
+            .iter()
+            .filter(|e| e.user_id.is_none())
+            .chain(extensions.iter().filter(|e| e.user_id.is_some()))
+        {
+            deadlines.insert(
+                ext.homework_id,
+                DateTime::<Utc>::from_timestamp(ext.deadline, 0).unwrap_or_default(),
+            );
+        }
+
+        Ok(deadlines)
+    }
+}
@@ -0,0 +1,320 @@
+//! 作业题目存储操作
+
+use std::collections::HashMap;
+
+use super::SeaOrmStorage;
+use crate::entity::grades::{
+    ActiveModel as GradeActiveModel, Column as GradeColumn, Entity as Grades,
+};
+use crate::entity::homework_question_files::{
+    Column as QuestionFileColumn, Entity as HomeworkQuestionFiles,
+};
+use crate::entity::homework_question_pools::{
+    Column as PoolColumn, Entity as HomeworkQuestionPools,
+};
+use crate::entity::homework_questions::{
+    ActiveModel as QuestionActiveModel, Column as QuestionColumn, Entity as HomeworkQuestions,
+};
+use crate::entity::homeworks::Entity as Homeworks;
+use crate::entity::submission_answers::{
+    ActiveModel as AnswerActiveModel, Column as AnswerColumn, Entity as SubmissionAnswers,
+};
+use crate::entity::submissions::{Column as SubmissionColumn, Entity as Submissions};
+use crate::errors::{HWSystemError, Result};
+use crate::models::{
+    files::responses::FileInfo,
+    grades::entities::Grade,
+    homework_questions::{
+        entities::{HomeworkQuestion, HomeworkQuestionPool, SubmissionAnswer},
+        requests::HomeworkQuestionInput,
+    },
+    submissions::entities::SubmissionStatus,
+};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, Set, TransactionTrait, sea_query::Expr,
+};
+
+impl SeaOrmStorage {
+    /// 整体替换作业题目（按传入顺序编号，同时清除随机抽题池）
+    pub async fn set_homework_questions_txn<C: ConnectionTrait>(
+        &self,
+        conn: &C,
+        homework_id: i64,
+        questions: Vec<HomeworkQuestionInput>,
+    ) -> Result<()> {
+        HomeworkQuestions::delete_many()
+            .filter(QuestionColumn::HomeworkId.eq(homework_id))
+            .exec(conn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("删除作业题目失败: {e}")))?;
+
+        HomeworkQuestionPools::delete_many()
+            .filter(PoolColumn::HomeworkId.eq(homework_id))
+            .exec(conn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("删除抽题池失败: {e}")))?;
+
+        for (position, question) in questions.into_iter().enumerate() {
+            let spec = serde_json::to_string(&question.spec)
+                .map_err(|e| HWSystemError::validation(format!("题目定义格式错误: {e}")))?;
+            let model = QuestionActiveModel {
+                homework_id: Set(homework_id),
+                position: Set(position as i32),
+                prompt: Set(question.prompt),
+                points: Set(question.points),
+                spec: Set(spec),
+                ..Default::default()
+            };
+            model
+                .insert(conn)
+                .await
+                .map_err(|e| HWSystemError::database_operation(format!("创建作业题目失败: {e}")))?;
+        }
+        Ok(())
+    }
+
+    /// 列出作业题目（按顺序排列，包含附件）
+    pub async fn list_homework_questions_impl(
+        &self,
+        homework_id: i64,
+    ) -> Result<Vec<HomeworkQuestion>> {
+        let models = HomeworkQuestions::find()
+            .filter(QuestionColumn::HomeworkId.eq(homework_id))
+            .order_by_asc(QuestionColumn::Position)
+            .order_by_asc(QuestionColumn::Id)
+            .all(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询作业题目失败: {e}")))?;
+
+        let question_ids: Vec<i64> = models.iter().map(|m| m.id).collect();
+        let file_links = if question_ids.is_empty() {
+            vec![]
+        } else {
+            HomeworkQuestionFiles::find()
+                .filter(QuestionFileColumn::QuestionId.is_in(question_ids))
+                .all(&self.db)
+                .await
+                .map_err(|e| HWSystemError::database_operation(format!("查询题目附件失败: {e}")))?
+        };
+        let file_ids: Vec<i64> = file_links.iter().map(|l| l.file_id).collect();
+        let files = self.get_files_by_ids_impl(&file_ids).await?;
+
+        let mut attachments: HashMap<i64, Vec<FileInfo>> = HashMap::new();
+        for link in file_links {
+            if let Some(file) = files.get(&link.file_id) {
+                attachments
+                    .entry(link.question_id)
+                    .or_default()
+                    .push(FileInfo {
+                        download_token: file.download_token.clone(),
+                        original_name: file.original_name.clone(),
+                        file_size: file.file_size,
+                        file_type: file.file_type.clone(),
+                    });
+            }
+        }
+
+        Ok(models
+            .into_iter()
+            .filter_map(|m| m.into_homework_question())
+            .map(|mut q| {
+                q.attachments = attachments.remove(&q.id).unwrap_or_default();
+                q
+            })
+            .collect())
+    }
+
+    /// 列出作业的随机抽题池
+    pub async fn list_homework_question_pools_impl(
+        &self,
+        homework_id: i64,
+    ) -> Result<Vec<HomeworkQuestionPool>> {
+        let models = HomeworkQuestionPools::find()
+            .filter(PoolColumn::HomeworkId.eq(homework_id))
+            .order_by_asc(PoolColumn::Id)
+            .all(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询抽题池失败: {e}")))?;
+
+        Ok(models.into_iter().map(|m| m.into_question_pool()).collect())
+    }
+
+    /// 作业是否已有提交
+    pub async fn has_homework_submissions_impl(&self, homework_id: i64) -> Result<bool> {
+        let count = Submissions::find()
+            .filter(SubmissionColumn::HomeworkId.eq(homework_id))
+            .count(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询提交数量失败: {e}")))?;
+        Ok(count > 0)
+    }
+
+    /// 写入提交的题目作答
+    pub async fn insert_submission_answers_txn<C: ConnectionTrait>(
+        &self,
+        conn: &C,
+        submission_id: i64,
+        answers: Vec<SubmissionAnswer>,
+    ) -> Result<()> {
+        for answer in answers {
+            let value = serde_json::to_string(&answer.answer)
+                .map_err(|e| HWSystemError::validation(format!("作答格式错误: {e}")))?;
+            let model = AnswerActiveModel {
+                submission_id: Set(submission_id),
+                question_id: Set(answer.question_id),
+                answer: Set(value),
+                is_correct: Set(answer.is_correct),
+                points: Set(answer.points),
+                ..Default::default()
+            };
+            model
+                .insert(conn)
+                .await
+                .map_err(|e| HWSystemError::database_operation(format!("保存题目作答失败: {e}")))?;
+        }
+        Ok(())
+    }
+
+    /// 批量获取提交的题目作答（submission_id -> 作答列表）
+    pub async fn list_submission_answers_impl(
+        &self,
+        submission_ids: &[i64],
+    ) -> Result<HashMap<i64, Vec<SubmissionAnswer>>> {
+        let mut map: HashMap<i64, Vec<SubmissionAnswer>> = HashMap::new();
+        if submission_ids.is_empty() {
+            return Ok(map);
+        }
+
+        let models = SubmissionAnswers::find()
+            .filter(AnswerColumn::SubmissionId.is_in(submission_ids.iter().copied()))
+            .order_by_asc(AnswerColumn::Id)
+            .all(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询题目作答失败: {e}")))?;
+
+        for model in models {
+            let submission_id = model.submission_id;
+            if let Some(answer) = model.into_submission_answer() {
+                map.entry(submission_id).or_default().push(answer);
+            }
+        }
+        Ok(map)
+    }
+
+    /// 设置题目作答得分（教师批阅）
+    pub async fn set_submission_answer_points_impl(
+        &self,
+        submission_id: i64,
+        scores: &[(i64, f64)],
+    ) -> Result<()> {
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("开启事务失败: {e}")))?;
+
+        for &(question_id, points) in scores {
+            SubmissionAnswers::update_many()
+                .col_expr(AnswerColumn::Points, Expr::value(Some(points)))
+                .filter(AnswerColumn::SubmissionId.eq(submission_id))
+                .filter(AnswerColumn::QuestionId.eq(question_id))
+                .exec(&txn)
+                .await
+                .map_err(|e| HWSystemError::database_operation(format!("更新题目得分失败: {e}")))?;
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("提交事务失败: {e}")))?;
+        Ok(())
+    }
+
+    /// 按题目作答得分生成或刷新评分
+    ///
+    /// 原始分数为各题得分之和，仍有未给分的作答时标记为待批阅；
+    /// 全部给分后提交状态更新为已评分。
+    pub async fn grade_submission_answers_impl(&self, submission_id: i64) -> Result<Option<Grade>> {
+        let Some(submission) = Submissions::find_by_id(submission_id)
+            .one(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询提交失败: {e}")))?
+        else {
+            return Ok(None);
+        };
+        let Some(homework) = Homeworks::find_by_id(submission.homework_id)
+            .one(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询作业失败: {e}")))?
+        else {
+            return Ok(None);
+        };
+
+        let answers = self
+            .list_submission_answers_impl(&[submission_id])
+            .await?
+            .remove(&submission_id)
+            .unwrap_or_default();
+        let score: f64 = answers.iter().filter_map(|a| a.points).sum();
+        let pending_review = answers.iter().any(|a| a.points.is_none());
+        let penalty = self.calculate_late_penalty(submission_id, score).await?;
+
+        let existing = Grades::find()
+            .filter(GradeColumn::SubmissionId.eq(submission_id))
+            .one(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询评分失败: {e}")))?;
+
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("开启事务失败: {e}")))?;
+
+        let now = chrono::Utc::now().timestamp();
+
+        let grade =
+            match existing {
+                Some(existing) => {
+                    let model = GradeActiveModel {
+                        id: Set(existing.id),
+                        score: Set(score),
+                        penalty: Set(penalty),
+                        rubric_scores: Set(None),
+                        pending_review: Set(pending_review),
+                        updated_at: Set(now),
+                        ..Default::default()
+                    };
+                    model.update(&txn).await.map_err(|e| {
+                        HWSystemError::database_operation(format!("更新评分失败: {e}"))
+                    })?
+                }
+                None => {
+                    let model = GradeActiveModel {
+                        submission_id: Set(submission_id),
+                        grader_id: Set(homework.created_by),
+                        score: Set(score),
+                        penalty: Set(penalty),
+                        pending_review: Set(pending_review),
+                        graded_at: Set(now),
+                        updated_at: Set(now),
+                        ..Default::default()
+                    };
+                    model.insert(&txn).await.map_err(|e| {
+                        HWSystemError::database_operation(format!("创建评分失败: {e}"))
+                    })?
+                }
+            };
+
+        if !pending_review {
+            self.update_submission_status_txn(&txn, submission_id, SubmissionStatus::GRADED)
+                .await?;
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("提交事务失败: {e}")))?;
+
+        Ok(Some(grade.into_grade()))
+    }
+}
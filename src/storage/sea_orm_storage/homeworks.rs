@@ -5,17 +5,22 @@ use std::collections::HashMap;
 use super::SeaOrmStorage;
 use crate::entity::class_users::{Column as ClassUserColumn, Entity as ClassUsers};
 use crate::entity::grades::{Column as GradeColumn, Entity as Grades};
+use crate::entity::homework_deadline_reminders::{
+    ActiveModel as HomeworkDeadlineReminderActiveModel, Entity as HomeworkDeadlineReminders,
+};
 use crate::entity::homework_files::{
     ActiveModel as HomeworkFileActiveModel, Column as HomeworkFileColumn, Entity as HomeworkFiles,
 };
+use crate::entity::homework_groups::{Column as HomeworkGroupColumn, Entity as HomeworkGroups};
 use crate::entity::homeworks::{ActiveModel, Column, Entity as Homeworks};
 use crate::entity::submissions::{Column as SubmissionColumn, Entity as Submissions};
 use crate::entity::users::{Column as UserColumn, Entity as Users};
 use crate::errors::{HWSystemError, Result};
 use crate::models::{
     PaginationInfo,
+    comments::entities::CommentTargetType,
     homeworks::{
-        entities::{DeadlineFilter, Homework, HomeworkUserStatus},
+        entities::{DeadlineFilter, Homework, HomeworkStatus, HomeworkUserStatus},
         requests::{
             AllHomeworksQuery, CreateHomeworkRequest, HomeworkListQuery, UpdateHomeworkRequest,
         },
@@ -55,6 +60,15 @@ impl SeaOrmStorage {
             max_score: Set(req.max_score.unwrap_or(100.0)),
             deadline: Set(req.deadline.map(|dt| dt.timestamp())),
             allow_late: Set(req.allow_late.unwrap_or(false)),
+            late_cutoff: Set(req.late_cutoff.map(|dt| dt.timestamp())),
+            late_penalty: Set(req
+                .late_penalty
+                .as_ref()
+                .and_then(|p| serde_json::to_string(p).ok())),
+            rubric_id: Set(req.rubric_id),
+            auto_grader: Set(req.auto_grader),
+            status: Set(req.status.unwrap_or(HomeworkStatus::Published).to_string()),
+            publish_at: Set(req.publish_at.map(|dt| dt.timestamp())),
             created_by: Set(created_by),
             created_at: Set(now),
             updated_at: Set(now),
@@ -72,11 +86,26 @@ impl SeaOrmStorage {
                 .await?;
         }
 
+        // 处理分配分组
+        let group_ids = req.group_ids.unwrap_or_default();
+        if !group_ids.is_empty() {
+            self.set_homework_groups_txn(&txn, result.id, &group_ids)
+                .await?;
+        }
+
+        // 处理题目
+        if let Some(questions) = req.questions {
+            self.set_homework_questions_txn(&txn, result.id, questions)
+                .await?;
+        }
+
         txn.commit()
             .await
             .map_err(|e| HWSystemError::database_operation(format!("提交事务失败: {e}")))?;
 
-        Ok(result.into_homework())
+        let mut homework = result.into_homework();
+        homework.group_ids = group_ids;
+        Ok(homework)
     }
 
     /// 通过 ID 获取作业
@@ -86,7 +115,28 @@ impl SeaOrmStorage {
             .await
             .map_err(|e| HWSystemError::database_operation(format!("查询作业失败: {e}")))?;
 
-        Ok(result.map(|m| m.into_homework()))
+        let Some(model) = result else {
+            return Ok(None);
+        };
+
+        let mut homework = model.into_homework();
+        homework.group_ids = self
+            .get_homework_group_ids_map(&[homework_id])
+            .await?
+            .remove(&homework_id)
+            .unwrap_or_default();
+
+        Ok(Some(homework))
+    }
+
+    /// 批量填充作业分配的分组 ID
+    async fn fill_homework_group_ids(&self, homeworks: &mut [Homework]) -> Result<()> {
+        let homework_ids: Vec<i64> = homeworks.iter().map(|h| h.id).collect();
+        let mut group_map = self.get_homework_group_ids_map(&homework_ids).await?;
+        for homework in homeworks.iter_mut() {
+            homework.group_ids = group_map.remove(&homework.id).unwrap_or_default();
+        }
+        Ok(())
     }
 
     /// 分页列出作业
@@ -109,6 +159,11 @@ impl SeaOrmStorage {
             select = select.filter(Column::CreatedBy.eq(created_by));
         }
 
+        // 学生视角只能看到已发布的作业
+        if current_user_id.is_some() {
+            select = select.filter(Column::Status.eq(HomeworkStatus::PUBLISHED));
+        }
+
         // 搜索条件（按标题搜索）
         if let Some(ref search) = query.search
             && !search.trim().is_empty()
@@ -132,13 +187,14 @@ impl SeaOrmStorage {
             .await
             .map_err(|e| HWSystemError::database_operation(format!("查询作业页数失败: {e}")))?;
 
-        let homeworks: Vec<Homework> = paginator
+        let mut homeworks: Vec<Homework> = paginator
             .fetch_page(page - 1)
             .await
             .map_err(|e| HWSystemError::database_operation(format!("查询作业列表失败: {e}")))?
             .into_iter()
             .map(|m| m.into_homework())
             .collect();
+        self.fill_homework_group_ids(&mut homeworks).await?;
 
         // 收集所有 created_by ID 并去重
         let creator_ids: Vec<i64> = homeworks
@@ -201,6 +257,7 @@ impl SeaOrmStorage {
                             status: sub.status.clone(),
                             is_late: sub.is_late,
                             score: None, // 稍后填充
+                            effective_score: None,
                         }
                     });
                 }
@@ -217,16 +274,17 @@ impl SeaOrmStorage {
                             HWSystemError::database_operation(format!("查询评分失败: {e}"))
                         })?;
 
-                    // 建立 submission_id -> score 的映射
-                    let grade_map: HashMap<i64, f64> = grades
+                    // 建立 submission_id -> (原始分数, 最终得分) 的映射
+                    let grade_map: HashMap<i64, (f64, f64)> = grades
                         .into_iter()
-                        .map(|g| (g.submission_id, g.score))
+                        .map(|g| (g.submission_id, (g.score, g.effective_score())))
                         .collect();
 
                     // 填充 score 并更新状态为 graded
                     for summary in my_submission_map.values_mut() {
-                        if let Some(score) = grade_map.get(&summary.id) {
+                        if let Some((score, effective_score)) = grade_map.get(&summary.id) {
                             summary.score = Some(*score);
+                            summary.effective_score = Some(*effective_score);
                             summary.status = "graded".to_string();
                         }
                     }
@@ -346,6 +404,16 @@ impl SeaOrmStorage {
             }
         }
 
+        // 学生视角：查询个人或分组延期
+        let extended_deadlines = match current_user_id {
+            Some(user_id) => {
+                let homework_ids: Vec<i64> = homeworks.iter().map(|h| h.id).collect();
+                self.get_user_extended_deadlines_impl(user_id, &homework_ids)
+                    .await?
+            }
+            None => HashMap::new(),
+        };
+
         // 构造带 creator 和 my_submission 的作业列表
         let items: Vec<HomeworkListItem> = homeworks
             .into_iter()
@@ -353,11 +421,13 @@ impl SeaOrmStorage {
                 let creator = creator_map.get(&homework.created_by).cloned();
                 let my_submission = my_submission_map.get(&homework.id).cloned();
                 let stats_summary = stats_map.get(&homework.id).cloned();
+                let extended_deadline = extended_deadlines.get(&homework.id).copied();
                 HomeworkListItem {
                     homework,
                     creator,
                     my_submission,
                     stats_summary,
+                    extended_deadline,
                 }
             })
             .collect();
@@ -420,6 +490,31 @@ impl SeaOrmStorage {
             model.allow_late = Set(allow_late);
         }
 
+        if let Some(late_cutoff) = update.late_cutoff {
+            model.late_cutoff = Set(late_cutoff.map(|dt| dt.timestamp()));
+        }
+
+        // 截止时间或扣分规则变化时需要重新计算已有评分的扣分
+        let penalty_changed = update.deadline.is_some() || update.late_penalty.is_some();
+        if let Some(late_penalty) = update.late_penalty {
+            model.late_penalty = Set(late_penalty
+                .as_ref()
+                .and_then(|p| serde_json::to_string(p).ok()));
+        }
+
+        if let Some(rubric_id) = update.rubric_id {
+            model.rubric_id = Set(rubric_id);
+        }
+
+        if let Some(auto_grader) = update.auto_grader {
+            model.auto_grader = Set(auto_grader);
+        }
+
+        if let Some(status) = update.status {
+            model.status = Set(status.to_string());
+            model.publish_at = Set(update.publish_at.map(|dt| dt.timestamp()));
+        }
+
         model
             .update(&txn)
             .await
@@ -431,10 +526,26 @@ impl SeaOrmStorage {
                 .await?;
         }
 
+        // 处理分配分组
+        if let Some(group_ids) = update.group_ids {
+            self.set_homework_groups_txn(&txn, homework_id, &group_ids)
+                .await?;
+        }
+
+        // 整体替换题目
+        if let Some(questions) = update.questions {
+            self.set_homework_questions_txn(&txn, homework_id, questions)
+                .await?;
+        }
+
         txn.commit()
             .await
             .map_err(|e| HWSystemError::database_operation(format!("提交事务失败: {e}")))?;
 
+        if penalty_changed {
+            self.refresh_grade_penalties(homework_id).await?;
+        }
+
         self.get_homework_by_id_impl(homework_id).await
     }
 
@@ -447,6 +558,15 @@ impl SeaOrmStorage {
             .await
             .map_err(|e| HWSystemError::database_operation(format!("删除作业附件关联失败: {e}")))?;
 
+        HomeworkGroups::delete_many()
+            .filter(HomeworkGroupColumn::HomeworkId.eq(homework_id))
+            .exec(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("删除作业分组关联失败: {e}")))?;
+
+        self.delete_comments_by_target_txn(&self.db, CommentTargetType::Homework, homework_id)
+            .await?;
+
         let result = Homeworks::delete_by_id(homework_id)
             .exec(&self.db)
             .await
@@ -538,9 +658,10 @@ impl SeaOrmStorage {
             return Ok((0, 0, 0, 0));
         }
 
-        // 2. 获取这些班级的所有作业
+        // 2. 获取这些班级的所有已发布作业
         let homeworks = Homeworks::find()
             .filter(Column::ClassId.is_in(class_ids))
+            .filter(Column::Status.eq(HomeworkStatus::PUBLISHED))
             .all(&self.db)
             .await
             .map_err(|e| HWSystemError::database_operation(format!("查询作业失败: {e}")))?;
@@ -807,22 +928,58 @@ impl SeaOrmStorage {
             });
         }
 
-        // 2. 构建基础查询
+        // 2. 构建基础查询（学生视角只能看到已发布的作业）
         let mut select = Homeworks::find().filter(Column::ClassId.is_in(class_ids.clone()));
+        if !is_teacher {
+            select = select.filter(Column::Status.eq(HomeworkStatus::PUBLISHED));
+        }
+
+        // 学生视角：个人或分组延期的作业按延期后的截止时间判断是否过期
+        let extended_deadlines = if is_teacher {
+            HashMap::new()
+        } else {
+            self.load_user_extended_deadlines(user_id, None).await?
+        };
+        let (extended_active, extended_expired): (Vec<i64>, Vec<i64>) = {
+            let (active, expired): (Vec<_>, Vec<_>) = extended_deadlines
+                .iter()
+                .partition(|(_, deadline)| deadline.timestamp() > now_ts);
+            (
+                active.into_iter().map(|(id, _)| *id).collect(),
+                expired.into_iter().map(|(id, _)| *id).collect(),
+            )
+        };
 
         // 截止日期过滤
         match query.deadline_filter.unwrap_or_default() {
             DeadlineFilter::Active => {
-                // 未过期：deadline 为空或 deadline > now
-                select = select.filter(Column::Deadline.is_null().or(Column::Deadline.gt(now_ts)));
+                // 未过期：deadline 为空或 deadline > now（或延期后未过期）
+                let mut cond = Condition::all()
+                    .add(Column::Deadline.is_null().or(Column::Deadline.gt(now_ts)));
+                if !extended_expired.is_empty() {
+                    cond = cond.add(Column::Id.is_not_in(extended_expired));
+                }
+                if !extended_active.is_empty() {
+                    cond = Condition::any()
+                        .add(cond)
+                        .add(Column::Id.is_in(extended_active));
+                }
+                select = select.filter(cond);
             }
             DeadlineFilter::Expired => {
-                // 已过期：deadline 不为空且 deadline <= now
-                select = select.filter(
-                    Column::Deadline
-                        .is_not_null()
-                        .and(Column::Deadline.lte(now_ts)),
-                );
+                // 已过期：deadline 不为空且 deadline <= now（或延期后已过期）
+                let mut cond = Condition::all()
+                    .add(Column::Deadline.is_not_null())
+                    .add(Column::Deadline.lte(now_ts));
+                if !extended_active.is_empty() {
+                    cond = cond.add(Column::Id.is_not_in(extended_active));
+                }
+                if !extended_expired.is_empty() {
+                    cond = Condition::any()
+                        .add(cond)
+                        .add(Column::Id.is_in(extended_expired));
+                }
+                select = select.filter(cond);
             }
             DeadlineFilter::All => {
                 // 不过滤
@@ -873,10 +1030,11 @@ impl SeaOrmStorage {
             .map_err(|e| HWSystemError::database_operation(format!("查询作业列表失败: {e}")))?;
 
         // 5. 转换为业务模型
-        let ordered_homeworks: Vec<Homework> = paged_homeworks
+        let mut ordered_homeworks: Vec<Homework> = paged_homeworks
             .into_iter()
             .map(|m| m.into_homework())
             .collect();
+        self.fill_homework_group_ids(&mut ordered_homeworks).await?;
 
         let homework_ids: Vec<i64> = ordered_homeworks.iter().map(|h| h.id).collect();
 
@@ -905,7 +1063,7 @@ impl SeaOrmStorage {
             .values()
             .map(|(id, _, _, _)| *id)
             .collect();
-        let mut grade_map: HashMap<i64, f64> = HashMap::new(); // submission_id -> score
+        let mut grade_map: HashMap<i64, (f64, f64)> = HashMap::new(); // submission_id -> (score, effective_score)
         if !submission_ids.is_empty() {
             let grades = Grades::find()
                 .filter(GradeColumn::SubmissionId.is_in(submission_ids))
@@ -914,7 +1072,7 @@ impl SeaOrmStorage {
                 .map_err(|e| HWSystemError::database_operation(format!("查询评分失败: {e}")))?;
 
             for grade in grades {
-                grade_map.insert(grade.submission_id, grade.score);
+                grade_map.insert(grade.submission_id, (grade.score, grade.effective_score()));
             }
         }
 
@@ -1069,8 +1227,8 @@ impl SeaOrmStorage {
                     my_submission_map
                         .get(&homework.id)
                         .map(|(id, version, status, is_late)| {
-                            let score = grade_map.get(id).copied();
-                            let final_status = if score.is_some() {
+                            let grade = grade_map.get(id).copied();
+                            let final_status = if grade.is_some() {
                                 "graded".to_string()
                             } else {
                                 status.clone()
@@ -1080,15 +1238,18 @@ impl SeaOrmStorage {
                                 version: *version,
                                 status: final_status,
                                 is_late: *is_late,
-                                score,
+                                score: grade.map(|(score, _)| score),
+                                effective_score: grade.map(|(_, effective)| effective),
                             }
                         });
                 let stats_summary = stats_map.get(&homework.id).cloned();
+                let extended_deadline = extended_deadlines.get(&homework.id).copied();
                 HomeworkListItem {
                     homework,
                     creator,
                     my_submission,
                     stats_summary,
+                    extended_deadline,
                 }
             })
             .collect();
@@ -1114,6 +1275,88 @@ impl SeaOrmStorage {
             .await
             .map_err(|e| HWSystemError::database_operation(format!("查询班级作业失败: {e}")))?;
 
-        Ok(result.into_iter().map(|m| m.into_homework()).collect())
+        let mut homeworks: Vec<Homework> = result.into_iter().map(|m| m.into_homework()).collect();
+        self.fill_homework_group_ids(&mut homeworks).await?;
+        Ok(homeworks)
+    }
+
+    /// 发布已到发布时间的定时作业
+    ///
+    /// 逐条以 `status = scheduled` 为条件更新，只返回本次实际完成发布的作业，
+    /// 避免多实例同时扫描时重复通知。
+    pub async fn publish_due_homeworks_impl(&self, now: i64) -> Result<Vec<Homework>> {
+        let due = Homeworks::find()
+            .filter(Column::Status.eq(HomeworkStatus::SCHEDULED))
+            .filter(Column::PublishAt.lte(now))
+            .order_by_asc(Column::PublishAt)
+            .all(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询待发布作业失败: {e}")))?;
+
+        let mut published = Vec::new();
+        for model in due {
+            let result = Homeworks::update_many()
+                .col_expr(Column::Status, Expr::value(HomeworkStatus::PUBLISHED))
+                .col_expr(Column::UpdatedAt, Expr::value(now))
+                .filter(Column::Id.eq(model.id))
+                .filter(Column::Status.eq(HomeworkStatus::SCHEDULED))
+                .exec(&self.db)
+                .await
+                .map_err(|e| HWSystemError::database_operation(format!("发布作业失败: {e}")))?;
+
+            if result.rows_affected > 0 {
+                let mut homework = model.into_homework();
+                homework.status = HomeworkStatus::Published;
+                published.push(homework);
+            }
+        }
+
+        self.fill_homework_group_ids(&mut published).await?;
+        Ok(published)
+    }
+
+    /// 列出截止时间在 (from, to] 范围内的已发布作业
+    pub async fn list_published_homeworks_due_between_impl(
+        &self,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<Homework>> {
+        let mut homeworks: Vec<Homework> = Homeworks::find()
+            .filter(Column::Status.eq(HomeworkStatus::PUBLISHED))
+            .filter(Column::Deadline.gt(from))
+            .filter(Column::Deadline.lte(to))
+            .order_by_asc(Column::Deadline)
+            .all(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询即将截止的作业失败: {e}")))?
+            .into_iter()
+            .map(|m| m.into_homework())
+            .collect();
+
+        self.fill_homework_group_ids(&mut homeworks).await?;
+        Ok(homeworks)
+    }
+
+    /// 占用一次截止提醒，返回是否为首次占用（已被其他实例或之前的运行占用时返回 false）
+    pub async fn claim_deadline_reminder_impl(
+        &self,
+        homework_id: i64,
+        offset_minutes: i64,
+        deadline: i64,
+    ) -> Result<bool> {
+        let model = HomeworkDeadlineReminderActiveModel {
+            homework_id: Set(homework_id),
+            offset_minutes: Set(offset_minutes),
+            deadline: Set(deadline),
+            sent_at: Set(chrono::Utc::now().timestamp()),
+        };
+
+        let result = HomeworkDeadlineReminders::insert(model)
+            .on_conflict_do_nothing()
+            .exec(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("记录截止提醒失败: {e}")))?;
+
+        Ok(matches!(result, sea_orm::TryInsertResult::Inserted(_)))
     }
 }
@@ -2,12 +2,22 @@
 //!
 //! 统一的数据库存储层，支持 SQLite、PostgreSQL 和 MySQL。
 
+mod announcements;
+mod auto_grade_runs;
+mod class_groups;
+mod class_join_requests;
 mod class_users;
 mod classes;
+mod comments;
 mod files;
 mod grades;
+mod homework_extensions;
+mod homework_questions;
 mod homeworks;
 mod notifications;
+mod profile_fields;
+mod question_bank;
+mod rubrics;
 mod submissions;
 mod system_settings;
 mod users;
@@ -119,22 +129,39 @@ impl SeaOrmStorage {
 
 // Storage trait 实现
 use crate::models::{
+    announcements::{
+        entities::{Announcement, AnnouncementRead},
+        requests::{AnnouncementListQuery, CreateAnnouncementRequest, UpdateAnnouncementRequest},
+        responses::AnnouncementListResponse,
+    },
+    class_groups::{
+        entities::ClassGroup,
+        requests::{CreateClassGroupRequest, UpdateClassGroupRequest},
+    },
     class_users::{
-        entities::{ClassUser, ClassUserRole},
-        requests::{ClassUserListQuery, UpdateClassUserRequest},
-        responses::ClassUserListResponse,
+        entities::{ClassJoinRequest, ClassUser, ClassUserRole},
+        requests::{ClassJoinRequestListQuery, ClassUserListQuery, UpdateClassUserRequest},
+        responses::{ClassJoinRequestListResponse, ClassUserListResponse},
     },
     classes::{
         entities::Class,
         requests::{ClassListQuery, CreateClassRequest, UpdateClassRequest},
         responses::ClassListResponse,
     },
+    comments::{
+        entities::{Comment, CommentTargetType},
+        requests::CreateCommentRequest,
+    },
+    auto_graders::entities::{AutoGradeResult, AutoGradeRun},
+    common::PaginationQuery,
     files::entities::File,
     grades::{
         entities::Grade,
         requests::{CreateGradeRequest, GradeListQuery, UpdateGradeRequest},
         responses::GradeListResponse,
     },
+    homework_extensions::{entities::HomeworkExtension, requests::SetHomeworkExtensionRequest},
+    homework_questions::entities::{HomeworkQuestion, HomeworkQuestionPool, SubmissionAnswer},
     homeworks::{
         entities::Homework,
         requests::{
@@ -147,6 +174,22 @@ use crate::models::{
         requests::{CreateNotificationRequest, NotificationListQuery},
         responses::NotificationListResponse,
     },
+    profile_fields::{
+        entities::ProfileField,
+        requests::{CreateProfileFieldRequest, UpdateProfileFieldRequest},
+    },
+    question_bank::{
+        entities::BankQuestion,
+        requests::{
+            BankQuestionFilter, CreateBankQuestionRequest, QuestionPoolDraft,
+            UpdateBankQuestionRequest,
+        },
+        responses::BankQuestionListResponse,
+    },
+    rubrics::{
+        entities::{Rubric, RubricScore},
+        requests::{CreateRubricRequest, UpdateRubricRequest},
+    },
     submissions::{
         entities::Submission,
         requests::{CreateSubmissionRequest, SubmissionListQuery},
@@ -163,7 +206,7 @@ use crate::models::{
 };
 use crate::storage::Storage;
 use async_trait::async_trait;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 #[async_trait]
 impl Storage for SeaOrmStorage {
@@ -242,6 +285,62 @@ impl Storage for SeaOrmStorage {
         self.get_users_by_ids_impl(ids).await
     }
 
+    // ============================================
+    // 自定义资料字段模块
+    // ============================================
+
+    async fn create_profile_field(&self, req: CreateProfileFieldRequest) -> Result<ProfileField> {
+        self.create_profile_field_impl(req).await
+    }
+
+    async fn get_profile_field_by_id(&self, field_id: i64) -> Result<Option<ProfileField>> {
+        self.get_profile_field_by_id_impl(field_id).await
+    }
+
+    async fn get_profile_field_by_key(&self, key: &str) -> Result<Option<ProfileField>> {
+        self.get_profile_field_by_key_impl(key).await
+    }
+
+    async fn list_profile_fields(&self) -> Result<Vec<ProfileField>> {
+        self.list_profile_fields_impl().await
+    }
+
+    async fn update_profile_field(
+        &self,
+        field_id: i64,
+        update: UpdateProfileFieldRequest,
+    ) -> Result<Option<ProfileField>> {
+        self.update_profile_field_impl(field_id, update).await
+    }
+
+    async fn delete_profile_field(&self, field_id: i64) -> Result<bool> {
+        self.delete_profile_field_impl(field_id).await
+    }
+
+    async fn get_user_profiles(
+        &self,
+        user_ids: &[i64],
+    ) -> Result<HashMap<i64, BTreeMap<String, String>>> {
+        self.get_user_profiles_impl(user_ids).await
+    }
+
+    async fn set_user_profile_values(
+        &self,
+        user_id: i64,
+        values: &[(i64, Option<String>)],
+    ) -> Result<()> {
+        self.set_user_profile_values_impl(user_id, values).await
+    }
+
+    async fn find_user_ids_by_profile_value(&self, field_id: i64, value: &str) -> Result<Vec<i64>> {
+        self.find_user_ids_by_profile_value_impl(field_id, value)
+            .await
+    }
+
+    async fn has_duplicate_profile_values(&self, field_id: i64) -> Result<bool> {
+        self.has_duplicate_profile_values_impl(field_id).await
+    }
+
     // ============================================
     // 文件模块
     // ============================================
@@ -393,6 +492,95 @@ impl Storage for SeaOrmStorage {
         self.list_all_class_users_impl(class_id).await
     }
 
+    // ============================================
+    // 加入班级申请模块
+    // ============================================
+
+    async fn create_class_join_request(
+        &self,
+        class_id: i64,
+        user_id: i64,
+        message: Option<String>,
+    ) -> Result<ClassJoinRequest> {
+        self.create_class_join_request_impl(class_id, user_id, message)
+            .await
+    }
+
+    async fn get_pending_class_join_request(
+        &self,
+        class_id: i64,
+        user_id: i64,
+    ) -> Result<Option<ClassJoinRequest>> {
+        self.get_pending_class_join_request_impl(class_id, user_id)
+            .await
+    }
+
+    async fn list_class_join_requests_with_pagination(
+        &self,
+        class_id: i64,
+        query: ClassJoinRequestListQuery,
+    ) -> Result<ClassJoinRequestListResponse> {
+        self.list_class_join_requests_with_pagination_impl(class_id, query)
+            .await
+    }
+
+    async fn handle_class_join_requests(
+        &self,
+        class_id: i64,
+        request_ids: &[i64],
+        approve: bool,
+        handler_id: i64,
+    ) -> Result<Vec<ClassJoinRequest>> {
+        self.handle_class_join_requests_impl(class_id, request_ids, approve, handler_id)
+            .await
+    }
+
+    // ============================================
+    // 班级分组模块
+    // ============================================
+
+    async fn create_class_group(
+        &self,
+        class_id: i64,
+        req: CreateClassGroupRequest,
+    ) -> Result<ClassGroup> {
+        self.create_class_group_impl(class_id, req).await
+    }
+
+    async fn get_class_group_by_id(&self, group_id: i64) -> Result<Option<ClassGroup>> {
+        self.get_class_group_by_id_impl(group_id).await
+    }
+
+    async fn list_class_groups(&self, class_id: i64) -> Result<Vec<ClassGroup>> {
+        self.list_class_groups_impl(class_id).await
+    }
+
+    async fn update_class_group(
+        &self,
+        group_id: i64,
+        update: UpdateClassGroupRequest,
+    ) -> Result<Option<ClassGroup>> {
+        self.update_class_group_impl(group_id, update).await
+    }
+
+    async fn delete_class_group(&self, group_id: i64) -> Result<bool> {
+        self.delete_class_group_impl(group_id).await
+    }
+
+    async fn count_class_group_members(&self, class_id: i64) -> Result<HashMap<i64, i64>> {
+        self.count_class_group_members_impl(class_id).await
+    }
+
+    async fn set_class_group_members(
+        &self,
+        class_id: i64,
+        group_id: Option<i64>,
+        user_ids: &[i64],
+    ) -> Result<Vec<i64>> {
+        self.set_class_group_members_impl(class_id, group_id, user_ids)
+            .await
+    }
+
     // ============================================
     // 作业模块
     // ============================================
@@ -468,6 +656,305 @@ impl Storage for SeaOrmStorage {
         self.list_all_homeworks_by_class_impl(class_id).await
     }
 
+    async fn publish_due_homeworks(&self, now: i64) -> Result<Vec<Homework>> {
+        self.publish_due_homeworks_impl(now).await
+    }
+
+    async fn list_published_homeworks_due_between(
+        &self,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<Homework>> {
+        self.list_published_homeworks_due_between_impl(from, to)
+            .await
+    }
+
+    async fn claim_deadline_reminder(
+        &self,
+        homework_id: i64,
+        offset_minutes: i64,
+        deadline: i64,
+    ) -> Result<bool> {
+        self.claim_deadline_reminder_impl(homework_id, offset_minutes, deadline)
+            .await
+    }
+
+    // ============================================
+    // 作业延期模块
+    // ============================================
+
+    async fn set_homework_extension(
+        &self,
+        homework_id: i64,
+        req: SetHomeworkExtensionRequest,
+        created_by: i64,
+    ) -> Result<HomeworkExtension> {
+        self.set_homework_extension_impl(homework_id, req, created_by)
+            .await
+    }
+
+    async fn get_homework_extension_by_id(
+        &self,
+        extension_id: i64,
+    ) -> Result<Option<HomeworkExtension>> {
+        self.get_homework_extension_by_id_impl(extension_id).await
+    }
+
+    async fn list_homework_extensions(&self, homework_id: i64) -> Result<Vec<HomeworkExtension>> {
+        self.list_homework_extensions_impl(homework_id).await
+    }
+
+    async fn delete_homework_extension(&self, extension_id: i64) -> Result<bool> {
+        self.delete_homework_extension_impl(extension_id).await
+    }
+
+    async fn get_user_extended_deadlines(
+        &self,
+        user_id: i64,
+        homework_ids: &[i64],
+    ) -> Result<HashMap<i64, chrono::DateTime<chrono::Utc>>> {
+        self.get_user_extended_deadlines_impl(user_id, homework_ids)
+            .await
+    }
+
+    // ============================================
+    // 评分细则模块
+    // ============================================
+
+    async fn create_rubric(&self, created_by: i64, req: CreateRubricRequest) -> Result<Rubric> {
+        self.create_rubric_impl(created_by, req).await
+    }
+
+    async fn get_rubric_by_id(&self, rubric_id: i64) -> Result<Option<Rubric>> {
+        self.get_rubric_by_id_impl(rubric_id).await
+    }
+
+    async fn list_rubrics(&self, created_by: Option<i64>) -> Result<Vec<Rubric>> {
+        self.list_rubrics_impl(created_by).await
+    }
+
+    async fn update_rubric(
+        &self,
+        rubric_id: i64,
+        update: UpdateRubricRequest,
+    ) -> Result<Option<Rubric>> {
+        self.update_rubric_impl(rubric_id, update).await
+    }
+
+    async fn delete_rubric(&self, rubric_id: i64) -> Result<bool> {
+        self.delete_rubric_impl(rubric_id).await
+    }
+
+    // ============================================
+    // 作业题目模块
+    // ============================================
+
+    async fn list_homework_questions(&self, homework_id: i64) -> Result<Vec<HomeworkQuestion>> {
+        self.list_homework_questions_impl(homework_id).await
+    }
+
+    async fn has_homework_submissions(&self, homework_id: i64) -> Result<bool> {
+        self.has_homework_submissions_impl(homework_id).await
+    }
+
+    async fn list_submission_answers(
+        &self,
+        submission_ids: &[i64],
+    ) -> Result<HashMap<i64, Vec<SubmissionAnswer>>> {
+        self.list_submission_answers_impl(submission_ids).await
+    }
+
+    async fn set_submission_answer_points(
+        &self,
+        submission_id: i64,
+        scores: &[(i64, f64)],
+    ) -> Result<()> {
+        self.set_submission_answer_points_impl(submission_id, scores)
+            .await
+    }
+
+    async fn grade_submission_answers(&self, submission_id: i64) -> Result<Option<Grade>> {
+        self.grade_submission_answers_impl(submission_id).await
+    }
+
+    async fn list_homework_question_pools(
+        &self,
+        homework_id: i64,
+    ) -> Result<Vec<HomeworkQuestionPool>> {
+        self.list_homework_question_pools_impl(homework_id).await
+    }
+
+    // ============================================
+    // 题库模块
+    // ============================================
+
+    async fn create_bank_question(
+        &self,
+        created_by: i64,
+        req: CreateBankQuestionRequest,
+    ) -> Result<BankQuestion> {
+        self.create_bank_question_impl(created_by, req).await
+    }
+
+    async fn get_bank_question_by_id(&self, question_id: i64) -> Result<Option<BankQuestion>> {
+        self.get_bank_question_by_id_impl(question_id).await
+    }
+
+    async fn list_bank_questions_with_pagination(
+        &self,
+        filter: BankQuestionFilter,
+        pagination: PaginationQuery,
+    ) -> Result<BankQuestionListResponse> {
+        self.list_bank_questions_with_pagination_impl(filter, pagination)
+            .await
+    }
+
+    async fn list_bank_questions(
+        &self,
+        filter: BankQuestionFilter,
+        limit: u64,
+    ) -> Result<Vec<BankQuestion>> {
+        self.list_bank_questions_impl(filter, limit).await
+    }
+
+    async fn update_bank_question(
+        &self,
+        question_id: i64,
+        user_id: i64,
+        update: UpdateBankQuestionRequest,
+    ) -> Result<Option<BankQuestion>> {
+        self.update_bank_question_impl(question_id, user_id, update)
+            .await
+    }
+
+    async fn delete_bank_question(&self, question_id: i64) -> Result<bool> {
+        self.delete_bank_question_impl(question_id).await
+    }
+
+    async fn add_bank_questions_to_homework(
+        &self,
+        homework_id: i64,
+        picked: Vec<BankQuestion>,
+        pools: Vec<QuestionPoolDraft>,
+    ) -> Result<()> {
+        self.add_bank_questions_to_homework_impl(homework_id, picked, pools)
+            .await
+    }
+
+    // ============================================
+    // 班级公告模块
+    // ============================================
+
+    async fn create_announcement(
+        &self,
+        class_id: i64,
+        created_by: i64,
+        req: CreateAnnouncementRequest,
+    ) -> Result<Announcement> {
+        self.create_announcement_impl(class_id, created_by, req)
+            .await
+    }
+
+    async fn get_announcement_by_id(&self, announcement_id: i64) -> Result<Option<Announcement>> {
+        self.get_announcement_by_id_impl(announcement_id).await
+    }
+
+    async fn list_announcements_with_pagination(
+        &self,
+        class_id: i64,
+        query: AnnouncementListQuery,
+    ) -> Result<AnnouncementListResponse> {
+        self.list_announcements_with_pagination_impl(class_id, query)
+            .await
+    }
+
+    async fn update_announcement(
+        &self,
+        announcement_id: i64,
+        update: UpdateAnnouncementRequest,
+        user_id: i64,
+    ) -> Result<Option<Announcement>> {
+        self.update_announcement_impl(announcement_id, update, user_id)
+            .await
+    }
+
+    async fn delete_announcement(&self, announcement_id: i64) -> Result<bool> {
+        self.delete_announcement_impl(announcement_id).await
+    }
+
+    async fn get_announcement_file_ids(&self, announcement_id: i64) -> Result<Vec<i64>> {
+        self.get_announcement_file_ids_impl(announcement_id).await
+    }
+
+    async fn mark_announcement_read(&self, announcement_id: i64, user_id: i64) -> Result<bool> {
+        self.mark_announcement_read_impl(announcement_id, user_id)
+            .await
+    }
+
+    async fn list_announcement_reads(&self, announcement_id: i64) -> Result<Vec<AnnouncementRead>> {
+        self.list_announcement_reads_impl(announcement_id).await
+    }
+
+    async fn count_announcement_reads(
+        &self,
+        announcement_ids: &[i64],
+    ) -> Result<HashMap<i64, i64>> {
+        self.count_announcement_reads_impl(announcement_ids).await
+    }
+
+    async fn list_read_announcement_ids(
+        &self,
+        user_id: i64,
+        announcement_ids: &[i64],
+    ) -> Result<HashSet<i64>> {
+        self.list_read_announcement_ids_impl(user_id, announcement_ids)
+            .await
+    }
+
+    // ============================================
+    // 讨论评论模块
+    // ============================================
+
+    async fn create_comment(
+        &self,
+        target_type: CommentTargetType,
+        target_id: i64,
+        class_id: i64,
+        user_id: i64,
+        req: CreateCommentRequest,
+    ) -> Result<Comment> {
+        self.create_comment_impl(target_type, target_id, class_id, user_id, req)
+            .await
+    }
+
+    async fn get_comment_by_id(&self, comment_id: i64) -> Result<Option<Comment>> {
+        self.get_comment_by_id_impl(comment_id).await
+    }
+
+    async fn list_comments(
+        &self,
+        target_type: CommentTargetType,
+        target_id: i64,
+    ) -> Result<Vec<Comment>> {
+        self.list_comments_impl(target_type, target_id).await
+    }
+
+    async fn update_comment(&self, comment_id: i64, content: String) -> Result<Option<Comment>> {
+        self.update_comment_impl(comment_id, content).await
+    }
+
+    async fn delete_comment(&self, comment_id: i64) -> Result<u64> {
+        self.delete_comment_impl(comment_id).await
+    }
+
+    async fn count_comments(
+        &self,
+        target_type: CommentTargetType,
+        target_ids: &[i64],
+    ) -> Result<HashMap<i64, i64>> {
+        self.count_comments_impl(target_type, target_ids).await
+    }
+
     // ============================================
     // 提交模块
     // ============================================
@@ -476,8 +963,9 @@ impl Storage for SeaOrmStorage {
         &self,
         creator_id: i64,
         req: CreateSubmissionRequest,
+        answers: Vec<SubmissionAnswer>,
     ) -> Result<Submission> {
-        self.create_submission_impl(creator_id, req).await
+        self.create_submission_impl(creator_id, req, answers).await
     }
 
     async fn get_submission_by_id(&self, submission_id: i64) -> Result<Option<Submission>> {
@@ -562,9 +1050,17 @@ impl Storage for SeaOrmStorage {
         page_size: i64,
         include_grades: bool,
         graded: Option<bool>,
+        group_id: Option<i64>,
     ) -> Result<SubmissionSummaryResponse> {
-        self.get_submission_summary_impl(homework_id, page, page_size, include_grades, graded)
-            .await
+        self.get_submission_summary_impl(
+            homework_id,
+            page,
+            page_size,
+            include_grades,
+            graded,
+            group_id,
+        )
+        .await
     }
 
     async fn list_user_submissions_for_teacher(
@@ -581,8 +1077,13 @@ impl Storage for SeaOrmStorage {
     // 评分模块
     // ============================================
 
-    async fn create_grade(&self, grader_id: i64, req: CreateGradeRequest) -> Result<Grade> {
-        self.create_grade_impl(grader_id, req).await
+    async fn create_grade(
+        &self,
+        grader_id: i64,
+        req: CreateGradeRequest,
+        rubric_scores: Vec<RubricScore>,
+    ) -> Result<Grade> {
+        self.create_grade_impl(grader_id, req, rubric_scores).await
     }
 
     async fn get_grade_by_id(&self, grade_id: i64) -> Result<Option<Grade>> {
@@ -597,8 +1098,10 @@ impl Storage for SeaOrmStorage {
         &self,
         grade_id: i64,
         update: UpdateGradeRequest,
+        rubric_scores: Option<Vec<RubricScore>>,
     ) -> Result<Option<Grade>> {
-        self.update_grade_impl(grade_id, update).await
+        self.update_grade_impl(grade_id, update, rubric_scores)
+            .await
     }
 
     async fn list_grades_with_pagination(
@@ -615,6 +1118,62 @@ impl Storage for SeaOrmStorage {
         self.get_grades_by_submission_ids_impl(submission_ids).await
     }
 
+    async fn confirm_grade(&self, grade_id: i64) -> Result<Option<Grade>> {
+        self.confirm_grade_impl(grade_id).await
+    }
+
+    // ============================================
+    // 自动评测模块
+    // ============================================
+
+    async fn create_auto_grade_run(
+        &self,
+        submission_id: i64,
+        grader: &str,
+    ) -> Result<AutoGradeRun> {
+        self.create_auto_grade_run_impl(submission_id, grader).await
+    }
+
+    async fn get_auto_grade_run_by_submission_id(
+        &self,
+        submission_id: i64,
+    ) -> Result<Option<AutoGradeRun>> {
+        self.get_auto_grade_run_by_submission_id_impl(submission_id)
+            .await
+    }
+
+    async fn list_pending_auto_grade_runs(&self, limit: u64) -> Result<Vec<AutoGradeRun>> {
+        self.list_pending_auto_grade_runs_impl(limit).await
+    }
+
+    async fn claim_auto_grade_run(&self, run_id: i64) -> Result<bool> {
+        self.claim_auto_grade_run_impl(run_id).await
+    }
+
+    async fn reset_running_auto_grade_runs(&self) -> Result<u64> {
+        self.reset_running_auto_grade_runs_impl().await
+    }
+
+    async fn requeue_auto_grade_run(
+        &self,
+        run_id: i64,
+        grader: &str,
+    ) -> Result<Option<AutoGradeRun>> {
+        self.requeue_auto_grade_run_impl(run_id, grader).await
+    }
+
+    async fn fail_auto_grade_run(&self, run_id: i64, error: String) -> Result<bool> {
+        self.fail_auto_grade_run_impl(run_id, error).await
+    }
+
+    async fn complete_auto_grade_run(
+        &self,
+        run_id: i64,
+        result: AutoGradeResult,
+    ) -> Result<Option<Grade>> {
+        self.complete_auto_grade_run_impl(run_id, result).await
+    }
+
     // ============================================
     // 通知模块
     // ============================================
@@ -0,0 +1,321 @@
+//! 自定义资料字段存储操作
+
+use std::collections::{BTreeMap, HashMap};
+
+use super::SeaOrmStorage;
+use crate::entity::profile_fields::{ActiveModel, Column, Entity as ProfileFields};
+use crate::entity::user_profile_values::{
+    ActiveModel as ValueActiveModel, Column as ValueColumn, Entity as UserProfileValues,
+};
+use crate::errors::{HWSystemError, Result};
+use crate::models::profile_fields::{
+    entities::{ProfileField, ProfileFieldType},
+    requests::{CreateProfileFieldRequest, UpdateProfileFieldRequest},
+};
+use crate::models::users::entities::User;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, FromQueryResult, QueryFilter, QueryOrder,
+    QuerySelect, Set, TransactionTrait, sea_query::OnConflict,
+};
+
+impl SeaOrmStorage {
+    /// 创建资料字段
+    pub async fn create_profile_field_impl(
+        &self,
+        req: CreateProfileFieldRequest,
+    ) -> Result<ProfileField> {
+        let now = chrono::Utc::now().timestamp();
+
+        let model = ActiveModel {
+            field_key: Set(req.key),
+            label: Set(req.label),
+            field_type: Set(req.field_type.unwrap_or(ProfileFieldType::Text).to_string()),
+            required: Set(req.required.unwrap_or(false)),
+            is_unique: Set(req.unique.unwrap_or(false)),
+            pattern: Set(req.pattern.filter(|p| !p.is_empty())),
+            max_length: Set(req.max_length.filter(|&l| l > 0)),
+            options: Set(encode_options(req.options)),
+            sort_order: Set(req.sort_order.unwrap_or(0)),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        let result = model
+            .insert(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("创建资料字段失败: {e}")))?;
+
+        Ok(result.into_profile_field())
+    }
+
+    /// 通过 ID 获取资料字段
+    pub async fn get_profile_field_by_id_impl(
+        &self,
+        field_id: i64,
+    ) -> Result<Option<ProfileField>> {
+        let result = ProfileFields::find_by_id(field_id)
+            .one(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询资料字段失败: {e}")))?;
+
+        Ok(result.map(|m| m.into_profile_field()))
+    }
+
+    /// 通过字段键获取资料字段
+    pub async fn get_profile_field_by_key_impl(&self, key: &str) -> Result<Option<ProfileField>> {
+        let result = ProfileFields::find()
+            .filter(Column::FieldKey.eq(key))
+            .one(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询资料字段失败: {e}")))?;
+
+        Ok(result.map(|m| m.into_profile_field()))
+    }
+
+    /// 列出所有资料字段（按 sort_order、id 排序）
+    pub async fn list_profile_fields_impl(&self) -> Result<Vec<ProfileField>> {
+        let result = ProfileFields::find()
+            .order_by_asc(Column::SortOrder)
+            .order_by_asc(Column::Id)
+            .all(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询资料字段列表失败: {e}")))?;
+
+        Ok(result.into_iter().map(|m| m.into_profile_field()).collect())
+    }
+
+    /// 更新资料字段
+    pub async fn update_profile_field_impl(
+        &self,
+        field_id: i64,
+        update: UpdateProfileFieldRequest,
+    ) -> Result<Option<ProfileField>> {
+        if self.get_profile_field_by_id_impl(field_id).await?.is_none() {
+            return Ok(None);
+        }
+
+        let mut model = ActiveModel {
+            id: Set(field_id),
+            updated_at: Set(chrono::Utc::now().timestamp()),
+            ..Default::default()
+        };
+
+        if let Some(label) = update.label {
+            model.label = Set(label);
+        }
+
+        if let Some(field_type) = update.field_type {
+            model.field_type = Set(field_type.to_string());
+        }
+
+        if let Some(required) = update.required {
+            model.required = Set(required);
+        }
+
+        if let Some(unique) = update.unique {
+            model.is_unique = Set(unique);
+        }
+
+        if let Some(pattern) = update.pattern {
+            model.pattern = Set(Some(pattern).filter(|p| !p.is_empty()));
+        }
+
+        if let Some(max_length) = update.max_length {
+            model.max_length = Set(Some(max_length).filter(|&l| l > 0));
+        }
+
+        if let Some(options) = update.options {
+            model.options = Set(encode_options(Some(options)));
+        }
+
+        if let Some(sort_order) = update.sort_order {
+            model.sort_order = Set(sort_order);
+        }
+
+        let result = model
+            .update(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("更新资料字段失败: {e}")))?;
+
+        Ok(Some(result.into_profile_field()))
+    }
+
+    /// 删除资料字段（使用事务保护，同时删除所有用户的字段值）
+    pub async fn delete_profile_field_impl(&self, field_id: i64) -> Result<bool> {
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("开启事务失败: {e}")))?;
+
+        UserProfileValues::delete_many()
+            .filter(ValueColumn::FieldId.eq(field_id))
+            .exec(&txn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("删除资料字段值失败: {e}")))?;
+
+        let result = ProfileFields::delete_by_id(field_id)
+            .exec(&txn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("删除资料字段失败: {e}")))?;
+
+        txn.commit()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("提交事务失败: {e}")))?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    /// 批量获取用户的资料字段值（user_id -> 字段键 -> 值）
+    pub async fn get_user_profiles_impl(
+        &self,
+        user_ids: &[i64],
+    ) -> Result<HashMap<i64, BTreeMap<String, String>>> {
+        if user_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        #[derive(FromQueryResult)]
+        struct ProfileValueRow {
+            user_id: i64,
+            field_key: String,
+            value: String,
+        }
+
+        let rows: Vec<ProfileValueRow> = UserProfileValues::find()
+            .select_only()
+            .column(ValueColumn::UserId)
+            .column(Column::FieldKey)
+            .column(ValueColumn::Value)
+            .inner_join(ProfileFields)
+            .filter(ValueColumn::UserId.is_in(user_ids.to_vec()))
+            .into_model::<ProfileValueRow>()
+            .all(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询用户资料失败: {e}")))?;
+
+        let mut result: HashMap<i64, BTreeMap<String, String>> = HashMap::new();
+        for row in rows {
+            result
+                .entry(row.user_id)
+                .or_default()
+                .insert(row.field_key, row.value);
+        }
+
+        Ok(result)
+    }
+
+    /// 为用户列表填充资料字段值
+    pub(super) async fn attach_user_profiles(&self, users: &mut [User]) -> Result<()> {
+        let user_ids: Vec<i64> = users.iter().map(|u| u.id).collect();
+        let mut profiles = self.get_user_profiles_impl(&user_ids).await?;
+        for user in users.iter_mut() {
+            if let Some(profile) = profiles.remove(&user.id) {
+                user.profile = profile;
+            }
+        }
+        Ok(())
+    }
+
+    /// 设置用户资料字段值（值为 None 时删除该字段值）
+    pub async fn set_user_profile_values_impl(
+        &self,
+        user_id: i64,
+        values: &[(i64, Option<String>)],
+    ) -> Result<()> {
+        if values.is_empty() {
+            return Ok(());
+        }
+
+        let now = chrono::Utc::now().timestamp();
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("开启事务失败: {e}")))?;
+
+        for (field_id, value) in values {
+            match value {
+                Some(value) => {
+                    let model = ValueActiveModel {
+                        user_id: Set(user_id),
+                        field_id: Set(*field_id),
+                        value: Set(value.clone()),
+                        updated_at: Set(now),
+                    };
+                    UserProfileValues::insert(model)
+                        .on_conflict(
+                            OnConflict::columns([ValueColumn::UserId, ValueColumn::FieldId])
+                                .update_columns([ValueColumn::Value, ValueColumn::UpdatedAt])
+                                .to_owned(),
+                        )
+                        .exec(&txn)
+                        .await
+                        .map_err(|e| {
+                            HWSystemError::database_operation(format!("保存用户资料失败: {e}"))
+                        })?;
+                }
+                None => {
+                    UserProfileValues::delete_many()
+                        .filter(ValueColumn::UserId.eq(user_id))
+                        .filter(ValueColumn::FieldId.eq(*field_id))
+                        .exec(&txn)
+                        .await
+                        .map_err(|e| {
+                            HWSystemError::database_operation(format!("删除用户资料失败: {e}"))
+                        })?;
+                }
+            }
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("提交事务失败: {e}")))?;
+
+        Ok(())
+    }
+
+    /// 查找资料字段值为指定值的用户
+    pub async fn find_user_ids_by_profile_value_impl(
+        &self,
+        field_id: i64,
+        value: &str,
+    ) -> Result<Vec<i64>> {
+        let rows = UserProfileValues::find()
+            .filter(ValueColumn::FieldId.eq(field_id))
+            .filter(ValueColumn::Value.eq(value))
+            .all(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询用户资料失败: {e}")))?;
+
+        Ok(rows.into_iter().map(|r| r.user_id).collect())
+    }
+
+    /// 检查资料字段是否存在重复值（用于将字段改为唯一前的校验）
+    pub async fn has_duplicate_profile_values_impl(&self, field_id: i64) -> Result<bool> {
+        #[derive(FromQueryResult)]
+        struct ValueCount {
+            count: i64,
+        }
+
+        let counts: Vec<ValueCount> = UserProfileValues::find()
+            .filter(ValueColumn::FieldId.eq(field_id))
+            .select_only()
+            .column_as(ValueColumn::UserId.count(), "count")
+            .group_by(ValueColumn::Value)
+            .into_model::<ValueCount>()
+            .all(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("统计资料字段值失败: {e}")))?;
+
+        Ok(counts.iter().any(|c| c.count > 1))
+    }
+}
+
+/// 单选项序列化为 JSON 数组存储，空列表存为 NULL
+fn encode_options(options: Option<Vec<String>>) -> Option<String> {
+    options
+        .filter(|o| !o.is_empty())
+        .and_then(|o| serde_json::to_string(&o).ok())
+}
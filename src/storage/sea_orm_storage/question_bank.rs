@@ -0,0 +1,518 @@
+//! 题库存储操作
+
+use std::collections::HashMap;
+
+use super::SeaOrmStorage;
+use crate::entity::bank_question_files::{
+    ActiveModel as BankQuestionFileActiveModel, Column as BankQuestionFileColumn,
+    Entity as BankQuestionFiles,
+};
+use crate::entity::bank_questions::{ActiveModel, Column, Entity as BankQuestions, Model};
+use crate::entity::homework_question_files::ActiveModel as HomeworkQuestionFileActiveModel;
+use crate::entity::homework_question_pools::ActiveModel as PoolActiveModel;
+use crate::entity::homework_questions::{
+    ActiveModel as QuestionActiveModel, Column as QuestionColumn, Entity as HomeworkQuestions,
+};
+use crate::errors::{HWSystemError, Result};
+use crate::models::{
+    PaginationInfo,
+    common::PaginationQuery,
+    files::responses::FileInfo,
+    question_bank::{
+        entities::{BankQuestion, BankQuestionScope},
+        requests::{
+            BankQuestionFilter, CreateBankQuestionRequest, QuestionPoolDraft,
+            UpdateBankQuestionRequest,
+        },
+        responses::BankQuestionListResponse,
+    },
+};
+use crate::utils::escape_like_pattern;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, EntityTrait, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, Select, Set, TransactionTrait, sea_query::LikeExpr,
+};
+
+/// 构造包含子串的 LIKE 条件（显式指定转义字符）
+fn like_contains(value: &str) -> LikeExpr {
+    LikeExpr::new(format!("%{}%", escape_like_pattern(value))).escape('\\')
+}
+
+/// 按查询条件构造题库查询
+fn bank_question_select(filter: &BankQuestionFilter) -> Select<BankQuestions> {
+    let mut select = BankQuestions::find();
+
+    match filter.scope {
+        BankQuestionScope::Mine => {
+            select = select.filter(Column::CreatedBy.eq(filter.viewer_id));
+        }
+        BankQuestionScope::Shared => {
+            select = select.filter(Column::IsShared.eq(true));
+        }
+        BankQuestionScope::All if !filter.is_admin => {
+            select = select.filter(
+                Condition::any()
+                    .add(Column::CreatedBy.eq(filter.viewer_id))
+                    .add(Column::IsShared.eq(true)),
+            );
+        }
+        BankQuestionScope::All => {}
+    }
+
+    if let Some(ref ids) = filter.ids {
+        select = select.filter(Column::Id.is_in(ids.iter().copied()));
+    }
+
+    // 标签以 JSON 数组存储，按带引号的完整标签匹配
+    if let Some(ref tag) = filter.tag
+        && let Ok(quoted) = serde_json::to_string(tag.trim())
+    {
+        select = select.filter(Column::Tags.like(like_contains(&quoted)));
+    }
+
+    if let Some(difficulty) = filter.difficulty {
+        select = select.filter(Column::Difficulty.eq(difficulty));
+    }
+
+    if let Some(ref knowledge_point) = filter.knowledge_point
+        && !knowledge_point.trim().is_empty()
+    {
+        select = select.filter(Column::KnowledgePoint.eq(knowledge_point.trim()));
+    }
+
+    // 题目定义以 {"type":"..."} 开头
+    if let Some(question_type) = filter.question_type
+        && let Ok(type_name) = serde_json::to_string(&question_type)
+    {
+        let prefix = format!("{{\"type\":{type_name}");
+        select =
+            select
+                .filter(Column::Spec.like(
+                    LikeExpr::new(format!("{}%", escape_like_pattern(&prefix))).escape('\\'),
+                ));
+    }
+
+    if let Some(ref search) = filter.search
+        && !search.trim().is_empty()
+    {
+        select = select.filter(Column::Prompt.like(like_contains(search.trim())));
+    }
+
+    select
+}
+
+impl SeaOrmStorage {
+    /// 创建题库题目（使用事务保护）
+    pub async fn create_bank_question_impl(
+        &self,
+        created_by: i64,
+        req: CreateBankQuestionRequest,
+    ) -> Result<BankQuestion> {
+        let spec = serde_json::to_string(&req.spec)
+            .map_err(|e| HWSystemError::validation(format!("题目定义格式错误: {e}")))?;
+        let tags = serde_json::to_string(&req.tags.unwrap_or_default())
+            .map_err(|e| HWSystemError::validation(format!("题目标签格式错误: {e}")))?;
+
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("开启事务失败: {e}")))?;
+
+        let now = chrono::Utc::now().timestamp();
+
+        let model = ActiveModel {
+            created_by: Set(created_by),
+            is_shared: Set(req.is_shared.unwrap_or(false)),
+            prompt: Set(req.prompt),
+            points: Set(req.points),
+            spec: Set(spec),
+            tags: Set(tags),
+            difficulty: Set(req.difficulty.unwrap_or(3)),
+            knowledge_point: Set(req.knowledge_point),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        let result = model
+            .insert(&txn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("创建题库题目失败: {e}")))?;
+
+        if let Some(tokens) = req.attachments {
+            self.set_bank_question_files_txn(&txn, result.id, tokens, created_by)
+                .await?;
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("提交事务失败: {e}")))?;
+
+        self.get_bank_question_by_id_impl(result.id)
+            .await?
+            .ok_or_else(|| HWSystemError::database_operation("创建题库题目后查询失败"))
+    }
+
+    /// 通过 ID 获取题库题目
+    pub async fn get_bank_question_by_id_impl(
+        &self,
+        question_id: i64,
+    ) -> Result<Option<BankQuestion>> {
+        let Some(model) = BankQuestions::find_by_id(question_id)
+            .one(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询题库题目失败: {e}")))?
+        else {
+            return Ok(None);
+        };
+
+        Ok(self.attach_bank_question_files(vec![model]).await?.pop())
+    }
+
+    /// 分页列出题库题目（按更新时间倒序）
+    pub async fn list_bank_questions_with_pagination_impl(
+        &self,
+        filter: BankQuestionFilter,
+        pagination: PaginationQuery,
+    ) -> Result<BankQuestionListResponse> {
+        let (page, page_size) = pagination.normalized();
+
+        let select = bank_question_select(&filter)
+            .order_by_desc(Column::UpdatedAt)
+            .order_by_desc(Column::Id);
+
+        let paginator = select.paginate(&self.db, page_size);
+        let total = paginator
+            .num_items()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询题库题目总数失败: {e}")))?;
+
+        let pages = paginator
+            .num_pages()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询题库题目页数失败: {e}")))?;
+
+        let models = paginator
+            .fetch_page(page - 1)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询题库题目列表失败: {e}")))?;
+
+        Ok(BankQuestionListResponse {
+            items: self.attach_bank_question_files(models).await?,
+            pagination: PaginationInfo {
+                page: page as i64,
+                page_size: page_size as i64,
+                total: total as i64,
+                total_pages: pages as i64,
+            },
+        })
+    }
+
+    /// 列出符合条件的全部题库题目（按 ID 排序）
+    pub async fn list_bank_questions_impl(
+        &self,
+        filter: BankQuestionFilter,
+        limit: u64,
+    ) -> Result<Vec<BankQuestion>> {
+        let models = bank_question_select(&filter)
+            .order_by_asc(Column::Id)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询题库题目列表失败: {e}")))?;
+
+        self.attach_bank_question_files(models).await
+    }
+
+    /// 更新题库题目
+    pub async fn update_bank_question_impl(
+        &self,
+        question_id: i64,
+        user_id: i64,
+        update: UpdateBankQuestionRequest,
+    ) -> Result<Option<BankQuestion>> {
+        if BankQuestions::find_by_id(question_id)
+            .one(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询题库题目失败: {e}")))?
+            .is_none()
+        {
+            return Ok(None);
+        }
+
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("开启事务失败: {e}")))?;
+
+        let mut model = ActiveModel {
+            id: Set(question_id),
+            updated_at: Set(chrono::Utc::now().timestamp()),
+            ..Default::default()
+        };
+
+        if let Some(prompt) = update.prompt {
+            model.prompt = Set(prompt);
+        }
+        if let Some(points) = update.points {
+            model.points = Set(points);
+        }
+        if let Some(spec) = update.spec {
+            let spec = serde_json::to_string(&spec)
+                .map_err(|e| HWSystemError::validation(format!("题目定义格式错误: {e}")))?;
+            model.spec = Set(spec);
+        }
+        if let Some(tags) = update.tags {
+            let tags = serde_json::to_string(&tags)
+                .map_err(|e| HWSystemError::validation(format!("题目标签格式错误: {e}")))?;
+            model.tags = Set(tags);
+        }
+        if let Some(difficulty) = update.difficulty {
+            model.difficulty = Set(difficulty);
+        }
+        if let Some(knowledge_point) = update.knowledge_point {
+            model.knowledge_point = Set(knowledge_point);
+        }
+        if let Some(is_shared) = update.is_shared {
+            model.is_shared = Set(is_shared);
+        }
+
+        model
+            .update(&txn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("更新题库题目失败: {e}")))?;
+
+        if let Some(tokens) = update.attachments {
+            self.set_bank_question_files_txn(&txn, question_id, tokens, user_id)
+                .await?;
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("提交事务失败: {e}")))?;
+
+        self.get_bank_question_by_id_impl(question_id).await
+    }
+
+    /// 删除题库题目
+    pub async fn delete_bank_question_impl(&self, question_id: i64) -> Result<bool> {
+        let result = BankQuestions::delete_by_id(question_id)
+            .exec(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("删除题库题目失败: {e}")))?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    /// 将题库题目追加到作业末尾（使用事务保护）
+    ///
+    /// 题干、分值、题目定义与附件按当前内容复制，之后修改题库不影响作业。
+    pub async fn add_bank_questions_to_homework_impl(
+        &self,
+        homework_id: i64,
+        picked: Vec<BankQuestion>,
+        pools: Vec<QuestionPoolDraft>,
+    ) -> Result<()> {
+        let bank_ids: Vec<i64> = picked
+            .iter()
+            .chain(pools.iter().flat_map(|p| p.candidates.iter()))
+            .map(|q| q.id)
+            .collect();
+        let file_ids = self.load_bank_question_file_ids(&bank_ids).await?;
+
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("开启事务失败: {e}")))?;
+
+        let last = HomeworkQuestions::find()
+            .filter(QuestionColumn::HomeworkId.eq(homework_id))
+            .order_by_desc(QuestionColumn::Position)
+            .one(&txn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询作业题目失败: {e}")))?;
+        let mut position = last.map_or(0, |q| q.position + 1);
+
+        for question in picked {
+            let files = file_ids.get(&question.id).cloned().unwrap_or_default();
+            self.copy_bank_question_txn(&txn, homework_id, position, None, None, question, files)
+                .await?;
+            position += 1;
+        }
+
+        for pool in pools {
+            let model = PoolActiveModel {
+                homework_id: Set(homework_id),
+                label: Set(pool.label),
+                draw_count: Set(pool.draw_count),
+                points: Set(pool.points),
+                ..Default::default()
+            };
+            let pool_model = model
+                .insert(&txn)
+                .await
+                .map_err(|e| HWSystemError::database_operation(format!("创建抽题池失败: {e}")))?;
+
+            for question in pool.candidates {
+                let files = file_ids.get(&question.id).cloned().unwrap_or_default();
+                self.copy_bank_question_txn(
+                    &txn,
+                    homework_id,
+                    position,
+                    Some(pool_model.id),
+                    Some(pool.points),
+                    question,
+                    files,
+                )
+                .await?;
+                position += 1;
+            }
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("提交事务失败: {e}")))?;
+
+        Ok(())
+    }
+
+    /// 复制一道题库题目到作业（含附件关联）
+    #[allow(clippy::too_many_arguments)]
+    async fn copy_bank_question_txn<C: ConnectionTrait>(
+        &self,
+        conn: &C,
+        homework_id: i64,
+        position: i32,
+        pool_id: Option<i64>,
+        points: Option<f64>,
+        question: BankQuestion,
+        file_ids: Vec<i64>,
+    ) -> Result<()> {
+        let spec = serde_json::to_string(&question.spec)
+            .map_err(|e| HWSystemError::validation(format!("题目定义格式错误: {e}")))?;
+        let model = QuestionActiveModel {
+            homework_id: Set(homework_id),
+            position: Set(position),
+            prompt: Set(question.prompt),
+            points: Set(points.unwrap_or(question.points)),
+            spec: Set(spec),
+            pool_id: Set(pool_id),
+            ..Default::default()
+        };
+        let inserted = model
+            .insert(conn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("创建作业题目失败: {e}")))?;
+
+        for file_id in file_ids {
+            HomeworkQuestionFileActiveModel {
+                question_id: Set(inserted.id),
+                file_id: Set(file_id),
+            }
+            .insert(conn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("创建附件关联失败: {e}")))?;
+
+            // 增加文件引用计数
+            self.increment_file_citation_txn(conn, file_id).await?;
+        }
+        Ok(())
+    }
+
+    /// 转换为业务模型（批量加载附件）
+    async fn attach_bank_question_files(&self, models: Vec<Model>) -> Result<Vec<BankQuestion>> {
+        let ids: Vec<i64> = models.iter().map(|m| m.id).collect();
+        let file_ids = self.load_bank_question_file_ids(&ids).await?;
+        let all_file_ids: Vec<i64> = file_ids.values().flatten().copied().collect();
+        let files = self.get_files_by_ids_impl(&all_file_ids).await?;
+
+        Ok(models
+            .into_iter()
+            .filter_map(|model| {
+                let attachments = file_ids
+                    .get(&model.id)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|id| files.get(id))
+                    .map(|file| FileInfo {
+                        download_token: file.download_token.clone(),
+                        original_name: file.original_name.clone(),
+                        file_size: file.file_size,
+                        file_type: file.file_type.clone(),
+                    })
+                    .collect();
+                model.into_bank_question(attachments)
+            })
+            .collect())
+    }
+
+    /// 批量获取题库题目附件 ID（bank_question_id -> file_id 列表）
+    async fn load_bank_question_file_ids(
+        &self,
+        question_ids: &[i64],
+    ) -> Result<HashMap<i64, Vec<i64>>> {
+        let mut map: HashMap<i64, Vec<i64>> = HashMap::new();
+        if question_ids.is_empty() {
+            return Ok(map);
+        }
+
+        let results = BankQuestionFiles::find()
+            .filter(BankQuestionFileColumn::BankQuestionId.is_in(question_ids.iter().copied()))
+            .all(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询题目附件失败: {e}")))?;
+
+        for model in results {
+            map.entry(model.bank_question_id)
+                .or_default()
+                .push(model.file_id);
+        }
+        Ok(map)
+    }
+
+    /// 设置题库题目附件（事务版本，通过 download_token，带所有权校验）
+    async fn set_bank_question_files_txn<C: ConnectionTrait>(
+        &self,
+        conn: &C,
+        question_id: i64,
+        tokens: Vec<String>,
+        user_id: i64,
+    ) -> Result<()> {
+        // 先删除旧的关联
+        BankQuestionFiles::delete_many()
+            .filter(BankQuestionFileColumn::BankQuestionId.eq(question_id))
+            .exec(conn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("删除旧附件关联失败: {e}")))?;
+
+        for token in tokens {
+            let file = self
+                .get_file_by_token_impl(&token)
+                .await?
+                .ok_or_else(|| HWSystemError::not_found(format!("文件不存在: {token}")))?;
+
+            // 校验文件所有权
+            if file.user_id != Some(user_id) {
+                return Err(HWSystemError::authorization(format!(
+                    "无权使用此文件: {token}"
+                )));
+            }
+
+            BankQuestionFileActiveModel {
+                bank_question_id: Set(question_id),
+                file_id: Set(file.id),
+            }
+            .insert(conn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("创建附件关联失败: {e}")))?;
+
+            // 增加文件引用计数
+            self.increment_file_citation_txn(conn, file.id).await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,243 @@
+//! 评分细则存储操作
+
+use std::collections::HashMap;
+
+use super::SeaOrmStorage;
+use crate::entity::homeworks::{Column as HomeworkColumn, Entity as Homeworks};
+use crate::entity::rubric_criteria::{
+    ActiveModel as CriterionActiveModel, Column as CriterionColumn, Entity as RubricCriteria,
+};
+use crate::entity::rubrics::{ActiveModel, Column, Entity as Rubrics, Model};
+use crate::errors::{HWSystemError, Result};
+use crate::models::rubrics::{
+    entities::{Rubric, RubricCriterion},
+    requests::{CreateRubricRequest, RubricCriterionInput, UpdateRubricRequest},
+};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseTransaction, EntityTrait, QueryFilter, QueryOrder, Set,
+    TransactionTrait, sea_query::Expr,
+};
+
+impl SeaOrmStorage {
+    /// 创建评分细则（使用事务保护）
+    pub async fn create_rubric_impl(
+        &self,
+        created_by: i64,
+        req: CreateRubricRequest,
+    ) -> Result<Rubric> {
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("开启事务失败: {e}")))?;
+
+        let now = chrono::Utc::now().timestamp();
+
+        let model = ActiveModel {
+            title: Set(req.title),
+            description: Set(req.description),
+            created_by: Set(created_by),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        let result = model
+            .insert(&txn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("创建评分细则失败: {e}")))?;
+
+        let criteria = self
+            .insert_rubric_criteria_txn(&txn, result.id, req.criteria)
+            .await?;
+
+        txn.commit()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("提交事务失败: {e}")))?;
+
+        Ok(result.into_rubric(criteria))
+    }
+
+    /// 通过 ID 获取评分细则（包含评分项）
+    pub async fn get_rubric_by_id_impl(&self, rubric_id: i64) -> Result<Option<Rubric>> {
+        let Some(model) = Rubrics::find_by_id(rubric_id)
+            .one(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询评分细则失败: {e}")))?
+        else {
+            return Ok(None);
+        };
+
+        let mut criteria = self.load_rubric_criteria(&[rubric_id]).await?;
+        let criteria = criteria.remove(&rubric_id).unwrap_or_default();
+
+        Ok(Some(model.into_rubric(criteria)))
+    }
+
+    /// 列出评分细则（可按创建者过滤，按更新时间倒序）
+    pub async fn list_rubrics_impl(&self, created_by: Option<i64>) -> Result<Vec<Rubric>> {
+        let mut select = Rubrics::find();
+        if let Some(created_by) = created_by {
+            select = select.filter(Column::CreatedBy.eq(created_by));
+        }
+
+        let models = select
+            .order_by_desc(Column::UpdatedAt)
+            .order_by_desc(Column::Id)
+            .all(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询评分细则列表失败: {e}")))?;
+
+        let rubric_ids: Vec<i64> = models.iter().map(|m| m.id).collect();
+        let mut criteria = self.load_rubric_criteria(&rubric_ids).await?;
+
+        Ok(models
+            .into_iter()
+            .map(|m| {
+                let items = criteria.remove(&m.id).unwrap_or_default();
+                m.into_rubric(items)
+            })
+            .collect())
+    }
+
+    /// 更新评分细则（传入评分项时整体替换）
+    pub async fn update_rubric_impl(
+        &self,
+        rubric_id: i64,
+        update: UpdateRubricRequest,
+    ) -> Result<Option<Rubric>> {
+        let Some(existing) = Rubrics::find_by_id(rubric_id)
+            .one(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询评分细则失败: {e}")))?
+        else {
+            return Ok(None);
+        };
+
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("开启事务失败: {e}")))?;
+
+        let mut model: ActiveModel = existing.into();
+        if let Some(title) = update.title {
+            model.title = Set(title);
+        }
+        if let Some(description) = update.description {
+            model.description = Set(Some(description));
+        }
+        model.updated_at = Set(chrono::Utc::now().timestamp());
+
+        let updated: Model = model
+            .update(&txn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("更新评分细则失败: {e}")))?;
+
+        if let Some(criteria) = update.criteria {
+            RubricCriteria::delete_many()
+                .filter(CriterionColumn::RubricId.eq(rubric_id))
+                .exec(&txn)
+                .await
+                .map_err(|e| HWSystemError::database_operation(format!("删除评分项失败: {e}")))?;
+            self.insert_rubric_criteria_txn(&txn, rubric_id, criteria)
+                .await?;
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("提交事务失败: {e}")))?;
+
+        let mut criteria = self.load_rubric_criteria(&[rubric_id]).await?;
+        let criteria = criteria.remove(&rubric_id).unwrap_or_default();
+
+        Ok(Some(updated.into_rubric(criteria)))
+    }
+
+    /// 删除评分细则（同时删除评分项并解除作业关联，已有评分明细保留）
+    pub async fn delete_rubric_impl(&self, rubric_id: i64) -> Result<bool> {
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("开启事务失败: {e}")))?;
+
+        Homeworks::update_many()
+            .col_expr(HomeworkColumn::RubricId, Expr::value(Option::<i64>::None))
+            .filter(HomeworkColumn::RubricId.eq(rubric_id))
+            .exec(&txn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("解除作业评分细则失败: {e}")))?;
+
+        RubricCriteria::delete_many()
+            .filter(CriterionColumn::RubricId.eq(rubric_id))
+            .exec(&txn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("删除评分项失败: {e}")))?;
+
+        let result = Rubrics::delete_by_id(rubric_id)
+            .exec(&txn)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("删除评分细则失败: {e}")))?;
+
+        txn.commit()
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("提交事务失败: {e}")))?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    /// 按顺序写入评分项
+    async fn insert_rubric_criteria_txn(
+        &self,
+        txn: &DatabaseTransaction,
+        rubric_id: i64,
+        criteria: Vec<RubricCriterionInput>,
+    ) -> Result<Vec<RubricCriterion>> {
+        let mut result = Vec::with_capacity(criteria.len());
+        for (position, criterion) in criteria.into_iter().enumerate() {
+            let levels = serde_json::to_string(&criterion.levels)
+                .map_err(|e| HWSystemError::validation(format!("评分档位格式错误: {e}")))?;
+            let model = CriterionActiveModel {
+                rubric_id: Set(rubric_id),
+                position: Set(position as i32),
+                title: Set(criterion.title),
+                description: Set(criterion.description),
+                levels: Set(levels),
+                ..Default::default()
+            };
+            let inserted = model
+                .insert(txn)
+                .await
+                .map_err(|e| HWSystemError::database_operation(format!("创建评分项失败: {e}")))?;
+            result.push(inserted.into_rubric_criterion());
+        }
+        Ok(result)
+    }
+
+    /// 批量加载评分项（rubric_id -> 按顺序排列的评分项）
+    async fn load_rubric_criteria(
+        &self,
+        rubric_ids: &[i64],
+    ) -> Result<HashMap<i64, Vec<RubricCriterion>>> {
+        let mut map: HashMap<i64, Vec<RubricCriterion>> = HashMap::new();
+        if rubric_ids.is_empty() {
+            return Ok(map);
+        }
+
+        let models = RubricCriteria::find()
+            .filter(CriterionColumn::RubricId.is_in(rubric_ids.iter().copied()))
+            .order_by_asc(CriterionColumn::RubricId)
+            .order_by_asc(CriterionColumn::Position)
+            .all(&self.db)
+            .await
+            .map_err(|e| HWSystemError::database_operation(format!("查询评分项失败: {e}")))?;
+
+        for model in models {
+            map.entry(model.rubric_id)
+                .or_default()
+                .push(model.into_rubric_criterion());
+        }
+        Ok(map)
+    }
+}
@@ -15,6 +15,7 @@ use crate::errors::{HWSystemError, Result};
 use crate::models::{
     PaginationInfo,
     files::responses::FileInfo,
+    homework_questions::entities::SubmissionAnswer,
     submissions::{
         entities::{Submission, SubmissionStatus},
         requests::{CreateSubmissionRequest, SubmissionListQuery},
@@ -36,6 +37,7 @@ impl SeaOrmStorage {
         &self,
         creator_id: i64,
         req: CreateSubmissionRequest,
+        answers: Vec<SubmissionAnswer>,
     ) -> Result<Submission> {
         let txn = self
             .db
@@ -60,10 +62,14 @@ impl SeaOrmStorage {
 
         let version = max_version + 1;
 
-        // 检查是否迟交
+        // 检查是否迟交（按学生的有效截止时间：个人延期 > 分组延期 > 作业截止时间）
         let homework = self.get_homework_by_id_impl(req.homework_id).await?;
         let is_late = if let Some(hw) = homework {
-            if let Some(deadline) = hw.deadline {
+            let extended = self
+                .get_user_extended_deadlines_impl(creator_id, &[hw.id])
+                .await?
+                .remove(&hw.id);
+            if let Some(deadline) = extended.or(hw.deadline) {
                 chrono::Utc::now() > deadline
             } else {
                 false
@@ -100,6 +106,12 @@ impl SeaOrmStorage {
                 .await?;
         }
 
+        // 保存题目作答
+        if !answers.is_empty() {
+            self.insert_submission_answers_txn(&txn, result.id, answers)
+                .await?;
+        }
+
         txn.commit()
             .await
             .map_err(|e| HWSystemError::database_operation(format!("提交事务失败: {e}")))?;
@@ -210,7 +222,10 @@ impl SeaOrmStorage {
                 let grade = grade_map.get(&s.id).map(|g| SubmissionGradeInfo {
                     id: g.id,
                     score: g.score,
+                    effective_score: g.effective_score(),
                     comment: g.comment.clone(),
+                    rubric_scores: g.rubric_score_list(),
+                    pending_review: g.pending_review,
                     graded_at: chrono::DateTime::from_timestamp(g.graded_at, 0)
                         .map(|dt| dt.to_rfc3339())
                         .unwrap_or_default(),
@@ -468,10 +483,17 @@ impl SeaOrmStorage {
         size: i64,
         include_grades: bool,
         graded: Option<bool>,
+        group_id: Option<i64>,
     ) -> Result<SubmissionSummaryResponse> {
         let page = page.max(1) as u64;
         let size = size.clamp(1, 100) as u64;
 
+        // 按分组筛选时，仅统计分组成员的提交
+        let group_member_ids = match group_id {
+            Some(group_id) => Some(self.list_class_group_student_ids_impl(&[group_id]).await?),
+            None => None,
+        };
+
         // === 步骤 1: 数据库聚合查询 - 获取每个 creator 的统计信息 ===
         #[derive(FromQueryResult)]
         struct CreatorStats {
@@ -480,8 +502,12 @@ impl SeaOrmStorage {
             total_versions: i64,
         }
 
-        let all_stats: Vec<CreatorStats> = Submissions::find()
-            .filter(Column::HomeworkId.eq(homework_id))
+        let mut stats_query = Submissions::find().filter(Column::HomeworkId.eq(homework_id));
+        if let Some(member_ids) = group_member_ids {
+            stats_query = stats_query.filter(Column::CreatorId.is_in(member_ids));
+        }
+
+        let all_stats: Vec<CreatorStats> = stats_query
             .select_only()
             .column(Column::CreatorId)
             .column_as(Column::Id.max(), "latest_submission_id")
@@ -511,8 +537,10 @@ impl SeaOrmStorage {
             all_stats.iter().map(|s| s.latest_submission_id).collect();
 
         // === 步骤 2: 查询评分状态并根据 graded 参数筛选 ===
+        // 仍有主观题待批阅的评分视为待批改
         let graded_submission_ids: HashSet<i64> = Grades::find()
             .filter(GradeColumn::SubmissionId.is_in(all_submission_ids.clone()))
+            .filter(GradeColumn::PendingReview.eq(false))
             .select_only()
             .column(GradeColumn::SubmissionId)
             .into_tuple::<i64>()
@@ -598,7 +626,10 @@ impl SeaOrmStorage {
                     grade_map.get(&sub.id).map(|g| SubmissionGradeInfo {
                         id: g.id,
                         score: g.score,
+                        effective_score: g.effective_score(),
                         comment: g.comment.clone(),
+                        rubric_scores: g.rubric_score_list(),
+                        pending_review: g.pending_review,
                         graded_at: chrono::DateTime::from_timestamp(g.graded_at, 0)
                             .map(|dt| dt.to_rfc3339())
                             .unwrap_or_default(),
@@ -724,7 +755,10 @@ impl SeaOrmStorage {
                     grade_map.get(&s.id).map(|g| SubmissionGradeInfo {
                         id: g.id,
                         score: g.score,
+                        effective_score: g.effective_score(),
                         comment: g.comment.clone(),
+                        rubric_scores: g.rubric_score_list(),
+                        pending_review: g.pending_review,
                         graded_at: chrono::DateTime::from_timestamp(g.graded_at, 0)
                             .map(|dt| dt.to_rfc3339())
                             .unwrap_or_default(),
@@ -824,13 +858,23 @@ impl SeaOrmStorage {
             .map(|g| SubmissionGradeInfo {
                 id: g.id,
                 score: g.score,
-                comment: g.comment,
+                effective_score: g.effective_score(),
+                comment: g.comment.clone(),
+                rubric_scores: g.rubric_score_list(),
+                pending_review: g.pending_review,
                 graded_at: chrono::DateTime::from_timestamp(g.graded_at, 0)
                     .map(|dt| dt.to_rfc3339())
                     .unwrap_or_default(),
             });
 
-        // 5. 查询作业信息
+        // 5. 查询题目作答
+        let answers = self
+            .list_submission_answers_impl(&[submission_id])
+            .await?
+            .remove(&submission_id)
+            .unwrap_or_default();
+
+        // 6. 查询作业信息
         let homework = Homeworks::find_by_id(submission.homework_id)
             .one(&self.db)
             .await
@@ -844,7 +888,7 @@ impl SeaOrmStorage {
                 }),
             });
 
-        // 6. 组装响应
+        // 7. 组装响应
         Ok(Some(SubmissionResponse {
             id: submission.id,
             homework_id: submission.homework_id,
@@ -859,6 +903,7 @@ impl SeaOrmStorage {
             version: submission.version,
             is_late: submission.is_late,
             homework,
+            answers,
         }))
     }
 
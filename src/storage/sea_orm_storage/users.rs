@@ -1,5 +1,6 @@
 use super::SeaOrmStorage;
-use crate::entity::users::{ActiveModel, Column, Entity as Users};
+use crate::entity::user_profile_values::{Column as ValueColumn, Entity as UserProfileValues};
+use crate::entity::users::{ActiveModel, Column, Entity as Users, Model as UserModel};
 use crate::errors::{HWSystemError, Result};
 use crate::models::{
     PaginationInfo,
@@ -12,7 +13,7 @@ use crate::models::{
 use crate::utils::escape_like_pattern;
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, Condition, EntityTrait, FromQueryResult, PaginatorTrait,
-    QueryFilter, QueryOrder, QuerySelect, Set,
+    QueryFilter, QueryOrder, QuerySelect, Set, sea_query::Query,
 };
 use std::collections::HashMap;
 
@@ -49,7 +50,7 @@ impl SeaOrmStorage {
             .await
             .map_err(|e| HWSystemError::database_operation(format!("查询用户失败: {e}")))?;
 
-        Ok(result.map(|m| m.into_user()))
+        self.user_with_profile(result).await
     }
 
     /// 通过用户名获取用户
@@ -60,7 +61,7 @@ impl SeaOrmStorage {
             .await
             .map_err(|e| HWSystemError::database_operation(format!("查询用户失败: {e}")))?;
 
-        Ok(result.map(|m| m.into_user()))
+        self.user_with_profile(result).await
     }
 
     /// 通过邮箱获取用户
@@ -71,7 +72,7 @@ impl SeaOrmStorage {
             .await
             .map_err(|e| HWSystemError::database_operation(format!("查询用户失败: {e}")))?;
 
-        Ok(result.map(|m| m.into_user()))
+        self.user_with_profile(result).await
     }
 
     /// 通过用户名或邮箱获取用户
@@ -89,7 +90,7 @@ impl SeaOrmStorage {
             .await
             .map_err(|e| HWSystemError::database_operation(format!("查询用户失败: {e}")))?;
 
-        Ok(result.map(|m| m.into_user()))
+        self.user_with_profile(result).await
     }
 
     /// 分页列出用户
@@ -105,13 +106,7 @@ impl SeaOrmStorage {
         if let Some(ref search) = query.search
             && !search.trim().is_empty()
         {
-            let escaped = escape_like_pattern(search.trim());
-            select = select.filter(
-                Condition::any()
-                    .add(Column::Username.contains(&escaped))
-                    .add(Column::Email.contains(&escaped))
-                    .add(Column::DisplayName.contains(&escaped)),
-            );
+            select = select.filter(user_search_condition(search.trim()));
         }
 
         // 角色筛选
@@ -144,8 +139,11 @@ impl SeaOrmStorage {
             .await
             .map_err(|e| HWSystemError::database_operation(format!("查询用户列表失败: {e}")))?;
 
+        let mut items: Vec<User> = users.into_iter().map(|m| m.into_user()).collect();
+        self.attach_user_profiles(&mut items).await?;
+
         Ok(UserListResponse {
-            items: users.into_iter().map(|m| m.into_user()).collect(),
+            items,
             pagination: PaginationInfo {
                 page: page as i64,
                 page_size: page_size as i64,
@@ -208,7 +206,7 @@ impl SeaOrmStorage {
         }
 
         match model.update(&self.db).await {
-            Ok(updated) => Ok(Some(updated.into_user())),
+            Ok(updated) => self.user_with_profile(Some(updated)).await,
             Err(e) => {
                 // SeaORM 的 RecordNotUpdated 错误表示记录不存在
                 if e.to_string().contains("RecordNotUpdated") {
@@ -274,15 +272,16 @@ impl SeaOrmStorage {
 
     /// 列出所有用户（用于导出，限制数量）
     pub async fn list_all_users_for_export_impl(&self, limit: u64) -> Result<Vec<User>> {
-        use crate::entity::users::Model;
-        let users: Vec<Model> = Users::find()
+        let users: Vec<UserModel> = Users::find()
             .order_by_asc(Column::Id)
             .limit(limit)
             .all(&self.db)
             .await
             .map_err(|e| HWSystemError::database_operation(format!("查询用户失败: {e}")))?;
 
-        Ok(users.into_iter().map(|m| m.into_user()).collect())
+        let mut users: Vec<User> = users.into_iter().map(|m| m.into_user()).collect();
+        self.attach_user_profiles(&mut users).await?;
+        Ok(users)
     }
 
     /// 列出用户（用于导出，支持筛选）
@@ -299,13 +298,7 @@ impl SeaOrmStorage {
         if let Some(search) = search
             && !search.trim().is_empty()
         {
-            let escaped = escape_like_pattern(search.trim());
-            select = select.filter(
-                Condition::any()
-                    .add(Column::Username.contains(&escaped))
-                    .add(Column::Email.contains(&escaped))
-                    .add(Column::DisplayName.contains(&escaped)),
-            );
+            select = select.filter(user_search_condition(search.trim()));
         }
 
         // 角色筛选
@@ -325,7 +318,9 @@ impl SeaOrmStorage {
             .await
             .map_err(|e| HWSystemError::database_operation(format!("查询用户失败: {e}")))?;
 
-        Ok(users.into_iter().map(|m| m.into_user()).collect())
+        let mut users: Vec<User> = users.into_iter().map(|m| m.into_user()).collect();
+        self.attach_user_profiles(&mut users).await?;
+        Ok(users)
     }
 
     /// 获取用户综合统计
@@ -445,6 +440,35 @@ impl SeaOrmStorage {
             .await
             .map_err(|e| HWSystemError::database_operation(format!("批量查询用户失败: {e}")))?;
 
-        Ok(users.into_iter().map(|u| (u.id, u.into_user())).collect())
+        let mut users: Vec<User> = users.into_iter().map(|m| m.into_user()).collect();
+        self.attach_user_profiles(&mut users).await?;
+        Ok(users.into_iter().map(|u| (u.id, u)).collect())
+    }
+
+    /// 转换为业务模型并填充资料字段值
+    async fn user_with_profile(&self, model: Option<UserModel>) -> Result<Option<User>> {
+        let Some(model) = model else {
+            return Ok(None);
+        };
+        let mut users = [model.into_user()];
+        self.attach_user_profiles(&mut users).await?;
+        let [user] = users;
+        Ok(Some(user))
     }
 }
+
+/// 用户搜索条件：匹配用户名、邮箱、显示名称或任一资料字段值
+fn user_search_condition(search: &str) -> Condition {
+    let escaped = escape_like_pattern(search);
+    let profile_matches = Query::select()
+        .column(ValueColumn::UserId)
+        .from(UserProfileValues)
+        .and_where(ValueColumn::Value.contains(&escaped))
+        .to_owned();
+
+    Condition::any()
+        .add(Column::Username.contains(&escaped))
+        .add(Column::Email.contains(&escaped))
+        .add(Column::DisplayName.contains(&escaped))
+        .add(Column::Id.in_subquery(profile_matches))
+}